@@ -0,0 +1,210 @@
+use chrono::NaiveDate;
+
+use crate::{
+    error::LibraError,
+    indexes::{InflationIndex, InflationObservation},
+    time::DayCountConvention,
+};
+
+use super::Redemption;
+
+/// How an inflation-linked flow is scaled: the index it is linked to, how that index is
+/// observed, and the reference value that flows are scaled from.
+#[derive(Clone, Debug)]
+pub struct CpiIndexation {
+    index: InflationIndex,
+    observation: InflationObservation,
+    base_value: f64,
+}
+
+impl CpiIndexation {
+    pub fn new(index: InflationIndex, observation: InflationObservation, base_value: f64) -> Self {
+        CpiIndexation {
+            index,
+            observation,
+            base_value,
+        }
+    }
+
+    pub fn index(&self) -> &InflationIndex {
+        &self.index
+    }
+
+    pub fn observation(&self) -> InflationObservation {
+        self.observation
+    }
+
+    pub fn base_value(&self) -> f64 {
+        self.base_value
+    }
+
+    /// The ratio of the reference value of the index at `date` to the base value.
+    pub fn index_ratio(&self, date: NaiveDate) -> Result<f64, LibraError> {
+        Ok(self.index.reference_value(date, self.observation)? / self.base_value)
+    }
+}
+
+/// A coupon paying a fixed real rate on a nominal scaled by the growth of an inflation index
+/// from a base value.
+#[derive(Clone, Debug)]
+pub struct CpiCoupon {
+    nominal: f64,
+    rate: f64,
+    day_count: DayCountConvention,
+    accrual_start: NaiveDate,
+    accrual_end: NaiveDate,
+    payment_date: NaiveDate,
+    indexation: CpiIndexation,
+}
+
+impl CpiCoupon {
+    /// Creates a new inflation-indexed coupon.
+    ///
+    /// # Arguments
+    ///
+    /// * `nominal` - The unindexed nominal on which interest accrues.
+    /// * `rate` - The real coupon rate.
+    /// * `day_count` - The day count convention used for accrual.
+    /// * `period` - The `(start, end)` dates of the accrual period.
+    /// * `payment_date` - The date the coupon is paid.
+    /// * `indexation` - How the nominal is scaled by the inflation index.
+    pub fn new(
+        nominal: f64,
+        rate: f64,
+        day_count: DayCountConvention,
+        period: (NaiveDate, NaiveDate),
+        payment_date: NaiveDate,
+        indexation: CpiIndexation,
+    ) -> Self {
+        CpiCoupon {
+            nominal,
+            rate,
+            day_count,
+            accrual_start: period.0,
+            accrual_end: period.1,
+            payment_date,
+            indexation,
+        }
+    }
+
+    pub fn nominal(&self) -> f64 {
+        self.nominal
+    }
+
+    /// The real coupon rate.
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    pub fn day_count(&self) -> DayCountConvention {
+        self.day_count
+    }
+
+    pub fn accrual_start(&self) -> NaiveDate {
+        self.accrual_start
+    }
+
+    pub fn accrual_end(&self) -> NaiveDate {
+        self.accrual_end
+    }
+
+    pub fn payment_date(&self) -> NaiveDate {
+        self.payment_date
+    }
+
+    pub fn indexation(&self) -> &CpiIndexation {
+        &self.indexation
+    }
+
+    /// The index ratio applied to this coupon, observed at the end of the accrual period.
+    pub fn index_ratio(&self) -> Result<f64, LibraError> {
+        self.indexation.index_ratio(self.accrual_end)
+    }
+
+    /// The accrual period of this coupon as a year fraction.
+    pub fn accrual_period(&self) -> Result<f64, LibraError> {
+        Ok(self
+            .day_count
+            .year_frac(&self.accrual_start, &self.accrual_end)?)
+    }
+
+    /// The amount paid by this coupon before indexation.
+    pub fn real_amount(&self) -> Result<f64, LibraError> {
+        Ok(self.nominal * self.rate * self.accrual_period()?)
+    }
+
+    /// The amount paid by this coupon.
+    pub fn amount(&self) -> Result<f64, LibraError> {
+        Ok(self.real_amount()? * self.index_ratio()?)
+    }
+
+    /// The interest accrued by this coupon up to `date`, indexed to the reference value at
+    /// `date`.
+    ///
+    /// This is zero up to the start of the accrual period and from the payment date onwards.
+    pub fn accrued_amount(&self, date: &NaiveDate) -> Result<f64, LibraError> {
+        if *date <= self.accrual_start || *date >= self.payment_date {
+            return Ok(0.0);
+        }
+
+        let end = (*date).min(self.accrual_end);
+        let accrual = self.day_count.year_frac(&self.accrual_start, &end)?;
+
+        Ok(self.nominal * self.rate * accrual * self.indexation.index_ratio(*date)?)
+    }
+}
+
+/// A repayment of principal scaled by the growth of an inflation index from a base value,
+/// optionally floored so that no less than the unindexed amount is repaid.
+#[derive(Clone, Debug)]
+pub struct CpiRedemption {
+    redemption: Redemption,
+    indexation: CpiIndexation,
+    floored: bool,
+}
+
+impl CpiRedemption {
+    /// Creates a new inflation-indexed redemption.
+    ///
+    /// # Arguments
+    ///
+    /// * `redemption` - The unindexed redemption.
+    /// * `indexation` - How the redemption is scaled by the inflation index.
+    /// * `floored` - Whether the index ratio is floored at one, as for TIPS.
+    pub fn new(redemption: Redemption, indexation: CpiIndexation, floored: bool) -> Self {
+        CpiRedemption {
+            redemption,
+            indexation,
+            floored,
+        }
+    }
+
+    /// The unindexed redemption.
+    pub fn redemption(&self) -> &Redemption {
+        &self.redemption
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        self.redemption.date()
+    }
+
+    pub fn indexation(&self) -> &CpiIndexation {
+        &self.indexation
+    }
+
+    pub fn is_floored(&self) -> bool {
+        self.floored
+    }
+
+    /// The index ratio applied to this redemption, including any floor.
+    pub fn index_ratio(&self) -> Result<f64, LibraError> {
+        let ratio = self.indexation.index_ratio(self.date())?;
+
+        Ok(if self.floored { ratio.max(1.0) } else { ratio })
+    }
+
+    /// The amount paid.
+    pub fn amount(&self) -> Result<f64, LibraError> {
+        Ok(self.redemption.amount() * self.index_ratio()?)
+    }
+}
@@ -0,0 +1,32 @@
+use chrono::NaiveDate;
+
+use super::CashFlow;
+
+/// A cash dividend paid per share of an equity.
+#[derive(Clone, Copy, Debug)]
+pub struct Dividend {
+    amount: f64,
+    date: NaiveDate,
+}
+
+impl Dividend {
+    /// Creates a dividend of `amount` per share going ex on `date`.
+    pub fn new(amount: f64, date: NaiveDate) -> Self {
+        Dividend { amount, date }
+    }
+
+    /// The amount paid per share.
+    pub fn amount(&self) -> f64 {
+        self.amount
+    }
+
+    /// The ex-dividend date, from which the share trades without the dividend.
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// This dividend as a simple cash flow.
+    pub fn cash_flow(&self) -> CashFlow {
+        CashFlow::new(self.amount, self.date)
+    }
+}
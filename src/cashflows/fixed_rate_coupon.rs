@@ -0,0 +1,104 @@
+use chrono::NaiveDate;
+
+use crate::error;
+
+use super::{CashFlow, InterestRate};
+
+/// A coupon paying a fixed rate of interest on a nominal over an accrual period.
+#[derive(Clone, Copy, Debug)]
+pub struct FixedRateCoupon {
+    nominal: f64,
+    rate: InterestRate,
+    accrual_start: NaiveDate,
+    accrual_end: NaiveDate,
+    payment_date: NaiveDate,
+    amount: f64,
+}
+
+impl FixedRateCoupon {
+    /// Creates a new fixed rate coupon.
+    ///
+    /// # Arguments
+    ///
+    /// * `nominal` - The nominal on which interest accrues.
+    /// * `rate` - The coupon rate, including the day count convention used for accrual.
+    /// * `accrual_start` - The date interest starts accruing.
+    /// * `accrual_end` - The date interest stops accruing.
+    /// * `payment_date` - The date the coupon is paid.
+    ///
+    /// # Returns
+    ///
+    /// The coupon, or an error if the accrual period ends before it starts.
+    pub fn new(
+        nominal: f64,
+        rate: InterestRate,
+        accrual_start: NaiveDate,
+        accrual_end: NaiveDate,
+        payment_date: NaiveDate,
+    ) -> Result<Self, error::InvalidDate> {
+        let amount = nominal * (rate.compound_factor_between(&accrual_start, &accrual_end)? - 1.0);
+
+        Ok(FixedRateCoupon {
+            nominal,
+            rate,
+            accrual_start,
+            accrual_end,
+            payment_date,
+            amount,
+        })
+    }
+
+    pub fn nominal(&self) -> f64 {
+        self.nominal
+    }
+
+    pub fn rate(&self) -> InterestRate {
+        self.rate
+    }
+
+    pub fn accrual_start(&self) -> NaiveDate {
+        self.accrual_start
+    }
+
+    pub fn accrual_end(&self) -> NaiveDate {
+        self.accrual_end
+    }
+
+    pub fn payment_date(&self) -> NaiveDate {
+        self.payment_date
+    }
+
+    /// The amount paid by this coupon.
+    pub fn amount(&self) -> f64 {
+        self.amount
+    }
+
+    /// The accrual period of this coupon as a year fraction.
+    pub fn accrual_period(&self) -> Result<f64, error::InvalidDate> {
+        self.rate
+            .day_count()
+            .year_frac(&self.accrual_start, &self.accrual_end)
+    }
+
+    /// The interest accrued by this coupon up to `date`.
+    ///
+    /// This is zero up to the start of the accrual period and from the payment date onwards.
+    pub fn accrued_amount(&self, date: &NaiveDate) -> Result<f64, error::InvalidDate> {
+        if *date <= self.accrual_start || *date >= self.payment_date {
+            return Ok(0.0);
+        }
+
+        let end = (*date).min(self.accrual_end);
+
+        Ok(self.nominal
+            * (self
+                .rate
+                .compound_factor_between(&self.accrual_start, &end)?
+                - 1.0))
+    }
+
+    /// This coupon as a simple cash flow on its payment date.
+    pub fn cash_flow(&self) -> CashFlow {
+        CashFlow::new(self.amount, self.payment_date)
+    }
+}
@@ -0,0 +1,123 @@
+use chrono::NaiveDate;
+
+use crate::{error::LibraError, indexes::IborIndex, time::DayCountConvention};
+
+/// A coupon paying `gearing * fixing + spread` on a nominal, where the fixing is the index rate
+/// for the accrual period.
+#[derive(Clone, Debug)]
+pub struct FloatingRateCoupon {
+    nominal: f64,
+    index: IborIndex,
+    gearing: f64,
+    spread: f64,
+    day_count: DayCountConvention,
+    accrual_start: NaiveDate,
+    accrual_end: NaiveDate,
+    payment_date: NaiveDate,
+}
+
+impl FloatingRateCoupon {
+    /// Creates a new floating rate coupon.
+    ///
+    /// # Arguments
+    ///
+    /// * `nominal` - The nominal on which interest accrues.
+    /// * `index` - The index which determines the coupon rate.
+    /// * `gearing` - The multiplier applied to the index fixing.
+    /// * `spread` - The spread added to the geared index fixing.
+    /// * `day_count` - The day count convention used for accrual.
+    /// * `period` - The `(start, end)` dates of the accrual period.
+    /// * `payment_date` - The date the coupon is paid.
+    pub fn new(
+        nominal: f64,
+        index: IborIndex,
+        gearing: f64,
+        spread: f64,
+        day_count: DayCountConvention,
+        period: (NaiveDate, NaiveDate),
+        payment_date: NaiveDate,
+    ) -> Self {
+        FloatingRateCoupon {
+            nominal,
+            index,
+            gearing,
+            spread,
+            day_count,
+            accrual_start: period.0,
+            accrual_end: period.1,
+            payment_date,
+        }
+    }
+
+    pub fn nominal(&self) -> f64 {
+        self.nominal
+    }
+
+    pub fn index(&self) -> &IborIndex {
+        &self.index
+    }
+
+    pub fn gearing(&self) -> f64 {
+        self.gearing
+    }
+
+    pub fn spread(&self) -> f64 {
+        self.spread
+    }
+
+    pub fn day_count(&self) -> DayCountConvention {
+        self.day_count
+    }
+
+    pub fn accrual_start(&self) -> NaiveDate {
+        self.accrual_start
+    }
+
+    pub fn accrual_end(&self) -> NaiveDate {
+        self.accrual_end
+    }
+
+    pub fn payment_date(&self) -> NaiveDate {
+        self.payment_date
+    }
+
+    /// The date on which the index rate for this coupon is fixed.
+    pub fn fixing_date(&self) -> NaiveDate {
+        self.index.fixing_date(self.accrual_start)
+    }
+
+    /// The index rate for the accrual period, either fixed or forecast.
+    pub fn index_fixing(&self) -> Result<f64, LibraError> {
+        self.index.rate(self.accrual_start, self.accrual_end)
+    }
+
+    /// The annualized rate paid by this coupon.
+    pub fn rate(&self) -> Result<f64, LibraError> {
+        Ok(self.gearing * self.index_fixing()? + self.spread)
+    }
+
+    /// The accrual period of this coupon as a year fraction.
+    pub fn accrual_period(&self) -> Result<f64, LibraError> {
+        Ok(self
+            .day_count
+            .year_frac(&self.accrual_start, &self.accrual_end)?)
+    }
+
+    /// The amount paid by this coupon.
+    pub fn amount(&self) -> Result<f64, LibraError> {
+        Ok(self.nominal * self.rate()? * self.accrual_period()?)
+    }
+
+    /// The interest accrued by this coupon up to `date`.
+    ///
+    /// This is zero up to the start of the accrual period and from the payment date onwards.
+    pub fn accrued_amount(&self, date: &NaiveDate) -> Result<f64, LibraError> {
+        if *date <= self.accrual_start || *date >= self.payment_date {
+            return Ok(0.0);
+        }
+
+        let end = (*date).min(self.accrual_end);
+
+        Ok(self.nominal * self.rate()? * self.day_count.year_frac(&self.accrual_start, &end)?)
+    }
+}
@@ -0,0 +1,268 @@
+use std::fmt;
+
+use chrono::NaiveDate;
+
+use crate::{
+    error,
+    time::{DayCountConvention, Frequency},
+};
+
+/// The way in which interest is compounded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compounding {
+    /// Simple interest: 1 + rt
+    Simple,
+    /// Compound interest: (1 + r/f)^(ft)
+    Compounded,
+    /// Continuously compounding interest: e^(rt)
+    Continuous,
+    /// Simple interest up to the first period, compounded thereafter.
+    SimpleThenCompounded,
+    /// Compounded interest up to the first period, simple thereafter.
+    CompoundedThenSimple,
+}
+
+impl fmt::Display for Compounding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Compounding::Simple => write!(f, "Simple"),
+            Compounding::Compounded => write!(f, "Compounded"),
+            Compounding::Continuous => write!(f, "Continuous"),
+            Compounding::SimpleThenCompounded => write!(f, "Simple then Compounded"),
+            Compounding::CompoundedThenSimple => write!(f, "Compounded then Simple"),
+        }
+    }
+}
+
+/// An interest rate together with the conventions needed to interpret it.
+///
+/// The frequency is only used by the compounded variants of `Compounding`.
+#[derive(Clone, Copy, Debug)]
+pub struct InterestRate {
+    rate: f64,
+    day_count: DayCountConvention,
+    compounding: Compounding,
+    frequency: Frequency,
+}
+
+impl InterestRate {
+    pub fn new(
+        rate: f64,
+        day_count: DayCountConvention,
+        compounding: Compounding,
+        frequency: Frequency,
+    ) -> Self {
+        InterestRate {
+            rate,
+            day_count,
+            compounding,
+            frequency,
+        }
+    }
+
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    pub fn day_count(&self) -> DayCountConvention {
+        self.day_count
+    }
+
+    pub fn compounding(&self) -> Compounding {
+        self.compounding
+    }
+
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    /// The factor by which an amount grows when invested at this rate for `time` years.
+    pub fn compound_factor(&self, time: f64) -> f64 {
+        let r = self.rate;
+        let f = self.frequency.periods_per_year() as f64;
+
+        let simple = || 1.0 + r * time;
+        let compounded = || (1.0 + r / f).powf(f * time);
+
+        match self.compounding {
+            Compounding::Simple => simple(),
+            Compounding::Compounded => compounded(),
+            Compounding::Continuous => (r * time).exp(),
+            Compounding::SimpleThenCompounded => {
+                if time <= 1.0 / f {
+                    simple()
+                } else {
+                    compounded()
+                }
+            }
+            Compounding::CompoundedThenSimple => {
+                if time <= 1.0 / f {
+                    compounded()
+                } else {
+                    simple()
+                }
+            }
+        }
+    }
+
+    /// The factor by which an amount grows when invested at this rate between `from` and `to`,
+    /// measuring time with this rate's day count convention.
+    pub fn compound_factor_between(
+        &self,
+        from: &NaiveDate,
+        to: &NaiveDate,
+    ) -> Result<f64, error::InvalidDate> {
+        Ok(self.compound_factor(self.day_count.year_frac(from, to)?))
+    }
+
+    /// The discount factor for `time` years at this rate.
+    pub fn discount_factor(&self, time: f64) -> f64 {
+        1.0 / self.compound_factor(time)
+    }
+
+    /// The discount factor between `from` and `to` at this rate.
+    pub fn discount_factor_between(
+        &self,
+        from: &NaiveDate,
+        to: &NaiveDate,
+    ) -> Result<f64, error::InvalidDate> {
+        Ok(1.0 / self.compound_factor_between(from, to)?)
+    }
+
+    /// The rate which produces `compound` as its compound factor over `time` years.
+    ///
+    /// # Arguments
+    ///
+    /// * `compound` - The compound factor to reproduce.
+    /// * `day_count` - The day count convention of the implied rate.
+    /// * `compounding` - The compounding of the implied rate.
+    /// * `frequency` - The compounding frequency of the implied rate.
+    /// * `time` - The time in years over which `compound` applies, which must be positive
+    ///   unless `compound` is one.
+    ///
+    /// # Returns
+    ///
+    /// The implied interest rate.
+    pub fn implied_rate(
+        compound: f64,
+        day_count: DayCountConvention,
+        compounding: Compounding,
+        frequency: Frequency,
+        time: f64,
+    ) -> InterestRate {
+        let f = frequency.periods_per_year() as f64;
+
+        let simple = || (compound - 1.0) / time;
+        let compounded = || (compound.powf(1.0 / (f * time)) - 1.0) * f;
+
+        let rate = if compound == 1.0 {
+            0.0
+        } else {
+            match compounding {
+                Compounding::Simple => simple(),
+                Compounding::Compounded => compounded(),
+                Compounding::Continuous => compound.ln() / time,
+                Compounding::SimpleThenCompounded => {
+                    if time <= 1.0 / f {
+                        simple()
+                    } else {
+                        compounded()
+                    }
+                }
+                Compounding::CompoundedThenSimple => {
+                    if time <= 1.0 / f {
+                        compounded()
+                    } else {
+                        simple()
+                    }
+                }
+            }
+        };
+
+        InterestRate::new(rate, day_count, compounding, frequency)
+    }
+
+    /// The rate which produces `compound` as its compound factor between `from` and `to`.
+    ///
+    /// Returns an error if `from` is after `to`, or if they are equal and `compound` is not one.
+    pub fn implied_rate_between(
+        compound: f64,
+        day_count: DayCountConvention,
+        compounding: Compounding,
+        frequency: Frequency,
+        from: &NaiveDate,
+        to: &NaiveDate,
+    ) -> Result<InterestRate, error::InvalidDate> {
+        let time = day_count.year_frac(from, to)?;
+
+        if time == 0.0 && compound != 1.0 {
+            return Err(error::InvalidDate);
+        }
+
+        Ok(InterestRate::implied_rate(
+            compound,
+            day_count,
+            compounding,
+            frequency,
+            time,
+        ))
+    }
+
+    /// The rate with the given compounding that is equivalent to this one over `time` years.
+    /// The day count convention is unchanged.
+    pub fn equivalent_rate(
+        &self,
+        compounding: Compounding,
+        frequency: Frequency,
+        time: f64,
+    ) -> InterestRate {
+        InterestRate::implied_rate(
+            self.compound_factor(time),
+            self.day_count,
+            compounding,
+            frequency,
+            time,
+        )
+    }
+
+    /// The rate with the given conventions that is equivalent to this one between `from` and `to`.
+    pub fn equivalent_rate_between(
+        &self,
+        day_count: DayCountConvention,
+        compounding: Compounding,
+        frequency: Frequency,
+        from: &NaiveDate,
+        to: &NaiveDate,
+    ) -> Result<InterestRate, error::InvalidDate> {
+        InterestRate::implied_rate_between(
+            self.compound_factor_between(from, to)?,
+            day_count,
+            compounding,
+            frequency,
+            from,
+            to,
+        )
+    }
+}
+
+impl fmt::Display for InterestRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.compounding {
+            Compounding::Simple | Compounding::Continuous => write!(
+                f,
+                "{:.6}% {} {}",
+                self.rate * 100.0,
+                self.day_count,
+                self.compounding
+            ),
+            _ => write!(
+                f,
+                "{:.6}% {} {} {}",
+                self.rate * 100.0,
+                self.day_count,
+                self.frequency,
+                self.compounding
+            ),
+        }
+    }
+}
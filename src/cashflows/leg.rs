@@ -0,0 +1,702 @@
+use chrono::NaiveDate;
+
+use crate::{
+    error::{self, LibraError},
+    indexes::{IborIndex, OvernightIndex},
+    pricing::YieldTermStructure,
+    time::{DayCountConvention, Frequency, Schedule},
+};
+
+use super::{
+    CashFlow, Compounding, CpiCoupon, CpiIndexation, CpiRedemption, FixedRateCoupon,
+    FloatingRateCoupon, InterestRate, OvernightIndexedCoupon, Redemption,
+};
+
+/// A single flow within a `Leg`.
+#[derive(Clone, Debug)]
+pub enum LegFlow {
+    /// A fixed interest payment.
+    FixedCoupon(FixedRateCoupon),
+    /// An interest payment determined by an index fixing.
+    FloatingCoupon(FloatingRateCoupon),
+    /// An interest payment determined by compounding an overnight index.
+    OvernightCoupon(OvernightIndexedCoupon),
+    /// A repayment of principal.
+    Redemption(Redemption),
+    /// A fixed real interest payment scaled by an inflation index.
+    CpiCoupon(CpiCoupon),
+    /// A repayment of principal scaled by an inflation index.
+    CpiRedemption(CpiRedemption),
+}
+
+impl LegFlow {
+    /// The date on which this flow is paid.
+    pub fn date(&self) -> NaiveDate {
+        match self {
+            LegFlow::FixedCoupon(coupon) => coupon.payment_date(),
+            LegFlow::FloatingCoupon(coupon) => coupon.payment_date(),
+            LegFlow::OvernightCoupon(coupon) => coupon.payment_date(),
+            LegFlow::Redemption(redemption) => redemption.date(),
+            LegFlow::CpiCoupon(coupon) => coupon.payment_date(),
+            LegFlow::CpiRedemption(redemption) => redemption.date(),
+        }
+    }
+
+    /// The amount paid by this flow.
+    pub fn amount(&self) -> Result<f64, LibraError> {
+        match self {
+            LegFlow::FixedCoupon(coupon) => Ok(coupon.amount()),
+            LegFlow::FloatingCoupon(coupon) => coupon.amount(),
+            LegFlow::OvernightCoupon(coupon) => coupon.amount(),
+            LegFlow::Redemption(redemption) => Ok(redemption.amount()),
+            LegFlow::CpiCoupon(coupon) => coupon.amount(),
+            LegFlow::CpiRedemption(redemption) => redemption.amount(),
+        }
+    }
+
+    /// The ratio by which an inflation-linked flow is scaled from its real amount, which is one
+    /// for flows that are not inflation-linked.
+    pub fn index_ratio(&self) -> Result<f64, LibraError> {
+        match self {
+            LegFlow::CpiCoupon(coupon) => coupon.index_ratio(),
+            LegFlow::CpiRedemption(redemption) => redemption.index_ratio(),
+            _ => Ok(1.0),
+        }
+    }
+
+    /// Returns `true` if this flow is an interest payment.
+    pub fn is_coupon(&self) -> bool {
+        !matches!(self, LegFlow::Redemption(_) | LegFlow::CpiRedemption(_))
+    }
+
+    /// The interest accrued by this flow at `date`, which is zero for redemptions.
+    pub fn accrued_amount(&self, date: &NaiveDate) -> Result<f64, LibraError> {
+        match self {
+            LegFlow::FixedCoupon(coupon) => Ok(coupon.accrued_amount(date)?),
+            LegFlow::FloatingCoupon(coupon) => coupon.accrued_amount(date),
+            LegFlow::OvernightCoupon(coupon) => coupon.accrued_amount(date),
+            LegFlow::CpiCoupon(coupon) => coupon.accrued_amount(date),
+            LegFlow::Redemption(_) | LegFlow::CpiRedemption(_) => Ok(0.0),
+        }
+    }
+
+    /// The nominal and accrual period, as a year fraction, of a coupon, or `None` for
+    /// redemptions.
+    pub fn accrual(&self) -> Result<Option<(f64, f64)>, LibraError> {
+        match self {
+            LegFlow::FixedCoupon(coupon) => Ok(Some((coupon.nominal(), coupon.accrual_period()?))),
+            LegFlow::FloatingCoupon(coupon) => {
+                Ok(Some((coupon.nominal(), coupon.accrual_period()?)))
+            }
+            LegFlow::OvernightCoupon(coupon) => {
+                Ok(Some((coupon.nominal(), coupon.accrual_period()?)))
+            }
+            LegFlow::CpiCoupon(coupon) => Ok(Some((coupon.nominal(), coupon.accrual_period()?))),
+            LegFlow::Redemption(_) | LegFlow::CpiRedemption(_) => Ok(None),
+        }
+    }
+
+    /// This flow as a simple cash flow.
+    pub fn cash_flow(&self) -> Result<CashFlow, LibraError> {
+        Ok(CashFlow::new(self.amount()?, self.date()))
+    }
+}
+
+/// An ordered sequence of interest and redemption flows.
+#[derive(Clone, Debug, Default)]
+pub struct Leg {
+    flows: Vec<LegFlow>,
+}
+
+impl Leg {
+    /// Creates a leg from `flows`, which are sorted by payment date.
+    pub fn new(mut flows: Vec<LegFlow>) -> Self {
+        flows.sort_by_key(|f| f.date());
+        Leg { flows }
+    }
+
+    pub fn flows(&self) -> &[LegFlow] {
+        &self.flows
+    }
+
+    /// All flows of this leg as simple cash flows.
+    pub fn cash_flows(&self) -> Result<Vec<CashFlow>, LibraError> {
+        self.flows.iter().map(|f| f.cash_flow()).collect()
+    }
+
+    /// The interest flows of this leg.
+    pub fn coupons(&self) -> impl Iterator<Item = &LegFlow> {
+        self.flows.iter().filter(|f| f.is_coupon())
+    }
+
+    /// The redemption flows of this leg, before any indexation.
+    pub fn redemptions(&self) -> impl Iterator<Item = &Redemption> {
+        self.flows.iter().filter_map(|f| match f {
+            LegFlow::Redemption(redemption) => Some(redemption),
+            LegFlow::CpiRedemption(redemption) => Some(redemption.redemption()),
+            _ => None,
+        })
+    }
+
+    /// The date of the last flow, or `None` if the leg is empty.
+    pub fn maturity_date(&self) -> Option<NaiveDate> {
+        self.flows.last().map(|f| f.date())
+    }
+
+    /// The principal outstanding at `date`: the notional of all redemptions paid after `date`.
+    pub fn outstanding_notional(&self, date: &NaiveDate) -> f64 {
+        self.redemptions()
+            .filter(|r| r.date() > *date)
+            .map(|r| r.notional())
+            .sum()
+    }
+
+    /// The interest accrued by all coupons of this leg at `date`.
+    pub fn accrued_amount(&self, date: &NaiveDate) -> Result<f64, LibraError> {
+        self.coupons().map(|c| c.accrued_amount(date)).sum()
+    }
+
+    /// The present value of all flows paid after `today`, discounted at `rate`.
+    pub fn present_value(&self, today: &NaiveDate, rate: &InterestRate) -> Result<f64, LibraError> {
+        self.flows
+            .iter()
+            .filter(|f| f.date() > *today)
+            .map(|f| Ok(f.cash_flow()?.present_value(today, rate)?))
+            .sum()
+    }
+
+    /// The present value at `today` of all flows paid after `today`, discounted off `curve`.
+    pub fn npv(
+        &self,
+        curve: &dyn YieldTermStructure,
+        today: &NaiveDate,
+    ) -> Result<f64, LibraError> {
+        self.flows
+            .iter()
+            .filter(|f| f.date() > *today)
+            .map(|f| f.cash_flow()?.npv(curve, today))
+            .sum()
+    }
+}
+
+/// Builds a `Leg` of fixed rate coupons from a schedule.
+///
+/// Notionals and coupon rates are given per period; if fewer values than periods are provided
+/// the last value is used for the remaining periods. This allows amortizing and sinking-fund
+/// notionals, and step-up coupons, to be described concisely.
+#[derive(Clone, Debug)]
+pub struct FixedRateLeg {
+    schedule: Schedule,
+    day_count: DayCountConvention,
+    notionals: Vec<f64>,
+    rates: Vec<f64>,
+    redemption: Option<f64>,
+    payment_lag: u32,
+}
+
+impl FixedRateLeg {
+    pub fn new(schedule: Schedule, day_count: DayCountConvention) -> Self {
+        FixedRateLeg {
+            schedule,
+            day_count,
+            notionals: Vec::new(),
+            rates: Vec::new(),
+            redemption: Some(100.0),
+            payment_lag: 0,
+        }
+    }
+
+    /// Uses a constant notional for every period.
+    pub fn with_notional(self, notional: f64) -> Self {
+        self.with_notionals(vec![notional])
+    }
+
+    /// Uses a notional per period.
+    pub fn with_notionals(mut self, notionals: Vec<f64>) -> Self {
+        self.notionals = notionals;
+        self
+    }
+
+    /// Uses a constant coupon rate for every period.
+    pub fn with_rate(self, rate: f64) -> Self {
+        self.with_rates(vec![rate])
+    }
+
+    /// Uses a coupon rate per period.
+    pub fn with_rates(mut self, rates: Vec<f64>) -> Self {
+        self.rates = rates;
+        self
+    }
+
+    /// Redeems the final notional at `percentage` percent rather than at par.
+    pub fn with_redemption(mut self, percentage: f64) -> Self {
+        self.redemption = Some(percentage);
+        self
+    }
+
+    /// Only emits interest flows, omitting redemptions of principal.
+    pub fn without_redemptions(mut self) -> Self {
+        self.redemption = None;
+        self
+    }
+
+    /// Pays each flow `days` business days after the end of its period.
+    pub fn with_payment_lag(mut self, days: u32) -> Self {
+        self.payment_lag = days;
+        self
+    }
+
+    /// Builds the leg.
+    ///
+    /// A redemption at par is emitted at the end of each period in which the notional decreases,
+    /// and the remaining notional is redeemed at the end of the final period.
+    pub fn build(&self) -> Result<Leg, LibraError> {
+        let periods = self.schedule.len();
+
+        check_per_period("notionals", &self.notionals, periods)?;
+        check_per_period("rates", &self.rates, periods)?;
+
+        let mut flows = Vec::new();
+
+        for (i, (start, end)) in self.schedule.periods().enumerate() {
+            let payment_date = payment_date(&self.schedule, end, self.payment_lag);
+            let rate = InterestRate::new(
+                per_period(&self.rates, i),
+                self.day_count,
+                Compounding::Simple,
+                Frequency::Annual,
+            );
+
+            flows.push(LegFlow::FixedCoupon(FixedRateCoupon::new(
+                per_period(&self.notionals, i),
+                rate,
+                start,
+                end,
+                payment_date,
+            )?));
+
+            if let Some(percentage) = self.redemption {
+                push_redemption(
+                    &mut flows,
+                    &self.notionals,
+                    i,
+                    periods,
+                    percentage,
+                    payment_date,
+                );
+            }
+        }
+
+        Ok(Leg::new(flows))
+    }
+}
+
+/// Builds a `Leg` of floating rate coupons on an index from a schedule.
+///
+/// Notionals, gearings and spreads are given per period in the same way as for `FixedRateLeg`.
+#[derive(Clone, Debug)]
+pub struct FloatingRateLeg {
+    schedule: Schedule,
+    index: IborIndex,
+    day_count: DayCountConvention,
+    notionals: Vec<f64>,
+    gearings: Vec<f64>,
+    spreads: Vec<f64>,
+    redemption: Option<f64>,
+    payment_lag: u32,
+}
+
+impl FloatingRateLeg {
+    pub fn new(schedule: Schedule, index: IborIndex, day_count: DayCountConvention) -> Self {
+        FloatingRateLeg {
+            schedule,
+            index,
+            day_count,
+            notionals: Vec::new(),
+            gearings: vec![1.0],
+            spreads: vec![0.0],
+            redemption: Some(100.0),
+            payment_lag: 0,
+        }
+    }
+
+    /// Uses a constant notional for every period.
+    pub fn with_notional(self, notional: f64) -> Self {
+        self.with_notionals(vec![notional])
+    }
+
+    /// Uses a notional per period.
+    pub fn with_notionals(mut self, notionals: Vec<f64>) -> Self {
+        self.notionals = notionals;
+        self
+    }
+
+    /// Uses a gearing per period.
+    pub fn with_gearings(mut self, gearings: Vec<f64>) -> Self {
+        self.gearings = gearings;
+        self
+    }
+
+    /// Uses a constant spread over the index for every period.
+    pub fn with_spread(self, spread: f64) -> Self {
+        self.with_spreads(vec![spread])
+    }
+
+    /// Uses a spread over the index per period.
+    pub fn with_spreads(mut self, spreads: Vec<f64>) -> Self {
+        self.spreads = spreads;
+        self
+    }
+
+    /// Redeems the final notional at `percentage` percent rather than at par.
+    pub fn with_redemption(mut self, percentage: f64) -> Self {
+        self.redemption = Some(percentage);
+        self
+    }
+
+    /// Only emits interest flows, omitting redemptions of principal.
+    pub fn without_redemptions(mut self) -> Self {
+        self.redemption = None;
+        self
+    }
+
+    /// Pays each flow `days` business days after the end of its period.
+    pub fn with_payment_lag(mut self, days: u32) -> Self {
+        self.payment_lag = days;
+        self
+    }
+
+    /// Builds the leg, with redemptions emitted as for `FixedRateLeg`.
+    pub fn build(&self) -> Result<Leg, LibraError> {
+        let periods = self.schedule.len();
+
+        check_per_period("notionals", &self.notionals, periods)?;
+        check_per_period("gearings", &self.gearings, periods)?;
+        check_per_period("spreads", &self.spreads, periods)?;
+
+        let mut flows = Vec::new();
+
+        for (i, (start, end)) in self.schedule.periods().enumerate() {
+            let payment_date = payment_date(&self.schedule, end, self.payment_lag);
+
+            flows.push(LegFlow::FloatingCoupon(FloatingRateCoupon::new(
+                per_period(&self.notionals, i),
+                self.index.clone(),
+                per_period(&self.gearings, i),
+                per_period(&self.spreads, i),
+                self.day_count,
+                (start, end),
+                payment_date,
+            )));
+
+            if let Some(percentage) = self.redemption {
+                push_redemption(
+                    &mut flows,
+                    &self.notionals,
+                    i,
+                    periods,
+                    percentage,
+                    payment_date,
+                );
+            }
+        }
+
+        Ok(Leg::new(flows))
+    }
+}
+
+/// Builds a `Leg` of overnight indexed coupons from a schedule.
+///
+/// Notionals and spreads are given per period in the same way as for `FixedRateLeg`.
+#[derive(Clone, Debug)]
+pub struct OvernightLeg {
+    schedule: Schedule,
+    index: OvernightIndex,
+    notionals: Vec<f64>,
+    spreads: Vec<f64>,
+    redemption: Option<f64>,
+    payment_lag: u32,
+}
+
+impl OvernightLeg {
+    pub fn new(schedule: Schedule, index: OvernightIndex) -> Self {
+        OvernightLeg {
+            schedule,
+            index,
+            notionals: Vec::new(),
+            spreads: vec![0.0],
+            redemption: Some(100.0),
+            payment_lag: 0,
+        }
+    }
+
+    /// Uses a constant notional for every period.
+    pub fn with_notional(self, notional: f64) -> Self {
+        self.with_notionals(vec![notional])
+    }
+
+    /// Uses a notional per period.
+    pub fn with_notionals(mut self, notionals: Vec<f64>) -> Self {
+        self.notionals = notionals;
+        self
+    }
+
+    /// Uses a constant spread over the compounded index rate for every period.
+    pub fn with_spread(self, spread: f64) -> Self {
+        self.with_spreads(vec![spread])
+    }
+
+    /// Uses a spread over the compounded index rate per period.
+    pub fn with_spreads(mut self, spreads: Vec<f64>) -> Self {
+        self.spreads = spreads;
+        self
+    }
+
+    /// Redeems the final notional at `percentage` percent rather than at par.
+    pub fn with_redemption(mut self, percentage: f64) -> Self {
+        self.redemption = Some(percentage);
+        self
+    }
+
+    /// Only emits interest flows, omitting redemptions of principal.
+    pub fn without_redemptions(mut self) -> Self {
+        self.redemption = None;
+        self
+    }
+
+    /// Pays each flow `days` business days after the end of its period.
+    pub fn with_payment_lag(mut self, days: u32) -> Self {
+        self.payment_lag = days;
+        self
+    }
+
+    /// Builds the leg, with redemptions emitted as for `FixedRateLeg`.
+    pub fn build(&self) -> Result<Leg, LibraError> {
+        let periods = self.schedule.len();
+
+        check_per_period("notionals", &self.notionals, periods)?;
+        check_per_period("spreads", &self.spreads, periods)?;
+
+        let mut flows = Vec::new();
+
+        for (i, (start, end)) in self.schedule.periods().enumerate() {
+            let payment_date = payment_date(&self.schedule, end, self.payment_lag);
+
+            flows.push(LegFlow::OvernightCoupon(OvernightIndexedCoupon::new(
+                per_period(&self.notionals, i),
+                self.index.clone(),
+                per_period(&self.spreads, i),
+                (start, end),
+                payment_date,
+            )));
+
+            if let Some(percentage) = self.redemption {
+                push_redemption(
+                    &mut flows,
+                    &self.notionals,
+                    i,
+                    periods,
+                    percentage,
+                    payment_date,
+                );
+            }
+        }
+
+        Ok(Leg::new(flows))
+    }
+}
+
+/// Builds a `Leg` of inflation-indexed coupons and redemptions from a schedule.
+///
+/// Notionals and real coupon rates are given per period in the same way as for `FixedRateLeg`.
+#[derive(Clone, Debug)]
+pub struct CpiLeg {
+    schedule: Schedule,
+    indexation: CpiIndexation,
+    day_count: DayCountConvention,
+    notionals: Vec<f64>,
+    rates: Vec<f64>,
+    redemption: Option<f64>,
+    floored: bool,
+}
+
+impl CpiLeg {
+    pub fn new(
+        schedule: Schedule,
+        indexation: CpiIndexation,
+        day_count: DayCountConvention,
+    ) -> Self {
+        CpiLeg {
+            schedule,
+            indexation,
+            day_count,
+            notionals: Vec::new(),
+            rates: Vec::new(),
+            redemption: Some(100.0),
+            floored: false,
+        }
+    }
+
+    /// Uses a constant notional for every period.
+    pub fn with_notional(self, notional: f64) -> Self {
+        self.with_notionals(vec![notional])
+    }
+
+    /// Uses a notional per period.
+    pub fn with_notionals(mut self, notionals: Vec<f64>) -> Self {
+        self.notionals = notionals;
+        self
+    }
+
+    /// Uses a constant real coupon rate for every period.
+    pub fn with_rate(self, rate: f64) -> Self {
+        self.with_rates(vec![rate])
+    }
+
+    /// Uses a real coupon rate per period.
+    pub fn with_rates(mut self, rates: Vec<f64>) -> Self {
+        self.rates = rates;
+        self
+    }
+
+    /// Redeems the final notional at `percentage` percent rather than at par.
+    pub fn with_redemption(mut self, percentage: f64) -> Self {
+        self.redemption = Some(percentage);
+        self
+    }
+
+    /// Floors the index ratio of redemptions at one, so that deflation never reduces the
+    /// principal repaid.
+    pub fn with_redemption_floor(mut self) -> Self {
+        self.floored = true;
+        self
+    }
+
+    /// Only emits interest flows, omitting redemptions of principal.
+    pub fn without_redemptions(mut self) -> Self {
+        self.redemption = None;
+        self
+    }
+
+    /// Builds the leg, with redemptions emitted as for `FixedRateLeg` and indexed.
+    pub fn build(&self) -> Result<Leg, LibraError> {
+        let periods = self.schedule.len();
+
+        check_per_period("notionals", &self.notionals, periods)?;
+        check_per_period("rates", &self.rates, periods)?;
+
+        let mut flows = Vec::new();
+
+        for (i, (start, end)) in self.schedule.periods().enumerate() {
+            flows.push(LegFlow::CpiCoupon(CpiCoupon::new(
+                per_period(&self.notionals, i),
+                per_period(&self.rates, i),
+                self.day_count,
+                (start, end),
+                end,
+                self.indexation.clone(),
+            )));
+
+            if let Some(percentage) = self.redemption {
+                push_redemption(&mut flows, &self.notionals, i, periods, percentage, end);
+            }
+        }
+
+        let flows = flows
+            .into_iter()
+            .map(|flow| match flow {
+                LegFlow::Redemption(redemption) => LegFlow::CpiRedemption(CpiRedemption::new(
+                    redemption,
+                    self.indexation.clone(),
+                    self.floored,
+                )),
+                flow => flow,
+            })
+            .collect();
+
+        Ok(Leg::new(flows))
+    }
+}
+
+/// Notionals for a loan repaid in equal instalments of principal over `periods` periods.
+pub fn amortizing_notionals(initial: f64, periods: usize) -> Vec<f64> {
+    let repayment = initial / periods as f64;
+
+    (0..periods)
+        .map(|i| initial - repayment * i as f64)
+        .collect()
+}
+
+/// Notionals for a loan repaid like an annuity, with level payments of principal plus interest,
+/// where `rate` is the interest rate per period.
+pub fn annuity_notionals(initial: f64, periods: usize, rate: f64) -> Vec<f64> {
+    if rate == 0.0 {
+        return amortizing_notionals(initial, periods);
+    }
+
+    let payment = initial * rate / (1.0 - (1.0 + rate).powi(-(periods as i32)));
+    let mut notional = initial;
+
+    (0..periods)
+        .map(|_| {
+            let current = notional;
+            notional -= payment - current * rate;
+            current
+        })
+        .collect()
+}
+
+/// Pushes the redemption due at the end of period `i`, if the notional decreases. Intermediate
+/// redemptions are at par and the final redemption is at `percentage`.
+fn push_redemption(
+    flows: &mut Vec<LegFlow>,
+    notionals: &[f64],
+    i: usize,
+    periods: usize,
+    percentage: f64,
+    date: NaiveDate,
+) {
+    let current = per_period(notionals, i);
+
+    let (notional, percentage) = if i + 1 < periods {
+        (current - per_period(notionals, i + 1), 100.0)
+    } else {
+        (current, percentage)
+    };
+
+    if notional != 0.0 {
+        flows.push(LegFlow::Redemption(Redemption::new(
+            notional, percentage, date,
+        )));
+    }
+}
+
+/// The date a flow for the period ending on `end` is paid, `lag` business days later.
+fn payment_date(schedule: &Schedule, end: NaiveDate, lag: u32) -> NaiveDate {
+    if lag == 0 {
+        end
+    } else {
+        schedule.calendar().advance(end, lag as i64)
+    }
+}
+
+fn check_per_period(
+    name: &str,
+    values: &[f64],
+    periods: usize,
+) -> Result<(), error::ArgumentRange> {
+    if values.is_empty() || values.len() > periods {
+        return Err(error::ArgumentRange::new(
+            &format!("number of {}", name),
+            1,
+            periods as i64,
+            values.len() as i64,
+        ));
+    }
+
+    Ok(())
+}
+
+fn per_period(values: &[f64], i: usize) -> f64 {
+    values[i.min(values.len() - 1)]
+}
@@ -1,16 +1,19 @@
 mod cashflow;
-mod coupons;
+mod cpi_coupon;
+mod dividend;
+mod fixed_rate_coupon;
+mod floating_rate_coupon;
+mod interest_rate;
+mod leg;
+mod overnight_coupon;
+mod redemption;
 
 pub use cashflow::*;
-pub use coupons::*;
-
-/// Represents a type of interest.
-#[derive(Clone, Copy, Debug)]
-pub enum InterestType {
-    /// Simple interest
-    Simple,
-    /// Compound interest
-    Compound,
-    /// Continuously compounding interest
-    Continuous,
-}
+pub use cpi_coupon::*;
+pub use dividend::*;
+pub use fixed_rate_coupon::*;
+pub use floating_rate_coupon::*;
+pub use interest_rate::*;
+pub use leg::*;
+pub use overnight_coupon::*;
+pub use redemption::*;
@@ -0,0 +1,109 @@
+use chrono::NaiveDate;
+
+use crate::{error::LibraError, indexes::OvernightIndex};
+
+/// A coupon paying the daily compounded rate of an overnight index over the accrual period plus
+/// a spread, on a nominal.
+#[derive(Clone, Debug)]
+pub struct OvernightIndexedCoupon {
+    nominal: f64,
+    index: OvernightIndex,
+    spread: f64,
+    accrual_start: NaiveDate,
+    accrual_end: NaiveDate,
+    payment_date: NaiveDate,
+}
+
+impl OvernightIndexedCoupon {
+    /// Creates a new overnight indexed coupon, accruing in the day count convention of the index.
+    ///
+    /// # Arguments
+    ///
+    /// * `nominal` - The nominal on which interest accrues.
+    /// * `index` - The index whose compounded rate determines the coupon rate.
+    /// * `spread` - The spread added to the compounded rate.
+    /// * `period` - The `(start, end)` dates of the accrual period.
+    /// * `payment_date` - The date the coupon is paid.
+    pub fn new(
+        nominal: f64,
+        index: OvernightIndex,
+        spread: f64,
+        period: (NaiveDate, NaiveDate),
+        payment_date: NaiveDate,
+    ) -> Self {
+        OvernightIndexedCoupon {
+            nominal,
+            index,
+            spread,
+            accrual_start: period.0,
+            accrual_end: period.1,
+            payment_date,
+        }
+    }
+
+    pub fn nominal(&self) -> f64 {
+        self.nominal
+    }
+
+    pub fn index(&self) -> &OvernightIndex {
+        &self.index
+    }
+
+    pub fn spread(&self) -> f64 {
+        self.spread
+    }
+
+    pub fn accrual_start(&self) -> NaiveDate {
+        self.accrual_start
+    }
+
+    pub fn accrual_end(&self) -> NaiveDate {
+        self.accrual_end
+    }
+
+    pub fn payment_date(&self) -> NaiveDate {
+        self.payment_date
+    }
+
+    /// The compounded index rate for the accrual period, from fixings and forecasts.
+    pub fn compounded_rate(&self) -> Result<f64, LibraError> {
+        self.index
+            .compounded_rate(self.accrual_start, self.accrual_end)
+    }
+
+    /// The annualized rate paid by this coupon.
+    pub fn rate(&self) -> Result<f64, LibraError> {
+        Ok(self.compounded_rate()? + self.spread)
+    }
+
+    /// The accrual period of this coupon as a year fraction.
+    pub fn accrual_period(&self) -> Result<f64, LibraError> {
+        Ok(self
+            .index
+            .day_count()
+            .year_frac(&self.accrual_start, &self.accrual_end)?)
+    }
+
+    /// The amount paid by this coupon.
+    pub fn amount(&self) -> Result<f64, LibraError> {
+        Ok(self.nominal * self.rate()? * self.accrual_period()?)
+    }
+
+    /// The interest accrued by this coupon up to `date`, at the rate for the whole period.
+    ///
+    /// This is zero up to the start of the accrual period and from the payment date onwards.
+    pub fn accrued_amount(&self, date: &NaiveDate) -> Result<f64, LibraError> {
+        if *date <= self.accrual_start || *date >= self.payment_date {
+            return Ok(0.0);
+        }
+
+        let end = (*date).min(self.accrual_end);
+
+        Ok(self.nominal
+            * self.rate()?
+            * self
+                .index
+                .day_count()
+                .year_frac(&self.accrual_start, &end)?)
+    }
+}
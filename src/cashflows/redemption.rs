@@ -0,0 +1,46 @@
+use chrono::NaiveDate;
+
+use super::CashFlow;
+
+/// A repayment of principal, paid as a percentage of the notional being redeemed.
+#[derive(Clone, Copy, Debug)]
+pub struct Redemption {
+    notional: f64,
+    percentage: f64,
+    date: NaiveDate,
+}
+
+impl Redemption {
+    /// Creates a new redemption of `notional` at `percentage` percent on `date`.
+    pub fn new(notional: f64, percentage: f64, date: NaiveDate) -> Self {
+        Redemption {
+            notional,
+            percentage,
+            date,
+        }
+    }
+
+    /// The notional being redeemed.
+    pub fn notional(&self) -> f64 {
+        self.notional
+    }
+
+    /// The redemption price as a percentage of the notional.
+    pub fn percentage(&self) -> f64 {
+        self.percentage
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// The amount paid.
+    pub fn amount(&self) -> f64 {
+        self.notional * self.percentage / 100.0
+    }
+
+    /// This redemption as a simple cash flow.
+    pub fn cash_flow(&self) -> CashFlow {
+        CashFlow::new(self.amount(), self.date)
+    }
+}
@@ -1,10 +1,29 @@
 use thiserror::Error;
 
+#[derive(Error, Debug)]
 pub enum LibraError {
-    ArgumentRange(ArgumentRange),
-    ConditionalArgumentRange(ConditionalArgumentRange),
-    InvalidDate(InvalidDate),
-    DifferentVariant(DifferentVariant),
+    #[error(transparent)]
+    ArgumentRange(#[from] ArgumentRange),
+    #[error(transparent)]
+    ConditionalArgumentRange(#[from] ConditionalArgumentRange),
+    #[error(transparent)]
+    InvalidDate(#[from] InvalidDate),
+    #[error(transparent)]
+    DifferentVariant(#[from] DifferentVariant),
+    #[error(transparent)]
+    NotBracketed(#[from] NotBracketed),
+    #[error(transparent)]
+    NoConvergence(#[from] NoConvergence),
+    #[error(transparent)]
+    NotPositive(#[from] NotPositive),
+    #[error(transparent)]
+    NotInRange(#[from] NotInRange),
+    #[error(transparent)]
+    NotIncreasing(#[from] NotIncreasing),
+    #[error(transparent)]
+    BelowIntrinsicValue(#[from] BelowIntrinsicValue),
+    #[error(transparent)]
+    AboveMaximumValue(#[from] AboveMaximumValue),
 }
 
 #[derive(Error, Debug)]
@@ -16,6 +35,17 @@ pub struct ArgumentRange {
     value: i64,
 }
 
+impl ArgumentRange {
+    pub fn new(name: &str, min: i64, max: i64, value: i64) -> Self {
+        ArgumentRange {
+            name: name.to_string(),
+            min,
+            max,
+            value,
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 #[error(
     "{name} must be in the range {min}=..{max}, but {value} was provided: {conditional_message}"
@@ -35,3 +65,125 @@ pub struct InvalidDate;
 #[derive(Error, Debug)]
 #[error("value was of a different variant than required")]
 pub struct DifferentVariant;
+
+#[derive(Error, Debug)]
+#[error("root not bracketed: f({lower})={f_lower}, f({upper})={f_upper}")]
+pub struct NotBracketed {
+    lower: f64,
+    upper: f64,
+    f_lower: f64,
+    f_upper: f64,
+}
+
+impl NotBracketed {
+    pub fn new(lower: f64, upper: f64, f_lower: f64, f_upper: f64) -> Self {
+        NotBracketed {
+            lower,
+            upper,
+            f_lower,
+            f_upper,
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("{solver} failed to converge after {evaluations} function evaluations")]
+pub struct NoConvergence {
+    solver: String,
+    evaluations: usize,
+}
+
+impl NoConvergence {
+    pub fn new(solver: &str, evaluations: usize) -> Self {
+        NoConvergence {
+            solver: solver.to_string(),
+            evaluations,
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("{name} must be positive, but {value} was provided")]
+pub struct NotPositive {
+    name: String,
+    value: f64,
+}
+
+impl NotPositive {
+    pub fn new(name: &str, value: f64) -> Self {
+        NotPositive {
+            name: name.to_string(),
+            value,
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("{name} must be in the range {min}..={max}, but {value} was provided")]
+pub struct NotInRange {
+    name: String,
+    min: f64,
+    max: f64,
+    value: f64,
+}
+
+impl NotInRange {
+    pub fn new(name: &str, min: f64, max: f64, value: f64) -> Self {
+        NotInRange {
+            name: name.to_string(),
+            min,
+            max,
+            value,
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("{name} must be strictly increasing, but {value} follows {previous}")]
+pub struct NotIncreasing {
+    name: String,
+    previous: f64,
+    value: f64,
+}
+
+impl NotIncreasing {
+    pub fn new(name: &str, previous: f64, value: f64) -> Self {
+        NotIncreasing {
+            name: name.to_string(),
+            previous,
+            value,
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("price {price} is below the intrinsic value {intrinsic_value}")]
+pub struct BelowIntrinsicValue {
+    price: f64,
+    intrinsic_value: f64,
+}
+
+impl BelowIntrinsicValue {
+    pub fn new(price: f64, intrinsic_value: f64) -> Self {
+        BelowIntrinsicValue {
+            price,
+            intrinsic_value,
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("price {price} is not below the maximum value {maximum_value}")]
+pub struct AboveMaximumValue {
+    price: f64,
+    maximum_value: f64,
+}
+
+impl AboveMaximumValue {
+    pub fn new(price: f64, maximum_value: f64) -> Self {
+        AboveMaximumValue {
+            price,
+            maximum_value,
+        }
+    }
+}
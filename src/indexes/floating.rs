@@ -0,0 +1,99 @@
+use std::rc::Rc;
+
+use crate::{
+    cashflows::{FloatingRateLeg, Leg, OvernightLeg},
+    error::LibraError,
+    pricing::YieldTermStructure,
+    time::{DayCountConvention, Schedule},
+};
+
+use super::{IborIndex, OvernightIndex};
+
+/// An index paid by a floating leg, either fixed once per period or compounded daily.
+#[derive(Clone, Debug)]
+pub enum FloatingIndex {
+    Ibor(IborIndex),
+    Overnight(OvernightIndex),
+}
+
+impl FloatingIndex {
+    pub fn name(&self) -> &str {
+        match self {
+            FloatingIndex::Ibor(index) => index.name(),
+            FloatingIndex::Overnight(index) => index.name(),
+        }
+    }
+
+    pub fn day_count(&self) -> DayCountConvention {
+        match self {
+            FloatingIndex::Ibor(index) => index.day_count(),
+            FloatingIndex::Overnight(index) => index.day_count(),
+        }
+    }
+
+    pub fn forward_curve(&self) -> &Rc<dyn YieldTermStructure> {
+        match self {
+            FloatingIndex::Ibor(index) => index.forward_curve(),
+            FloatingIndex::Overnight(index) => index.forward_curve(),
+        }
+    }
+
+    /// Forecasts future fixings off `forward_curve` instead, keeping any recorded fixings.
+    pub fn with_forward_curve(self, forward_curve: Rc<dyn YieldTermStructure>) -> Self {
+        match self {
+            FloatingIndex::Ibor(index) => {
+                FloatingIndex::Ibor(index.with_forward_curve(forward_curve))
+            }
+            FloatingIndex::Overnight(index) => {
+                FloatingIndex::Overnight(index.with_forward_curve(forward_curve))
+            }
+        }
+    }
+
+    /// Builds a leg of coupons on this index without redemptions, accruing in the day count
+    /// convention of the index.
+    ///
+    /// # Arguments
+    ///
+    /// * `schedule` - The periods of the leg.
+    /// * `notionals` - The notional of each period, given as for `FloatingRateLeg`.
+    /// * `spread` - The spread added to the index rate.
+    /// * `payment_lag` - The number of business days after the end of each period each coupon is
+    ///   paid.
+    pub fn leg(
+        &self,
+        schedule: &Schedule,
+        notionals: Vec<f64>,
+        spread: f64,
+        payment_lag: u32,
+    ) -> Result<Leg, LibraError> {
+        match self {
+            FloatingIndex::Ibor(index) => {
+                FloatingRateLeg::new(schedule.clone(), index.clone(), index.day_count())
+                    .with_notionals(notionals)
+                    .with_spread(spread)
+                    .with_payment_lag(payment_lag)
+                    .without_redemptions()
+                    .build()
+            }
+            FloatingIndex::Overnight(index) => OvernightLeg::new(schedule.clone(), index.clone())
+                .with_notionals(notionals)
+                .with_spread(spread)
+                .with_payment_lag(payment_lag)
+                .without_redemptions()
+                .build(),
+        }
+    }
+}
+
+impl From<IborIndex> for FloatingIndex {
+    fn from(index: IborIndex) -> Self {
+        FloatingIndex::Ibor(index)
+    }
+}
+
+impl From<OvernightIndex> for FloatingIndex {
+    fn from(index: OvernightIndex) -> Self {
+        FloatingIndex::Overnight(index)
+    }
+}
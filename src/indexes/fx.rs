@@ -0,0 +1,67 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+
+use crate::{
+    error::{InvalidDate, LibraError},
+    pricing::YieldTermStructure,
+};
+
+/// An exchange rate index, quoted as the number of units of the domestic currency per unit of
+/// the foreign currency.
+#[derive(Clone, Debug)]
+pub struct FxIndex {
+    name: String,
+    fixings: BTreeMap<NaiveDate, f64>,
+}
+
+impl FxIndex {
+    pub fn new(name: &str) -> Self {
+        FxIndex {
+            name: name.to_string(),
+            fixings: BTreeMap::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Records the fixing of the index on `date`.
+    pub fn add_fixing(&mut self, date: NaiveDate, rate: f64) {
+        self.fixings.insert(date, rate);
+    }
+
+    /// The recorded fixing on `date`, if there is one.
+    pub fn fixing(&self, date: NaiveDate) -> Option<f64> {
+        self.fixings.get(&date).copied()
+    }
+
+    /// The rate on `date`, which is its fixing if one is recorded and otherwise the forward rate
+    /// implied by covered interest parity.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date of the rate.
+    /// * `spot` - The exchange rate on the reference date of `domestic_curve`.
+    /// * `domestic_curve` - The discount curve of the domestic currency.
+    /// * `foreign_curve` - The discount curve of the foreign currency, including any cross
+    ///   currency basis, with the same reference date.
+    ///
+    /// # Returns
+    ///
+    /// The rate, or an error if `date` is before the reference date and has no fixing.
+    pub fn rate(
+        &self,
+        date: NaiveDate,
+        spot: f64,
+        domestic_curve: &dyn YieldTermStructure,
+        foreign_curve: &dyn YieldTermStructure,
+    ) -> Result<f64, LibraError> {
+        match self.fixing(date) {
+            Some(rate) => Ok(rate),
+            None if date < domestic_curve.reference_date() => Err(InvalidDate.into()),
+            None => Ok(spot * foreign_curve.discount(date)? / domestic_curve.discount(date)?),
+        }
+    }
+}
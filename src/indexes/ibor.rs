@@ -0,0 +1,131 @@
+use std::{collections::BTreeMap, fmt, rc::Rc};
+
+use chrono::{Months, NaiveDate};
+
+use crate::{
+    cashflows::Compounding,
+    error::LibraError,
+    pricing::YieldTermStructure,
+    time::{Calendar, DayCountConvention, Frequency},
+};
+
+/// An interbank offered rate index, such as SONIA term rates or EURIBOR, which is fixed a number
+/// of business days before the start of the period it applies to.
+#[derive(Clone)]
+pub struct IborIndex {
+    name: String,
+    tenor: Months,
+    fixing_days: u32,
+    calendar: Calendar,
+    day_count: DayCountConvention,
+    forward_curve: Rc<dyn YieldTermStructure>,
+    fixings: BTreeMap<NaiveDate, f64>,
+}
+
+impl IborIndex {
+    /// Creates a new index.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the index.
+    /// * `tenor` - The length of the period the index rate applies to.
+    /// * `fixing_days` - The number of business days between fixing and the start of the period.
+    /// * `calendar` - The calendar used to determine fixing dates.
+    /// * `day_count` - The day count convention the index rate is quoted with.
+    /// * `forward_curve` - The curve used to forecast future fixings. Pass a
+    ///   [`RelinkableYieldTermStructure`](crate::pricing::RelinkableYieldTermStructure) to
+    ///   forecast off a curve that is replaced later.
+    pub fn new(
+        name: &str,
+        tenor: Months,
+        fixing_days: u32,
+        calendar: Calendar,
+        day_count: DayCountConvention,
+        forward_curve: Rc<dyn YieldTermStructure>,
+    ) -> Self {
+        IborIndex {
+            name: name.to_string(),
+            tenor,
+            fixing_days,
+            calendar,
+            day_count,
+            forward_curve,
+            fixings: BTreeMap::new(),
+        }
+    }
+
+    /// Forecasts future fixings off `forward_curve` instead, keeping any recorded fixings.
+    pub fn with_forward_curve(mut self, forward_curve: Rc<dyn YieldTermStructure>) -> Self {
+        self.forward_curve = forward_curve;
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn tenor(&self) -> Months {
+        self.tenor
+    }
+
+    pub fn fixing_days(&self) -> u32 {
+        self.fixing_days
+    }
+
+    pub fn calendar(&self) -> Calendar {
+        self.calendar
+    }
+
+    pub fn day_count(&self) -> DayCountConvention {
+        self.day_count
+    }
+
+    pub fn forward_curve(&self) -> &Rc<dyn YieldTermStructure> {
+        &self.forward_curve
+    }
+
+    /// Records a past fixing of the index. Fixings must be added before the index is used to
+    /// build coupons, as each coupon keeps its own copy of the index.
+    pub fn add_fixing(&mut self, fixing_date: NaiveDate, rate: f64) {
+        self.fixings.insert(fixing_date, rate);
+    }
+
+    /// The date on which the rate for a period starting on `value_date` is fixed.
+    pub fn fixing_date(&self, value_date: NaiveDate) -> NaiveDate {
+        self.calendar
+            .advance(value_date, -(self.fixing_days as i64))
+    }
+
+    /// The rate for the period between `start` and `end`.
+    ///
+    /// A recorded fixing is used if one exists for the fixing date of `start`; otherwise the
+    /// rate is forecast as the simple forward rate of the forward curve in the index's day count.
+    pub fn rate(&self, start: NaiveDate, end: NaiveDate) -> Result<f64, LibraError> {
+        match self.fixings.get(&self.fixing_date(start)) {
+            Some(rate) => Ok(*rate),
+            None => Ok(self
+                .forward_curve
+                .forward_rate(
+                    start,
+                    end,
+                    self.day_count,
+                    Compounding::Simple,
+                    Frequency::Annual,
+                )?
+                .rate()),
+        }
+    }
+}
+
+impl fmt::Debug for IborIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IborIndex")
+            .field("name", &self.name)
+            .field("tenor", &self.tenor)
+            .field("fixing_days", &self.fixing_days)
+            .field("calendar", &self.calendar)
+            .field("day_count", &self.day_count)
+            .field("fixings", &self.fixings)
+            .finish_non_exhaustive()
+    }
+}
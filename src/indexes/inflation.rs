@@ -0,0 +1,162 @@
+use std::{collections::BTreeMap, rc::Rc};
+
+use chrono::{Datelike, Months, NaiveDate};
+
+use crate::{
+    error::{InvalidDate, LibraError},
+    pricing::{first_of_month, ZeroInflationCurve},
+};
+
+/// How the reference value of an index is found for a date within a month.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InflationInterpolation {
+    /// The fixing for the lagged month is used for every day of the month.
+    Flat,
+    /// The value moves linearly through the month from the fixing for the lagged month towards
+    /// the fixing for the month after it.
+    Linear,
+}
+
+/// The lag and interpolation with which an inflation-linked flow observes its index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InflationObservation {
+    lag: Months,
+    interpolation: InflationInterpolation,
+}
+
+impl InflationObservation {
+    pub fn new(lag: Months, interpolation: InflationInterpolation) -> Self {
+        InflationObservation { lag, interpolation }
+    }
+
+    /// The three month lag with daily interpolation used by index-linked gilts issued since
+    /// 2005, TIPS and most other inflation-linked bonds.
+    pub fn three_month() -> Self {
+        Self::new(Months::new(3), InflationInterpolation::Linear)
+    }
+
+    /// The eight month lag used by index-linked gilts issued before 2005, which observe the
+    /// RPI for a whole month.
+    pub fn eight_month() -> Self {
+        Self::new(Months::new(8), InflationInterpolation::Flat)
+    }
+
+    pub fn lag(&self) -> Months {
+        self.lag
+    }
+
+    pub fn interpolation(&self) -> InflationInterpolation {
+        self.interpolation
+    }
+}
+
+/// A monthly price index, such as the UK RPI or the euro area HICP.
+///
+/// Fixings are recorded against the month they measure, not the month they are published in.
+/// Months without a fixing are forecast from a zero-coupon inflation curve, grown from the
+/// fixing for the curve's base month.
+#[derive(Clone, Debug)]
+pub struct InflationIndex {
+    name: String,
+    fixings: BTreeMap<NaiveDate, f64>,
+    curve: Option<Rc<ZeroInflationCurve>>,
+}
+
+impl InflationIndex {
+    /// Creates an index with no fixings.
+    pub fn new(name: &str) -> Self {
+        InflationIndex {
+            name: name.to_string(),
+            fixings: BTreeMap::new(),
+            curve: None,
+        }
+    }
+
+    /// The UK Retail Prices Index, referenced by index-linked gilts.
+    pub fn uk_rpi() -> Self {
+        Self::new("UK RPI")
+    }
+
+    /// The UK Consumer Prices Index.
+    pub fn uk_cpi() -> Self {
+        Self::new("UK CPI")
+    }
+
+    /// The euro area Harmonised Index of Consumer Prices excluding tobacco.
+    pub fn eu_hicp() -> Self {
+        Self::new("EU HICPxT")
+    }
+
+    /// The US Consumer Price Index for All Urban Consumers, referenced by TIPS.
+    pub fn us_cpi() -> Self {
+        Self::new("US CPI-U")
+    }
+
+    /// Forecasts months without a fixing from `curve`.
+    pub fn with_curve(mut self, curve: Rc<ZeroInflationCurve>) -> Self {
+        self.curve = Some(curve);
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn curve(&self) -> Option<&Rc<ZeroInflationCurve>> {
+        self.curve.as_ref()
+    }
+
+    /// Records the fixing for the month containing `date`. Fixings must be added before the
+    /// index is used to build flows, as each flow keeps its own copy of the index.
+    pub fn add_fixing(&mut self, date: NaiveDate, value: f64) {
+        self.fixings.insert(first_of_month(date), value);
+    }
+
+    /// The fixing for the month containing `date`, either recorded or forecast.
+    ///
+    /// # Returns
+    ///
+    /// The fixing, or an error if it has not been recorded and cannot be forecast.
+    pub fn fixing(&self, date: NaiveDate) -> Result<f64, LibraError> {
+        let month = first_of_month(date);
+
+        if let Some(value) = self.fixings.get(&month) {
+            return Ok(*value);
+        }
+
+        let curve = self.curve.as_ref().ok_or(InvalidDate)?;
+        let base = self.fixings.get(&curve.base_date()).ok_or(InvalidDate)?;
+
+        Ok(base * curve.index_growth(month)?)
+    }
+
+    /// The reference value of the index for `date`, observed with `observation`.
+    pub fn reference_value(
+        &self,
+        date: NaiveDate,
+        observation: InflationObservation,
+    ) -> Result<f64, LibraError> {
+        let month = first_of_month(date)
+            .checked_sub_months(observation.lag())
+            .ok_or(InvalidDate)?;
+        let value = self.fixing(month)?;
+
+        match observation.interpolation() {
+            InflationInterpolation::Flat => Ok(value),
+            InflationInterpolation::Linear if date.day() == 1 => Ok(value),
+            InflationInterpolation::Linear => {
+                let next_month = month
+                    .checked_add_months(Months::new(1))
+                    .ok_or(InvalidDate)?;
+                let days_in_month = (first_of_month(date)
+                    .checked_add_months(Months::new(1))
+                    .ok_or(InvalidDate)?
+                    - first_of_month(date))
+                .num_days();
+                let weight = (date.day() - 1) as f64 / days_in_month as f64;
+
+                Ok(value + weight * (self.fixing(next_month)? - value))
+            }
+        }
+    }
+}
@@ -0,0 +1,11 @@
+mod floating;
+mod fx;
+mod ibor;
+mod inflation;
+mod overnight;
+
+pub use floating::*;
+pub use fx::*;
+pub use ibor::*;
+pub use inflation::*;
+pub use overnight::*;
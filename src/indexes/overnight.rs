@@ -0,0 +1,123 @@
+use std::{collections::BTreeMap, fmt, rc::Rc};
+
+use chrono::NaiveDate;
+
+use crate::{
+    error::{InvalidDate, LibraError},
+    pricing::YieldTermStructure,
+    time::{Calendar, DayCountConvention},
+};
+
+/// An overnight rate index, such as SONIA or SOFR, which is fixed each business day for the
+/// period to the next business day.
+#[derive(Clone)]
+pub struct OvernightIndex {
+    name: String,
+    calendar: Calendar,
+    day_count: DayCountConvention,
+    forward_curve: Rc<dyn YieldTermStructure>,
+    fixings: BTreeMap<NaiveDate, f64>,
+}
+
+impl OvernightIndex {
+    /// Creates a new index.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the index.
+    /// * `calendar` - The calendar whose business days the index is fixed on.
+    /// * `day_count` - The day count convention the index rate is quoted with.
+    /// * `forward_curve` - The curve used to forecast future fixings. Pass a
+    ///   [`RelinkableYieldTermStructure`](crate::pricing::RelinkableYieldTermStructure) to
+    ///   forecast off a curve that is replaced later.
+    pub fn new(
+        name: &str,
+        calendar: Calendar,
+        day_count: DayCountConvention,
+        forward_curve: Rc<dyn YieldTermStructure>,
+    ) -> Self {
+        OvernightIndex {
+            name: name.to_string(),
+            calendar,
+            day_count,
+            forward_curve,
+            fixings: BTreeMap::new(),
+        }
+    }
+
+    /// Forecasts future fixings off `forward_curve` instead, keeping any recorded fixings.
+    pub fn with_forward_curve(mut self, forward_curve: Rc<dyn YieldTermStructure>) -> Self {
+        self.forward_curve = forward_curve;
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn calendar(&self) -> Calendar {
+        self.calendar
+    }
+
+    pub fn day_count(&self) -> DayCountConvention {
+        self.day_count
+    }
+
+    pub fn forward_curve(&self) -> &Rc<dyn YieldTermStructure> {
+        &self.forward_curve
+    }
+
+    /// Records the fixing of the index for the overnight period starting on `date`. Fixings
+    /// must be added before the index is used to build coupons, as each coupon keeps its own
+    /// copy of the index.
+    pub fn add_fixing(&mut self, date: NaiveDate, rate: f64) {
+        self.fixings.insert(date, rate);
+    }
+
+    /// The simple rate between `start` and `end` equivalent to compounding the index daily.
+    ///
+    /// Recorded fixings are compounded over each business day until the first day without one,
+    /// from which the growth to `end` is forecast off the forward curve.
+    ///
+    /// # Returns
+    ///
+    /// The compounded rate, or an error if `end` is not after `start` or a fixing is missing
+    /// before the reference date of the forward curve.
+    pub fn compounded_rate(&self, start: NaiveDate, end: NaiveDate) -> Result<f64, LibraError> {
+        if end <= start {
+            return Err(InvalidDate.into());
+        }
+
+        let accrual = self.day_count.year_frac(&start, &end)?;
+        let mut growth = 1.0;
+        let mut date = start;
+
+        while date < end {
+            match self.fixings.get(&date) {
+                Some(rate) => {
+                    let next = self.calendar.advance(date, 1).min(end);
+                    growth *= 1.0 + rate * self.day_count.year_frac(&date, &next)?;
+                    date = next;
+                }
+                None => {
+                    growth *=
+                        self.forward_curve.discount(date)? / self.forward_curve.discount(end)?;
+                    break;
+                }
+            }
+        }
+
+        Ok((growth - 1.0) / accrual)
+    }
+}
+
+impl fmt::Debug for OvernightIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OvernightIndex")
+            .field("name", &self.name)
+            .field("calendar", &self.calendar)
+            .field("day_count", &self.day_count)
+            .field("fixings", &self.fixings)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,110 @@
+use std::fmt;
+
+use chrono::NaiveDate;
+
+use crate::error::{InvalidDate, LibraError};
+
+use super::{Exercise, Payoff};
+
+/// How the fixings of an Asian option are averaged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AverageType {
+    Arithmetic,
+    Geometric,
+}
+
+impl AverageType {
+    /// The average of `values`.
+    pub fn average(&self, values: &[f64]) -> f64 {
+        let n = values.len() as f64;
+
+        match self {
+            AverageType::Arithmetic => values.iter().sum::<f64>() / n,
+            AverageType::Geometric => (values.iter().map(|v| v.ln()).sum::<f64>() / n).exp(),
+        }
+    }
+}
+
+impl fmt::Display for AverageType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AverageType::Arithmetic => write!(f, "Arithmetic"),
+            AverageType::Geometric => write!(f, "Geometric"),
+        }
+    }
+}
+
+/// An option whose payoff is applied to the average of the underlying's price on a set of
+/// fixing dates, rather than its price at expiry.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AsianOption {
+    average_type: AverageType,
+    payoff: Payoff,
+    fixing_dates: Vec<NaiveDate>,
+    exercise: Exercise,
+}
+
+impl AsianOption {
+    /// Creates an Asian option.
+    ///
+    /// # Arguments
+    ///
+    /// * `average_type` - How the fixings are averaged.
+    /// * `payoff` - The payoff applied to the average.
+    /// * `fixing_dates` - The dates the underlying is fixed on, in any order.
+    /// * `exercise` - The exercise of the option.
+    ///
+    /// # Returns
+    ///
+    /// The option, or an error if there are no fixing dates.
+    pub fn new(
+        average_type: AverageType,
+        payoff: Payoff,
+        mut fixing_dates: Vec<NaiveDate>,
+        exercise: Exercise,
+    ) -> Result<Self, LibraError> {
+        if fixing_dates.is_empty() {
+            return Err(InvalidDate.into());
+        }
+
+        fixing_dates.sort();
+        fixing_dates.dedup();
+
+        Ok(AsianOption {
+            average_type,
+            payoff,
+            fixing_dates,
+            exercise,
+        })
+    }
+
+    pub fn average_type(&self) -> AverageType {
+        self.average_type
+    }
+
+    pub fn payoff(&self) -> &Payoff {
+        &self.payoff
+    }
+
+    /// The fixing dates, in ascending order.
+    pub fn fixing_dates(&self) -> &[NaiveDate] {
+        &self.fixing_dates
+    }
+
+    pub fn exercise(&self) -> &Exercise {
+        &self.exercise
+    }
+}
+
+impl fmt::Display for AsianOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} average {} option over {} fixings, {}",
+            self.average_type,
+            self.payoff,
+            self.fixing_dates.len(),
+            self.exercise
+        )
+    }
+}
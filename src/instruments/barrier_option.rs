@@ -0,0 +1,249 @@
+use std::fmt;
+
+use chrono::NaiveDate;
+
+use super::{Exercise, Payoff};
+
+/// Whether a barrier option starts or stops paying once the underlying reaches the barrier, and
+/// from which side it is reached.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BarrierType {
+    /// Knocked in by the underlying falling to the barrier.
+    DownIn,
+    /// Knocked in by the underlying rising to the barrier.
+    UpIn,
+    /// Knocked out by the underlying falling to the barrier.
+    DownOut,
+    /// Knocked out by the underlying rising to the barrier.
+    UpOut,
+}
+
+impl BarrierType {
+    /// Whether the barrier is below the underlying.
+    pub fn is_down(&self) -> bool {
+        matches!(self, BarrierType::DownIn | BarrierType::DownOut)
+    }
+
+    /// Whether the option only pays once the barrier has been reached.
+    pub fn is_knock_in(&self) -> bool {
+        matches!(self, BarrierType::DownIn | BarrierType::UpIn)
+    }
+
+    /// Whether the barrier has been reached when the underlying is at `price`.
+    pub fn is_breached(&self, barrier: f64, price: f64) -> bool {
+        match self.is_down() {
+            true => price <= barrier,
+            false => price >= barrier,
+        }
+    }
+}
+
+impl fmt::Display for BarrierType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BarrierType::DownIn => write!(f, "Down-and-in"),
+            BarrierType::UpIn => write!(f, "Up-and-in"),
+            BarrierType::DownOut => write!(f, "Down-and-out"),
+            BarrierType::UpOut => write!(f, "Up-and-out"),
+        }
+    }
+}
+
+/// An option which is knocked in or out when the underlying reaches a barrier before expiry.
+///
+/// A knocked-out option pays its rebate when the barrier is reached, and a knock-in option which
+/// is never knocked in pays its rebate at expiry. The barrier is monitored continuously unless
+/// monitoring dates are given.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BarrierOption {
+    barrier_type: BarrierType,
+    barrier: f64,
+    rebate: f64,
+    payoff: Payoff,
+    exercise: Exercise,
+    monitoring_dates: Vec<NaiveDate>,
+}
+
+impl BarrierOption {
+    /// Creates a continuously monitored barrier option.
+    ///
+    /// # Arguments
+    ///
+    /// * `barrier_type` - Whether the option is knocked in or out, and from which side.
+    /// * `barrier` - The level of the barrier.
+    /// * `rebate` - The amount paid if the option is knocked out, or never knocked in.
+    /// * `payoff` - The payoff if the option is alive at expiry.
+    /// * `exercise` - The exercise of the option.
+    pub fn new(
+        barrier_type: BarrierType,
+        barrier: f64,
+        rebate: f64,
+        payoff: Payoff,
+        exercise: Exercise,
+    ) -> Self {
+        BarrierOption {
+            barrier_type,
+            barrier,
+            rebate,
+            payoff,
+            exercise,
+            monitoring_dates: Vec::new(),
+        }
+    }
+
+    /// Monitors the barrier only at the close of `dates`, in any order.
+    pub fn with_monitoring_dates(mut self, mut dates: Vec<NaiveDate>) -> Self {
+        dates.sort();
+        dates.dedup();
+        self.monitoring_dates = dates;
+        self
+    }
+
+    pub fn barrier_type(&self) -> BarrierType {
+        self.barrier_type
+    }
+
+    pub fn barrier(&self) -> f64 {
+        self.barrier
+    }
+
+    pub fn rebate(&self) -> f64 {
+        self.rebate
+    }
+
+    pub fn payoff(&self) -> &Payoff {
+        &self.payoff
+    }
+
+    pub fn exercise(&self) -> &Exercise {
+        &self.exercise
+    }
+
+    /// The dates the barrier is monitored on, in ascending order, which are empty when it is
+    /// monitored continuously.
+    pub fn monitoring_dates(&self) -> &[NaiveDate] {
+        &self.monitoring_dates
+    }
+}
+
+impl fmt::Display for BarrierOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} option with barrier {}, {}",
+            self.barrier_type, self.payoff, self.barrier, self.exercise
+        )
+    }
+}
+
+/// Whether a double barrier option starts or stops paying once the underlying reaches either
+/// barrier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DoubleBarrierType {
+    KnockIn,
+    KnockOut,
+}
+
+impl fmt::Display for DoubleBarrierType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DoubleBarrierType::KnockIn => write!(f, "Knock-in"),
+            DoubleBarrierType::KnockOut => write!(f, "Knock-out"),
+        }
+    }
+}
+
+/// An option which is knocked in or out when the underlying reaches either of a lower and an
+/// upper barrier before expiry.
+///
+/// Rebates are paid as for [`BarrierOption`], and the barriers are monitored continuously unless
+/// monitoring dates are given.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DoubleBarrierOption {
+    barrier_type: DoubleBarrierType,
+    lower_barrier: f64,
+    upper_barrier: f64,
+    rebate: f64,
+    payoff: Payoff,
+    exercise: Exercise,
+    monitoring_dates: Vec<NaiveDate>,
+}
+
+impl DoubleBarrierOption {
+    /// Creates a continuously monitored double barrier option.
+    ///
+    /// # Arguments
+    ///
+    /// * `barrier_type` - Whether the option is knocked in or out.
+    /// * `lower_barrier` - The level of the lower barrier.
+    /// * `upper_barrier` - The level of the upper barrier.
+    /// * `rebate` - The amount paid if the option is knocked out, or never knocked in.
+    /// * `payoff` - The payoff if the option is alive at expiry.
+    /// * `exercise` - The exercise of the option.
+    pub fn new(
+        barrier_type: DoubleBarrierType,
+        lower_barrier: f64,
+        upper_barrier: f64,
+        rebate: f64,
+        payoff: Payoff,
+        exercise: Exercise,
+    ) -> Self {
+        DoubleBarrierOption {
+            barrier_type,
+            lower_barrier,
+            upper_barrier,
+            rebate,
+            payoff,
+            exercise,
+            monitoring_dates: Vec::new(),
+        }
+    }
+
+    /// Monitors the barriers only at the close of `dates`, in any order.
+    pub fn with_monitoring_dates(mut self, mut dates: Vec<NaiveDate>) -> Self {
+        dates.sort();
+        dates.dedup();
+        self.monitoring_dates = dates;
+        self
+    }
+
+    pub fn barrier_type(&self) -> DoubleBarrierType {
+        self.barrier_type
+    }
+
+    pub fn lower_barrier(&self) -> f64 {
+        self.lower_barrier
+    }
+
+    pub fn upper_barrier(&self) -> f64 {
+        self.upper_barrier
+    }
+
+    pub fn rebate(&self) -> f64 {
+        self.rebate
+    }
+
+    pub fn payoff(&self) -> &Payoff {
+        &self.payoff
+    }
+
+    pub fn exercise(&self) -> &Exercise {
+        &self.exercise
+    }
+
+    /// The dates the barriers are monitored on, in ascending order, which are empty when they
+    /// are monitored continuously.
+    pub fn monitoring_dates(&self) -> &[NaiveDate] {
+        &self.monitoring_dates
+    }
+}
+
+impl fmt::Display for DoubleBarrierOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} option with barriers {} and {}, {}",
+            self.barrier_type, self.payoff, self.lower_barrier, self.upper_barrier, self.exercise
+        )
+    }
+}
@@ -0,0 +1,140 @@
+use std::rc::Rc;
+
+use crate::cashflows::Leg;
+use crate::error::LibraError;
+use crate::indexes::FloatingIndex;
+use crate::pricing::YieldTermStructure;
+use crate::time::Schedule;
+
+/// A swap exchanging the rates of two floating indexes on the same nominal, such as 3 month
+/// against 6 month EURIBOR or SOFR against Term SOFR, without exchanging notionals.
+#[derive(Clone, Debug)]
+pub struct BasisSwap {
+    nominal: f64,
+    pay_schedule: Schedule,
+    pay_index: FloatingIndex,
+    pay_spread: f64,
+    receive_schedule: Schedule,
+    receive_index: FloatingIndex,
+    receive_spread: f64,
+    payment_lag: u32,
+}
+
+impl BasisSwap {
+    /// Creates a basis swap with no spreads, whose flows are paid at the end of each period.
+    ///
+    /// # Arguments
+    ///
+    /// * `nominal` - The nominal both legs accrue on.
+    /// * `pay_schedule` - The periods of the paid leg.
+    /// * `pay_index` - The index of the paid leg.
+    /// * `receive_schedule` - The periods of the received leg.
+    /// * `receive_index` - The index of the received leg.
+    pub fn new(
+        nominal: f64,
+        pay_schedule: Schedule,
+        pay_index: FloatingIndex,
+        receive_schedule: Schedule,
+        receive_index: FloatingIndex,
+    ) -> Self {
+        BasisSwap {
+            nominal,
+            pay_schedule,
+            pay_index,
+            pay_spread: 0.0,
+            receive_schedule,
+            receive_index,
+            receive_spread: 0.0,
+            payment_lag: 0,
+        }
+    }
+
+    /// Adds `spread` to the index rate of the paid leg.
+    pub fn with_pay_spread(mut self, spread: f64) -> Self {
+        self.pay_spread = spread;
+        self
+    }
+
+    /// Adds `spread` to the index rate of the received leg.
+    pub fn with_receive_spread(mut self, spread: f64) -> Self {
+        self.receive_spread = spread;
+        self
+    }
+
+    /// Pays the flows of both legs `days` business days after the end of each period.
+    pub fn with_payment_lag(mut self, days: u32) -> Self {
+        self.payment_lag = days;
+        self
+    }
+
+    pub fn nominal(&self) -> f64 {
+        self.nominal
+    }
+
+    pub fn pay_schedule(&self) -> &Schedule {
+        &self.pay_schedule
+    }
+
+    pub fn pay_index(&self) -> &FloatingIndex {
+        &self.pay_index
+    }
+
+    pub fn pay_spread(&self) -> f64 {
+        self.pay_spread
+    }
+
+    pub fn receive_schedule(&self) -> &Schedule {
+        &self.receive_schedule
+    }
+
+    pub fn receive_index(&self) -> &FloatingIndex {
+        &self.receive_index
+    }
+
+    pub fn receive_spread(&self) -> f64 {
+        self.receive_spread
+    }
+
+    pub fn payment_lag(&self) -> u32 {
+        self.payment_lag
+    }
+
+    /// The coupons of the paid leg.
+    pub fn pay_leg(&self) -> Result<Leg, LibraError> {
+        self.pay_leg_on(self.pay_index.forward_curve().clone())
+    }
+
+    /// The coupons of the paid leg, with fixings forecast off `forward_curve`.
+    pub fn pay_leg_on(&self, forward_curve: Rc<dyn YieldTermStructure>) -> Result<Leg, LibraError> {
+        self.pay_index
+            .clone()
+            .with_forward_curve(forward_curve)
+            .leg(
+                &self.pay_schedule,
+                vec![self.nominal],
+                self.pay_spread,
+                self.payment_lag,
+            )
+    }
+
+    /// The coupons of the received leg.
+    pub fn receive_leg(&self) -> Result<Leg, LibraError> {
+        self.receive_leg_on(self.receive_index.forward_curve().clone())
+    }
+
+    /// The coupons of the received leg, with fixings forecast off `forward_curve`.
+    pub fn receive_leg_on(
+        &self,
+        forward_curve: Rc<dyn YieldTermStructure>,
+    ) -> Result<Leg, LibraError> {
+        self.receive_index
+            .clone()
+            .with_forward_curve(forward_curve)
+            .leg(
+                &self.receive_schedule,
+                vec![self.nominal],
+                self.receive_spread,
+                self.payment_lag,
+            )
+    }
+}
@@ -1,120 +1,424 @@
-use core::error;
 use std::fmt;
 
 use chrono::NaiveDate;
 
-use crate::cashflows::{CashFlow, Coupons};
+use crate::cashflows::{
+    CashFlow, CpiIndexation, CpiLeg, FixedRateLeg, FloatingRateLeg, InterestRate, Leg, LegFlow,
+    Redemption,
+};
 
-use crate::error::InvalidDate;
-use crate::time::{Calendar, DateAdjustment, DayCountConvention};
+use crate::error::{InvalidDate, LibraError};
+use crate::indexes::IborIndex;
+use crate::time::{Calendar, DateAdjustment, DayCountConvention, Schedule};
 
+/// A bond paying the flows of a `Leg`.
+///
+/// Prices are quoted per 100 of the notional outstanding at the settlement date.
 #[derive(Clone, Debug)]
 pub struct Bond {
+    settlement_days: u32,
     calendar: Calendar,
-    day_count: DayCountConvention,
-    date_adjustment: DateAdjustment,
     issue_date: NaiveDate,
     maturity_date: NaiveDate,
-    face_value: f64,
-    principle: f64,
-    coupons: Option<Coupons>,
+    issue_price: Option<f64>,
+    leg: Leg,
 }
 
 impl Bond {
-    pub fn new(
+    /// Creates a bond paying the flows of `leg`, which may include amortizing or sinking-fund
+    /// redemptions and step-up coupons.
+    ///
+    /// # Arguments
+    ///
+    /// * `settlement_days` - The number of business days between trade and settlement.
+    /// * `calendar` - The calendar used to determine settlement dates.
+    /// * `issue_date` - The date the bond was issued.
+    /// * `leg` - The interest and redemption flows of the bond.
+    ///
+    /// # Returns
+    ///
+    /// The bond, or an error if `leg` has no flows after `issue_date`.
+    pub fn from_leg(
+        settlement_days: u32,
         calendar: Calendar,
-        day_count: DayCountConvention,
-        date_adjustment: DateAdjustment,
         issue_date: NaiveDate,
-        maturity_date: NaiveDate,
-        face_value: f64,
-        principle: f64,
-        coupons: Option<Coupons>,
-    ) -> Self {
-        Bond {
+        leg: Leg,
+    ) -> Result<Self, LibraError> {
+        let maturity_date = match leg.maturity_date() {
+            Some(date) if date > issue_date => date,
+            _ => return Err(InvalidDate.into()),
+        };
+
+        Ok(Bond {
+            settlement_days,
             calendar,
-            day_count,
-            date_adjustment,
             issue_date,
             maturity_date,
-            face_value,
-            principle,
-            coupons,
-        }
+            issue_price: None,
+            leg,
+        })
+    }
+
+    /// Sets the price, per 100 of face amount, at which the bond was issued.
+    pub fn with_issue_price(mut self, issue_price: f64) -> Self {
+        self.issue_price = Some(issue_price);
+        self
+    }
+
+    pub fn settlement_days(&self) -> u32 {
+        self.settlement_days
+    }
+
+    pub fn calendar(&self) -> Calendar {
+        self.calendar
+    }
+
+    pub fn issue_date(&self) -> NaiveDate {
+        self.issue_date
+    }
+
+    pub fn maturity_date(&self) -> NaiveDate {
+        self.maturity_date
+    }
+
+    pub fn issue_price(&self) -> Option<f64> {
+        self.issue_price
+    }
+
+    /// The notional outstanding when the bond is issued.
+    pub fn face_amount(&self) -> f64 {
+        self.notional(self.issue_date)
+    }
+
+    /// The final redemption of the bond.
+    pub fn redemption(&self) -> Option<&Redemption> {
+        self.leg.redemptions().last()
+    }
+
+    /// The date on which a trade made on `trade_date` settles.
+    pub fn settlement_date(&self, trade_date: NaiveDate) -> NaiveDate {
+        self.calendar
+            .advance(trade_date, self.settlement_days as i64)
     }
 
+    /// The price including accrued interest, for settlement on `settlement`.
+    ///
+    /// # Arguments
+    ///
+    /// * `yield_to_maturity` - The yield used to discount all flows after `settlement`.
+    /// * `settlement` - The settlement date to discount back to.
+    ///
+    /// # Returns
+    ///
+    /// The dirty price per 100 of outstanding notional.
     pub fn dirty_price(
         &self,
-        yield_to_maturity: f64,
-        today: NaiveDate,
-    ) -> Result<f64, InvalidDate> {
-        let values = self
-            .cash_flows()
-            .iter()
-            .filter(|c| c.date() >= today)
-            .map(|c| c.compound_present_value(&today, yield_to_maturity, self.day_count))
-            .collect::<Result<Vec<f64>, InvalidDate>>();
-
-        match values {
-            Ok(values) => Ok(values.iter().sum::<f64>()),
-            Err(err) => Err(err),
-        }
+        yield_to_maturity: &InterestRate,
+        settlement: NaiveDate,
+    ) -> Result<f64, LibraError> {
+        let notional = self.outstanding_notional(settlement)?;
+
+        Ok(self.leg.present_value(&settlement, yield_to_maturity)? / notional * 100.0)
     }
 
+    /// The price excluding accrued interest, for settlement on `settlement`.
     pub fn clean_price(
         &self,
-        yield_to_maturity: f64,
-        today: NaiveDate,
-    ) -> Result<f64, InvalidDate> {
-        if today > self.maturity_date {
-            return Err(InvalidDate);
+        yield_to_maturity: &InterestRate,
+        settlement: NaiveDate,
+    ) -> Result<f64, LibraError> {
+        Ok(self.dirty_price(yield_to_maturity, settlement)? - self.accrued_amount(settlement)?)
+    }
+
+    /// The interest accrued at `settlement`, per 100 of outstanding notional.
+    pub fn accrued_amount(&self, settlement: NaiveDate) -> Result<f64, LibraError> {
+        let notional = self.outstanding_notional(settlement)?;
+
+        Ok(self.leg.accrued_amount(&settlement)? / notional * 100.0)
+    }
+
+    /// The principal outstanding at `date`, after any redemptions paid on or before it.
+    pub fn notional(&self, date: NaiveDate) -> f64 {
+        self.leg.outstanding_notional(&date)
+    }
+
+    pub fn leg(&self) -> &Leg {
+        &self.leg
+    }
+
+    pub fn cash_flows(&self) -> Result<Vec<CashFlow>, LibraError> {
+        self.leg.cash_flows()
+    }
+
+    /// The outstanding notional, or an error if the bond has matured by `settlement`.
+    fn outstanding_notional(&self, settlement: NaiveDate) -> Result<f64, InvalidDate> {
+        match self.notional(settlement) {
+            n if n == 0.0 || settlement >= self.maturity_date => Err(InvalidDate),
+            n => Ok(n),
         }
+    }
+}
 
-        let cash_flows = self.cash_flows();
-
-        let prev = match cash_flows.iter().position(|c| c.date() > today) {
-            Some(i) => {
-                if i > 0 {
-                    cash_flows.get(i - 1)
-                } else {
-                    None
-                }
-            }
-            None => return Err(InvalidDate),
-        };
+impl fmt::Display for Bond {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Bond:\n Calendar: {}, Settlement Days: {}\n Issued: {}, Maturity: {}\n Face: {}, Redemption: {:?}", self.calendar, self.settlement_days, self.issue_date, self.maturity_date, self.face_amount(), self.redemption().map(|r| r.percentage()))
+    }
+}
 
-        let prev = match prev {
-            Some(p) => p,
-            None => return self.dirty_price(yield_to_maturity, today),
-        };
+/// A bond paying only a redemption at maturity.
+#[derive(Clone, Debug)]
+pub struct ZeroCouponBond {
+    settlement_days: u32,
+    calendar: Calendar,
+    face_amount: f64,
+    issue_date: NaiveDate,
+    maturity_date: NaiveDate,
+    adjustment: DateAdjustment,
+    redemption: f64,
+    issue_price: Option<f64>,
+}
 
-        let accrued = prev.value() * self.day_count.year_frac(&prev.date(), &today)?;
+impl ZeroCouponBond {
+    pub fn new(
+        settlement_days: u32,
+        calendar: Calendar,
+        face_amount: f64,
+        issue_date: NaiveDate,
+        maturity_date: NaiveDate,
+    ) -> Self {
+        ZeroCouponBond {
+            settlement_days,
+            calendar,
+            face_amount,
+            issue_date,
+            maturity_date,
+            adjustment: DateAdjustment::Following,
+            redemption: 100.0,
+            issue_price: None,
+        }
+    }
 
-        Ok(self.dirty_price(yield_to_maturity, today)? - accrued)
+    /// Adjusts the maturity date with `adjustment` rather than to the following business day.
+    pub fn with_adjustment(mut self, adjustment: DateAdjustment) -> Self {
+        self.adjustment = adjustment;
+        self
     }
 
-    pub fn cash_flows(&self) -> Vec<CashFlow> {
-        let mut cash_flows = Vec::new();
+    /// Redeems at `percentage` percent of face amount rather than at par.
+    pub fn with_redemption(mut self, percentage: f64) -> Self {
+        self.redemption = percentage;
+        self
+    }
 
-        match self.coupons {
-            None => {}
-            Some(coupons) => {
-                cash_flows.append(
-                    &mut coupons
-                        .cash_flows(self.issue_date, self.maturity_date, self.principle)
-                        .unwrap(),
-                );
-            }
-        };
+    /// Sets the price, per 100 of face amount, at which the bond was issued.
+    pub fn with_issue_price(mut self, issue_price: f64) -> Self {
+        self.issue_price = Some(issue_price);
+        self
+    }
+
+    pub fn build(&self) -> Result<Bond, LibraError> {
+        let maturity = self.calendar.adjust(self.maturity_date, self.adjustment);
+        let leg = Leg::new(vec![LegFlow::Redemption(Redemption::new(
+            self.face_amount,
+            self.redemption,
+            maturity,
+        ))]);
 
-        cash_flows.push(CashFlow::new(self.principle, self.maturity_date));
+        let bond = Bond::from_leg(self.settlement_days, self.calendar, self.issue_date, leg)?;
 
-        cash_flows
+        Ok(match self.issue_price {
+            Some(price) => bond.with_issue_price(price),
+            None => bond,
+        })
     }
 }
 
-impl fmt::Display for Bond {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Bond:\n Calendar: {}, Day Count: {}, Date Adjustment: {}\n Issued: {}, Maturity: {}\n Face: {}\n Coupon: {:?}", self.calendar, self.day_count, self.date_adjustment, self.issue_date, self.maturity_date, self.face_value, self.coupons)
+/// A bond paying fixed rate coupons on a schedule, issued at the start of the schedule.
+#[derive(Clone, Debug)]
+pub struct FixedRateBond {
+    settlement_days: u32,
+    leg: FixedRateLeg,
+    calendar: Calendar,
+    issue_date: NaiveDate,
+    issue_price: Option<f64>,
+}
+
+impl FixedRateBond {
+    pub fn new(
+        settlement_days: u32,
+        face_amount: f64,
+        schedule: Schedule,
+        coupon_rate: f64,
+        day_count: DayCountConvention,
+    ) -> Self {
+        FixedRateBond {
+            settlement_days,
+            calendar: schedule.calendar(),
+            issue_date: schedule.start_date(),
+            leg: FixedRateLeg::new(schedule, day_count)
+                .with_notional(face_amount)
+                .with_rate(coupon_rate),
+            issue_price: None,
+        }
+    }
+
+    /// Uses a coupon rate per period, for step-up bonds.
+    pub fn with_rates(mut self, rates: Vec<f64>) -> Self {
+        self.leg = self.leg.with_rates(rates);
+        self
+    }
+
+    /// Redeems at `percentage` percent of face amount rather than at par.
+    pub fn with_redemption(mut self, percentage: f64) -> Self {
+        self.leg = self.leg.with_redemption(percentage);
+        self
+    }
+
+    /// Sets the price, per 100 of face amount, at which the bond was issued.
+    pub fn with_issue_price(mut self, issue_price: f64) -> Self {
+        self.issue_price = Some(issue_price);
+        self
+    }
+
+    pub fn build(&self) -> Result<Bond, LibraError> {
+        let bond = Bond::from_leg(
+            self.settlement_days,
+            self.calendar,
+            self.issue_date,
+            self.leg.build()?,
+        )?;
+
+        Ok(match self.issue_price {
+            Some(price) => bond.with_issue_price(price),
+            None => bond,
+        })
+    }
+}
+
+/// A bond paying coupons on an index plus a spread, issued at the start of the schedule.
+#[derive(Clone, Debug)]
+pub struct FloatingRateBond {
+    settlement_days: u32,
+    leg: FloatingRateLeg,
+    calendar: Calendar,
+    issue_date: NaiveDate,
+    issue_price: Option<f64>,
+}
+
+impl FloatingRateBond {
+    pub fn new(
+        settlement_days: u32,
+        face_amount: f64,
+        schedule: Schedule,
+        index: IborIndex,
+        day_count: DayCountConvention,
+    ) -> Self {
+        FloatingRateBond {
+            settlement_days,
+            calendar: schedule.calendar(),
+            issue_date: schedule.start_date(),
+            leg: FloatingRateLeg::new(schedule, index, day_count).with_notional(face_amount),
+            issue_price: None,
+        }
+    }
+
+    /// Pays `spread` over the index.
+    pub fn with_spread(mut self, spread: f64) -> Self {
+        self.leg = self.leg.with_spread(spread);
+        self
+    }
+
+    /// Redeems at `percentage` percent of face amount rather than at par.
+    pub fn with_redemption(mut self, percentage: f64) -> Self {
+        self.leg = self.leg.with_redemption(percentage);
+        self
+    }
+
+    /// Sets the price, per 100 of face amount, at which the bond was issued.
+    pub fn with_issue_price(mut self, issue_price: f64) -> Self {
+        self.issue_price = Some(issue_price);
+        self
+    }
+
+    pub fn build(&self) -> Result<Bond, LibraError> {
+        let bond = Bond::from_leg(
+            self.settlement_days,
+            self.calendar,
+            self.issue_date,
+            self.leg.build()?,
+        )?;
+
+        Ok(match self.issue_price {
+            Some(price) => bond.with_issue_price(price),
+            None => bond,
+        })
+    }
+}
+
+/// A bond paying a real coupon rate on a notional indexed to inflation, such as an index-linked
+/// gilt or a TIPS, issued at the start of the schedule.
+#[derive(Clone, Debug)]
+pub struct InflationLinkedBond {
+    settlement_days: u32,
+    leg: CpiLeg,
+    calendar: Calendar,
+    issue_date: NaiveDate,
+    issue_price: Option<f64>,
+}
+
+impl InflationLinkedBond {
+    /// Creates an inflation-linked bond.
+    ///
+    /// # Arguments
+    ///
+    /// * `settlement_days` - The number of business days between trade and settlement.
+    /// * `face_amount` - The unindexed notional.
+    /// * `schedule` - The coupon schedule.
+    /// * `real_coupon_rate` - The coupon rate paid on the indexed notional.
+    /// * `day_count` - The day count convention used for accrual.
+    /// * `indexation` - The index, its observation lag and the base value of the bond.
+    pub fn new(
+        settlement_days: u32,
+        face_amount: f64,
+        schedule: Schedule,
+        real_coupon_rate: f64,
+        day_count: DayCountConvention,
+        indexation: CpiIndexation,
+    ) -> Self {
+        InflationLinkedBond {
+            settlement_days,
+            calendar: schedule.calendar(),
+            issue_date: schedule.start_date(),
+            leg: CpiLeg::new(schedule, indexation, day_count)
+                .with_notional(face_amount)
+                .with_rate(real_coupon_rate),
+            issue_price: None,
+        }
+    }
+
+    /// Floors the index ratio of the redemption at one, as for TIPS.
+    pub fn with_redemption_floor(mut self) -> Self {
+        self.leg = self.leg.with_redemption_floor();
+        self
+    }
+
+    /// Sets the price, per 100 of face amount, at which the bond was issued.
+    pub fn with_issue_price(mut self, issue_price: f64) -> Self {
+        self.issue_price = Some(issue_price);
+        self
+    }
+
+    pub fn build(&self) -> Result<Bond, LibraError> {
+        let bond = Bond::from_leg(
+            self.settlement_days,
+            self.calendar,
+            self.issue_date,
+            self.leg.build()?,
+        )?;
+
+        Ok(match self.issue_price {
+            Some(price) => bond.with_issue_price(price),
+            None => bond,
+        })
     }
 }
@@ -0,0 +1,139 @@
+use std::fmt;
+
+use chrono::NaiveDate;
+
+use crate::error::{ArgumentRange, InvalidDate, LibraError};
+use crate::time::DayCountConvention;
+
+use super::Bond;
+
+/// The exchange a government bond future is listed on, which sets the conventions of its
+/// conversion factors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BondFutureExchange {
+    /// CME Treasury futures, with conversion factors found at a 6% semiannual yield from the
+    /// first day of the delivery month, with the time to maturity rounded down to whole
+    /// quarters.
+    Cme,
+    /// Eurex Bund, Bobl, Schatz and Buxl futures, with conversion factors found at a 6% annual
+    /// yield on the delivery date.
+    Eurex,
+    /// ICE long gilt futures, with conversion factors found at a 4% semiannual yield from the
+    /// first day of the delivery month under the DMO price formula.
+    IceGilt,
+}
+
+impl BondFutureExchange {
+    /// The coupon rate of the contract's notional bond, at which conversion factors are found.
+    pub fn notional_coupon(&self) -> f64 {
+        match self {
+            BondFutureExchange::Cme | BondFutureExchange::Eurex => 0.06,
+            BondFutureExchange::IceGilt => 0.04,
+        }
+    }
+
+    /// The face amount of the bonds delivered against one contract.
+    pub fn contract_size(&self) -> f64 {
+        100_000.0
+    }
+
+    /// The day count convention used for repo rates in the currency of the contract.
+    pub fn repo_day_count(&self) -> DayCountConvention {
+        match self {
+            BondFutureExchange::Cme | BondFutureExchange::Eurex => DayCountConvention::Actual360,
+            BondFutureExchange::IceGilt => DayCountConvention::Actual365Fixed,
+        }
+    }
+}
+
+impl fmt::Display for BondFutureExchange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BondFutureExchange::Cme => write!(f, "CME"),
+            BondFutureExchange::Eurex => write!(f, "Eurex"),
+            BondFutureExchange::IceGilt => write!(f, "ICE"),
+        }
+    }
+}
+
+/// A government bond future, delivering any bond from a basket on the delivery date in
+/// exchange for the futures price scaled by the bond's conversion factor.
+#[derive(Clone, Debug)]
+pub struct BondFuture {
+    exchange: BondFutureExchange,
+    delivery_date: NaiveDate,
+    notional_coupon: f64,
+    deliverables: Vec<Bond>,
+}
+
+impl BondFuture {
+    /// Creates a bond future.
+    ///
+    /// # Arguments
+    ///
+    /// * `exchange` - The exchange the future is listed on.
+    /// * `delivery_date` - The date the bond is delivered and the invoice amount paid.
+    /// * `deliverables` - The basket of bonds that may be delivered.
+    ///
+    /// # Returns
+    ///
+    /// The future, or an error if the basket is empty or any bond matures by the delivery date.
+    pub fn new(
+        exchange: BondFutureExchange,
+        delivery_date: NaiveDate,
+        deliverables: Vec<Bond>,
+    ) -> Result<Self, LibraError> {
+        if deliverables.is_empty() {
+            return Err(ArgumentRange::new("number of deliverable bonds", 1, i64::MAX, 0).into());
+        }
+
+        if deliverables
+            .iter()
+            .any(|bond| bond.maturity_date() <= delivery_date)
+        {
+            return Err(InvalidDate.into());
+        }
+
+        Ok(BondFuture {
+            exchange,
+            delivery_date,
+            notional_coupon: exchange.notional_coupon(),
+            deliverables,
+        })
+    }
+
+    /// Finds conversion factors at `notional_coupon` instead of the exchange's standard rate.
+    pub fn with_notional_coupon(mut self, notional_coupon: f64) -> Self {
+        self.notional_coupon = notional_coupon;
+        self
+    }
+
+    pub fn exchange(&self) -> BondFutureExchange {
+        self.exchange
+    }
+
+    pub fn delivery_date(&self) -> NaiveDate {
+        self.delivery_date
+    }
+
+    pub fn notional_coupon(&self) -> f64 {
+        self.notional_coupon
+    }
+
+    pub fn deliverables(&self) -> &[Bond] {
+        &self.deliverables
+    }
+}
+
+impl fmt::Display for BondFuture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} bond future delivering {}\n Notional coupon {}\n Deliverable bonds {}",
+            self.exchange,
+            self.delivery_date,
+            self.notional_coupon,
+            self.deliverables.len()
+        )
+    }
+}
@@ -0,0 +1,167 @@
+use std::fmt;
+
+use chrono::{Days, NaiveDate};
+
+use super::Bond;
+
+/// Whether the issuer may redeem the bond early or the holder may demand early redemption.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CallabilityType {
+    /// The issuer may redeem the bond.
+    Call,
+    /// The holder may demand redemption.
+    Put,
+}
+
+impl fmt::Display for CallabilityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CallabilityType::Call => write!(f, "Call"),
+            CallabilityType::Put => write!(f, "Put"),
+        }
+    }
+}
+
+/// A single date on which a bond may be called or put.
+#[derive(Clone, Copy, Debug)]
+pub struct Callability {
+    kind: CallabilityType,
+    price: f64,
+    date: NaiveDate,
+}
+
+impl Callability {
+    /// Creates a callability exercised on `date` at a clean `price` per 100 of notional.
+    pub fn new(kind: CallabilityType, price: f64, date: NaiveDate) -> Self {
+        Callability { kind, price, date }
+    }
+
+    pub fn kind(&self) -> CallabilityType {
+        self.kind
+    }
+
+    /// The clean exercise price per 100 of outstanding notional.
+    pub fn price(&self) -> f64 {
+        self.price
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+}
+
+/// A make-whole call, allowing the issuer to redeem at any time in a window at the greater of a
+/// floor price and the value of the remaining flows discounted at a spread over the curve.
+#[derive(Clone, Copy, Debug)]
+pub struct MakeWholeCall {
+    start: NaiveDate,
+    end: NaiveDate,
+    spread: f64,
+    floor: f64,
+}
+
+impl MakeWholeCall {
+    /// Creates a make-whole call.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first date the bond may be called.
+    /// * `end` - The last date the bond may be called.
+    /// * `spread` - The spread over the discount curve used to value the remaining flows.
+    /// * `floor` - The minimum clean call price per 100 of notional, usually par.
+    pub fn new(start: NaiveDate, end: NaiveDate, spread: f64, floor: f64) -> Self {
+        MakeWholeCall {
+            start,
+            end,
+            spread,
+            floor,
+        }
+    }
+
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    pub fn end(&self) -> NaiveDate {
+        self.end
+    }
+
+    pub fn spread(&self) -> f64 {
+        self.spread
+    }
+
+    pub fn floor(&self) -> f64 {
+        self.floor
+    }
+}
+
+/// A bond with embedded Bermudan call or put options, and optionally a make-whole call.
+#[derive(Clone, Debug)]
+pub struct CallableBond {
+    bond: Bond,
+    callabilities: Vec<Callability>,
+    make_whole: Option<MakeWholeCall>,
+    notice_days: u64,
+}
+
+impl CallableBond {
+    /// Creates a callable or puttable bond from the underlying `bond` and its exercise dates.
+    pub fn new(bond: Bond, mut callabilities: Vec<Callability>) -> Self {
+        callabilities.sort_by_key(|c| c.date());
+
+        CallableBond {
+            bond,
+            callabilities,
+            make_whole: None,
+            notice_days: 0,
+        }
+    }
+
+    /// Adds a make-whole call.
+    pub fn with_make_whole(mut self, make_whole: MakeWholeCall) -> Self {
+        self.make_whole = Some(make_whole);
+        self
+    }
+
+    /// Requires exercise to be notified `days` calendar days before each exercise date.
+    pub fn with_notice_days(mut self, days: u64) -> Self {
+        self.notice_days = days;
+        self
+    }
+
+    pub fn bond(&self) -> &Bond {
+        &self.bond
+    }
+
+    pub fn callabilities(&self) -> &[Callability] {
+        &self.callabilities
+    }
+
+    pub fn make_whole(&self) -> Option<&MakeWholeCall> {
+        self.make_whole.as_ref()
+    }
+
+    pub fn notice_days(&self) -> u64 {
+        self.notice_days
+    }
+
+    /// The date by which exercise on `callability` must be notified.
+    pub fn notice_date(&self, callability: &Callability) -> NaiveDate {
+        callability
+            .date()
+            .checked_sub_days(Days::new(self.notice_days))
+            .unwrap_or(callability.date())
+    }
+}
+
+impl fmt::Display for CallableBond {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Callable {}", self.bond)?;
+
+        for c in &self.callabilities {
+            write!(f, "\n {} at {} on {}", c.kind(), c.price(), c.date())?;
+        }
+
+        Ok(())
+    }
+}
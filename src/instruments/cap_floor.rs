@@ -0,0 +1,145 @@
+use std::fmt;
+
+use crate::cashflows::{FloatingRateCoupon, Leg, LegFlow};
+use crate::error::{ArgumentRange, DifferentVariant, LibraError};
+
+/// Whether a cap floor instrument limits the rate paid from above, below, or both.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CapFloorType {
+    /// A strip of calls on the coupon rates, paying their excess over the cap rates.
+    Cap,
+    /// A strip of puts on the coupon rates, paying their shortfall below the floor rates.
+    Floor,
+    /// A long cap and a short floor, limiting the coupon rates of a floating rate borrower to
+    /// between the floor and cap rates.
+    Collar,
+}
+
+impl fmt::Display for CapFloorType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CapFloorType::Cap => write!(f, "Cap"),
+            CapFloorType::Floor => write!(f, "Floor"),
+            CapFloorType::Collar => write!(f, "Collar"),
+        }
+    }
+}
+
+/// A cap, floor or collar on the coupons of a floating rate leg, each of whose coupons is an
+/// optionlet on its rate paid on the coupon's nominal and accrual period.
+///
+/// Cap and floor rates are given per coupon, with the last rate applying to any remaining
+/// coupons, in the same way as the rates of a `FixedRateLeg`.
+#[derive(Clone, Debug)]
+pub struct CapFloor {
+    kind: CapFloorType,
+    coupons: Vec<FloatingRateCoupon>,
+    cap_rates: Vec<f64>,
+    floor_rates: Vec<f64>,
+}
+
+impl CapFloor {
+    /// Creates a cap on the floating coupons of `leg`, which is usually built without
+    /// redemptions.
+    ///
+    /// # Returns
+    ///
+    /// The cap, or an error if `leg` has a coupon which is not a floating rate coupon or the
+    /// number of rates is not between one and the number of coupons.
+    pub fn cap(leg: &Leg, cap_rates: Vec<f64>) -> Result<Self, LibraError> {
+        Self::new(CapFloorType::Cap, leg, cap_rates, Vec::new())
+    }
+
+    /// Creates a floor on the floating coupons of `leg`.
+    pub fn floor(leg: &Leg, floor_rates: Vec<f64>) -> Result<Self, LibraError> {
+        Self::new(CapFloorType::Floor, leg, Vec::new(), floor_rates)
+    }
+
+    /// Creates a collar on the floating coupons of `leg`, which is long a cap at `cap_rates`
+    /// and short a floor at `floor_rates`.
+    pub fn collar(
+        leg: &Leg,
+        cap_rates: Vec<f64>,
+        floor_rates: Vec<f64>,
+    ) -> Result<Self, LibraError> {
+        Self::new(CapFloorType::Collar, leg, cap_rates, floor_rates)
+    }
+
+    fn new(
+        kind: CapFloorType,
+        leg: &Leg,
+        cap_rates: Vec<f64>,
+        floor_rates: Vec<f64>,
+    ) -> Result<Self, LibraError> {
+        let coupons = leg
+            .coupons()
+            .map(|flow| match flow {
+                LegFlow::FloatingCoupon(coupon) => Ok(coupon.clone()),
+                _ => Err(DifferentVariant.into()),
+            })
+            .collect::<Result<Vec<_>, LibraError>>()?;
+
+        let per_coupon = |name: &str, rates: Vec<f64>, required: bool| {
+            if !required {
+                return Ok(Vec::new());
+            }
+
+            if rates.is_empty() || rates.len() > coupons.len() {
+                return Err(LibraError::from(ArgumentRange::new(
+                    &format!("number of {}", name),
+                    1,
+                    coupons.len() as i64,
+                    rates.len() as i64,
+                )));
+            }
+
+            Ok((0..coupons.len())
+                .map(|i| rates[i.min(rates.len() - 1)])
+                .collect())
+        };
+
+        let cap_rates = per_coupon("cap rates", cap_rates, kind != CapFloorType::Floor)?;
+        let floor_rates = per_coupon("floor rates", floor_rates, kind != CapFloorType::Cap)?;
+
+        Ok(CapFloor {
+            kind,
+            coupons,
+            cap_rates,
+            floor_rates,
+        })
+    }
+
+    pub fn kind(&self) -> CapFloorType {
+        self.kind
+    }
+
+    /// The coupons whose rates are capped or floored, in order of payment.
+    pub fn coupons(&self) -> &[FloatingRateCoupon] {
+        &self.coupons
+    }
+
+    /// The cap rate of each coupon, which is empty for floors.
+    pub fn cap_rates(&self) -> &[f64] {
+        &self.cap_rates
+    }
+
+    /// The floor rate of each coupon, which is empty for caps.
+    pub fn floor_rates(&self) -> &[f64] {
+        &self.floor_rates
+    }
+}
+
+impl fmt::Display for CapFloor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} on {} coupons", self.kind, self.coupons.len())?;
+
+        if let Some(rate) = self.cap_rates.first() {
+            write!(f, ", cap at {:.4}%", rate * 100.0)?;
+        }
+        if let Some(rate) = self.floor_rates.first() {
+            write!(f, ", floor at {:.4}%", rate * 100.0)?;
+        }
+
+        Ok(())
+    }
+}
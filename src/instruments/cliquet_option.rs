@@ -0,0 +1,150 @@
+use std::fmt;
+
+use chrono::NaiveDate;
+
+use crate::error::{InvalidDate, LibraError};
+
+use super::{Exercise, OptionType};
+
+/// A cliquet, or ratchet, option, which pays the sum of coupons on the returns of the underlying
+/// over consecutive periods, whose strikes reset to the price at the start of each period.
+///
+/// The coupon of each period is `omega (S_end / S_start - moneyness)`, where `omega` is one for a
+/// call and minus one for a put, bounded below by the local floor and above by any local cap. The
+/// sum of the coupons is bounded by any global floor and cap, and paid at expiry.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CliquetOption {
+    option_type: OptionType,
+    moneyness: f64,
+    reset_dates: Vec<NaiveDate>,
+    exercise: Exercise,
+    local_floor: f64,
+    local_cap: Option<f64>,
+    global_floor: Option<f64>,
+    global_cap: Option<f64>,
+}
+
+impl CliquetOption {
+    /// Creates a cliquet option whose coupons are floored at zero, so that each is a
+    /// forward-starting option on the return over its period.
+    ///
+    /// # Arguments
+    ///
+    /// * `option_type` - Whether the coupons are paid on rises or falls of the underlying.
+    /// * `moneyness` - The strike of each period as a fraction of the price at its start.
+    /// * `reset_dates` - The start dates of the periods, in any order. Each period ends at the
+    ///   start of the next, and the last at expiry.
+    /// * `exercise` - The exercise of the option.
+    ///
+    /// # Returns
+    ///
+    /// The option, or an error if there are no reset dates.
+    pub fn new(
+        option_type: OptionType,
+        moneyness: f64,
+        mut reset_dates: Vec<NaiveDate>,
+        exercise: Exercise,
+    ) -> Result<Self, LibraError> {
+        if reset_dates.is_empty() {
+            return Err(InvalidDate.into());
+        }
+
+        reset_dates.sort();
+        reset_dates.dedup();
+
+        Ok(CliquetOption {
+            option_type,
+            moneyness,
+            reset_dates,
+            exercise,
+            local_floor: 0.0,
+            local_cap: None,
+            global_floor: None,
+            global_cap: None,
+        })
+    }
+
+    /// Floors each coupon at `floor` instead of zero.
+    pub fn with_local_floor(mut self, floor: f64) -> Self {
+        self.local_floor = floor;
+        self
+    }
+
+    /// Caps each coupon at `cap`.
+    pub fn with_local_cap(mut self, cap: f64) -> Self {
+        self.local_cap = Some(cap);
+        self
+    }
+
+    /// Floors the sum of the coupons at `floor`.
+    pub fn with_global_floor(mut self, floor: f64) -> Self {
+        self.global_floor = Some(floor);
+        self
+    }
+
+    /// Caps the sum of the coupons at `cap`.
+    pub fn with_global_cap(mut self, cap: f64) -> Self {
+        self.global_cap = Some(cap);
+        self
+    }
+
+    pub fn option_type(&self) -> OptionType {
+        self.option_type
+    }
+
+    pub fn moneyness(&self) -> f64 {
+        self.moneyness
+    }
+
+    /// The start dates of the periods, in ascending order.
+    pub fn reset_dates(&self) -> &[NaiveDate] {
+        &self.reset_dates
+    }
+
+    pub fn exercise(&self) -> &Exercise {
+        &self.exercise
+    }
+
+    pub fn local_floor(&self) -> f64 {
+        self.local_floor
+    }
+
+    pub fn local_cap(&self) -> Option<f64> {
+        self.local_cap
+    }
+
+    pub fn global_floor(&self) -> Option<f64> {
+        self.global_floor
+    }
+
+    pub fn global_cap(&self) -> Option<f64> {
+        self.global_cap
+    }
+
+    /// The coupon of a period over which the underlying grows by `ratio`.
+    pub fn coupon(&self, ratio: f64) -> f64 {
+        let coupon = (self.option_type.sign() * (ratio - self.moneyness)).max(self.local_floor);
+
+        self.local_cap.map_or(coupon, |cap| coupon.min(cap))
+    }
+
+    /// The amount paid when the coupons sum to `total`.
+    pub fn value(&self, total: f64) -> f64 {
+        let floored = self.global_floor.map_or(total, |floor| total.max(floor));
+
+        self.global_cap.map_or(floored, |cap| floored.min(cap))
+    }
+}
+
+impl fmt::Display for CliquetOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Cliquet {} at moneyness {} with {} resets, {}",
+            self.option_type,
+            self.moneyness,
+            self.reset_dates.len(),
+            self.exercise
+        )
+    }
+}
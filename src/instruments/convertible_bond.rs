@@ -0,0 +1,120 @@
+use std::fmt;
+
+use crate::cashflows::Dividend;
+use crate::time::DateAdjustment;
+
+use super::{Bond, Callability};
+
+/// A bond the holder may exchange for shares of the issuer's equity, with optional call and put
+/// features.
+#[derive(Clone, Debug)]
+pub struct ConvertibleBond {
+    bond: Bond,
+    conversion_ratio: f64,
+    callabilities: Vec<Callability>,
+    soft_call_trigger: Option<f64>,
+    dividends: Vec<Dividend>,
+}
+
+impl ConvertibleBond {
+    /// Creates a convertible bond.
+    ///
+    /// # Arguments
+    ///
+    /// * `bond` - The underlying bond, whose flows are paid while the bond is not converted.
+    /// * `conversion_ratio` - The number of shares received per 100 of outstanding notional.
+    ///
+    /// # Returns
+    ///
+    /// The bond, convertible at any time up to maturity.
+    pub fn new(bond: Bond, conversion_ratio: f64) -> Self {
+        ConvertibleBond {
+            bond,
+            conversion_ratio,
+            callabilities: Vec::new(),
+            soft_call_trigger: None,
+            dividends: Vec::new(),
+        }
+    }
+
+    /// Adds dates on which the issuer may call or the holder may put the bond.
+    pub fn with_callabilities(mut self, callabilities: Vec<Callability>) -> Self {
+        self.callabilities.extend(callabilities);
+        self.callabilities.sort_by_key(|c| c.date());
+        self
+    }
+
+    /// Only allows the issuer to call when the share price is at least `trigger` percent of the
+    /// conversion price.
+    pub fn with_soft_call_trigger(mut self, trigger: f64) -> Self {
+        self.soft_call_trigger = Some(trigger);
+        self
+    }
+
+    /// Sets the dividends expected on the underlying shares.
+    ///
+    /// Ex-dividend dates are moved to the following business day of the bond's calendar.
+    pub fn with_dividends(mut self, dividends: Vec<Dividend>) -> Self {
+        let calendar = self.bond.calendar();
+
+        self.dividends = dividends
+            .iter()
+            .map(|d| {
+                Dividend::new(
+                    d.amount(),
+                    calendar.adjust(d.date(), DateAdjustment::Following),
+                )
+            })
+            .collect();
+        self.dividends.sort_by_key(|d| d.date());
+        self
+    }
+
+    pub fn bond(&self) -> &Bond {
+        &self.bond
+    }
+
+    /// The number of shares received per 100 of outstanding notional.
+    pub fn conversion_ratio(&self) -> f64 {
+        self.conversion_ratio
+    }
+
+    /// The share price at which converting is worth par.
+    pub fn conversion_price(&self) -> f64 {
+        100.0 / self.conversion_ratio
+    }
+
+    pub fn callabilities(&self) -> &[Callability] {
+        &self.callabilities
+    }
+
+    /// The share price, as a percentage of the conversion price, needed for a call to be allowed.
+    pub fn soft_call_trigger(&self) -> Option<f64> {
+        self.soft_call_trigger
+    }
+
+    pub fn dividends(&self) -> &[Dividend] {
+        &self.dividends
+    }
+
+    /// The value of converting 100 of outstanding notional when the share price is `spot`.
+    pub fn parity(&self, spot: f64) -> f64 {
+        self.conversion_ratio * spot
+    }
+}
+
+impl fmt::Display for ConvertibleBond {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Convertible {}\n Conversion ratio {}",
+            self.bond, self.conversion_ratio
+        )?;
+
+        for c in &self.callabilities {
+            write!(f, "\n {} at {} on {}", c.kind(), c.price(), c.date())?;
+        }
+
+        Ok(())
+    }
+}
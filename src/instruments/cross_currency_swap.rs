@@ -0,0 +1,159 @@
+use std::fmt;
+
+use crate::indexes::{FloatingIndex, FxIndex};
+use crate::time::Schedule;
+
+/// How the notional of the domestic leg of a cross-currency swap evolves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NotionalReset {
+    /// The domestic notional is fixed for the life of the swap.
+    Constant,
+    /// The domestic notional is reset at the start of each period to the foreign notional at
+    /// the exchange rate fixed on that date, with the change in notional exchanged.
+    MarkToMarket,
+}
+
+impl fmt::Display for NotionalReset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotionalReset::Constant => write!(f, "Constant"),
+            NotionalReset::MarkToMarket => write!(f, "Mark-to-Market"),
+        }
+    }
+}
+
+/// A swap exchanging floating rates in two currencies on notionals which are themselves
+/// exchanged at the start and end of the swap.
+///
+/// The holder lends the foreign notional and borrows the domestic notional: it pays the foreign
+/// notional and receives the domestic notional at the start, receives the foreign leg and pays
+/// the domestic leg, and reverses the notional exchange at the end. The opposite position has
+/// the negated value.
+#[derive(Clone, Debug)]
+pub struct CrossCurrencySwap {
+    schedule: Schedule,
+    foreign_nominal: f64,
+    foreign_index: FloatingIndex,
+    foreign_spread: f64,
+    domestic_nominal: f64,
+    domestic_index: FloatingIndex,
+    domestic_spread: f64,
+    fx_index: FxIndex,
+    reset: NotionalReset,
+    initial_exchange: bool,
+    final_exchange: bool,
+}
+
+impl CrossCurrencySwap {
+    /// Creates a swap with constant notionals, no spreads, and notionals exchanged at both the
+    /// start and the end.
+    ///
+    /// # Arguments
+    ///
+    /// * `schedule` - The periods of both legs.
+    /// * `foreign_nominal` - The notional of the foreign leg, in the foreign currency.
+    /// * `foreign_index` - The index of the foreign leg.
+    /// * `domestic_nominal` - The initial notional of the domestic leg, in the domestic
+    ///   currency.
+    /// * `domestic_index` - The index of the domestic leg.
+    /// * `fx_index` - The exchange rate index fixed for notional resets.
+    pub fn new(
+        schedule: Schedule,
+        foreign_nominal: f64,
+        foreign_index: FloatingIndex,
+        domestic_nominal: f64,
+        domestic_index: FloatingIndex,
+        fx_index: FxIndex,
+    ) -> Self {
+        CrossCurrencySwap {
+            schedule,
+            foreign_nominal,
+            foreign_index,
+            foreign_spread: 0.0,
+            domestic_nominal,
+            domestic_index,
+            domestic_spread: 0.0,
+            fx_index,
+            reset: NotionalReset::Constant,
+            initial_exchange: true,
+            final_exchange: true,
+        }
+    }
+
+    /// Adds `spread` to the index rate of the foreign leg, which is where the cross-currency
+    /// basis is usually quoted.
+    pub fn with_foreign_spread(mut self, spread: f64) -> Self {
+        self.foreign_spread = spread;
+        self
+    }
+
+    /// Adds `spread` to the index rate of the domestic leg.
+    pub fn with_domestic_spread(mut self, spread: f64) -> Self {
+        self.domestic_spread = spread;
+        self
+    }
+
+    /// Resets the domestic notional according to `reset`.
+    pub fn with_notional_reset(mut self, reset: NotionalReset) -> Self {
+        self.reset = reset;
+        self
+    }
+
+    /// Does not exchange notionals at the start of the swap, as for a swap hedging an existing
+    /// position.
+    pub fn without_initial_exchange(mut self) -> Self {
+        self.initial_exchange = false;
+        self
+    }
+
+    /// Does not exchange notionals at the end of the swap.
+    pub fn without_final_exchange(mut self) -> Self {
+        self.final_exchange = false;
+        self
+    }
+
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    pub fn foreign_nominal(&self) -> f64 {
+        self.foreign_nominal
+    }
+
+    pub fn foreign_index(&self) -> &FloatingIndex {
+        &self.foreign_index
+    }
+
+    pub fn foreign_spread(&self) -> f64 {
+        self.foreign_spread
+    }
+
+    /// The notional of the domestic leg over the first period.
+    pub fn domestic_nominal(&self) -> f64 {
+        self.domestic_nominal
+    }
+
+    pub fn domestic_index(&self) -> &FloatingIndex {
+        &self.domestic_index
+    }
+
+    pub fn domestic_spread(&self) -> f64 {
+        self.domestic_spread
+    }
+
+    pub fn fx_index(&self) -> &FxIndex {
+        &self.fx_index
+    }
+
+    pub fn notional_reset(&self) -> NotionalReset {
+        self.reset
+    }
+
+    pub fn initial_exchange(&self) -> bool {
+        self.initial_exchange
+    }
+
+    pub fn final_exchange(&self) -> bool {
+        self.final_exchange
+    }
+}
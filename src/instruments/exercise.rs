@@ -0,0 +1,87 @@
+use std::fmt;
+
+use chrono::NaiveDate;
+
+use crate::error::{InvalidDate, LibraError};
+
+/// The dates on which an option may be exercised.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Exercise {
+    /// Exercisable only on its expiry date.
+    European(NaiveDate),
+    /// Exercisable on any day between an earliest and a latest date, inclusive.
+    American {
+        earliest: NaiveDate,
+        latest: NaiveDate,
+    },
+    /// Exercisable on any of a set of dates, in ascending order.
+    Bermudan(Vec<NaiveDate>),
+}
+
+impl Exercise {
+    /// Creates an American exercise between `earliest` and `latest`.
+    ///
+    /// # Returns
+    ///
+    /// The exercise, or an error if `latest` is before `earliest`.
+    pub fn american(earliest: NaiveDate, latest: NaiveDate) -> Result<Self, LibraError> {
+        if latest < earliest {
+            return Err(InvalidDate.into());
+        }
+
+        Ok(Exercise::American { earliest, latest })
+    }
+
+    /// Creates a Bermudan exercise from `dates` in any order.
+    ///
+    /// # Returns
+    ///
+    /// The exercise, or an error if `dates` is empty.
+    pub fn bermudan(mut dates: Vec<NaiveDate>) -> Result<Self, LibraError> {
+        if dates.is_empty() {
+            return Err(InvalidDate.into());
+        }
+
+        dates.sort();
+        dates.dedup();
+
+        Ok(Exercise::Bermudan(dates))
+    }
+
+    /// The dates on which the option may be exercised, in ascending order. For American
+    /// exercise, these are the earliest and latest dates.
+    pub fn dates(&self) -> Vec<NaiveDate> {
+        match self {
+            Exercise::European(date) => vec![*date],
+            Exercise::American { earliest, latest } => vec![*earliest, *latest],
+            Exercise::Bermudan(dates) => dates.clone(),
+        }
+    }
+
+    /// The last date on which the option may be exercised.
+    pub fn last_date(&self) -> NaiveDate {
+        match self {
+            Exercise::European(date) => *date,
+            Exercise::American { latest, .. } => *latest,
+            Exercise::Bermudan(dates) => dates[dates.len() - 1],
+        }
+    }
+}
+
+impl fmt::Display for Exercise {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Exercise::European(date) => write!(f, "European ({})", date),
+            Exercise::American { earliest, latest } => {
+                write!(f, "American ({} to {})", earliest, latest)
+            }
+            Exercise::Bermudan(dates) => write!(
+                f,
+                "Bermudan ({} dates from {} to {})",
+                dates.len(),
+                dates[0],
+                dates[dates.len() - 1]
+            ),
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use std::fmt;
+
+use chrono::NaiveDate;
+
+use crate::error::{InvalidDate, LibraError};
+use crate::indexes::IborIndex;
+use crate::time::{DateAdjustment, Period};
+
+/// The side of a FRA.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FraPosition {
+    /// Pays the strike and receives the index rate, gaining when rates rise.
+    Long,
+    /// Receives the strike and pays the index rate.
+    Short,
+}
+
+impl FraPosition {
+    /// The sign of the settlement amount received for a positive index rate less strike.
+    pub fn sign(&self) -> f64 {
+        match self {
+            FraPosition::Long => 1.0,
+            FraPosition::Short => -1.0,
+        }
+    }
+}
+
+impl fmt::Display for FraPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FraPosition::Long => write!(f, "Long"),
+            FraPosition::Short => write!(f, "Short"),
+        }
+    }
+}
+
+/// When and how the settlement amount of a FRA is paid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FraSettlement {
+    /// Paid on the value date, discounted from the maturity date at the fixed index rate, as is
+    /// market standard.
+    Discounted,
+    /// Paid undiscounted on the maturity date.
+    InArrears,
+}
+
+/// A forward rate agreement, exchanging a fixed strike for the rate of an index over a future
+/// period on a notional.
+#[derive(Clone, Debug)]
+pub struct Fra {
+    position: FraPosition,
+    value_date: NaiveDate,
+    maturity_date: NaiveDate,
+    strike: f64,
+    notional: f64,
+    index: IborIndex,
+    settlement: FraSettlement,
+}
+
+impl Fra {
+    /// Creates a FRA, such as a 3x6 FRA with a `start` of 3 months and an `end` of 6 months.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Whether the FRA pays or receives the strike.
+    /// * `trade_date` - The date the FRA is traded, from which the spot date is the fixing days
+    ///   of `index` later.
+    /// * `start` - The period from the spot date to the value date.
+    /// * `end` - The period from the spot date to the maturity date.
+    /// * `strike` - The fixed rate, in the day count convention of `index`.
+    /// * `notional` - The notional the rates accrue on.
+    /// * `index` - The index fixed on the value date.
+    ///
+    /// # Returns
+    ///
+    /// The FRA, with both dates adjusted to business days of the index's calendar by the modified
+    /// following convention, or an error if the maturity date is not after the value date.
+    pub fn new(
+        position: FraPosition,
+        trade_date: NaiveDate,
+        start: Period,
+        end: Period,
+        strike: f64,
+        notional: f64,
+        index: IborIndex,
+    ) -> Result<Self, LibraError> {
+        let calendar = index.calendar();
+        let spot_date = calendar.advance(trade_date, index.fixing_days() as i64);
+        let value_date = calendar.advance_by(spot_date, start, DateAdjustment::ModifiedFollowing);
+        let maturity_date = calendar.advance_by(spot_date, end, DateAdjustment::ModifiedFollowing);
+
+        Self::from_dates(position, value_date, maturity_date, strike, notional, index)
+    }
+
+    /// Creates a FRA over the period between `value_date` and `maturity_date`.
+    ///
+    /// Returns an error if the maturity date is not after the value date.
+    pub fn from_dates(
+        position: FraPosition,
+        value_date: NaiveDate,
+        maturity_date: NaiveDate,
+        strike: f64,
+        notional: f64,
+        index: IborIndex,
+    ) -> Result<Self, LibraError> {
+        if maturity_date <= value_date {
+            return Err(InvalidDate.into());
+        }
+
+        Ok(Fra {
+            position,
+            value_date,
+            maturity_date,
+            strike,
+            notional,
+            index,
+            settlement: FraSettlement::Discounted,
+        })
+    }
+
+    /// Pays the settlement amount according to `settlement` instead.
+    pub fn with_settlement(mut self, settlement: FraSettlement) -> Self {
+        self.settlement = settlement;
+        self
+    }
+
+    pub fn position(&self) -> FraPosition {
+        self.position
+    }
+
+    /// The start of the period the index rate applies to.
+    pub fn value_date(&self) -> NaiveDate {
+        self.value_date
+    }
+
+    /// The end of the period the index rate applies to.
+    pub fn maturity_date(&self) -> NaiveDate {
+        self.maturity_date
+    }
+
+    /// The date the index rate is fixed.
+    pub fn fixing_date(&self) -> NaiveDate {
+        self.index.fixing_date(self.value_date)
+    }
+
+    pub fn strike(&self) -> f64 {
+        self.strike
+    }
+
+    pub fn notional(&self) -> f64 {
+        self.notional
+    }
+
+    pub fn index(&self) -> &IborIndex {
+        &self.index
+    }
+
+    pub fn settlement(&self) -> FraSettlement {
+        self.settlement
+    }
+
+    /// The date the settlement amount is paid.
+    pub fn payment_date(&self) -> NaiveDate {
+        match self.settlement {
+            FraSettlement::Discounted => self.value_date,
+            FraSettlement::InArrears => self.maturity_date,
+        }
+    }
+
+    /// The fraction of a year the rates accrue over, in the day count convention of the index.
+    pub fn accrual_fraction(&self) -> Result<f64, LibraError> {
+        Ok(self
+            .index
+            .day_count()
+            .year_frac(&self.value_date, &self.maturity_date)?)
+    }
+}
+
+impl fmt::Display for Fra {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} FRA on {} {} to {}\n Strike {}\n Notional {}",
+            self.position,
+            self.index.name(),
+            self.value_date,
+            self.maturity_date,
+            self.strike,
+            self.notional
+        )
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt;
+
+use super::{Exercise, OptionType};
+
+/// Whether a lookback option is struck at the extremum of the underlying or pays the extremum
+/// against a fixed strike.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LookbackType {
+    /// A call pays the final price less the minimum, and a put the maximum less the final price.
+    FloatingStrike,
+    /// A call pays the maximum less the strike, and a put the strike less the minimum, if
+    /// positive.
+    FixedStrike { strike: f64 },
+}
+
+impl fmt::Display for LookbackType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LookbackType::FloatingStrike => write!(f, "floating strike"),
+            LookbackType::FixedStrike { strike } => write!(f, "fixed strike {}", strike),
+        }
+    }
+}
+
+/// An option whose payoff depends on the minimum or maximum price of the underlying up to
+/// expiry, which is monitored continuously.
+///
+/// Calls struck at the minimum and puts on the minimum depend on the minimum, and the others on
+/// the maximum.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LookbackOption {
+    option_type: OptionType,
+    lookback_type: LookbackType,
+    exercise: Exercise,
+    running_extremum: Option<f64>,
+}
+
+impl LookbackOption {
+    /// Creates a lookback option whose extremum starts from the spot on the valuation date.
+    pub fn new(option_type: OptionType, lookback_type: LookbackType, exercise: Exercise) -> Self {
+        LookbackOption {
+            option_type,
+            lookback_type,
+            exercise,
+            running_extremum: None,
+        }
+    }
+
+    /// Starts the extremum the option depends on from `extremum`, the minimum or maximum already
+    /// observed, or the spot if that is beyond it.
+    pub fn with_running_extremum(mut self, extremum: f64) -> Self {
+        self.running_extremum = Some(extremum);
+        self
+    }
+
+    pub fn option_type(&self) -> OptionType {
+        self.option_type
+    }
+
+    pub fn lookback_type(&self) -> LookbackType {
+        self.lookback_type
+    }
+
+    pub fn exercise(&self) -> &Exercise {
+        &self.exercise
+    }
+
+    pub fn running_extremum(&self) -> Option<f64> {
+        self.running_extremum
+    }
+
+    /// Whether the payoff depends on the minimum rather than the maximum of the underlying.
+    pub fn depends_on_minimum(&self) -> bool {
+        match self.lookback_type {
+            LookbackType::FloatingStrike => self.option_type == OptionType::Call,
+            LookbackType::FixedStrike { .. } => self.option_type == OptionType::Put,
+        }
+    }
+
+    /// The extremum observed so far when the spot is `spot`.
+    pub fn extremum(&self, spot: f64) -> f64 {
+        match (self.running_extremum, self.depends_on_minimum()) {
+            (Some(extremum), true) => extremum.min(spot),
+            (Some(extremum), false) => extremum.max(spot),
+            (None, _) => spot,
+        }
+    }
+
+    /// The amount paid when the underlying finishes at `price` with `extremum` its minimum or
+    /// maximum, whichever the payoff depends on.
+    pub fn value(&self, price: f64, extremum: f64) -> f64 {
+        let omega = self.option_type.sign();
+
+        match self.lookback_type {
+            LookbackType::FloatingStrike => omega * (price - extremum),
+            LookbackType::FixedStrike { strike } => (omega * (extremum - strike)).max(0.0),
+        }
+    }
+}
+
+impl fmt::Display for LookbackOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Lookback {} {}, {}",
+            self.option_type, self.lookback_type, self.exercise
+        )
+    }
+}
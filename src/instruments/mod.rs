@@ -1,3 +1,39 @@
+mod asian_option;
+mod barrier_option;
+mod basis_swap;
 mod bond;
+mod bond_future;
+mod callable_bond;
+mod cap_floor;
+mod cliquet_option;
+mod convertible_bond;
+mod cross_currency_swap;
+mod exercise;
+mod fra;
+mod lookback_option;
+mod option_type;
+mod payoff;
+mod stir_future;
+mod swap;
+mod swaption;
+mod vanilla_option;
 
+pub use asian_option::*;
+pub use barrier_option::*;
+pub use basis_swap::*;
 pub use bond::*;
+pub use bond_future::*;
+pub use callable_bond::*;
+pub use cap_floor::*;
+pub use cliquet_option::*;
+pub use convertible_bond::*;
+pub use cross_currency_swap::*;
+pub use exercise::*;
+pub use fra::*;
+pub use lookback_option::*;
+pub use option_type::*;
+pub use payoff::*;
+pub use stir_future::*;
+pub use swap::*;
+pub use swaption::*;
+pub use vanilla_option::*;
@@ -0,0 +1,27 @@
+use std::fmt;
+
+/// Whether an option gives the right to buy or to sell its underlying.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OptionType {
+    Call,
+    Put,
+}
+
+impl OptionType {
+    /// The sign of the payoff in the underlying less the strike, which is negative for puts.
+    pub fn sign(&self) -> f64 {
+        match self {
+            OptionType::Call => 1.0,
+            OptionType::Put => -1.0,
+        }
+    }
+}
+
+impl fmt::Display for OptionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OptionType::Call => write!(f, "Call"),
+            OptionType::Put => write!(f, "Put"),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt;
+
+use super::OptionType;
+
+/// The amount an option pays as a function of the price of its underlying at exercise.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Payoff {
+    /// Pays the difference between the underlying and the strike, if positive for a call or
+    /// negative for a put.
+    PlainVanilla {
+        option_type: OptionType,
+        strike: f64,
+    },
+    /// Pays a fixed amount of cash if the option finishes in the money.
+    CashOrNothing {
+        option_type: OptionType,
+        strike: f64,
+        cash: f64,
+    },
+    /// Pays the underlying if the option finishes in the money.
+    AssetOrNothing {
+        option_type: OptionType,
+        strike: f64,
+    },
+    /// Pays the difference between the underlying and the strike, which may be negative, if the
+    /// underlying finishes beyond the trigger.
+    Gap {
+        option_type: OptionType,
+        strike: f64,
+        trigger: f64,
+    },
+}
+
+impl Payoff {
+    pub fn option_type(&self) -> OptionType {
+        match self {
+            Payoff::PlainVanilla { option_type, .. }
+            | Payoff::CashOrNothing { option_type, .. }
+            | Payoff::AssetOrNothing { option_type, .. }
+            | Payoff::Gap { option_type, .. } => *option_type,
+        }
+    }
+
+    /// The strike the payoff is measured from.
+    pub fn strike(&self) -> f64 {
+        match self {
+            Payoff::PlainVanilla { strike, .. }
+            | Payoff::CashOrNothing { strike, .. }
+            | Payoff::AssetOrNothing { strike, .. }
+            | Payoff::Gap { strike, .. } => *strike,
+        }
+    }
+
+    /// The level the underlying must finish beyond for the option to pay, which is the strike
+    /// except for gap options.
+    pub fn trigger(&self) -> f64 {
+        match self {
+            Payoff::Gap { trigger, .. } => *trigger,
+            _ => self.strike(),
+        }
+    }
+
+    /// The amount paid when the underlying is at `price`.
+    pub fn value(&self, price: f64) -> f64 {
+        let omega = self.option_type().sign();
+        let in_the_money = omega * (price - self.trigger()) > 0.0;
+
+        match self {
+            Payoff::PlainVanilla { strike, .. } => (omega * (price - strike)).max(0.0),
+            Payoff::CashOrNothing { cash, .. } if in_the_money => *cash,
+            Payoff::AssetOrNothing { .. } if in_the_money => price,
+            Payoff::Gap { strike, .. } if in_the_money => omega * (price - strike),
+            _ => 0.0,
+        }
+    }
+}
+
+impl fmt::Display for Payoff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Payoff::PlainVanilla {
+                option_type,
+                strike,
+            } => write!(f, "{} {}", option_type, strike),
+            Payoff::CashOrNothing {
+                option_type,
+                strike,
+                cash,
+            } => write!(
+                f,
+                "Cash-or-nothing {} {} paying {}",
+                option_type, strike, cash
+            ),
+            Payoff::AssetOrNothing {
+                option_type,
+                strike,
+            } => write!(f, "Asset-or-nothing {} {}", option_type, strike),
+            Payoff::Gap {
+                option_type,
+                strike,
+                trigger,
+            } => write!(f, "Gap {} {} triggered at {}", option_type, strike, trigger),
+        }
+    }
+}
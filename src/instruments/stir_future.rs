@@ -0,0 +1,195 @@
+use std::fmt;
+
+use chrono::{Datelike, Months, NaiveDate};
+
+use crate::error::{DifferentVariant, InvalidDate, LibraError};
+use crate::indexes::FloatingIndex;
+use crate::time::{imm_date, imm_date_from_code, DateAdjustment};
+
+/// A short-term interest rate futures contract, settling on the rate of an index over a
+/// reference period.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StirContract {
+    /// CME one-month SOFR futures, settling on the arithmetic average of SOFR over the calendar
+    /// month.
+    Sofr1M,
+    /// CME three-month SOFR futures, settling on SOFR compounded between consecutive quarterly
+    /// IMM dates.
+    Sofr3M,
+    /// ICE three-month SONIA futures, settling on SONIA compounded between consecutive quarterly
+    /// IMM dates.
+    Sonia3M,
+    /// ICE three-month Euribor futures, settling on the Euribor fixing for the three months from
+    /// the IMM date.
+    Euribor3M,
+}
+
+impl StirContract {
+    /// The change in value of one contract for a one basis point change in the futures rate.
+    pub fn basis_point_value(&self) -> f64 {
+        match self {
+            StirContract::Sofr1M => 41.67,
+            StirContract::Sofr3M | StirContract::Sonia3M | StirContract::Euribor3M => 25.0,
+        }
+    }
+
+    /// Returns `true` if the contract settles on an overnight rate averaged over its reference
+    /// period rather than compounded.
+    pub fn is_averaged(&self) -> bool {
+        matches!(self, StirContract::Sofr1M)
+    }
+
+    /// The start and end of the reference period of the contract for `year` and `month`,
+    /// before any adjustment to business days.
+    fn reference_period(&self, year: i32, month: u32) -> Option<(NaiveDate, NaiveDate)> {
+        match self {
+            StirContract::Sofr1M => {
+                let start = NaiveDate::from_ymd_opt(year, month, 1)?;
+                Some((start, start.checked_add_months(Months::new(1))?))
+            }
+            StirContract::Sofr3M | StirContract::Sonia3M => {
+                let end =
+                    NaiveDate::from_ymd_opt(year, month, 1)?.checked_add_months(Months::new(3))?;
+                Some((imm_date(year, month)?, imm_date(end.year(), end.month())?))
+            }
+            StirContract::Euribor3M => {
+                let start = imm_date(year, month)?;
+                Some((start, start.checked_add_months(Months::new(3))?))
+            }
+        }
+    }
+}
+
+impl fmt::Display for StirContract {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StirContract::Sofr1M => write!(f, "SOFR 1M"),
+            StirContract::Sofr3M => write!(f, "SOFR 3M"),
+            StirContract::Sonia3M => write!(f, "SONIA 3M"),
+            StirContract::Euribor3M => write!(f, "Euribor 3M"),
+        }
+    }
+}
+
+/// A short-term interest rate future, quoted as 100 less the rate of its index over the
+/// reference period.
+#[derive(Clone, Debug)]
+pub struct StirFuture {
+    contract: StirContract,
+    reference_start: NaiveDate,
+    reference_end: NaiveDate,
+    index: FloatingIndex,
+}
+
+impl StirFuture {
+    /// Creates the future on `contract` for a contract month.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract` - The contract traded.
+    /// * `year` - The year of the contract month.
+    /// * `month` - The contract month, which for quarterly contracts is the month the reference
+    ///   period starts in.
+    /// * `index` - The index the contract settles on, an overnight index for SOFR and SONIA
+    ///   contracts and an IBOR index for Euribor contracts.
+    ///
+    /// # Returns
+    ///
+    /// The future, or an error if the month is invalid or the index is of the wrong kind.
+    pub fn new(
+        contract: StirContract,
+        year: i32,
+        month: u32,
+        index: FloatingIndex,
+    ) -> Result<Self, LibraError> {
+        match (contract, &index) {
+            (StirContract::Euribor3M, FloatingIndex::Ibor(_)) => {}
+            (StirContract::Euribor3M, _) | (_, FloatingIndex::Ibor(_)) => {
+                return Err(DifferentVariant.into())
+            }
+            _ => {}
+        }
+
+        let (start, end) = contract.reference_period(year, month).ok_or(InvalidDate)?;
+
+        let (reference_start, reference_end) = match &index {
+            FloatingIndex::Ibor(ibor) => {
+                let calendar = ibor.calendar();
+                (
+                    calendar.adjust(start, DateAdjustment::Following),
+                    calendar.adjust(end, DateAdjustment::ModifiedFollowing),
+                )
+            }
+            FloatingIndex::Overnight(_) => (start, end),
+        };
+
+        Ok(StirFuture {
+            contract,
+            reference_start,
+            reference_end,
+            index,
+        })
+    }
+
+    /// Creates the future on `contract` with the IMM code `code`, such as `Z5`, taking the first
+    /// contract month whose IMM date is not before `reference_date`.
+    pub fn from_code(
+        contract: StirContract,
+        code: &str,
+        reference_date: NaiveDate,
+        index: FloatingIndex,
+    ) -> Result<Self, LibraError> {
+        let date = imm_date_from_code(code, reference_date)?;
+
+        Self::new(contract, date.year(), date.month(), index)
+    }
+
+    pub fn contract(&self) -> StirContract {
+        self.contract
+    }
+
+    /// The first day of the period the settlement rate applies to.
+    pub fn reference_start(&self) -> NaiveDate {
+        self.reference_start
+    }
+
+    /// The end of the period the settlement rate applies to, which is excluded from it.
+    pub fn reference_end(&self) -> NaiveDate {
+        self.reference_end
+    }
+
+    pub fn index(&self) -> &FloatingIndex {
+        &self.index
+    }
+
+    /// The last day the contract trades, which is the fixing date of Euribor contracts and the
+    /// last business day of the reference period otherwise.
+    pub fn last_trading_date(&self) -> NaiveDate {
+        match &self.index {
+            FloatingIndex::Ibor(index) => index.fixing_date(self.reference_start),
+            FloatingIndex::Overnight(index) => index.calendar().advance(self.reference_end, -1),
+        }
+    }
+
+    /// The fraction of a year the reference period spans, in the day count convention of the
+    /// index.
+    pub fn accrual_fraction(&self) -> Result<f64, LibraError> {
+        Ok(self
+            .index
+            .day_count()
+            .year_frac(&self.reference_start, &self.reference_end)?)
+    }
+}
+
+impl fmt::Display for StirFuture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} future on {} {} to {}",
+            self.contract,
+            self.index.name(),
+            self.reference_start,
+            self.reference_end
+        )
+    }
+}
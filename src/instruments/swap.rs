@@ -0,0 +1,335 @@
+use std::{fmt, rc::Rc};
+
+use crate::cashflows::{FixedRateLeg, FloatingRateLeg, Leg, OvernightLeg};
+use crate::error::LibraError;
+use crate::indexes::{IborIndex, OvernightIndex};
+use crate::pricing::YieldTermStructure;
+use crate::time::{DayCountConvention, Schedule};
+
+/// The direction of a swap exchanging a fixed rate for a floating rate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SwapType {
+    /// Pays the fixed rate and receives the floating rate.
+    Payer,
+    /// Receives the fixed rate and pays the floating rate.
+    Receiver,
+}
+
+impl SwapType {
+    /// The sign of the flows of the fixed leg, which is negative when they are paid.
+    pub fn fixed_sign(&self) -> f64 {
+        match self {
+            SwapType::Payer => -1.0,
+            SwapType::Receiver => 1.0,
+        }
+    }
+}
+
+impl fmt::Display for SwapType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SwapType::Payer => write!(f, "Payer"),
+            SwapType::Receiver => write!(f, "Receiver"),
+        }
+    }
+}
+
+/// A swap exchanging a leg of fixed coupons for a leg of floating coupons, without exchanging
+/// notionals.
+pub trait FixedFloatSwap {
+    fn swap_type(&self) -> SwapType;
+
+    /// The rate paid by the fixed leg.
+    fn fixed_rate(&self) -> f64;
+
+    /// The spread over the index paid by the floating leg.
+    fn spread(&self) -> f64;
+
+    /// The curve the floating leg's index forecasts fixings off.
+    fn forward_curve(&self) -> Rc<dyn YieldTermStructure>;
+
+    /// The coupons of the fixed leg.
+    fn fixed_leg(&self) -> Result<Leg, LibraError>;
+
+    /// The coupons of the floating leg, with fixings forecast off `forward_curve` instead of the
+    /// index's own curve.
+    fn floating_leg_on(&self, forward_curve: Rc<dyn YieldTermStructure>)
+        -> Result<Leg, LibraError>;
+
+    /// The coupons of the floating leg.
+    fn floating_leg(&self) -> Result<Leg, LibraError> {
+        self.floating_leg_on(self.forward_curve())
+    }
+}
+
+/// A swap exchanging a fixed rate for an interbank offered rate plus a spread.
+#[derive(Clone, Debug)]
+pub struct VanillaSwap {
+    swap_type: SwapType,
+    nominal: f64,
+    fixed_schedule: Schedule,
+    fixed_rate: f64,
+    fixed_day_count: DayCountConvention,
+    floating_schedule: Schedule,
+    index: IborIndex,
+    spread: f64,
+    floating_day_count: DayCountConvention,
+    payment_lag: u32,
+}
+
+impl VanillaSwap {
+    /// Creates a swap with no spread, whose floating leg accrues in the day count convention of
+    /// the index and whose flows are paid at the end of each period.
+    ///
+    /// # Arguments
+    ///
+    /// * `swap_type` - Whether the swap pays or receives the fixed rate.
+    /// * `nominal` - The nominal both legs accrue on.
+    /// * `fixed_schedule` - The periods of the fixed leg.
+    /// * `fixed_rate` - The rate paid by the fixed leg.
+    /// * `fixed_day_count` - The day count convention of the fixed leg.
+    /// * `floating_schedule` - The periods of the floating leg.
+    /// * `index` - The index fixed for each period of the floating leg.
+    pub fn new(
+        swap_type: SwapType,
+        nominal: f64,
+        fixed_schedule: Schedule,
+        fixed_rate: f64,
+        fixed_day_count: DayCountConvention,
+        floating_schedule: Schedule,
+        index: IborIndex,
+    ) -> Self {
+        VanillaSwap {
+            swap_type,
+            nominal,
+            fixed_schedule,
+            fixed_rate,
+            fixed_day_count,
+            floating_schedule,
+            floating_day_count: index.day_count(),
+            index,
+            spread: 0.0,
+            payment_lag: 0,
+        }
+    }
+
+    /// Adds `spread` to the index rate paid by the floating leg.
+    pub fn with_spread(mut self, spread: f64) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Accrues the floating leg in `day_count` instead.
+    pub fn with_floating_day_count(mut self, day_count: DayCountConvention) -> Self {
+        self.floating_day_count = day_count;
+        self
+    }
+
+    /// Pays the flows of both legs `days` business days after the end of each period.
+    pub fn with_payment_lag(mut self, days: u32) -> Self {
+        self.payment_lag = days;
+        self
+    }
+
+    pub fn nominal(&self) -> f64 {
+        self.nominal
+    }
+
+    pub fn fixed_schedule(&self) -> &Schedule {
+        &self.fixed_schedule
+    }
+
+    pub fn fixed_day_count(&self) -> DayCountConvention {
+        self.fixed_day_count
+    }
+
+    pub fn floating_schedule(&self) -> &Schedule {
+        &self.floating_schedule
+    }
+
+    pub fn index(&self) -> &IborIndex {
+        &self.index
+    }
+
+    pub fn floating_day_count(&self) -> DayCountConvention {
+        self.floating_day_count
+    }
+
+    pub fn payment_lag(&self) -> u32 {
+        self.payment_lag
+    }
+}
+
+impl FixedFloatSwap for VanillaSwap {
+    fn swap_type(&self) -> SwapType {
+        self.swap_type
+    }
+
+    fn fixed_rate(&self) -> f64 {
+        self.fixed_rate
+    }
+
+    fn spread(&self) -> f64 {
+        self.spread
+    }
+
+    fn forward_curve(&self) -> Rc<dyn YieldTermStructure> {
+        self.index.forward_curve().clone()
+    }
+
+    fn fixed_leg(&self) -> Result<Leg, LibraError> {
+        FixedRateLeg::new(self.fixed_schedule.clone(), self.fixed_day_count)
+            .with_notional(self.nominal)
+            .with_rate(self.fixed_rate)
+            .with_payment_lag(self.payment_lag)
+            .without_redemptions()
+            .build()
+    }
+
+    fn floating_leg_on(
+        &self,
+        forward_curve: Rc<dyn YieldTermStructure>,
+    ) -> Result<Leg, LibraError> {
+        let index = self.index.clone().with_forward_curve(forward_curve);
+
+        FloatingRateLeg::new(
+            self.floating_schedule.clone(),
+            index,
+            self.floating_day_count,
+        )
+        .with_notional(self.nominal)
+        .with_spread(self.spread)
+        .with_payment_lag(self.payment_lag)
+        .without_redemptions()
+        .build()
+    }
+}
+
+/// A swap exchanging a fixed rate for the daily compounded rate of an overnight index plus a
+/// spread.
+#[derive(Clone, Debug)]
+pub struct OvernightIndexedSwap {
+    swap_type: SwapType,
+    nominal: f64,
+    fixed_schedule: Schedule,
+    fixed_rate: f64,
+    fixed_day_count: DayCountConvention,
+    overnight_schedule: Schedule,
+    index: OvernightIndex,
+    spread: f64,
+    payment_lag: u32,
+}
+
+impl OvernightIndexedSwap {
+    /// Creates a swap with no spread, whose flows are paid at the end of each period.
+    ///
+    /// # Arguments
+    ///
+    /// * `swap_type` - Whether the swap pays or receives the fixed rate.
+    /// * `nominal` - The nominal both legs accrue on.
+    /// * `fixed_schedule` - The periods of the fixed leg.
+    /// * `fixed_rate` - The rate paid by the fixed leg.
+    /// * `fixed_day_count` - The day count convention of the fixed leg.
+    /// * `overnight_schedule` - The periods of the overnight leg, which accrues in the day count
+    ///   convention of the index.
+    /// * `index` - The index compounded over each period of the overnight leg.
+    pub fn new(
+        swap_type: SwapType,
+        nominal: f64,
+        fixed_schedule: Schedule,
+        fixed_rate: f64,
+        fixed_day_count: DayCountConvention,
+        overnight_schedule: Schedule,
+        index: OvernightIndex,
+    ) -> Self {
+        OvernightIndexedSwap {
+            swap_type,
+            nominal,
+            fixed_schedule,
+            fixed_rate,
+            fixed_day_count,
+            overnight_schedule,
+            index,
+            spread: 0.0,
+            payment_lag: 0,
+        }
+    }
+
+    /// Adds `spread` to the compounded rate paid by the overnight leg.
+    pub fn with_spread(mut self, spread: f64) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Pays the flows of both legs `days` business days after the end of each period, as is
+    /// usual for overnight indexed swaps.
+    pub fn with_payment_lag(mut self, days: u32) -> Self {
+        self.payment_lag = days;
+        self
+    }
+
+    pub fn nominal(&self) -> f64 {
+        self.nominal
+    }
+
+    pub fn fixed_schedule(&self) -> &Schedule {
+        &self.fixed_schedule
+    }
+
+    pub fn fixed_day_count(&self) -> DayCountConvention {
+        self.fixed_day_count
+    }
+
+    pub fn overnight_schedule(&self) -> &Schedule {
+        &self.overnight_schedule
+    }
+
+    pub fn index(&self) -> &OvernightIndex {
+        &self.index
+    }
+
+    pub fn payment_lag(&self) -> u32 {
+        self.payment_lag
+    }
+}
+
+impl FixedFloatSwap for OvernightIndexedSwap {
+    fn swap_type(&self) -> SwapType {
+        self.swap_type
+    }
+
+    fn fixed_rate(&self) -> f64 {
+        self.fixed_rate
+    }
+
+    fn spread(&self) -> f64 {
+        self.spread
+    }
+
+    fn forward_curve(&self) -> Rc<dyn YieldTermStructure> {
+        self.index.forward_curve().clone()
+    }
+
+    fn fixed_leg(&self) -> Result<Leg, LibraError> {
+        FixedRateLeg::new(self.fixed_schedule.clone(), self.fixed_day_count)
+            .with_notional(self.nominal)
+            .with_rate(self.fixed_rate)
+            .with_payment_lag(self.payment_lag)
+            .without_redemptions()
+            .build()
+    }
+
+    fn floating_leg_on(
+        &self,
+        forward_curve: Rc<dyn YieldTermStructure>,
+    ) -> Result<Leg, LibraError> {
+        let index = self.index.clone().with_forward_curve(forward_curve);
+
+        OvernightLeg::new(self.overnight_schedule.clone(), index)
+            .with_notional(self.nominal)
+            .with_spread(self.spread)
+            .with_payment_lag(self.payment_lag)
+            .without_redemptions()
+            .build()
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt;
+
+use super::{Exercise, FixedFloatSwap, SwapType, VanillaSwap};
+
+/// How a cash-settled swaption's settlement amount is computed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CashSettlementMethod {
+    /// The value of the underlying swap off the collateral discount curve, which is worth the
+    /// same as physical settlement.
+    CollateralizedCashPrice,
+    /// The intrinsic value annuitised at the par swap rate fixed on exercise, as for EUR and GBP
+    /// swaptions.
+    ParYieldCurve,
+}
+
+/// Whether exercising a swaption enters the underlying swap or pays its value in cash.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SwaptionSettlement {
+    /// The holder enters the underlying swap.
+    Physical,
+    /// The holder receives a settlement amount computed by the given method.
+    Cash(CashSettlementMethod),
+}
+
+impl fmt::Display for SwaptionSettlement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SwaptionSettlement::Physical => write!(f, "Physical"),
+            SwaptionSettlement::Cash(CashSettlementMethod::CollateralizedCashPrice) => {
+                write!(f, "Cash (collateralized cash price)")
+            }
+            SwaptionSettlement::Cash(CashSettlementMethod::ParYieldCurve) => {
+                write!(f, "Cash (par yield curve)")
+            }
+        }
+    }
+}
+
+/// An option to enter an interest rate swap.
+///
+/// A payer swaption is an option to enter the swap as the payer of the fixed rate, and is a
+/// call on the swap rate; a receiver swaption is a put. Exercising on a date enters the periods
+/// of the swap which start on or after it, so that a Bermudan swaption on a swap whose periods
+/// start on each exercise date is co-terminal.
+#[derive(Clone, Debug)]
+pub struct Swaption {
+    swap: VanillaSwap,
+    exercise: Exercise,
+    settlement: SwaptionSettlement,
+}
+
+impl Swaption {
+    /// Creates a physically settled swaption on `swap`, which is a payer or receiver swaption as
+    /// the swap pays or receives the fixed rate.
+    pub fn new(swap: VanillaSwap, exercise: Exercise) -> Self {
+        Swaption {
+            swap,
+            exercise,
+            settlement: SwaptionSettlement::Physical,
+        }
+    }
+
+    /// Settles the swaption according to `settlement`.
+    pub fn with_settlement(mut self, settlement: SwaptionSettlement) -> Self {
+        self.settlement = settlement;
+        self
+    }
+
+    pub fn swap(&self) -> &VanillaSwap {
+        &self.swap
+    }
+
+    pub fn exercise(&self) -> &Exercise {
+        &self.exercise
+    }
+
+    pub fn settlement(&self) -> SwaptionSettlement {
+        self.settlement
+    }
+
+    /// Whether the swaption enters a swap paying or receiving the fixed rate.
+    pub fn swap_type(&self) -> SwapType {
+        self.swap.swap_type()
+    }
+
+    /// The fixed rate of the underlying swap.
+    pub fn strike(&self) -> f64 {
+        self.swap.fixed_rate()
+    }
+}
+
+impl fmt::Display for Swaption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} swaption at {:.4}% into {} to {}, {}, {} settlement",
+            self.swap_type(),
+            self.strike() * 100.0,
+            self.swap.fixed_schedule().start_date(),
+            self.swap.fixed_schedule().end_date(),
+            self.exercise,
+            self.settlement
+        )
+    }
+}
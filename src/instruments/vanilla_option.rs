@@ -0,0 +1,30 @@
+use std::fmt;
+
+use super::{Exercise, Payoff};
+
+/// An option on a single underlying, paying `payoff` of the underlying's price when exercised.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VanillaOption {
+    payoff: Payoff,
+    exercise: Exercise,
+}
+
+impl VanillaOption {
+    pub fn new(payoff: Payoff, exercise: Exercise) -> Self {
+        VanillaOption { payoff, exercise }
+    }
+
+    pub fn payoff(&self) -> &Payoff {
+        &self.payoff
+    }
+
+    pub fn exercise(&self) -> &Exercise {
+        &self.exercise
+    }
+}
+
+impl fmt::Display for VanillaOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} option, {}", self.payoff, self.exercise)
+    }
+}
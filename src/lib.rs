@@ -1,5 +1,7 @@
 pub mod cashflows;
 pub mod error;
+pub mod indexes;
 pub mod instruments;
+pub mod math;
 pub mod pricing;
 pub mod time;
@@ -1,24 +1,36 @@
 use chrono::{Months, NaiveDate};
 use libra::{
-    cashflows::Coupons,
-    instruments::Bond,
-    time::{Calendar, DateAdjustment, DayCountConvention},
+    cashflows::{Compounding, InterestRate},
+    instruments::FixedRateBond,
+    time::{Calendar, DateAdjustment, DateGeneration, DayCountConvention, Frequency, Schedule},
 };
 
 fn main() {
-    let bond = Bond::new(
-        Calendar::UnitedKingdom,
-        DayCountConvention::Thirty360,
-        DateAdjustment::Following,
+    let schedule = Schedule::new(
         NaiveDate::from_ymd_opt(1999, 5, 26).unwrap(),
         NaiveDate::from_ymd_opt(2009, 5, 26).unwrap(),
-        98.0,
-        100.0,
-        Some(Coupons::Fixed(0.055, Months::new(12))),
+        Months::new(12),
+        Calendar::UnitedKingdom,
+        DateAdjustment::Following,
+        DateGeneration::Backward,
+    )
+    .unwrap();
+
+    let bond = FixedRateBond::new(1, 100.0, schedule, 0.055, DayCountConvention::Thirty360)
+        .build()
+        .unwrap();
+
+    let yield_to_maturity = InterestRate::new(
+        0.0544,
+        DayCountConvention::Thirty360,
+        Compounding::Compounded,
+        Frequency::Annual,
     );
 
+    let settlement = bond.settlement_date(NaiveDate::from_ymd_opt(2006, 7, 13).unwrap());
+
     println!(
         "{:.2}",
-        bond.clean_price(0.0544, NaiveDate::from_ymd_opt(2006, 7, 14).unwrap()).unwrap()
+        bond.clean_price(&yield_to_maturity, settlement).unwrap()
     );
 }
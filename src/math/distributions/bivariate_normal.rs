@@ -0,0 +1,131 @@
+use std::f64::consts::PI;
+
+use super::normal_cdf;
+
+/// The negative Gauss-Legendre abscissae on [-1, 1] and their weights, for 6, 12 and 20 points.
+const GAUSS_LEGENDRE_6: [(f64, f64); 3] = [
+    (-0.932_469_514_203_152_2, 0.171_324_492_379_170_5),
+    (-0.661_209_386_466_264_7, 0.360_761_573_048_138_4),
+    (-0.238_619_186_083_197, 0.467_913_934_572_690_4),
+];
+const GAUSS_LEGENDRE_12: [(f64, f64); 6] = [
+    (-0.981_560_634_246_719_1, 0.047_175_336_386_511_77),
+    (-0.904_117_256_370_475, 0.106_939_325_995_318_3),
+    (-0.769_902_674_194_305, 0.160_078_328_543_346_4),
+    (-0.587_317_954_286_617_1, 0.203_167_426_723_065_9),
+    (-0.367_831_498_998_180_2, 0.233_492_536_538_354_7),
+    (-0.125_233_408_511_469_2, 0.249_147_045_813_402_9),
+];
+const GAUSS_LEGENDRE_20: [(f64, f64); 10] = [
+    (-0.993_128_599_185_094_9, 0.017_614_007_139_152_12),
+    (-0.963_971_927_277_913_8, 0.040_601_429_800_386_94),
+    (-0.912_234_428_251_326, 0.062_672_048_334_109_06),
+    (-0.839_116_971_822_218_8, 0.083_276_741_576_704_75),
+    (-0.746_331_906_460_150_8, 0.101_930_119_817_240_4),
+    (-0.636_053_680_726_515, 0.118_194_531_961_518_4),
+    (-0.510_867_001_950_827_1, 0.131_688_638_449_176_6),
+    (-0.373_706_088_715_419_6, 0.142_096_109_318_382_1),
+    (-0.227_785_851_141_645_1, 0.149_172_986_472_603_7),
+    (-0.076_526_521_133_497_33, 0.152_753_387_130_725_9),
+];
+
+/// The cumulative distribution function of the standard bivariate normal distribution, the
+/// probability that `X <= x` and `Y <= y` when `X` and `Y` are standard normal with
+/// correlation `rho`.
+///
+/// Uses the method of Drezner and Wesolowsky as refined by Genz (2004), which integrates over
+/// the correlation by Gauss-Legendre quadrature and is accurate to about 1e-15.
+pub fn bivariate_normal_cdf(x: f64, y: f64, rho: f64) -> f64 {
+    if rho >= 1.0 {
+        return normal_cdf(x.min(y));
+    }
+    if rho <= -1.0 {
+        return (normal_cdf(x) - normal_cdf(-y)).max(0.0);
+    }
+
+    upper_orthant(-x, -y, rho)
+}
+
+/// The probability that `X > h` and `Y > k` when `X` and `Y` are standard normal with
+/// correlation `rho`, for `|rho| < 1`.
+fn upper_orthant(h: f64, k: f64, rho: f64) -> f64 {
+    let rule: &[(f64, f64)] = if rho.abs() < 0.3 {
+        &GAUSS_LEGENDRE_6
+    } else if rho.abs() < 0.75 {
+        &GAUSS_LEGENDRE_12
+    } else {
+        &GAUSS_LEGENDRE_20
+    };
+
+    // For moderate correlations, integrate the density over the correlation from zero, where
+    // the probability is the product of the marginals, along the arcsine of the correlation.
+    if rho.abs() < 0.925 {
+        let hk = h * k;
+        let hs = 0.5 * (h * h + k * k);
+        let angle = rho.asin();
+
+        let integral: f64 = rule
+            .iter()
+            .flat_map(|(node, weight)| [(*node, *weight), (-node, *weight)])
+            .map(|(node, weight)| {
+                let sin = (0.5 * angle * (node + 1.0)).sin();
+                weight * ((sin * hk - hs) / (1.0 - sin * sin)).exp()
+            })
+            .sum();
+
+        return integral * angle / (4.0 * PI) + normal_cdf(-h) * normal_cdf(-k);
+    }
+
+    // For correlations near one in magnitude, integrate from perfect correlation instead,
+    // after removing the leading terms of the singularity there in closed form.
+    let (k, hk) = if rho < 0.0 { (-k, -h * k) } else { (k, h * k) };
+
+    let a2 = (1.0 - rho) * (1.0 + rho);
+    let a = a2.sqrt();
+    let b2 = (h - k) * (h - k);
+    let c = (4.0 - hk) / 8.0;
+    let d = (12.0 - hk) / 16.0;
+
+    let mut probability = a
+        * (-0.5 * (b2 / a2 + hk)).exp()
+        * (1.0 - c * (b2 - a2) * (1.0 - d * b2 / 5.0) / 3.0 + c * d * a2 * a2 / 5.0);
+
+    if hk > -160.0 {
+        let b = b2.sqrt();
+        probability -= (-0.5 * hk).exp()
+            * (2.0 * PI).sqrt()
+            * normal_cdf(-b / a)
+            * b
+            * (1.0 - c * b2 * (1.0 - d * b2 / 5.0) / 3.0);
+    }
+
+    let half_a = 0.5 * a;
+    let integral: f64 = rule
+        .iter()
+        .flat_map(|(node, weight)| [(*node, *weight), (-node, *weight)])
+        .map(|(node, weight)| {
+            let x2 = (half_a * (node + 1.0)).powi(2);
+            let r = (1.0 - x2).sqrt();
+            half_a
+                * weight
+                * ((-b2 / (2.0 * x2) - hk / (1.0 + r)).exp() / r
+                    - (-0.5 * (b2 / x2 + hk)).exp() * (1.0 + c * x2 * (1.0 + d * x2)))
+        })
+        .sum();
+
+    probability = -(probability + integral) / (2.0 * PI);
+
+    if rho > 0.0 {
+        probability + normal_cdf(-h.max(k))
+    } else if k > h {
+        // The probability that X > h and -Y > k, from which that of X > h and Y > -k follows.
+        let difference = if h < 0.0 {
+            normal_cdf(k) - normal_cdf(h)
+        } else {
+            normal_cdf(-h) - normal_cdf(-k)
+        };
+        difference - probability
+    } else {
+        -probability
+    }
+}
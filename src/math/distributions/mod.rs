@@ -0,0 +1,7 @@
+mod bivariate_normal;
+mod error_function;
+mod normal;
+
+pub use bivariate_normal::*;
+pub use error_function::*;
+pub use normal::*;
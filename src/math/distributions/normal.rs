@@ -0,0 +1,94 @@
+use std::f64::consts::{PI, SQRT_2};
+
+use super::erfc;
+
+/// The density of the standard normal distribution at `x`.
+pub fn normal_pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp() / (2.0 * PI).sqrt()
+}
+
+/// The cumulative distribution function of the standard normal distribution at `x`.
+///
+/// Found from the complementary error function, so that it has full relative accuracy far into
+/// the lower tail.
+pub fn normal_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / SQRT_2)
+}
+
+/// The inverse of the cumulative distribution function of the standard normal distribution.
+///
+/// Uses the rational approximation of Acklam, refined by a step of Halley's method.
+///
+/// # Returns
+///
+/// The quantile of `p`, which is infinite when `p` is zero or one and NaN outside that range.
+pub fn inverse_normal_cdf(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const LOW: f64 = 0.024_25;
+
+    if p.is_nan() || !(0.0..=1.0).contains(&p) {
+        return f64::NAN;
+    }
+    if p == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if p == 1.0 {
+        return f64::INFINITY;
+    }
+
+    let polynomial = |coefficients: &[f64], x: f64| coefficients.iter().fold(0.0, |s, c| s * x + c);
+
+    // The quantile of a lower tail probability.
+    let tail = |q: f64| {
+        let r = (-2.0 * q.ln()).sqrt();
+        polynomial(&C, r) / (polynomial(&D, r) * r + 1.0)
+    };
+
+    let x = if p < LOW {
+        tail(p)
+    } else if p > 1.0 - LOW {
+        -tail(1.0 - p)
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        polynomial(&A, r) * q / (polynomial(&B, r) * r + 1.0)
+    };
+
+    // Halley's method, with the error measured in the smaller tail to keep its precision.
+    let error = if p < 0.5 {
+        normal_cdf(x) - p
+    } else {
+        (1.0 - p) - normal_cdf(-x)
+    };
+    let u = error / normal_pdf(x);
+
+    x - u / (1.0 + 0.5 * x * u)
+}
@@ -0,0 +1,166 @@
+//! Piecewise cubic interpolations, each described by the first derivative of the cubic at every
+//! point. The cubic on each interval is the Hermite cubic matching the values and derivatives at
+//! either end.
+
+use crate::math::solvers::solve_tridiagonal;
+
+/// The slope of the straight line across interval `i`.
+fn secant(xs: &[f64], ys: &[f64], i: usize) -> f64 {
+    (ys[i] - ys[i - 1]) / (xs[i] - xs[i - 1])
+}
+
+/// The derivatives at each point of the cubic spline through the points, which has continuous
+/// first and second derivatives.
+///
+/// The spline is natural, with zero second derivative at either end, unless `end_derivatives`
+/// gives the first derivatives at the first and last points.
+pub(super) fn spline_slopes(
+    xs: &[f64],
+    ys: &[f64],
+    end_derivatives: Option<(f64, f64)>,
+) -> Vec<f64> {
+    let n = xs.len();
+    let mut lower = vec![0.0; n];
+    let mut diagonal = vec![0.0; n];
+    let mut upper = vec![0.0; n];
+    let mut rhs = vec![0.0; n];
+
+    // Continuity of the second derivative at each interior point.
+    for i in 1..n - 1 {
+        let (h0, h1) = (xs[i] - xs[i - 1], xs[i + 1] - xs[i]);
+
+        lower[i] = h1;
+        diagonal[i] = 2.0 * (h0 + h1);
+        upper[i] = h0;
+        rhs[i] = 3.0 * (h1 * secant(xs, ys, i) + h0 * secant(xs, ys, i + 1));
+    }
+
+    match end_derivatives {
+        Some((first, last)) => {
+            diagonal[0] = 1.0;
+            rhs[0] = first;
+            diagonal[n - 1] = 1.0;
+            rhs[n - 1] = last;
+        }
+        None => {
+            diagonal[0] = 2.0;
+            upper[0] = 1.0;
+            rhs[0] = 3.0 * secant(xs, ys, 1);
+            lower[n - 1] = 1.0;
+            diagonal[n - 1] = 2.0;
+            rhs[n - 1] = 3.0 * secant(xs, ys, n - 1);
+        }
+    }
+
+    solve_tridiagonal(&lower, &diagonal, &upper, &rhs)
+}
+
+/// The derivatives at each point chosen by Akima's method, which weights the slopes of the
+/// neighbouring intervals to avoid the oscillation of a spline near outliers.
+pub(super) fn akima_slopes(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+    let n = xs.len();
+
+    // The slopes of the intervals, with two extra intervals extrapolated quadratically at
+    // either end. Interval `i` has slope `m[i + 1]`.
+    let mut m = vec![secant(xs, ys, 1); n + 3];
+    if n > 2 {
+        for i in 1..n {
+            m[i + 1] = secant(xs, ys, i);
+        }
+        m[1] = 2.0 * m[2] - m[3];
+        m[0] = 2.0 * m[1] - m[2];
+        m[n + 1] = 2.0 * m[n] - m[n - 1];
+        m[n + 2] = 2.0 * m[n + 1] - m[n];
+    }
+
+    (0..n)
+        .map(|i| {
+            let w0 = (m[i + 3] - m[i + 2]).abs();
+            let w1 = (m[i + 1] - m[i]).abs();
+
+            if w0 + w1 == 0.0 {
+                (m[i + 1] + m[i + 2]) / 2.0
+            } else {
+                (w0 * m[i + 1] + w1 * m[i + 2]) / (w0 + w1)
+            }
+        })
+        .collect()
+}
+
+/// The derivatives at each point chosen by the method of Fritsch and Carlson, which limits the
+/// derivatives so that the cubic is monotonic wherever the points are.
+pub(super) fn fritsch_carlson_slopes(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+    let n = xs.len();
+    let secants: Vec<f64> = (1..n).map(|i| secant(xs, ys, i)).collect();
+
+    let mut slopes = vec![0.0; n];
+    slopes[0] = secants[0];
+    slopes[n - 1] = secants[n - 2];
+
+    for i in 1..n - 1 {
+        slopes[i] = if secants[i - 1] * secants[i] <= 0.0 {
+            0.0
+        } else {
+            (secants[i - 1] + secants[i]) / 2.0
+        };
+    }
+
+    for (i, secant) in secants.iter().enumerate() {
+        if *secant == 0.0 {
+            slopes[i] = 0.0;
+            slopes[i + 1] = 0.0;
+            continue;
+        }
+
+        let alpha = slopes[i] / secant;
+        let beta = slopes[i + 1] / secant;
+        let radius = alpha.hypot(beta);
+
+        if radius > 3.0 {
+            slopes[i] = 3.0 * alpha / radius * secant;
+            slopes[i + 1] = 3.0 * beta / radius * secant;
+        }
+    }
+
+    slopes
+}
+
+/// The local coordinates `(h, t)` of `x` on interval `i`, where `t` is zero at the start of the
+/// interval and one at the end.
+fn coordinates(xs: &[f64], i: usize, x: f64) -> (f64, f64) {
+    let h = xs[i] - xs[i - 1];
+
+    (h, (x - xs[i - 1]) / h)
+}
+
+/// The value at `x` of the cubic on interval `i`.
+pub(super) fn value(xs: &[f64], ys: &[f64], slopes: &[f64], i: usize, x: f64) -> f64 {
+    let (h, t) = coordinates(xs, i, x);
+    let (t2, t3) = (t * t, t * t * t);
+
+    (2.0 * t3 - 3.0 * t2 + 1.0) * ys[i - 1]
+        + (t3 - 2.0 * t2 + t) * h * slopes[i - 1]
+        + (3.0 * t2 - 2.0 * t3) * ys[i]
+        + (t3 - t2) * h * slopes[i]
+}
+
+/// The first derivative at `x` of the cubic on interval `i`.
+pub(super) fn derivative(xs: &[f64], ys: &[f64], slopes: &[f64], i: usize, x: f64) -> f64 {
+    let (h, t) = coordinates(xs, i, x);
+    let t2 = t * t;
+
+    (6.0 * t2 - 6.0 * t) * (ys[i - 1] - ys[i]) / h
+        + (3.0 * t2 - 4.0 * t + 1.0) * slopes[i - 1]
+        + (3.0 * t2 - 2.0 * t) * slopes[i]
+}
+
+/// The integral from the start of interval `i` to `x` of the cubic on the interval.
+pub(super) fn antiderivative(xs: &[f64], ys: &[f64], slopes: &[f64], i: usize, x: f64) -> f64 {
+    let (h, t) = coordinates(xs, i, x);
+    let (t2, t3, t4) = (t * t, t * t * t, t * t * t * t);
+
+    h * ((t4 / 2.0 - t3 + t) * ys[i - 1]
+        + (t4 / 4.0 - 2.0 * t3 / 3.0 + t2 / 2.0) * h * slopes[i - 1]
+        + (t3 - t4 / 2.0) * ys[i]
+        + (t4 / 4.0 - t3 / 3.0) * h * slopes[i])
+}
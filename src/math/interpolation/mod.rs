@@ -0,0 +1,356 @@
+mod cubic;
+mod monotone_convex;
+mod two_dimensional;
+
+pub use two_dimensional::*;
+
+use std::cmp::Ordering;
+
+use crate::error::{ArgumentRange, LibraError, NotIncreasing};
+
+/// Checks that `xs` is strictly increasing, so that every interval has a positive length.
+pub(crate) fn check_increasing(name: &str, xs: &[f64]) -> Result<(), NotIncreasing> {
+    match xs
+        .windows(2)
+        .find(|w| w[0].partial_cmp(&w[1]) != Some(Ordering::Less))
+    {
+        Some(w) => Err(NotIncreasing::new(name, w[0], w[1])),
+        None => Ok(()),
+    }
+}
+
+/// A scheme for interpolating between points.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Interpolation {
+    /// Straight lines between points.
+    Linear,
+    /// Straight lines between the logarithms of the values, which must be positive.
+    LogLinear,
+    /// The value at the end of each interval, so that each point applies to the interval
+    /// leading up to it.
+    BackwardFlat,
+    /// The value at the start of each interval, so that each point applies until the next.
+    ForwardFlat,
+    /// A cubic spline with continuous first and second derivatives, whose second derivative is
+    /// zero at the first and last points.
+    CubicNatural,
+    /// A cubic spline with continuous first and second derivatives, with the given first
+    /// derivatives at the first and last points.
+    CubicClamped {
+        first_derivative: f64,
+        last_derivative: f64,
+    },
+    /// Akima's piecewise cubic, which has a continuous first derivative and is less prone than a
+    /// spline to oscillate near outlying points.
+    Akima,
+    /// A piecewise cubic with a continuous first derivative, limited by the method of Fritsch
+    /// and Carlson to be monotonic wherever the points are.
+    MonotoneCubic,
+    /// The monotone convex method of Hagan and West, for values which are zero rates at times
+    /// given by the abscissae. The implied forward rates are continuous and reproduce the
+    /// average forward rate between each pair of points.
+    MonotoneConvex,
+}
+
+/// How an interpolation is continued outside its first and last points.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Extrapolation {
+    /// The formula used on the first or last interval is extended, so that linear
+    /// interpolations extrapolate linearly and flat interpolations extrapolate flat. Monotone
+    /// convex interpolations extrapolate with flat forward rates.
+    Extend,
+    /// The value at the first or last point is used.
+    Flat,
+    /// The tangent to the interpolation at the first or last point is used.
+    Linear,
+}
+
+/// An interpolation of a function through a set of points.
+#[derive(Clone, Debug)]
+pub struct Interpolator {
+    method: Interpolation,
+    extrapolation: Extrapolation,
+    xs: Vec<f64>,
+    ys: Vec<f64>,
+    /// The first derivative at each point for cubic interpolations, or the forward rate at each
+    /// point for monotone convex interpolations.
+    coefficients: Vec<f64>,
+}
+
+impl Interpolator {
+    /// Creates an interpolation through the points `(xs[i], ys[i])`, which extends its first and
+    /// last intervals outside the points.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The interpolation scheme.
+    /// * `xs` - The strictly increasing abscissae.
+    /// * `ys` - The values at each abscissa.
+    ///
+    /// # Returns
+    ///
+    /// The interpolation, or an error if fewer than two points are given, the abscissae are not
+    /// strictly increasing or the lengths of `xs` and `ys` differ.
+    pub fn new(method: Interpolation, xs: Vec<f64>, ys: Vec<f64>) -> Result<Self, LibraError> {
+        if xs.len() < 2 {
+            return Err(
+                ArgumentRange::new("number of points", 2, i64::MAX, xs.len() as i64).into(),
+            );
+        }
+
+        check_increasing("abscissae", &xs)?;
+
+        if ys.len() != xs.len() {
+            return Err(ArgumentRange::new(
+                "number of values",
+                xs.len() as i64,
+                xs.len() as i64,
+                ys.len() as i64,
+            )
+            .into());
+        }
+
+        let coefficients = match method {
+            Interpolation::CubicNatural => cubic::spline_slopes(&xs, &ys, None),
+            Interpolation::CubicClamped {
+                first_derivative,
+                last_derivative,
+            } => cubic::spline_slopes(&xs, &ys, Some((first_derivative, last_derivative))),
+            Interpolation::Akima => cubic::akima_slopes(&xs, &ys),
+            Interpolation::MonotoneCubic => cubic::fritsch_carlson_slopes(&xs, &ys),
+            Interpolation::MonotoneConvex => monotone_convex::node_forwards(&xs, &ys),
+            _ => Vec::new(),
+        };
+
+        Ok(Interpolator {
+            method,
+            extrapolation: Extrapolation::Extend,
+            xs,
+            ys,
+            coefficients,
+        })
+    }
+
+    /// Continues the interpolation outside its points with `extrapolation`.
+    pub fn with_extrapolation(mut self, extrapolation: Extrapolation) -> Self {
+        self.extrapolation = extrapolation;
+        self
+    }
+
+    pub fn method(&self) -> Interpolation {
+        self.method
+    }
+
+    pub fn extrapolation(&self) -> Extrapolation {
+        self.extrapolation
+    }
+
+    pub fn xs(&self) -> &[f64] {
+        &self.xs
+    }
+
+    pub fn ys(&self) -> &[f64] {
+        &self.ys
+    }
+
+    /// The interpolated value at `x`.
+    pub fn value(&self, x: f64) -> f64 {
+        match self.extrapolation_edge(x) {
+            None => self.extended_value(x),
+            Some(edge) => match self.extrapolation {
+                Extrapolation::Linear => {
+                    self.extended_value(edge) + self.extended_derivative(edge) * (x - edge)
+                }
+                _ => self.extended_value(edge),
+            },
+        }
+    }
+
+    /// The first derivative of the interpolation at `x`.
+    pub fn derivative(&self, x: f64) -> f64 {
+        match self.extrapolation_edge(x) {
+            None => self.extended_derivative(x),
+            Some(edge) => match self.extrapolation {
+                Extrapolation::Linear => self.extended_derivative(edge),
+                _ => 0.0,
+            },
+        }
+    }
+
+    /// The integral of the interpolation from the first abscissa to `x`.
+    pub fn primitive(&self, x: f64) -> f64 {
+        let n = self.xs.len();
+        let (first, last) = (self.xs[0], self.xs[n - 1]);
+
+        if x <= first {
+            return self.tail_integral(first, x);
+        }
+
+        let end = self.segment(x);
+        let mut integral = 0.0;
+
+        for i in 1..end {
+            integral += self.segment_integral(i, self.xs[i - 1], self.xs[i]);
+        }
+
+        if x <= last {
+            integral + self.segment_integral(end, self.xs[end - 1], x)
+        } else {
+            integral
+                + self.segment_integral(end, self.xs[end - 1], last)
+                + self.tail_integral(last, x)
+        }
+    }
+
+    /// The first or last abscissa if `x` lies beyond it and the interpolation is not extended
+    /// there.
+    fn extrapolation_edge(&self, x: f64) -> Option<f64> {
+        let (first, last) = (self.xs[0], self.xs[self.xs.len() - 1]);
+
+        match self.extrapolation {
+            Extrapolation::Extend => None,
+            _ if x < first => Some(first),
+            _ if x > last => Some(last),
+            _ => None,
+        }
+    }
+
+    /// The integral from `edge`, the first or last abscissa, to `x` beyond it.
+    fn tail_integral(&self, edge: f64, x: f64) -> f64 {
+        let (value, slope) = (self.extended_value(edge), self.extended_derivative(edge));
+
+        match (self.extrapolation, self.method) {
+            (Extrapolation::Flat, _)
+            | (_, Interpolation::BackwardFlat | Interpolation::ForwardFlat) => value * (x - edge),
+            (Extrapolation::Linear, _) => value * (x - edge) + slope * (x - edge).powi(2) / 2.0,
+            (Extrapolation::Extend, _) => {
+                let i = self.segment(edge);
+                self.segment_integral(i, edge, x)
+            }
+        }
+    }
+
+    /// The index `i` of the interval `[xs[i - 1], xs[i]]` used for `x`, with the first and last
+    /// intervals used outside the points.
+    fn segment(&self, x: f64) -> usize {
+        self.xs
+            .partition_point(|xi| *xi < x)
+            .clamp(1, self.xs.len() - 1)
+    }
+
+    /// The value at `x` of the interpolation with its first and last intervals extended.
+    fn extended_value(&self, x: f64) -> f64 {
+        let i = self.segment(x);
+        let (x0, x1, y0, y1) = (self.xs[i - 1], self.xs[i], self.ys[i - 1], self.ys[i]);
+
+        match self.method {
+            Interpolation::Linear => y0 + (y1 - y0) * (x - x0) / (x1 - x0),
+            Interpolation::LogLinear => y0 * (y1 / y0).powf((x - x0) / (x1 - x0)),
+            Interpolation::BackwardFlat if x <= x0 => y0,
+            Interpolation::BackwardFlat => y1,
+            Interpolation::ForwardFlat if x >= x1 => y1,
+            Interpolation::ForwardFlat => y0,
+            Interpolation::CubicNatural
+            | Interpolation::CubicClamped { .. }
+            | Interpolation::Akima
+            | Interpolation::MonotoneCubic => {
+                cubic::value(&self.xs, &self.ys, &self.coefficients, i, x)
+            }
+            Interpolation::MonotoneConvex => {
+                monotone_convex::value(&self.xs, &self.ys, &self.coefficients, x)
+            }
+        }
+    }
+
+    /// The first derivative at `x` of the interpolation with its first and last intervals
+    /// extended.
+    fn extended_derivative(&self, x: f64) -> f64 {
+        let i = self.segment(x);
+        let (x0, x1, y0, y1) = (self.xs[i - 1], self.xs[i], self.ys[i - 1], self.ys[i]);
+
+        match self.method {
+            Interpolation::Linear => (y1 - y0) / (x1 - x0),
+            Interpolation::LogLinear => self.extended_value(x) * (y1 / y0).ln() / (x1 - x0),
+            Interpolation::BackwardFlat | Interpolation::ForwardFlat => 0.0,
+            Interpolation::CubicNatural
+            | Interpolation::CubicClamped { .. }
+            | Interpolation::Akima
+            | Interpolation::MonotoneCubic => {
+                cubic::derivative(&self.xs, &self.ys, &self.coefficients, i, x)
+            }
+            Interpolation::MonotoneConvex => {
+                monotone_convex::derivative(&self.xs, &self.ys, &self.coefficients, x)
+            }
+        }
+    }
+
+    /// The integral from `a` to `b` of the formula used on interval `i`.
+    fn segment_integral(&self, i: usize, a: f64, b: f64) -> f64 {
+        let (x0, x1, y0, y1) = (self.xs[i - 1], self.xs[i], self.ys[i - 1], self.ys[i]);
+
+        match self.method {
+            Interpolation::Linear => {
+                let slope = (y1 - y0) / (x1 - x0);
+                y0 * (b - a) + slope / 2.0 * ((b - x0).powi(2) - (a - x0).powi(2))
+            }
+            Interpolation::LogLinear => {
+                let k = (y1 / y0).ln() / (x1 - x0);
+
+                if k.abs() < 1.0e-12 {
+                    y0 * (b - a)
+                } else {
+                    y0 / k * ((k * (b - x0)).exp() - (k * (a - x0)).exp())
+                }
+            }
+            Interpolation::BackwardFlat => y1 * (b - a),
+            Interpolation::ForwardFlat => y0 * (b - a),
+            Interpolation::CubicNatural
+            | Interpolation::CubicClamped { .. }
+            | Interpolation::Akima
+            | Interpolation::MonotoneCubic => {
+                cubic::antiderivative(&self.xs, &self.ys, &self.coefficients, i, b)
+                    - cubic::antiderivative(&self.xs, &self.ys, &self.coefficients, i, a)
+            }
+            Interpolation::MonotoneConvex => {
+                monotone_convex::integral(&self.xs, &self.ys, &self.coefficients, a, b)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::LibraError;
+
+    use super::{Interpolation, Interpolation2D, Interpolator, Interpolator2D};
+
+    #[test]
+    fn abscissae_must_be_strictly_increasing() {
+        for xs in [
+            vec![1.0, 2.0, 2.0, 3.0],
+            vec![1.0, 3.0, 2.0, 4.0],
+            vec![1.0, f64::NAN, 3.0, 4.0],
+        ] {
+            for method in [Interpolation::Linear, Interpolation::CubicNatural] {
+                let result = Interpolator::new(method, xs.clone(), vec![1.0; 4]);
+                assert!(matches!(result, Err(LibraError::NotIncreasing(_))));
+            }
+
+            let result = Interpolator2D::new(
+                Interpolation2D::Bilinear,
+                xs.clone(),
+                vec![0.0, 1.0],
+                vec![vec![1.0; 2]; 4],
+            );
+            assert!(matches!(result, Err(LibraError::NotIncreasing(_))));
+
+            let result = Interpolator2D::new(
+                Interpolation2D::Bilinear,
+                vec![0.0, 1.0],
+                xs.clone(),
+                vec![vec![1.0; 4]; 2],
+            );
+            assert!(matches!(result, Err(LibraError::NotIncreasing(_))));
+        }
+    }
+}
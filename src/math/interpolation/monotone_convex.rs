@@ -0,0 +1,215 @@
+//! The monotone convex method of Hagan and West, which interpolates zero rates `ys` at times
+//! `xs` by choosing instantaneous forward rates that reproduce the average forward over each
+//! interval, remain positive where the discrete forwards are, and avoid spurious oscillation.
+
+/// The average forward rate over interval `i`, implied by the zero rates at either end.
+fn discrete_forward(xs: &[f64], ys: &[f64], i: usize) -> f64 {
+    (xs[i] * ys[i] - xs[i - 1] * ys[i - 1]) / (xs[i] - xs[i - 1])
+}
+
+/// The instantaneous forward rate at each point.
+pub(super) fn node_forwards(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+    let n = xs.len();
+
+    if n == 2 {
+        return vec![discrete_forward(xs, ys, 1); 2];
+    }
+
+    let mut forwards = vec![0.0; n];
+
+    for i in 1..n - 1 {
+        let span = xs[i + 1] - xs[i - 1];
+        forwards[i] = (xs[i] - xs[i - 1]) / span * discrete_forward(xs, ys, i + 1)
+            + (xs[i + 1] - xs[i]) / span * discrete_forward(xs, ys, i);
+    }
+
+    let first = discrete_forward(xs, ys, 1);
+    let last = discrete_forward(xs, ys, n - 1);
+    forwards[0] = first - 0.5 * (forwards[1] - first);
+    forwards[n - 1] = last - 0.5 * (forwards[n - 2] - last);
+
+    // The collar of Hagan and West, which keeps each node forward between zero and twice the
+    // smallest adjacent discrete forward so that the forward stays positive over every interval
+    // whose discrete forward is.
+    for (i, forward) in forwards.iter_mut().enumerate() {
+        let adjacent = (i.max(1)..=(i + 1).min(n - 1))
+            .map(|j| discrete_forward(xs, ys, j))
+            .fold(f64::INFINITY, f64::min);
+
+        if adjacent > 0.0 {
+            *forward = forward.clamp(0.0, 2.0 * adjacent);
+        }
+    }
+
+    forwards
+}
+
+/// The deviation `g(u)` of the forward from the discrete forward at the fraction `u` through an
+/// interval, and its integral from zero to `u`, given the deviations `g0` and `g1` at either end.
+fn deviation(g0: f64, g1: f64, u: f64) -> (f64, f64) {
+    // The integral of ((eta - s) / eta)^2 from zero to min(u, eta).
+    let decaying = |eta: f64| eta / 3.0 * (1.0 - ((eta - u.min(eta)) / eta).powi(3));
+    // The integral of ((s - eta) / (1 - eta))^2 from eta to max(u, eta).
+    let growing = |eta: f64| {
+        if u <= eta {
+            0.0
+        } else {
+            (u - eta).powi(3) / (3.0 * (1.0 - eta).powi(2))
+        }
+    };
+
+    if g0 == 0.0 && g1 == 0.0 {
+        (0.0, 0.0)
+    } else if (g0 < 0.0 && -0.5 * g0 <= g1 && g1 <= -2.0 * g0)
+        || (g0 > 0.0 && -0.5 * g0 >= g1 && g1 >= -2.0 * g0)
+    {
+        (
+            g0 * (1.0 - 4.0 * u + 3.0 * u * u) + g1 * (-2.0 * u + 3.0 * u * u),
+            g0 * (u - 2.0 * u * u + u.powi(3)) + g1 * (u.powi(3) - u * u),
+        )
+    } else if (g0 < 0.0 && g1 > -2.0 * g0) || (g0 > 0.0 && g1 < -2.0 * g0) {
+        let eta = (g1 + 2.0 * g0) / (g1 - g0);
+        let g = if u <= eta {
+            g0
+        } else {
+            g0 + (g1 - g0) * ((u - eta) / (1.0 - eta)).powi(2)
+        };
+
+        (g, g0 * u + (g1 - g0) * growing(eta))
+    } else if (g0 > 0.0 && g1 < 0.0 && g1 > -0.5 * g0) || (g0 < 0.0 && g1 > 0.0 && g1 < -0.5 * g0) {
+        let eta = 3.0 * g1 / (g1 - g0);
+        let g = if u < eta {
+            g1 + (g0 - g1) * ((eta - u) / eta).powi(2)
+        } else {
+            g1
+        };
+
+        (g, g1 * u + (g0 - g1) * decaying(eta))
+    } else {
+        let eta = g1 / (g1 + g0);
+        let a = -g0 * g1 / (g0 + g1);
+        // If either deviation is zero the shape collapses onto that end of the interval, where
+        // the deviation is the one at that end.
+        let g = if u == 0.0 && eta == 0.0 {
+            g0
+        } else if u == 1.0 && eta == 1.0 {
+            g1
+        } else if u < eta {
+            a + (g0 - a) * ((eta - u) / eta).powi(2)
+        } else {
+            a + (g1 - a) * ((u - eta) / (1.0 - eta)).powi(2)
+        };
+
+        let mut integral = a * u + (g1 - a) * growing(eta);
+        if eta > 0.0 {
+            integral += (g0 - a) * decaying(eta);
+        }
+
+        (g, integral)
+    }
+}
+
+/// The instantaneous forward rate at `x` and its integral from zero to `x`, with flat forwards
+/// outside the points.
+pub(super) fn forward_and_integral(xs: &[f64], ys: &[f64], forwards: &[f64], x: f64) -> (f64, f64) {
+    let n = xs.len();
+
+    if x <= xs[0] {
+        return (forwards[0], xs[0] * ys[0] + forwards[0] * (x - xs[0]));
+    }
+
+    if x >= xs[n - 1] {
+        return (
+            forwards[n - 1],
+            xs[n - 1] * ys[n - 1] + forwards[n - 1] * (x - xs[n - 1]),
+        );
+    }
+
+    let i = xs.partition_point(|xi| *xi < x).clamp(1, n - 1);
+    let h = xs[i] - xs[i - 1];
+    let average = discrete_forward(xs, ys, i);
+    let u = (x - xs[i - 1]) / h;
+    let (g, integral) = deviation(forwards[i - 1] - average, forwards[i] - average, u);
+
+    (
+        average + g,
+        xs[i - 1] * ys[i - 1] + h * (average * u + integral),
+    )
+}
+
+/// The interpolated zero rate at `x`, which is the forward at zero if `x` is zero.
+pub(super) fn value(xs: &[f64], ys: &[f64], forwards: &[f64], x: f64) -> f64 {
+    let (forward, integral) = forward_and_integral(xs, ys, forwards, x);
+
+    if x == 0.0 {
+        forward
+    } else {
+        integral / x
+    }
+}
+
+/// The derivative of the interpolated zero rate at `x`.
+pub(super) fn derivative(xs: &[f64], ys: &[f64], forwards: &[f64], x: f64) -> f64 {
+    const STEP: f64 = 1.0e-6;
+
+    if x == 0.0 {
+        return (value(xs, ys, forwards, STEP) - value(xs, ys, forwards, 0.0)) / STEP;
+    }
+
+    let (forward, integral) = forward_and_integral(xs, ys, forwards, x);
+
+    (forward - integral / x) / x
+}
+
+/// The integral of the interpolated zero rate from `a` to `b`, by Simpson's rule.
+pub(super) fn integral(xs: &[f64], ys: &[f64], forwards: &[f64], a: f64, b: f64) -> f64 {
+    const INTERVALS: usize = 64;
+
+    let h = (b - a) / INTERVALS as f64;
+    let mut sum = value(xs, ys, forwards, a) + value(xs, ys, forwards, b);
+
+    for k in 1..INTERVALS {
+        let weight = if k % 2 == 1 { 4.0 } else { 2.0 };
+        sum += weight * value(xs, ys, forwards, a + k as f64 * h);
+    }
+
+    sum * h / 3.0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::interpolation::{Interpolation, Interpolator};
+
+    #[test]
+    fn forwards_are_continuous_at_nodes_between_equal_discrete_forwards() {
+        let xs = vec![1.0, 2.0, 3.0, 4.0];
+        let interpolator = Interpolator::new(
+            Interpolation::MonotoneConvex,
+            xs.clone(),
+            vec![0.02, 0.02, 0.02, 0.025],
+        )
+        .unwrap();
+        let forward = |x: f64| interpolator.value(x) + x * interpolator.derivative(x);
+
+        for x in xs {
+            assert!(interpolator.derivative(x).is_finite());
+            assert!((forward(x) - forward(x + 1.0e-7)).abs() < 1.0e-6);
+        }
+    }
+
+    #[test]
+    fn reproduces_the_nodes_with_positive_forwards() {
+        let xs = vec![0.5, 1.0, 2.0, 3.0, 4.0];
+        let ys = vec![0.05, 0.05, 0.06, 0.041, 0.045];
+        let interpolator =
+            Interpolator::new(Interpolation::MonotoneConvex, xs.clone(), ys.clone()).unwrap();
+
+        for (x, y) in xs.iter().zip(&ys) {
+            assert!((interpolator.value(*x) - y).abs() < 1.0e-14);
+        }
+        for k in 0..=700 {
+            let x = 0.5 + k as f64 * 0.005;
+            assert!(interpolator.value(x) + x * interpolator.derivative(x) > 0.0);
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use crate::error::{ArgumentRange, LibraError};
+
+use super::{check_increasing, Extrapolation, Interpolation, Interpolator};
+
+/// A scheme for interpolating between points on a rectangular grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpolation2D {
+    /// Linear in each direction within each cell of the grid.
+    Bilinear,
+    /// Natural cubic splines in each direction, giving a surface with continuous first and
+    /// second derivatives.
+    Bicubic,
+}
+
+impl Interpolation2D {
+    /// The scheme used along each axis.
+    fn axis(&self) -> Interpolation {
+        match self {
+            Interpolation2D::Bilinear => Interpolation::Linear,
+            Interpolation2D::Bicubic => Interpolation::CubicNatural,
+        }
+    }
+}
+
+/// An interpolation of a function of two variables through its values on a grid, such as a
+/// volatility surface.
+///
+/// The surface is interpolated along the `y` axis for each `x` on the grid, and the results
+/// interpolated along the `x` axis. Outside the grid it is extrapolated along each axis with the
+/// same policy.
+#[derive(Clone, Debug)]
+pub struct Interpolator2D {
+    method: Interpolation2D,
+    extrapolation: Extrapolation,
+    xs: Vec<f64>,
+    ys: Vec<f64>,
+    rows: Vec<Interpolator>,
+}
+
+impl Interpolator2D {
+    /// Creates an interpolation through the points `(xs[i], ys[j], zs[i][j])`, which extends the
+    /// edge cells outside the grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The interpolation scheme.
+    /// * `xs` - The strictly increasing abscissae along the first axis.
+    /// * `ys` - The strictly increasing abscissae along the second axis.
+    /// * `zs` - The values, with a row for each of `xs` holding the value at each of `ys`.
+    ///
+    /// # Returns
+    ///
+    /// The interpolation, or an error if fewer than two abscissae are given along either axis, the
+    /// abscissae along either axis are not strictly increasing or the shape of `zs` does not
+    /// match them.
+    pub fn new(
+        method: Interpolation2D,
+        xs: Vec<f64>,
+        ys: Vec<f64>,
+        zs: Vec<Vec<f64>>,
+    ) -> Result<Self, LibraError> {
+        if xs.len() < 2 {
+            return Err(
+                ArgumentRange::new("number of points", 2, i64::MAX, xs.len() as i64).into(),
+            );
+        }
+
+        check_increasing("abscissae", &xs)?;
+
+        if zs.len() != xs.len() {
+            return Err(ArgumentRange::new(
+                "number of rows",
+                xs.len() as i64,
+                xs.len() as i64,
+                zs.len() as i64,
+            )
+            .into());
+        }
+
+        let rows = zs
+            .into_iter()
+            .map(|row| Interpolator::new(method.axis(), ys.clone(), row))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Interpolator2D {
+            method,
+            extrapolation: Extrapolation::Extend,
+            xs,
+            ys,
+            rows,
+        })
+    }
+
+    /// Continues the interpolation outside the grid with `extrapolation` along each axis.
+    pub fn with_extrapolation(mut self, extrapolation: Extrapolation) -> Self {
+        self.extrapolation = extrapolation;
+        self.rows = self
+            .rows
+            .into_iter()
+            .map(|row| row.with_extrapolation(extrapolation))
+            .collect();
+        self
+    }
+
+    pub fn method(&self) -> Interpolation2D {
+        self.method
+    }
+
+    pub fn extrapolation(&self) -> Extrapolation {
+        self.extrapolation
+    }
+
+    pub fn xs(&self) -> &[f64] {
+        &self.xs
+    }
+
+    pub fn ys(&self) -> &[f64] {
+        &self.ys
+    }
+
+    /// The interpolated value at `(x, y)`.
+    pub fn value(&self, x: f64, y: f64) -> f64 {
+        self.cross_section(y).value(x)
+    }
+
+    /// The partial derivative of the interpolation with respect to `x` at `(x, y)`.
+    pub fn derivative_x(&self, x: f64, y: f64) -> f64 {
+        self.cross_section(y).derivative(x)
+    }
+
+    /// The partial derivative of the interpolation with respect to `y` at `(x, y)`.
+    pub fn derivative_y(&self, x: f64, y: f64) -> f64 {
+        let slopes = self.rows.iter().map(|row| row.derivative(y)).collect();
+
+        self.along_x(slopes).value(x)
+    }
+
+    /// The interpolation along the `x` axis at `y`.
+    fn cross_section(&self, y: f64) -> Interpolator {
+        self.along_x(self.rows.iter().map(|row| row.value(y)).collect())
+    }
+
+    /// The interpolation along the `x` axis through `values` at each of `xs`.
+    fn along_x(&self, values: Vec<f64>) -> Interpolator {
+        Interpolator::new(self.method.axis(), self.xs.clone(), values)
+            .expect("a value for each row")
+            .with_extrapolation(self.extrapolation)
+    }
+}
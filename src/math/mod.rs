@@ -0,0 +1,3 @@
+pub mod distributions;
+pub mod interpolation;
+pub mod solvers;
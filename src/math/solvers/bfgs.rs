@@ -0,0 +1,190 @@
+use crate::error::{LibraError, NoConvergence};
+
+use super::{
+    has_converged, max_norm, Constraint, Minimum, Optimizer, DEFAULT_MAX_OPTIMIZER_EVALUATIONS,
+    DEFAULT_OPTIMIZER_TOLERANCE,
+};
+
+/// The relative size of the finite difference steps used to find the gradient.
+const GRADIENT_STEP: f64 = 1.0e-6;
+
+/// The fraction of the decrease predicted by the gradient that a line search step must achieve.
+const SUFFICIENT_DECREASE: f64 = 1.0e-4;
+
+/// The Broyden-Fletcher-Goldfarb-Shanno quasi-Newton method, which builds an approximation to
+/// the inverse Hessian from successive gradients and searches along the direction it gives.
+///
+/// Gradients are found by central differences, and each step is found by backtracking until the
+/// objective decreases sufficiently.
+#[derive(Clone, Copy, Debug)]
+pub struct Bfgs {
+    max_evaluations: usize,
+    tolerance: f64,
+}
+
+impl Bfgs {
+    pub fn new() -> Self {
+        Bfgs {
+            max_evaluations: DEFAULT_MAX_OPTIMIZER_EVALUATIONS,
+            tolerance: DEFAULT_OPTIMIZER_TOLERANCE,
+        }
+    }
+
+    pub fn with_max_evaluations(mut self, max_evaluations: usize) -> Self {
+        self.max_evaluations = max_evaluations;
+        self
+    }
+
+    /// Stops when the gradient is smaller than `tolerance`, or the objective changes by less
+    /// than `tolerance` relative to its size and the parameters by less than its square root.
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+}
+
+impl Default for Bfgs {
+    fn default() -> Self {
+        Bfgs::new()
+    }
+}
+
+/// The gradient of `f` at `x` by central differences, or one-sided differences at the bounds.
+fn gradient(f: &dyn Fn(&[f64]) -> f64, x: &[f64], constraint: &Constraint) -> Vec<f64> {
+    (0..x.len())
+        .map(|i| {
+            let step = constraint.difference_step(i, x[i], GRADIENT_STEP).abs();
+            let (mut up, mut down) = (x.to_vec(), x.to_vec());
+            up[i] += step;
+            down[i] -= step;
+            constraint.project(&mut up);
+            constraint.project(&mut down);
+
+            (f(&up) - f(&down)) / (up[i] - down[i])
+        })
+        .collect()
+}
+
+impl Optimizer for Bfgs {
+    fn minimize(
+        &self,
+        f: &dyn Fn(&[f64]) -> f64,
+        initial: &[f64],
+        constraint: &Constraint,
+    ) -> Result<Minimum, LibraError> {
+        constraint.check(initial.len())?;
+
+        let n = initial.len();
+        let dot = |a: &[f64], b: &[f64]| a.iter().zip(b).map(|(x, y)| x * y).sum::<f64>();
+
+        let mut x = initial.to_vec();
+        constraint.project(&mut x);
+        let mut value = f(&x);
+        let mut g = gradient(f, &x, constraint);
+        let mut evaluations = 1 + 2 * n;
+
+        let identity = |n: usize| -> Vec<Vec<f64>> {
+            (0..n)
+                .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+                .collect()
+        };
+        let mut inverse_hessian = identity(n);
+        let mut converged = false;
+
+        while evaluations < self.max_evaluations {
+            // Parameters held at a bound by the gradient are left where they are.
+            let free = |i: usize| match constraint.bounds().get(i) {
+                Some((lower, _)) if x[i] <= *lower && g[i] > 0.0 => false,
+                Some((_, upper)) if x[i] >= *upper && g[i] < 0.0 => false,
+                _ => true,
+            };
+            let projected: Vec<f64> = (0..n).map(|i| if free(i) { g[i] } else { 0.0 }).collect();
+
+            if max_norm(&projected) <= self.tolerance {
+                converged = true;
+                break;
+            }
+
+            let mut direction: Vec<f64> = (0..n)
+                .map(|i| {
+                    if free(i) {
+                        -dot(&inverse_hessian[i], &projected)
+                    } else {
+                        0.0
+                    }
+                })
+                .collect();
+            if dot(&direction, &projected) >= 0.0 {
+                inverse_hessian = identity(n);
+                direction = projected.iter().map(|gi| -gi).collect();
+            }
+
+            // Backtrack along the direction until the objective decreases sufficiently.
+            let mut length = 1.0;
+            let (x_next, next_value) = loop {
+                let mut trial: Vec<f64> = x
+                    .iter()
+                    .zip(&direction)
+                    .map(|(a, d)| a + length * d)
+                    .collect();
+                constraint.project(&mut trial);
+                let trial_value = f(&trial);
+                evaluations += 1;
+
+                let step: Vec<f64> = trial.iter().zip(&x).map(|(a, b)| a - b).collect();
+                if trial_value <= value + SUFFICIENT_DECREASE * dot(&g, &step) {
+                    break (trial, trial_value);
+                }
+
+                length /= 2.0;
+                if length < f64::EPSILON || evaluations >= self.max_evaluations {
+                    break (x.clone(), value);
+                }
+            };
+
+            // The objective cannot be reduced along the direction, so the parameters are at a
+            // minimum to the precision of the gradient.
+            if x_next == x {
+                converged = true;
+                break;
+            }
+
+            let g_next = gradient(f, &x_next, constraint);
+            evaluations += 2 * n;
+
+            converged = has_converged(&x, &x_next, value, next_value, self.tolerance);
+            let s: Vec<f64> = x_next.iter().zip(&x).map(|(a, b)| a - b).collect();
+            let y: Vec<f64> = g_next.iter().zip(&g).map(|(a, b)| a - b).collect();
+            (x, value, g) = (x_next, next_value, g_next);
+
+            if converged {
+                break;
+            }
+
+            // Update the inverse Hessian as (I - rho s y') H (I - rho y s') + rho s s'.
+            let sy = dot(&s, &y);
+            if sy > f64::EPSILON * dot(&y, &y).sqrt() * dot(&s, &s).sqrt() {
+                let rho = 1.0 / sy;
+                let hy: Vec<f64> = inverse_hessian.iter().map(|row| dot(row, &y)).collect();
+                let yhy = dot(&y, &hy);
+
+                for i in 0..n {
+                    for j in 0..n {
+                        inverse_hessian[i][j] += -rho * (hy[i] * s[j] + s[i] * hy[j])
+                            + (rho * rho * yhy + rho) * s[i] * s[j];
+                    }
+                }
+            }
+        }
+
+        if !converged {
+            return Err(NoConvergence::new("BFGS", self.max_evaluations).into());
+        }
+
+        Ok(Minimum {
+            parameters: x,
+            value,
+            evaluations,
+        })
+    }
+}
@@ -0,0 +1,75 @@
+use crate::error::{LibraError, NoConvergence, NotBracketed};
+
+use super::{Solver1D, DEFAULT_MAX_EVALUATIONS};
+
+/// The bisection method, which halves the bracket at each step.
+///
+/// Convergence is slow but guaranteed once a root is bracketed.
+#[derive(Clone, Copy, Debug)]
+pub struct Bisection {
+    max_evaluations: usize,
+}
+
+impl Bisection {
+    pub fn new() -> Self {
+        Bisection {
+            max_evaluations: DEFAULT_MAX_EVALUATIONS,
+        }
+    }
+
+    pub fn with_max_evaluations(mut self, max_evaluations: usize) -> Self {
+        self.max_evaluations = max_evaluations;
+        self
+    }
+}
+
+impl Default for Bisection {
+    fn default() -> Self {
+        Bisection::new()
+    }
+}
+
+impl Solver1D for Bisection {
+    fn solve_bracketed(
+        &self,
+        f: &dyn Fn(f64) -> f64,
+        accuracy: f64,
+        lower: f64,
+        upper: f64,
+    ) -> Result<f64, LibraError> {
+        let (f_lower, f_upper) = (f(lower), f(upper));
+
+        if f_lower == 0.0 {
+            return Ok(lower);
+        }
+        if f_upper == 0.0 {
+            return Ok(upper);
+        }
+        if f_lower * f_upper > 0.0 {
+            return Err(NotBracketed::new(lower, upper, f_lower, f_upper).into());
+        }
+
+        // Orient the search so that f is negative at `x` and positive at `x + dx`.
+        let (mut x, mut dx) = if f_lower < 0.0 {
+            (lower, upper - lower)
+        } else {
+            (upper, lower - upper)
+        };
+
+        for _ in 2..self.max_evaluations {
+            dx /= 2.0;
+            let midpoint = x + dx;
+            let f_midpoint = f(midpoint);
+
+            if f_midpoint <= 0.0 {
+                x = midpoint;
+            }
+
+            if dx.abs() < accuracy || f_midpoint == 0.0 {
+                return Ok(midpoint);
+            }
+        }
+
+        Err(NoConvergence::new("bisection", self.max_evaluations).into())
+    }
+}
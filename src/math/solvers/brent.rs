@@ -0,0 +1,128 @@
+use crate::error::{LibraError, NoConvergence, NotBracketed};
+
+use super::{Solver1D, DEFAULT_MAX_EVALUATIONS};
+
+/// Brent's method, combining bisection, secant and inverse quadratic interpolation steps.
+///
+/// Convergence is guaranteed once a root is bracketed, and is superlinear for smooth functions.
+#[derive(Clone, Copy, Debug)]
+pub struct Brent {
+    max_evaluations: usize,
+}
+
+impl Brent {
+    pub fn new() -> Self {
+        Brent {
+            max_evaluations: DEFAULT_MAX_EVALUATIONS,
+        }
+    }
+
+    pub fn with_max_evaluations(mut self, max_evaluations: usize) -> Self {
+        self.max_evaluations = max_evaluations;
+        self
+    }
+}
+
+impl Default for Brent {
+    fn default() -> Self {
+        Brent::new()
+    }
+}
+
+impl Solver1D for Brent {
+    fn solve_bracketed(
+        &self,
+        f: &dyn Fn(f64) -> f64,
+        accuracy: f64,
+        lower: f64,
+        upper: f64,
+    ) -> Result<f64, LibraError> {
+        let (mut a, mut b) = (lower, upper);
+        let (mut fa, mut fb) = (f(a), f(b));
+
+        if fa == 0.0 {
+            return Ok(a);
+        }
+        if fb == 0.0 {
+            return Ok(b);
+        }
+        if fa * fb > 0.0 {
+            return Err(NotBracketed::new(a, b, fa, fb).into());
+        }
+
+        let (mut c, mut fc) = (b, fb);
+        let (mut d, mut e) = (b - a, b - a);
+
+        for _ in 2..self.max_evaluations {
+            if fb * fc > 0.0 {
+                c = a;
+                fc = fa;
+                d = b - a;
+                e = d;
+            }
+
+            if fc.abs() < fb.abs() {
+                a = b;
+                b = c;
+                c = a;
+                fa = fb;
+                fb = fc;
+                fc = fa;
+            }
+
+            let tolerance = 2.0 * f64::EPSILON * b.abs() + 0.5 * accuracy;
+            let midpoint = 0.5 * (c - b);
+
+            if midpoint.abs() <= tolerance || fb == 0.0 {
+                return Ok(b);
+            }
+
+            if e.abs() >= tolerance && fa.abs() > fb.abs() {
+                // Attempt inverse quadratic interpolation, or a secant step if only two points
+                // are distinct.
+                let s = fb / fa;
+                let (mut p, mut q) = if a == c {
+                    (2.0 * midpoint * s, 1.0 - s)
+                } else {
+                    let q = fa / fc;
+                    let r = fb / fc;
+                    (
+                        s * (2.0 * midpoint * q * (q - r) - (b - a) * (r - 1.0)),
+                        (q - 1.0) * (r - 1.0) * (s - 1.0),
+                    )
+                };
+
+                if p > 0.0 {
+                    q = -q;
+                }
+                p = p.abs();
+
+                let min1 = 3.0 * midpoint * q - (tolerance * q).abs();
+                let min2 = (e * q).abs();
+
+                if 2.0 * p < min1.min(min2) {
+                    e = d;
+                    d = p / q;
+                } else {
+                    d = midpoint;
+                    e = d;
+                }
+            } else {
+                d = midpoint;
+                e = d;
+            }
+
+            a = b;
+            fa = fb;
+
+            b += if d.abs() > tolerance {
+                d
+            } else {
+                tolerance.copysign(midpoint)
+            };
+            fb = f(b);
+        }
+
+        Err(NoConvergence::new("Brent", self.max_evaluations).into())
+    }
+}
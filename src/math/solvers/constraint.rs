@@ -0,0 +1,73 @@
+use crate::error::{ArgumentRange, LibraError};
+
+/// Bounds on the parameters of a multidimensional optimization.
+///
+/// Optimizers keep to the bounds by projecting each trial point onto them.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Constraint {
+    bounds: Vec<(f64, f64)>,
+}
+
+impl Constraint {
+    /// No bounds on any parameter.
+    pub fn none() -> Self {
+        Constraint { bounds: Vec::new() }
+    }
+
+    /// Bounds each parameter between the `(lower, upper)` pair at its position.
+    pub fn bounded(bounds: Vec<(f64, f64)>) -> Self {
+        Constraint { bounds }
+    }
+
+    /// Bounds each of `parameters` parameters below by zero.
+    pub fn non_negative(parameters: usize) -> Self {
+        Constraint {
+            bounds: vec![(0.0, f64::INFINITY); parameters],
+        }
+    }
+
+    pub fn bounds(&self) -> &[(f64, f64)] {
+        &self.bounds
+    }
+
+    /// Whether every parameter lies within its bounds.
+    pub fn is_satisfied(&self, parameters: &[f64]) -> bool {
+        self.bounds
+            .iter()
+            .zip(parameters)
+            .all(|((lower, upper), x)| lower <= x && x <= upper)
+    }
+
+    /// Moves each parameter to the nearest point within its bounds.
+    pub fn project(&self, parameters: &mut [f64]) {
+        for ((lower, upper), x) in self.bounds.iter().zip(parameters.iter_mut()) {
+            *x = x.max(*lower).min(*upper);
+        }
+    }
+
+    /// Checks that the constraint applies to `parameters` parameters.
+    pub(super) fn check(&self, parameters: usize) -> Result<(), LibraError> {
+        if self.bounds.is_empty() || self.bounds.len() == parameters {
+            Ok(())
+        } else {
+            Err(ArgumentRange::new(
+                "number of bounds",
+                parameters as i64,
+                parameters as i64,
+                self.bounds.len() as i64,
+            )
+            .into())
+        }
+    }
+
+    /// A step of about `size` for finite differences in parameter `i` from `x`, reversed if a
+    /// forward step would leave the bounds.
+    pub(super) fn difference_step(&self, i: usize, x: f64, size: f64) -> f64 {
+        let step = size * x.abs().max(1.0);
+
+        match self.bounds.get(i) {
+            Some((_, upper)) if x + step > *upper => -step,
+            _ => step,
+        }
+    }
+}
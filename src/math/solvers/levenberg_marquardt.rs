@@ -0,0 +1,162 @@
+use crate::error::{LibraError, NoConvergence};
+
+use super::{
+    has_converged, linear::solve_linear_system, max_norm, Constraint, Minimum,
+    DEFAULT_MAX_OPTIMIZER_EVALUATIONS, DEFAULT_OPTIMIZER_TOLERANCE,
+};
+
+/// The relative size of the finite difference steps used to find the Jacobian.
+const JACOBIAN_STEP: f64 = 1.0e-7;
+
+/// The Levenberg-Marquardt method for nonlinear least squares, which blends Gauss-Newton and
+/// gradient descent steps by damping the Gauss-Newton equations.
+///
+/// The Jacobian of the residuals is found by forward differences.
+#[derive(Clone, Copy, Debug)]
+pub struct LevenbergMarquardt {
+    max_evaluations: usize,
+    tolerance: f64,
+}
+
+impl LevenbergMarquardt {
+    pub fn new() -> Self {
+        LevenbergMarquardt {
+            max_evaluations: DEFAULT_MAX_OPTIMIZER_EVALUATIONS,
+            tolerance: DEFAULT_OPTIMIZER_TOLERANCE,
+        }
+    }
+
+    pub fn with_max_evaluations(mut self, max_evaluations: usize) -> Self {
+        self.max_evaluations = max_evaluations;
+        self
+    }
+
+    /// Stops when the sum of squares changes by less than `tolerance` relative to its size, and
+    /// the parameters by less than its square root.
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Minimizes the sum of squares of `residuals`.
+    ///
+    /// # Arguments
+    ///
+    /// * `residuals` - The residuals for a set of parameters, which must always have the same
+    ///   length.
+    /// * `initial` - The starting parameters.
+    /// * `constraint` - The bounds the parameters must stay within.
+    ///
+    /// # Returns
+    ///
+    /// The minimum, whose value is the sum of squared residuals, or an error if the constraint
+    /// does not match the number of parameters or the method does not converge.
+    pub fn minimize(
+        &self,
+        residuals: &dyn Fn(&[f64]) -> Vec<f64>,
+        initial: &[f64],
+        constraint: &Constraint,
+    ) -> Result<Minimum, LibraError> {
+        constraint.check(initial.len())?;
+
+        let sum_of_squares = |r: &[f64]| r.iter().map(|ri| ri * ri).sum::<f64>();
+        let n = initial.len();
+
+        let mut x = initial.to_vec();
+        constraint.project(&mut x);
+        let mut r = residuals(&x);
+        let mut value = sum_of_squares(&r);
+        let mut evaluations = 1;
+        let mut damping = 1.0e-3;
+
+        while evaluations < self.max_evaluations {
+            // The Jacobian, stored by column.
+            let jacobian: Vec<Vec<f64>> = (0..n)
+                .map(|j| {
+                    let mut shifted = x.clone();
+                    let step = constraint.difference_step(j, x[j], JACOBIAN_STEP);
+                    shifted[j] += step;
+
+                    residuals(&shifted)
+                        .iter()
+                        .zip(&r)
+                        .map(|(a, b)| (a - b) / step)
+                        .collect()
+                })
+                .collect();
+            evaluations += n;
+
+            let dot = |a: &[f64], b: &[f64]| a.iter().zip(b).map(|(x, y)| x * y).sum::<f64>();
+            let gradient: Vec<f64> = jacobian.iter().map(|column| dot(column, &r)).collect();
+            let normal: Vec<Vec<f64>> = jacobian
+                .iter()
+                .map(|a| jacobian.iter().map(|b| dot(a, b)).collect())
+                .collect();
+
+            if max_norm(&gradient) <= self.tolerance * (1.0 + value) {
+                return Ok(Minimum {
+                    parameters: x,
+                    value,
+                    evaluations,
+                });
+            }
+
+            loop {
+                let mut damped = normal.clone();
+                for (i, row) in damped.iter_mut().enumerate() {
+                    row[i] += damping * normal[i][i].max(f64::EPSILON);
+                }
+
+                let step = solve_linear_system(damped, gradient.iter().map(|g| -g).collect());
+                let mut x_next: Vec<f64> = match step {
+                    Some(step) => x.iter().zip(&step).map(|(a, b)| a + b).collect(),
+                    None => x.clone(),
+                };
+                constraint.project(&mut x_next);
+
+                let r_next = residuals(&x_next);
+                let next_value = sum_of_squares(&r_next);
+                evaluations += 1;
+
+                if next_value < value {
+                    let converged = has_converged(&x, &x_next, value, next_value, self.tolerance);
+                    (x, r, value) = (x_next, r_next, next_value);
+                    damping = (damping / 10.0).max(1.0e-12);
+
+                    if converged {
+                        return Ok(Minimum {
+                            parameters: x,
+                            value,
+                            evaluations,
+                        });
+                    }
+                    break;
+                }
+
+                damping *= 10.0;
+
+                // No step reduces the sum of squares, so the parameters are at a minimum to
+                // machine precision.
+                if damping > 1.0e16 {
+                    return Ok(Minimum {
+                        parameters: x,
+                        value,
+                        evaluations,
+                    });
+                }
+
+                if evaluations >= self.max_evaluations {
+                    break;
+                }
+            }
+        }
+
+        Err(NoConvergence::new("Levenberg-Marquardt", self.max_evaluations).into())
+    }
+}
+
+impl Default for LevenbergMarquardt {
+    fn default() -> Self {
+        LevenbergMarquardt::new()
+    }
+}
@@ -0,0 +1,66 @@
+/// Solves the square linear system `a x = b` by Gaussian elimination with partial pivoting.
+///
+/// Returns `None` if `a` is singular.
+pub(super) fn solve_linear_system(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+
+    for column in 0..n {
+        let pivot =
+            (column..n).max_by(|i, j| a[*i][column].abs().total_cmp(&a[*j][column].abs()))?;
+
+        if a[pivot][column].abs() < f64::MIN_POSITIVE {
+            return None;
+        }
+
+        a.swap(column, pivot);
+        b.swap(column, pivot);
+
+        let pivot_row = a[column].clone();
+
+        for row in column + 1..n {
+            let factor = a[row][column] / pivot_row[column];
+
+            for (entry, pivot) in a[row][column..].iter_mut().zip(&pivot_row[column..]) {
+                *entry -= factor * pivot;
+            }
+            b[row] -= factor * b[column];
+        }
+    }
+
+    let mut x = vec![0.0; n];
+
+    for row in (0..n).rev() {
+        let sum: f64 = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+
+    Some(x)
+}
+
+/// Solves the tridiagonal system with the given sub-diagonal, diagonal, super-diagonal and right
+/// hand side by the Thomas algorithm.
+pub(crate) fn solve_tridiagonal(
+    lower: &[f64],
+    diagonal: &[f64],
+    upper: &[f64],
+    rhs: &[f64],
+) -> Vec<f64> {
+    let n = diagonal.len();
+    let mut diagonal = diagonal.to_vec();
+    let mut rhs = rhs.to_vec();
+
+    for i in 1..n {
+        let factor = lower[i] / diagonal[i - 1];
+        diagonal[i] -= factor * upper[i - 1];
+        rhs[i] -= factor * rhs[i - 1];
+    }
+
+    let mut solution = vec![0.0; n];
+    solution[n - 1] = rhs[n - 1] / diagonal[n - 1];
+
+    for i in (0..n - 1).rev() {
+        solution[i] = (rhs[i] - upper[i] * solution[i + 1]) / diagonal[i];
+    }
+
+    solution
+}
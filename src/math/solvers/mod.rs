@@ -0,0 +1,176 @@
+mod bfgs;
+mod bisection;
+mod brent;
+mod constraint;
+mod levenberg_marquardt;
+mod linear;
+mod nelder_mead;
+mod newton;
+mod ridder;
+mod secant;
+
+pub use bfgs::*;
+pub use bisection::*;
+pub use brent::*;
+pub use constraint::*;
+pub use levenberg_marquardt::*;
+pub use nelder_mead::*;
+pub use newton::*;
+pub use ridder::*;
+pub use secant::*;
+
+pub(crate) use linear::solve_tridiagonal;
+
+use crate::error::{LibraError, NoConvergence, NotBracketed};
+
+/// The default maximum number of function evaluations used by solvers.
+pub const DEFAULT_MAX_EVALUATIONS: usize = 100;
+
+/// The default maximum number of function evaluations used by multidimensional optimizers.
+pub const DEFAULT_MAX_OPTIMIZER_EVALUATIONS: usize = 10_000;
+
+/// The default tolerance used by multidimensional optimizers.
+pub const DEFAULT_OPTIMIZER_TOLERANCE: f64 = 1.0e-8;
+
+/// A one-dimensional root finder.
+pub trait Solver1D {
+    /// Finds a root of `f` between `lower` and `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function to find a root of.
+    /// * `accuracy` - The tolerance on the root.
+    /// * `lower` - The lower end of the bracket.
+    /// * `upper` - The upper end of the bracket.
+    ///
+    /// # Returns
+    ///
+    /// The root, or an error if `f(lower)` and `f(upper)` have the same sign or the solver does
+    /// not converge.
+    fn solve_bracketed(
+        &self,
+        f: &dyn Fn(f64) -> f64,
+        accuracy: f64,
+        lower: f64,
+        upper: f64,
+    ) -> Result<f64, LibraError>;
+
+    /// Finds a root of `f` near `guess`, first searching outwards in steps of `step` for a bracket.
+    fn solve(
+        &self,
+        f: &dyn Fn(f64) -> f64,
+        accuracy: f64,
+        guess: f64,
+        step: f64,
+    ) -> Result<f64, LibraError> {
+        let (lower, upper) = bracket(
+            f,
+            guess,
+            step,
+            (f64::MIN, f64::MAX),
+            DEFAULT_MAX_EVALUATIONS,
+        )?;
+
+        self.solve_bracketed(f, accuracy, lower, upper)
+    }
+}
+
+/// Searches outwards from `guess` for an interval on which `f` changes sign.
+///
+/// # Arguments
+///
+/// * `f` - The function to bracket a root of.
+/// * `guess` - The centre of the initial interval.
+/// * `step` - The half-width of the initial interval.
+/// * `domain` - The `(min, max)` limits the interval may not be expanded beyond.
+/// * `max_evaluations` - The maximum number of function evaluations.
+///
+/// # Returns
+///
+/// The bracketing interval `(lower, upper)`.
+pub fn bracket(
+    f: &dyn Fn(f64) -> f64,
+    guess: f64,
+    step: f64,
+    domain: (f64, f64),
+    max_evaluations: usize,
+) -> Result<(f64, f64), LibraError> {
+    const GROWTH: f64 = 1.6;
+
+    let (min, max) = domain;
+    let mut lower = (guess - step).max(min);
+    let mut upper = (guess + step).min(max);
+    let mut f_lower = f(lower);
+    let mut f_upper = f(upper);
+
+    for _ in 2..max_evaluations {
+        if f_lower * f_upper <= 0.0 {
+            return Ok((lower, upper));
+        }
+
+        if (f_lower.abs() < f_upper.abs() && lower > min) || upper >= max {
+            lower = (lower + GROWTH * (lower - upper)).max(min);
+            f_lower = f(lower);
+        } else {
+            upper = (upper + GROWTH * (upper - lower)).min(max);
+            f_upper = f(upper);
+        }
+
+        if lower <= min && upper >= max && f_lower * f_upper > 0.0 {
+            return Err(NotBracketed::new(lower, upper, f_lower, f_upper).into());
+        }
+    }
+
+    if f_lower * f_upper <= 0.0 {
+        Ok((lower, upper))
+    } else {
+        Err(NoConvergence::new("bracketing", max_evaluations).into())
+    }
+}
+
+/// The result of a multidimensional optimization.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Minimum {
+    /// The parameters at the minimum.
+    pub parameters: Vec<f64>,
+    /// The value of the objective at the minimum.
+    pub value: f64,
+    /// The number of times the objective was evaluated.
+    pub evaluations: usize,
+}
+
+/// A multidimensional minimizer.
+pub trait Optimizer {
+    /// Finds a local minimum of `f`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The objective function.
+    /// * `initial` - The starting parameters.
+    /// * `constraint` - The bounds the parameters must stay within.
+    ///
+    /// # Returns
+    ///
+    /// The minimum, or an error if the constraint does not match the number of parameters or
+    /// the optimizer does not converge.
+    fn minimize(
+        &self,
+        f: &dyn Fn(&[f64]) -> f64,
+        initial: &[f64],
+        constraint: &Constraint,
+    ) -> Result<Minimum, LibraError>;
+}
+
+/// The largest absolute component of `x`.
+fn max_norm(x: &[f64]) -> f64 {
+    x.iter().fold(0.0, |norm, xi| norm.max(xi.abs()))
+}
+
+/// Whether a step from `x` to `x_next`, moving the objective from `value` to `next_value`, is
+/// small enough to stop at `tolerance`.
+fn has_converged(x: &[f64], x_next: &[f64], value: f64, next_value: f64, tolerance: f64) -> bool {
+    let step: Vec<f64> = x.iter().zip(x_next).map(|(a, b)| b - a).collect();
+
+    (value - next_value).abs() <= tolerance * (1.0 + next_value.abs())
+        && max_norm(&step) <= tolerance.sqrt() * (1.0 + max_norm(x_next))
+}
@@ -0,0 +1,146 @@
+use std::cell::Cell;
+
+use crate::error::{LibraError, NoConvergence};
+
+use super::{
+    has_converged, Constraint, Minimum, Optimizer, DEFAULT_MAX_OPTIMIZER_EVALUATIONS,
+    DEFAULT_OPTIMIZER_TOLERANCE,
+};
+
+/// The Nelder-Mead downhill simplex method, which needs no derivatives.
+///
+/// A simplex of points around the starting parameters is reflected, expanded and contracted
+/// until it surrounds a minimum.
+#[derive(Clone, Copy, Debug)]
+pub struct NelderMead {
+    max_evaluations: usize,
+    tolerance: f64,
+    initial_step: f64,
+}
+
+impl NelderMead {
+    pub fn new() -> Self {
+        NelderMead {
+            max_evaluations: DEFAULT_MAX_OPTIMIZER_EVALUATIONS,
+            tolerance: DEFAULT_OPTIMIZER_TOLERANCE,
+            initial_step: 0.1,
+        }
+    }
+
+    pub fn with_max_evaluations(mut self, max_evaluations: usize) -> Self {
+        self.max_evaluations = max_evaluations;
+        self
+    }
+
+    /// Stops when the values at the points of the simplex differ by less than `tolerance`
+    /// relative to their size, and the points by less than its square root.
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Sets the size of the initial simplex in each direction, relative to the size of each
+    /// starting parameter and at least `initial_step`.
+    pub fn with_initial_step(mut self, initial_step: f64) -> Self {
+        self.initial_step = initial_step;
+        self
+    }
+}
+
+impl Default for NelderMead {
+    fn default() -> Self {
+        NelderMead::new()
+    }
+}
+
+impl Optimizer for NelderMead {
+    fn minimize(
+        &self,
+        f: &dyn Fn(&[f64]) -> f64,
+        initial: &[f64],
+        constraint: &Constraint,
+    ) -> Result<Minimum, LibraError> {
+        constraint.check(initial.len())?;
+
+        let n = initial.len();
+        let evaluations = Cell::new(0);
+        let evaluate = |mut x: Vec<f64>| {
+            constraint.project(&mut x);
+            evaluations.set(evaluations.get() + 1);
+            let value = f(&x);
+            (x, value)
+        };
+
+        let mut simplex = vec![evaluate(initial.to_vec())];
+        for i in 0..n {
+            let mut vertex = initial.to_vec();
+            vertex[i] += constraint.difference_step(i, vertex[i], self.initial_step);
+            simplex.push(evaluate(vertex));
+        }
+
+        // A point `weight` of the way from the centroid to the worst point.
+        let along = |centroid: &[f64], worst: &[f64], weight: f64| -> Vec<f64> {
+            centroid
+                .iter()
+                .zip(worst)
+                .map(|(c, w)| c + weight * (w - c))
+                .collect()
+        };
+
+        loop {
+            simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+            let (best, worst) = (&simplex[0], &simplex[n]);
+            if simplex
+                .iter()
+                .all(|vertex| has_converged(&vertex.0, &best.0, worst.1, best.1, self.tolerance))
+            {
+                let (parameters, value) = simplex.swap_remove(0);
+                return Ok(Minimum {
+                    parameters,
+                    value,
+                    evaluations: evaluations.get(),
+                });
+            }
+
+            if evaluations.get() >= self.max_evaluations {
+                return Err(NoConvergence::new("Nelder-Mead", self.max_evaluations).into());
+            }
+
+            let centroid: Vec<f64> = (0..n)
+                .map(|j| simplex[..n].iter().map(|v| v.0[j]).sum::<f64>() / n as f64)
+                .collect();
+            let worst = simplex[n].clone();
+
+            let reflected = evaluate(along(&centroid, &worst.0, -1.0));
+
+            let replacement = if reflected.1 < simplex[0].1 {
+                let expanded = evaluate(along(&centroid, &worst.0, -2.0));
+                Some(if expanded.1 < reflected.1 {
+                    expanded
+                } else {
+                    reflected
+                })
+            } else if reflected.1 < simplex[n - 1].1 {
+                Some(reflected)
+            } else if reflected.1 < worst.1 {
+                let contracted = evaluate(along(&centroid, &worst.0, -0.5));
+                (contracted.1 <= reflected.1).then_some(contracted)
+            } else {
+                let contracted = evaluate(along(&centroid, &worst.0, 0.5));
+                (contracted.1 < worst.1).then_some(contracted)
+            };
+
+            match replacement {
+                Some(vertex) => simplex[n] = vertex,
+                None => {
+                    // Shrink every point halfway towards the best.
+                    let best = simplex[0].0.clone();
+                    for vertex in simplex.iter_mut().skip(1) {
+                        *vertex = evaluate(along(&best, &vertex.0, 0.5));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use crate::error::{LibraError, NoConvergence, NotBracketed};
+
+use super::DEFAULT_MAX_EVALUATIONS;
+
+/// Newton's method, which steps to the root of the tangent at the last point.
+///
+/// Convergence is quadratic near a simple root, but the method can diverge from a poor guess or
+/// where the derivative vanishes.
+#[derive(Clone, Copy, Debug)]
+pub struct Newton {
+    max_evaluations: usize,
+}
+
+impl Newton {
+    pub fn new() -> Self {
+        Newton {
+            max_evaluations: DEFAULT_MAX_EVALUATIONS,
+        }
+    }
+
+    pub fn with_max_evaluations(mut self, max_evaluations: usize) -> Self {
+        self.max_evaluations = max_evaluations;
+        self
+    }
+
+    /// Finds a root of `f` starting from `guess`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function to find a root of.
+    /// * `derivative` - The derivative of `f`.
+    /// * `accuracy` - The tolerance on the root.
+    /// * `guess` - The starting point.
+    ///
+    /// # Returns
+    ///
+    /// The root, or an error if the derivative vanishes or the method does not converge.
+    pub fn solve(
+        &self,
+        f: &dyn Fn(f64) -> f64,
+        derivative: &dyn Fn(f64) -> f64,
+        accuracy: f64,
+        guess: f64,
+    ) -> Result<f64, LibraError> {
+        let mut x = guess;
+
+        for _ in 0..self.max_evaluations {
+            let (value, slope) = (f(x), derivative(x));
+
+            if value == 0.0 {
+                return Ok(x);
+            }
+            if slope == 0.0 || !slope.is_finite() {
+                break;
+            }
+
+            let dx = value / slope;
+            x -= dx;
+
+            if dx.abs() < accuracy {
+                return Ok(x);
+            }
+        }
+
+        Err(NoConvergence::new("Newton", self.max_evaluations).into())
+    }
+}
+
+impl Default for Newton {
+    fn default() -> Self {
+        Newton::new()
+    }
+}
+
+/// Newton's method safeguarded by bisection, which keeps the root bracketed and bisects whenever
+/// a Newton step would leave the bracket or converge too slowly.
+#[derive(Clone, Copy, Debug)]
+pub struct NewtonSafe {
+    max_evaluations: usize,
+}
+
+impl NewtonSafe {
+    pub fn new() -> Self {
+        NewtonSafe {
+            max_evaluations: DEFAULT_MAX_EVALUATIONS,
+        }
+    }
+
+    pub fn with_max_evaluations(mut self, max_evaluations: usize) -> Self {
+        self.max_evaluations = max_evaluations;
+        self
+    }
+
+    /// Finds a root of `f` between `lower` and `upper`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function to find a root of.
+    /// * `derivative` - The derivative of `f`.
+    /// * `accuracy` - The tolerance on the root.
+    /// * `lower` - The lower end of the bracket.
+    /// * `upper` - The upper end of the bracket.
+    ///
+    /// # Returns
+    ///
+    /// The root, or an error if `f(lower)` and `f(upper)` have the same sign or the method does
+    /// not converge.
+    pub fn solve_bracketed(
+        &self,
+        f: &dyn Fn(f64) -> f64,
+        derivative: &dyn Fn(f64) -> f64,
+        accuracy: f64,
+        lower: f64,
+        upper: f64,
+    ) -> Result<f64, LibraError> {
+        let (f_lower, f_upper) = (f(lower), f(upper));
+
+        if f_lower == 0.0 {
+            return Ok(lower);
+        }
+        if f_upper == 0.0 {
+            return Ok(upper);
+        }
+        if f_lower * f_upper > 0.0 {
+            return Err(NotBracketed::new(lower, upper, f_lower, f_upper).into());
+        }
+
+        // Orient the bracket so that f is negative at `low` and positive at `high`.
+        let (mut low, mut high) = if f_lower < 0.0 {
+            (lower, upper)
+        } else {
+            (upper, lower)
+        };
+
+        let mut x = 0.5 * (lower + upper);
+        let mut dx_previous = (upper - lower).abs();
+        let mut dx = dx_previous;
+        let (mut value, mut slope) = (f(x), derivative(x));
+
+        for _ in 0..self.max_evaluations {
+            let leaves_bracket = ((x - high) * slope - value) * ((x - low) * slope - value) > 0.0;
+            let too_slow = (2.0 * value).abs() > (dx_previous * slope).abs();
+
+            dx_previous = dx;
+            if leaves_bracket || too_slow {
+                dx = 0.5 * (high - low);
+                x = low + dx;
+            } else {
+                dx = value / slope;
+                x -= dx;
+            }
+
+            if dx.abs() < accuracy {
+                return Ok(x);
+            }
+
+            (value, slope) = (f(x), derivative(x));
+
+            if value == 0.0 {
+                return Ok(x);
+            }
+            if value < 0.0 {
+                low = x;
+            } else {
+                high = x;
+            }
+        }
+
+        Err(NoConvergence::new("Newton-safe", self.max_evaluations).into())
+    }
+}
+
+impl Default for NewtonSafe {
+    fn default() -> Self {
+        NewtonSafe::new()
+    }
+}
@@ -0,0 +1,90 @@
+use crate::error::{LibraError, NoConvergence, NotBracketed};
+
+use super::{Solver1D, DEFAULT_MAX_EVALUATIONS};
+
+/// Ridder's method, which fits an exponential through the ends and midpoint of the bracket.
+///
+/// The root stays bracketed and convergence is quadratic for smooth functions.
+#[derive(Clone, Copy, Debug)]
+pub struct Ridder {
+    max_evaluations: usize,
+}
+
+impl Ridder {
+    pub fn new() -> Self {
+        Ridder {
+            max_evaluations: DEFAULT_MAX_EVALUATIONS,
+        }
+    }
+
+    pub fn with_max_evaluations(mut self, max_evaluations: usize) -> Self {
+        self.max_evaluations = max_evaluations;
+        self
+    }
+}
+
+impl Default for Ridder {
+    fn default() -> Self {
+        Ridder::new()
+    }
+}
+
+impl Solver1D for Ridder {
+    fn solve_bracketed(
+        &self,
+        f: &dyn Fn(f64) -> f64,
+        accuracy: f64,
+        lower: f64,
+        upper: f64,
+    ) -> Result<f64, LibraError> {
+        let (mut a, mut b) = (lower, upper);
+        let (mut fa, mut fb) = (f(a), f(b));
+
+        if fa == 0.0 {
+            return Ok(a);
+        }
+        if fb == 0.0 {
+            return Ok(b);
+        }
+        if fa * fb > 0.0 {
+            return Err(NotBracketed::new(a, b, fa, fb).into());
+        }
+
+        let mut root = f64::NAN;
+        let mut evaluations = 2;
+
+        while evaluations + 2 <= self.max_evaluations {
+            let midpoint = 0.5 * (a + b);
+            let f_midpoint = f(midpoint);
+            let s = (f_midpoint * f_midpoint - fa * fb).sqrt();
+
+            if s == 0.0 {
+                return Ok(midpoint);
+            }
+
+            let next = midpoint + (midpoint - a) * (fa - fb).signum() * f_midpoint / s;
+            let f_next = f(next);
+            evaluations += 2;
+
+            if (next - root).abs() < accuracy || f_next == 0.0 {
+                return Ok(next);
+            }
+            root = next;
+
+            // Keep the smallest bracket among the points evaluated.
+            if f_midpoint * f_next < 0.0 {
+                (a, fa, b, fb) = (midpoint, f_midpoint, next, f_next);
+            } else if fa * f_next < 0.0 {
+                (b, fb) = (next, f_next);
+            } else {
+                (a, fa) = (next, f_next);
+            }
+
+            if (b - a).abs() < accuracy {
+                return Ok(root);
+            }
+        }
+
+        Err(NoConvergence::new("Ridder", self.max_evaluations).into())
+    }
+}
@@ -0,0 +1,74 @@
+use crate::error::{LibraError, NoConvergence, NotBracketed};
+
+use super::{Solver1D, DEFAULT_MAX_EVALUATIONS};
+
+/// The secant method, which steps to the root of the line through the last two points.
+///
+/// Convergence is superlinear for smooth functions near the root, but the root is not kept
+/// bracketed, so the method can fail where Brent's method would not.
+#[derive(Clone, Copy, Debug)]
+pub struct Secant {
+    max_evaluations: usize,
+}
+
+impl Secant {
+    pub fn new() -> Self {
+        Secant {
+            max_evaluations: DEFAULT_MAX_EVALUATIONS,
+        }
+    }
+
+    pub fn with_max_evaluations(mut self, max_evaluations: usize) -> Self {
+        self.max_evaluations = max_evaluations;
+        self
+    }
+}
+
+impl Default for Secant {
+    fn default() -> Self {
+        Secant::new()
+    }
+}
+
+impl Solver1D for Secant {
+    fn solve_bracketed(
+        &self,
+        f: &dyn Fn(f64) -> f64,
+        accuracy: f64,
+        lower: f64,
+        upper: f64,
+    ) -> Result<f64, LibraError> {
+        let (f_lower, f_upper) = (f(lower), f(upper));
+
+        if f_lower == 0.0 {
+            return Ok(lower);
+        }
+        if f_upper == 0.0 {
+            return Ok(upper);
+        }
+        if f_lower * f_upper > 0.0 {
+            return Err(NotBracketed::new(lower, upper, f_lower, f_upper).into());
+        }
+
+        // Start from the end with the smaller function value.
+        let ((mut x0, mut f0), (mut x1, mut f1)) = if f_lower.abs() < f_upper.abs() {
+            ((upper, f_upper), (lower, f_lower))
+        } else {
+            ((lower, f_lower), (upper, f_upper))
+        };
+
+        for _ in 2..self.max_evaluations {
+            let dx = (x0 - x1) * f1 / (f1 - f0);
+            x0 = x1;
+            f0 = f1;
+            x1 += dx;
+            f1 = f(x1);
+
+            if dx.abs() < accuracy || f1 == 0.0 {
+                return Ok(x1);
+            }
+        }
+
+        Err(NoConvergence::new("secant", self.max_evaluations).into())
+    }
+}
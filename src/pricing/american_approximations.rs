@@ -0,0 +1,529 @@
+use crate::{
+    error::{DifferentVariant, InvalidDate, LibraError, NotPositive},
+    instruments::{Exercise, OptionType, Payoff, VanillaOption},
+    math::{
+        distributions::{bivariate_normal_cdf, normal_cdf, normal_pdf},
+        solvers::{bracket, Brent, Solver1D, DEFAULT_MAX_EVALUATIONS},
+    },
+};
+
+use super::{black_formula, GeneralizedBlackScholesProcess, OptionResults};
+
+/// The accuracy to which critical prices are solved, relative to the strike.
+const CRITICAL_PRICE_ACCURACY: f64 = 1.0e-10;
+
+/// The change in spot, relative to the spot, used to find delta and gamma.
+const SPOT_BUMP: f64 = 1.0e-4;
+
+/// Prices American vanilla options with the quadratic approximation of Barone-Adesi and Whaley
+/// (1987), which adds an early exercise premium of a power of the spot to the European value.
+///
+/// The zero rates and dividend yield to expiry are used. Delta and gamma are found by bumping the
+/// spot, and theta is implied by the Black-Scholes equation.
+#[derive(Clone, Debug)]
+pub struct BaroneAdesiWhaleyEngine {
+    process: GeneralizedBlackScholesProcess,
+}
+
+impl BaroneAdesiWhaleyEngine {
+    pub fn new(process: GeneralizedBlackScholesProcess) -> Self {
+        BaroneAdesiWhaleyEngine { process }
+    }
+
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    /// Prices `option`.
+    ///
+    /// # Returns
+    ///
+    /// The value, delta, gamma and theta, or an error if the option is not a plain vanilla
+    /// American option exercisable from the reference date of the process, or the volatility is
+    /// not positive.
+    pub fn calculate(&self, option: &VanillaOption) -> Result<OptionResults, LibraError> {
+        approximate_results(&self.process, option, &barone_adesi_whaley)
+    }
+}
+
+/// Prices American vanilla options with the approximation of Bjerksund and Stensland (2002),
+/// which values the option as if it were exercised on reaching one flat boundary before a time
+/// part way to expiry and another after it. This gives a lower bound on the value, which is
+/// floored at the value of the European option.
+///
+/// The zero rates and dividend yield to expiry are used. Puts are priced as calls by the
+/// put-call transformation. Delta and gamma are found by bumping the spot, and theta is implied
+/// by the Black-Scholes equation.
+#[derive(Clone, Debug)]
+pub struct BjerksundStenslandEngine {
+    process: GeneralizedBlackScholesProcess,
+}
+
+impl BjerksundStenslandEngine {
+    pub fn new(process: GeneralizedBlackScholesProcess) -> Self {
+        BjerksundStenslandEngine { process }
+    }
+
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    /// Prices `option`.
+    ///
+    /// # Returns
+    ///
+    /// The value, delta, gamma and theta, or an error if the option is not a plain vanilla
+    /// American option exercisable from the reference date of the process, or the volatility is
+    /// not positive.
+    pub fn calculate(&self, option: &VanillaOption) -> Result<OptionResults, LibraError> {
+        approximate_results(&self.process, option, &|inputs| {
+            Ok(match inputs.option_type {
+                OptionType::Call => bjerksund_stensland_call(inputs),
+                OptionType::Put => bjerksund_stensland_call(&inputs.transformed()),
+            })
+        })
+    }
+}
+
+/// Prices American vanilla options with the QD+ approximation of Li (2010), which refines the
+/// quadratic approximation of Barone-Adesi and Whaley with a correction for the change in the
+/// early exercise premium with time, and finds the exercise boundary by smooth pasting.
+///
+/// The zero rates and dividend yield to expiry are used. Calls are priced as puts by the
+/// put-call transformation. Delta and gamma are found by bumping the spot, and theta is implied
+/// by the Black-Scholes equation.
+#[derive(Clone, Debug)]
+pub struct QdPlusAmericanEngine {
+    process: GeneralizedBlackScholesProcess,
+}
+
+impl QdPlusAmericanEngine {
+    pub fn new(process: GeneralizedBlackScholesProcess) -> Self {
+        QdPlusAmericanEngine { process }
+    }
+
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    /// Prices `option`.
+    ///
+    /// # Returns
+    ///
+    /// The value, delta, gamma and theta, or an error if the option is not a plain vanilla
+    /// American option exercisable from the reference date of the process, or the volatility is
+    /// not positive.
+    pub fn calculate(&self, option: &VanillaOption) -> Result<OptionResults, LibraError> {
+        approximate_results(&self.process, option, &|inputs| match inputs.option_type {
+            OptionType::Call => qd_plus_put(&inputs.transformed()),
+            OptionType::Put => qd_plus_put(inputs),
+        })
+    }
+}
+
+/// The inputs to an approximation for an American option, with flat rates and volatility.
+#[derive(Clone, Copy, Debug)]
+struct ApproximationInputs {
+    option_type: OptionType,
+    spot: f64,
+    strike: f64,
+    time: f64,
+    risk_free_rate: f64,
+    dividend_yield: f64,
+    volatility: f64,
+}
+
+impl ApproximationInputs {
+    /// The inputs with the spot moved to `spot`.
+    fn with_spot(self, spot: f64) -> Self {
+        ApproximationInputs { spot, ..self }
+    }
+
+    /// The inputs of the option of the other type with the same value, found by swapping the
+    /// spot and strike and the risk-free rate and dividend yield.
+    fn transformed(&self) -> Self {
+        ApproximationInputs {
+            option_type: match self.option_type {
+                OptionType::Call => OptionType::Put,
+                OptionType::Put => OptionType::Call,
+            },
+            spot: self.strike,
+            strike: self.spot,
+            risk_free_rate: self.dividend_yield,
+            dividend_yield: self.risk_free_rate,
+            ..*self
+        }
+    }
+
+    /// The value of exercising immediately.
+    fn intrinsic_value(&self) -> f64 {
+        (self.option_type.sign() * (self.spot - self.strike)).max(0.0)
+    }
+
+    /// The value of the European option with the spot at `spot`.
+    fn european(&self, spot: f64) -> f64 {
+        let forward = spot * ((self.risk_free_rate - self.dividend_yield) * self.time).exp();
+
+        black_formula(
+            self.option_type,
+            forward,
+            self.strike,
+            self.volatility,
+            self.time,
+        )
+        .value
+            * (-self.risk_free_rate * self.time).exp()
+    }
+
+    /// The first Black-Scholes `d` term with the spot at `spot`.
+    fn d1(&self, spot: f64) -> f64 {
+        let std_dev = self.volatility * self.time.sqrt();
+
+        ((spot / self.strike).ln() + (self.risk_free_rate - self.dividend_yield) * self.time)
+            / std_dev
+            + 0.5 * std_dev
+    }
+}
+
+/// The value, delta, gamma and theta of `option` under `process` by the approximation `value`.
+fn approximate_results(
+    process: &GeneralizedBlackScholesProcess,
+    option: &VanillaOption,
+    value: &dyn Fn(&ApproximationInputs) -> Result<f64, LibraError>,
+) -> Result<OptionResults, LibraError> {
+    let (
+        Exercise::American { earliest, latest },
+        Payoff::PlainVanilla {
+            option_type,
+            strike,
+        },
+    ) = (option.exercise(), option.payoff())
+    else {
+        return Err(DifferentVariant.into());
+    };
+
+    if *earliest > process.reference_date() {
+        return Err(InvalidDate.into());
+    }
+
+    let sigma = process.volatility();
+    if sigma <= 0.0 {
+        return Err(NotPositive::new("volatility", sigma).into());
+    }
+
+    let time = process.time(*latest)?;
+    let inputs = ApproximationInputs {
+        option_type: *option_type,
+        spot: process.spot(),
+        strike: *strike,
+        time,
+        risk_free_rate: process.risk_free_rate(time),
+        dividend_yield: process.dividend_yield(time),
+        volatility: sigma,
+    };
+
+    let value_at = |spot: f64| match time > 0.0 {
+        true => value(&inputs.with_spot(spot)),
+        false => Ok(inputs.with_spot(spot).intrinsic_value()),
+    };
+
+    let bump = SPOT_BUMP * inputs.spot;
+    let values = [
+        value_at(inputs.spot - bump)?,
+        value_at(inputs.spot)?,
+        value_at(inputs.spot + bump)?,
+    ];
+
+    Ok(OptionResults::from_grid(
+        process,
+        time,
+        values[1],
+        (values[2] - values[0]) / (2.0 * bump),
+        (values[2] - 2.0 * values[1] + values[0]) / (bump * bump),
+    ))
+}
+
+/// The value of an American option by the approximation of Barone-Adesi and Whaley.
+fn barone_adesi_whaley(inputs: &ApproximationInputs) -> Result<f64, LibraError> {
+    let ApproximationInputs {
+        option_type,
+        spot,
+        strike,
+        time,
+        risk_free_rate: r,
+        dividend_yield: q,
+        volatility: sigma,
+    } = *inputs;
+
+    // A call on an equity paying no dividends, or a put when rates are not positive, is never
+    // exercised early.
+    let never_exercised = match option_type {
+        OptionType::Call => q <= 0.0,
+        OptionType::Put => r <= 0.0,
+    };
+    if never_exercised {
+        return Ok(inputs.european(spot));
+    }
+
+    let omega = option_type.sign();
+    let variance = sigma * sigma;
+    let n = 2.0 * (r - q) / variance;
+    let m = 2.0 * r / variance;
+    let carry_discount = (-q * time).exp();
+
+    // The ratio of m to 1 - exp(-rT), which tends to 2 / (sigma^2 T) as the rate goes to zero.
+    let m_over_h = match r == 0.0 {
+        true => 2.0 / (variance * time),
+        false => m / (1.0 - (-r * time).exp()),
+    };
+    let exponent = 0.5 * (-(n - 1.0) + omega * ((n - 1.0).powi(2) + 4.0 * m_over_h).sqrt());
+
+    // The early exercise premium at the critical price, over the critical price.
+    let premium_ratio =
+        |s: f64| omega * (1.0 - carry_discount * normal_cdf(omega * inputs.d1(s))) / exponent;
+
+    let critical_price = {
+        let objective = |s: f64| omega * (s - strike) - inputs.european(s) - s * premium_ratio(s);
+
+        // The seed of Barone-Adesi and Whaley, from the critical price at infinite expiry.
+        let perpetual_exponent = 0.5 * (-(n - 1.0) + omega * ((n - 1.0).powi(2) + 4.0 * m).sqrt());
+        let perpetual = strike / (1.0 - 1.0 / perpetual_exponent);
+        let decay =
+            -((r - q) * time + omega * 2.0 * sigma * time.sqrt()) * strike / (perpetual - strike);
+        let guess = strike + (perpetual - strike) * (1.0 - decay.exp());
+
+        solve_critical_price(&objective, guess, option_type, strike)?
+    };
+
+    if omega * (spot - critical_price) >= 0.0 {
+        return Ok(inputs.intrinsic_value());
+    }
+
+    let premium = critical_price * premium_ratio(critical_price);
+
+    Ok(inputs.european(spot) + premium * (spot / critical_price).powf(exponent))
+}
+
+/// The value of an American call by the approximation of Bjerksund and Stensland (2002).
+fn bjerksund_stensland_call(inputs: &ApproximationInputs) -> f64 {
+    let ApproximationInputs {
+        spot,
+        strike,
+        time,
+        risk_free_rate: r,
+        dividend_yield: q,
+        volatility: sigma,
+        ..
+    } = *inputs;
+
+    let b = r - q;
+
+    if b >= r {
+        return inputs.european(spot);
+    }
+
+    let variance = sigma * sigma;
+    let t1 = 0.5 * (5.0_f64.sqrt() - 1.0) * time;
+    let beta = (0.5 - b / variance) + ((b / variance - 0.5).powi(2) + 2.0 * r / variance).sqrt();
+    let b_infinity = beta / (beta - 1.0) * strike;
+    let b_zero = strike.max(r / (r - b) * strike);
+
+    // The boundaries before and after t1, which never fall below the boundary at expiry even
+    // when a strongly negative cost of carry would take the formula there.
+    let boundary = |t: f64| {
+        let h =
+            -(b * t + 2.0 * sigma * t.sqrt()) * strike * strike / ((b_infinity - b_zero) * b_zero);
+        b_zero + (b_infinity - b_zero) * (1.0 - h.min(0.0).exp())
+    };
+    let i1 = boundary(t1);
+    let i2 = boundary(time);
+
+    if spot >= i2 {
+        return spot - strike;
+    }
+
+    let alpha1 = (i1 - strike) * i1.powf(-beta);
+    let alpha2 = (i2 - strike) * i2.powf(-beta);
+
+    let parameters = BjerksundStenslandParameters { spot, r, b, sigma };
+    let phi = |t: f64, gamma: f64, h: f64, i: f64| parameters.phi(t, gamma, h, i);
+    let psi = |gamma: f64, h: f64| parameters.psi(time, gamma, h, i2, i1, t1);
+
+    let value = alpha2 * spot.powf(beta) - alpha2 * phi(t1, beta, i2, i2) + phi(t1, 1.0, i2, i2)
+        - phi(t1, 1.0, i1, i2)
+        - strike * phi(t1, 0.0, i2, i2)
+        + strike * phi(t1, 0.0, i1, i2)
+        + alpha1 * phi(t1, beta, i1, i2)
+        - alpha1 * psi(beta, i1)
+        + psi(1.0, i1)
+        - psi(1.0, strike)
+        - strike * psi(0.0, i1)
+        + strike * psi(0.0, strike);
+
+    // The flat boundaries can undervalue the option below its European value when the cost of
+    // carry is strongly negative.
+    value.max(inputs.european(spot))
+}
+
+/// The market parameters of the Bjerksund-Stensland functions, with cost of carry `b`.
+struct BjerksundStenslandParameters {
+    spot: f64,
+    r: f64,
+    b: f64,
+    sigma: f64,
+}
+
+impl BjerksundStenslandParameters {
+    /// The drift term shared by the functions for the power `gamma` of the spot.
+    fn drift(&self, gamma: f64) -> f64 {
+        self.b + (gamma - 0.5) * self.sigma * self.sigma
+    }
+
+    /// The logarithm of the growth of the discounted expected power `gamma` of the spot over `t`
+    /// years.
+    fn lambda(&self, t: f64, gamma: f64) -> f64 {
+        (-self.r + gamma * self.b + 0.5 * gamma * (gamma - 1.0) * self.sigma * self.sigma) * t
+    }
+
+    /// The power of the ratio of a boundary to the spot in the reflected terms.
+    fn kappa(&self, gamma: f64) -> f64 {
+        2.0 * self.b / (self.sigma * self.sigma) + 2.0 * gamma - 1.0
+    }
+
+    /// The value of receiving the power `gamma` of the spot at `t` if it is below `h` then,
+    /// knocked out on reaching `i` before.
+    fn phi(&self, t: f64, gamma: f64, h: f64, i: f64) -> f64 {
+        let s = self.spot;
+        let std_dev = self.sigma * t.sqrt();
+        let d = -((s / h).ln() + self.drift(gamma) * t) / std_dev;
+
+        self.lambda(t, gamma).exp()
+            * s.powf(gamma)
+            * (normal_cdf(d)
+                - (i / s).powf(self.kappa(gamma)) * normal_cdf(d - 2.0 * (i / s).ln() / std_dev))
+    }
+
+    /// The value of receiving the power `gamma` of the spot at `t` if it is below `h` then,
+    /// knocked out on reaching `i1` before `t1` or `i2` between `t1` and `t`.
+    fn psi(&self, t: f64, gamma: f64, h: f64, i2: f64, i1: f64, t1: f64) -> f64 {
+        let s = self.spot;
+        let drift = self.drift(gamma);
+        let (std_dev_t1, std_dev_t) = (self.sigma * t1.sqrt(), self.sigma * t.sqrt());
+        let kappa = self.kappa(gamma);
+        let rho = (t1 / t).sqrt();
+
+        let e = |log_ratio: f64, sign: f64| (log_ratio + sign * drift * t1) / std_dev_t1;
+        let f = |log_ratio: f64| (log_ratio + drift * t) / std_dev_t;
+
+        let e1 = e((s / i1).ln(), 1.0);
+        let e2 = e((i2 * i2 / (s * i1)).ln(), 1.0);
+        let e3 = e((s / i1).ln(), -1.0);
+        let e4 = e((i2 * i2 / (s * i1)).ln(), -1.0);
+        let f1 = f((s / h).ln());
+        let f2 = f((i2 * i2 / (s * h)).ln());
+        let f3 = f((i1 * i1 / (s * h)).ln());
+        let f4 = f((s * i1 * i1 / (h * i2 * i2)).ln());
+
+        self.lambda(t, gamma).exp()
+            * s.powf(gamma)
+            * (bivariate_normal_cdf(-e1, -f1, rho)
+                - (i2 / s).powf(kappa) * bivariate_normal_cdf(-e2, -f2, rho)
+                - (i1 / s).powf(kappa) * bivariate_normal_cdf(-e3, -f3, -rho)
+                + (i1 / i2).powf(kappa) * bivariate_normal_cdf(-e4, -f4, -rho))
+    }
+}
+
+/// The value of an American put by the QD+ approximation of Li.
+fn qd_plus_put(inputs: &ApproximationInputs) -> Result<f64, LibraError> {
+    let ApproximationInputs {
+        spot,
+        strike,
+        time,
+        risk_free_rate: r,
+        dividend_yield: q,
+        volatility: sigma,
+        ..
+    } = *inputs;
+
+    if r <= 0.0 {
+        return Ok(inputs.european(spot));
+    }
+
+    let variance = sigma * sigma;
+    let h = 1.0 - (-r * time).exp();
+    let alpha = 2.0 * r / variance;
+    let beta = 2.0 * (r - q) / variance;
+    let root = ((beta - 1.0).powi(2) + 4.0 * alpha / h).sqrt();
+    let lambda = 0.5 * (-(beta - 1.0) - root);
+    let lambda_derivative = alpha / (h * h * root);
+    let denominator = 2.0 * lambda + beta - 1.0;
+
+    // The European theta with the spot at `s`.
+    let theta = |s: f64| {
+        let d1 = inputs.d1(s);
+        let d2 = d1 - sigma * time.sqrt();
+
+        r * strike * (-r * time).exp() * normal_cdf(-d2)
+            - q * s * (-q * time).exp() * normal_cdf(-d1)
+            - 0.5 * sigma * s * (-q * time).exp() * normal_pdf(d1) / time.sqrt()
+    };
+
+    // The first order coefficient of the correction, multiplied by the early exercise premium
+    // at the boundary `s` to keep it finite.
+    let scaled_c0 = |s: f64, premium: f64| {
+        -(1.0 - h) * alpha / denominator
+            * ((1.0 / h + lambda_derivative / denominator) * premium
+                - theta(s) * (r * time).exp() / r)
+    };
+
+    // Smooth pasting of the value at the exercise boundary.
+    let objective = |s: f64| {
+        let premium = strike - s - inputs.european(s);
+
+        s * (1.0 - (-q * time).exp() * normal_cdf(-inputs.d1(s)))
+            + lambda * premium
+            + scaled_c0(s, premium)
+    };
+
+    let limit = match q > r {
+        true => strike * r / q,
+        false => strike,
+    };
+    let critical_price = solve_critical_price(&objective, 0.5 * limit, OptionType::Put, limit)?;
+
+    if spot <= critical_price {
+        return Ok(strike - spot);
+    }
+
+    let premium = strike - critical_price - inputs.european(critical_price);
+    let c0 = scaled_c0(critical_price, premium) / premium;
+    let b = (1.0 - h) * alpha * lambda_derivative / (2.0 * denominator);
+    let x = (spot / critical_price).ln();
+
+    Ok(inputs.european(spot)
+        + premium * (spot / critical_price).powf(lambda) / (1.0 - b * x * x - c0 * x))
+}
+
+/// The price at which an option is first exercised, as the root of `objective` near `guess`.
+/// The critical price of a call lies above `limit` and that of a put below it.
+fn solve_critical_price(
+    objective: &dyn Fn(f64) -> f64,
+    guess: f64,
+    option_type: OptionType,
+    limit: f64,
+) -> Result<f64, LibraError> {
+    let domain = match option_type {
+        OptionType::Call => (limit, f64::MAX),
+        OptionType::Put => (limit * f64::EPSILON, limit),
+    };
+    let guess = guess.clamp(domain.0, domain.1);
+    let (lower, upper) = bracket(
+        objective,
+        guess,
+        0.1 * limit,
+        domain,
+        DEFAULT_MAX_EVALUATIONS,
+    )?;
+
+    Brent::new().solve_bracketed(objective, CRITICAL_PRICE_ACCURACY * limit, lower, upper)
+}
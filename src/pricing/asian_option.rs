@@ -0,0 +1,190 @@
+use std::rc::Rc;
+
+use crate::{
+    error::{DifferentVariant, LibraError},
+    instruments::{AsianOption, AverageType, OptionType, Payoff},
+};
+
+use super::{
+    black_formula, bumped_results, european_expiry,
+    montecarlo::{MonteCarloSettings, Path, Statistics, TimeGrid},
+    GeneralizedBlackScholesProcess, OptionResults,
+};
+
+/// Prices Asian options on the geometric average of discrete fixings, with plain vanilla payoffs
+/// and European exercise, under the generalized Black-Scholes process.
+///
+/// The geometric average of lognormal prices is lognormal, so the option is priced with the
+/// Black formula on the forward of the average. Fixings on the reference date are fixed at the
+/// spot. Delta and gamma are found by bumping the spot, and theta is implied by the
+/// Black-Scholes equation.
+#[derive(Clone, Debug)]
+pub struct AnalyticDiscreteGeometricAsianEngine {
+    process: GeneralizedBlackScholesProcess,
+}
+
+impl AnalyticDiscreteGeometricAsianEngine {
+    pub fn new(process: GeneralizedBlackScholesProcess) -> Self {
+        AnalyticDiscreteGeometricAsianEngine { process }
+    }
+
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    /// Prices `option`.
+    ///
+    /// # Returns
+    ///
+    /// The value, delta, gamma and theta, or an error if the option does not average
+    /// geometrically, is not a plain vanilla European option, has expired, or has fixings before
+    /// the reference date.
+    pub fn calculate(&self, option: &AsianOption) -> Result<OptionResults, LibraError> {
+        let process = &self.process;
+        let Payoff::PlainVanilla {
+            option_type,
+            strike,
+        } = *option.payoff()
+        else {
+            return Err(DifferentVariant.into());
+        };
+        if option.average_type() != AverageType::Geometric {
+            return Err(DifferentVariant.into());
+        }
+
+        let time = european_expiry(process, option.exercise())?;
+        let fixings = fixing_times(process, option)?;
+
+        bumped_results(process, time, &|process| {
+            Ok(geometric_average_value(
+                process,
+                option_type,
+                strike,
+                &fixings,
+                time,
+            ))
+        })
+    }
+}
+
+/// Prices Asian options on discrete fixings with European exercise under the generalized
+/// Black-Scholes process by Monte Carlo simulation.
+///
+/// The fixing dates are placed on the grid of each path. Arithmetic averages of plain vanilla
+/// payoffs use the same option on the geometric average, whose value is known exactly, as a
+/// control variate, which removes most of their variance.
+#[derive(Clone, Debug)]
+pub struct McAsianEngine {
+    process: GeneralizedBlackScholesProcess,
+    settings: MonteCarloSettings,
+}
+
+impl McAsianEngine {
+    pub fn new(process: GeneralizedBlackScholesProcess, settings: MonteCarloSettings) -> Self {
+        McAsianEngine { process, settings }
+    }
+
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    pub fn settings(&self) -> &MonteCarloSettings {
+        &self.settings
+    }
+
+    /// Prices `option`.
+    ///
+    /// # Returns
+    ///
+    /// The statistics of the discounted payoff, whose mean is the value, or an error if the
+    /// option is not European, has expired, has fixings before the reference date, or the paths
+    /// cannot be simulated.
+    pub fn calculate(&self, option: &AsianOption) -> Result<Statistics, LibraError> {
+        let process = &self.process;
+        let time = european_expiry(process, option.exercise())?;
+        let fixings = fixing_times(process, option)?;
+
+        let mut mandatory = fixings.clone();
+        mandatory.push(time);
+        let grid = TimeGrid::with_mandatory_times(&mandatory, self.settings.steps())?;
+        let indices: Vec<usize> = fixings.iter().map(|t| grid.closest_index(*t)).collect();
+
+        let discount = process.risk_free_curve().discount_time(time);
+        let fixed = |path: &Path| -> Vec<f64> { indices.iter().map(|i| path.value(*i)).collect() };
+        let payoff = option.payoff();
+        let average_type = option.average_type();
+        let pricer = |path: &Path| payoff.value(average_type.average(&fixed(path))) * discount;
+
+        let mut simulation = self.settings.simulation(Rc::new(process.clone()), grid)?;
+        match (average_type, payoff) {
+            (
+                AverageType::Arithmetic,
+                Payoff::PlainVanilla {
+                    option_type,
+                    strike,
+                },
+            ) => {
+                let control = |path: &Path| {
+                    payoff.value(AverageType::Geometric.average(&fixed(path))) * discount
+                };
+                let control_value =
+                    geometric_average_value(process, *option_type, *strike, &fixings, time);
+
+                simulation.run_with_control_variate(
+                    &pricer,
+                    &control,
+                    control_value,
+                    self.settings.samples(),
+                )
+            }
+            _ => Ok(simulation.run(&pricer, self.settings.samples())),
+        }
+    }
+}
+
+/// The times of the fixings of `option`, or an error if any is before the reference date.
+fn fixing_times(
+    process: &GeneralizedBlackScholesProcess,
+    option: &AsianOption,
+) -> Result<Vec<f64>, LibraError> {
+    option
+        .fixing_dates()
+        .iter()
+        .map(|date| process.time(*date))
+        .collect()
+}
+
+/// The value of a plain vanilla option on the geometric average of the underlying at
+/// `fixings`, paid in `time` years.
+fn geometric_average_value(
+    process: &GeneralizedBlackScholesProcess,
+    option_type: OptionType,
+    strike: f64,
+    fixings: &[f64],
+    time: f64,
+) -> f64 {
+    let n = fixings.len() as f64;
+    let variance_rate = process.volatility().powi(2);
+
+    // The logarithm of the average is normal, with this mean and variance.
+    let mean = fixings
+        .iter()
+        .map(|t| process.forward(*t).ln() - 0.5 * variance_rate * t)
+        .sum::<f64>()
+        / n;
+    let variance = variance_rate
+        * fixings
+            .iter()
+            .map(|s| fixings.iter().map(|t| s.min(*t)).sum::<f64>())
+            .sum::<f64>()
+        / (n * n);
+
+    let forward = (mean + 0.5 * variance).exp();
+    let volatility = match time > 0.0 {
+        true => (variance / time).sqrt(),
+        false => 0.0,
+    };
+
+    process.risk_free_curve().discount_time(time)
+        * black_formula(option_type, forward, strike, volatility, time).value
+}
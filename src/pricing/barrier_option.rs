@@ -0,0 +1,636 @@
+use std::rc::Rc;
+
+use chrono::NaiveDate;
+
+use crate::{
+    error::{DifferentVariant, LibraError, NotInRange, NotPositive},
+    instruments::{
+        BarrierOption, BarrierType, DoubleBarrierOption, DoubleBarrierType, Exercise, OptionType,
+        Payoff,
+    },
+    math::distributions::normal_cdf,
+};
+
+use super::{
+    bumped_results, european_expiry, european_results,
+    montecarlo::{MonteCarloSettings, Path, Statistics, TimeGrid},
+    GeneralizedBlackScholesProcess, OptionResults,
+};
+
+/// The constant `-zeta(1/2) / sqrt(2 pi)` of the continuity correction of Broadie, Glasserman
+/// and Kou (1997), which shifts a barrier away from the underlying by this many standard
+/// deviations over the monitoring interval to price discrete monitoring with continuous formulas.
+const DISCRETE_MONITORING_SHIFT: f64 = 0.582_597_157_939_010_6;
+
+/// The number of terms either side of zero summed in the series of Ikeda and Kunitomo.
+const DOUBLE_BARRIER_TERMS: i32 = 5;
+
+/// The gap below which two times are treated as the same.
+const TIME_TOLERANCE: f64 = 1.0e-10;
+
+/// Prices single barrier options on plain vanilla payoffs with European exercise under the
+/// generalized Black-Scholes process, using the closed forms of Reiner and Rubinstein (1991) for
+/// continuously monitored barriers.
+///
+/// Discretely monitored barriers are priced with the continuity correction of Broadie,
+/// Glasserman and Kou, shifting the barrier away from the underlying according to the average
+/// interval between monitoring dates. The zero rates and dividend yield to expiry are used, and
+/// an option whose barrier the spot is already beyond is treated as knocked in or out. Delta and
+/// gamma are found by bumping the spot, and theta is implied by the Black-Scholes equation.
+#[derive(Clone, Debug)]
+pub struct AnalyticBarrierEngine {
+    process: GeneralizedBlackScholesProcess,
+}
+
+impl AnalyticBarrierEngine {
+    pub fn new(process: GeneralizedBlackScholesProcess) -> Self {
+        AnalyticBarrierEngine { process }
+    }
+
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    /// Prices `option`.
+    ///
+    /// # Returns
+    ///
+    /// The value, delta, gamma and theta, or an error if the option is not a plain vanilla
+    /// European option, has expired, or the volatility is not positive.
+    pub fn calculate(&self, option: &BarrierOption) -> Result<OptionResults, LibraError> {
+        let process = &self.process;
+        let Payoff::PlainVanilla { .. } = option.payoff() else {
+            return Err(DifferentVariant.into());
+        };
+
+        let time = european_expiry(process, option.exercise())?;
+        let sigma = positive_volatility(process)?;
+
+        let monitoring = monitoring_times(process, option.monitoring_dates(), time)?;
+        let shift = |direction: f64| {
+            monitoring_shift(
+                &monitoring,
+                time,
+                option.monitoring_dates(),
+                sigma,
+                direction,
+            )
+        };
+        let direction = if option.barrier_type().is_down() {
+            -1.0
+        } else {
+            1.0
+        };
+        let barrier = shift(direction).map(|shift| option.barrier() * shift);
+
+        bumped_results(process, time, &|process| {
+            Ok(barrier_value(process, option, barrier, time))
+        })
+    }
+}
+
+/// Prices double barrier options on plain vanilla payoffs with European exercise under the
+/// generalized Black-Scholes process, using the series of Ikeda and Kunitomo (1992) for flat,
+/// continuously monitored barriers.
+///
+/// Knock-in options are priced as the vanilla option less the knock-out option, with any rebate
+/// paid at expiry if neither barrier is reached. Rebates on knock-out options, which are paid
+/// when a barrier is reached, are only priced by Monte Carlo. Discretely monitored barriers are
+/// priced with the continuity correction of Broadie, Glasserman and Kou. Delta and gamma are
+/// found by bumping the spot, and theta is implied by the Black-Scholes equation.
+#[derive(Clone, Debug)]
+pub struct AnalyticDoubleBarrierEngine {
+    process: GeneralizedBlackScholesProcess,
+}
+
+impl AnalyticDoubleBarrierEngine {
+    pub fn new(process: GeneralizedBlackScholesProcess) -> Self {
+        AnalyticDoubleBarrierEngine { process }
+    }
+
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    /// Prices `option`.
+    ///
+    /// # Returns
+    ///
+    /// The value, delta, gamma and theta, or an error if the option is not a plain vanilla
+    /// European option, is a knock-out option with a rebate, has expired, has its lower barrier
+    /// above its upper barrier, or the volatility is not positive.
+    pub fn calculate(&self, option: &DoubleBarrierOption) -> Result<OptionResults, LibraError> {
+        let process = &self.process;
+        let Payoff::PlainVanilla { .. } = option.payoff() else {
+            return Err(DifferentVariant.into());
+        };
+        if option.barrier_type() == DoubleBarrierType::KnockOut && option.rebate() != 0.0 {
+            let rebate = option.rebate();
+            return Err(NotInRange::new("rebate of a knock-out option", 0.0, 0.0, rebate).into());
+        }
+
+        let time = european_expiry(process, option.exercise())?;
+        let sigma = positive_volatility(process)?;
+        check_barrier_order(option)?;
+
+        let monitoring = monitoring_times(process, option.monitoring_dates(), time)?;
+        let shift = |direction: f64| {
+            monitoring_shift(
+                &monitoring,
+                time,
+                option.monitoring_dates(),
+                sigma,
+                direction,
+            )
+        };
+        let barriers = shift(-1.0)
+            .zip(shift(1.0))
+            .map(|(down, up)| (option.lower_barrier() * down, option.upper_barrier() * up));
+
+        bumped_results(process, time, &|process| {
+            Ok(double_barrier_value(process, option, barriers, time))
+        })
+    }
+}
+
+/// Prices single barrier options with European exercise under the generalized Black-Scholes
+/// process by Monte Carlo simulation.
+///
+/// Continuously monitored barriers are checked between the steps of each path with the
+/// probability that a Brownian bridge between their ends reaches the barrier, so that each path
+/// is weighted by its probability of survival rather than simulated on a fine grid. Discretely
+/// monitored barriers are checked on their monitoring dates, which are placed on the grid.
+/// Rebates on knock-out options are paid at the end of the step in which the barrier is reached.
+#[derive(Clone, Debug)]
+pub struct McBarrierEngine {
+    process: GeneralizedBlackScholesProcess,
+    settings: MonteCarloSettings,
+}
+
+impl McBarrierEngine {
+    pub fn new(process: GeneralizedBlackScholesProcess, settings: MonteCarloSettings) -> Self {
+        McBarrierEngine { process, settings }
+    }
+
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    pub fn settings(&self) -> &MonteCarloSettings {
+        &self.settings
+    }
+
+    /// Prices `option`.
+    ///
+    /// # Returns
+    ///
+    /// The statistics of the discounted payoff, whose mean is the value, or an error if the
+    /// option is not European, has expired, or the paths cannot be simulated.
+    pub fn calculate(&self, option: &BarrierOption) -> Result<Statistics, LibraError> {
+        let barrier_type = option.barrier_type();
+        let barrier = option.barrier();
+
+        simulate_barrier(
+            &self.process,
+            &self.settings,
+            BarrierTerms {
+                payoff: option.payoff(),
+                exercise: option.exercise(),
+                monitoring_dates: option.monitoring_dates(),
+                knock_in: barrier_type.is_knock_in(),
+                rebate: option.rebate(),
+            },
+            &|price| barrier_type.is_breached(barrier, price),
+            &|start, end, variance| crossing_probability(start, end, barrier, variance),
+        )
+    }
+}
+
+/// Prices double barrier options with European exercise under the generalized Black-Scholes
+/// process by Monte Carlo simulation.
+///
+/// Barriers are monitored as by [`McBarrierEngine`], with the probability of a continuously
+/// monitored path reaching either barrier between steps found as if the barriers were reached
+/// independently, which is accurate when the steps are short beside the time the underlying
+/// takes to cross from one barrier to the other.
+#[derive(Clone, Debug)]
+pub struct McDoubleBarrierEngine {
+    process: GeneralizedBlackScholesProcess,
+    settings: MonteCarloSettings,
+}
+
+impl McDoubleBarrierEngine {
+    pub fn new(process: GeneralizedBlackScholesProcess, settings: MonteCarloSettings) -> Self {
+        McDoubleBarrierEngine { process, settings }
+    }
+
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    pub fn settings(&self) -> &MonteCarloSettings {
+        &self.settings
+    }
+
+    /// Prices `option`.
+    ///
+    /// # Returns
+    ///
+    /// The statistics of the discounted payoff, whose mean is the value, or an error if the
+    /// option is not European, has expired, has its lower barrier above its upper barrier, or
+    /// the paths cannot be simulated.
+    pub fn calculate(&self, option: &DoubleBarrierOption) -> Result<Statistics, LibraError> {
+        check_barrier_order(option)?;
+        let (lower, upper) = (option.lower_barrier(), option.upper_barrier());
+
+        simulate_barrier(
+            &self.process,
+            &self.settings,
+            BarrierTerms {
+                payoff: option.payoff(),
+                exercise: option.exercise(),
+                monitoring_dates: option.monitoring_dates(),
+                knock_in: option.barrier_type() == DoubleBarrierType::KnockIn,
+                rebate: option.rebate(),
+            },
+            &|price| price <= lower || price >= upper,
+            &|start, end, variance| {
+                let survival = (1.0 - crossing_probability(start, end, lower, variance))
+                    * (1.0 - crossing_probability(start, end, upper, variance));
+                1.0 - survival
+            },
+        )
+    }
+}
+
+/// The volatility of `process`, or an error if it is not positive.
+fn positive_volatility(process: &GeneralizedBlackScholesProcess) -> Result<f64, LibraError> {
+    let sigma = process.volatility();
+
+    match sigma > 0.0 {
+        true => Ok(sigma),
+        false => Err(NotPositive::new("volatility", sigma).into()),
+    }
+}
+
+/// Returns an error if the lower barrier of `option` is not below its upper barrier.
+fn check_barrier_order(option: &DoubleBarrierOption) -> Result<(), LibraError> {
+    let width = option.upper_barrier() - option.lower_barrier();
+
+    match width > 0.0 {
+        true => Ok(()),
+        false => Err(NotPositive::new("distance between barriers", width).into()),
+    }
+}
+
+/// The times of the monitoring dates after the reference date of `process` up to `maturity`.
+fn monitoring_times(
+    process: &GeneralizedBlackScholesProcess,
+    dates: &[NaiveDate],
+    maturity: f64,
+) -> Result<Vec<f64>, LibraError> {
+    Ok(dates
+        .iter()
+        .filter(|d| **d > process.reference_date())
+        .map(|d| process.time(*d))
+        .collect::<Result<Vec<f64>, LibraError>>()?
+        .into_iter()
+        .filter(|t| *t <= maturity + TIME_TOLERANCE)
+        .collect())
+}
+
+/// The factor by which a barrier in `direction`, one for above the underlying and minus one for
+/// below, is moved to price discrete monitoring with continuous formulas.
+///
+/// # Returns
+///
+/// One for continuous monitoring, or `None` if the barrier is discretely monitored but has no
+/// monitoring dates left before expiry, so can no longer be reached.
+fn monitoring_shift(
+    monitoring: &[f64],
+    maturity: f64,
+    dates: &[NaiveDate],
+    sigma: f64,
+    direction: f64,
+) -> Option<f64> {
+    if dates.is_empty() {
+        return Some(1.0);
+    }
+    if monitoring.is_empty() {
+        return None;
+    }
+
+    let interval = maturity / monitoring.len() as f64;
+    Some((direction * DISCRETE_MONITORING_SHIFT * sigma * interval.sqrt()).exp())
+}
+
+/// The value of a single barrier option with its barrier at `barrier`, or `None` if it can no
+/// longer be reached, expiring in `time` years.
+fn barrier_value(
+    process: &GeneralizedBlackScholesProcess,
+    option: &BarrierOption,
+    barrier: Option<f64>,
+    time: f64,
+) -> f64 {
+    let payoff = option.payoff();
+    let barrier_type = option.barrier_type();
+    let knock_in = barrier_type.is_knock_in();
+    let rebate = option.rebate();
+    let spot = process.spot();
+
+    let vanilla = || european_results(process, payoff, time).value;
+    let discount = process.risk_free_curve().discount_time(time);
+
+    let Some(h) = barrier else {
+        return if knock_in {
+            rebate * discount
+        } else {
+            vanilla()
+        };
+    };
+    if barrier_type.is_breached(option.barrier(), spot) {
+        return if knock_in { vanilla() } else { rebate };
+    }
+    if time <= 0.0 {
+        return if knock_in { rebate } else { payoff.value(spot) };
+    }
+
+    let strike = payoff.strike();
+    let sigma = process.volatility();
+    let r = process.risk_free_rate(time);
+    let b = r - process.dividend_yield(time);
+    let dividend_discount = process.dividend_curve().discount_time(time);
+
+    let std_dev = sigma * time.sqrt();
+    let mu = (b - 0.5 * sigma * sigma) / (sigma * sigma);
+    let phi = payoff.option_type().sign();
+    let eta = if barrier_type.is_down() { 1.0 } else { -1.0 };
+    let ratio = h / spot;
+
+    let x1 = (spot / strike).ln() / std_dev + (1.0 + mu) * std_dev;
+    let x2 = (spot / h).ln() / std_dev + (1.0 + mu) * std_dev;
+    let y1 = (h * h / (spot * strike)).ln() / std_dev + (1.0 + mu) * std_dev;
+    let y2 = (h / spot).ln() / std_dev + (1.0 + mu) * std_dev;
+
+    // The terms of Reiner and Rubinstein, from which each kind of option is built.
+    let vanilla_term = |x: f64| {
+        phi * spot * dividend_discount * normal_cdf(phi * x)
+            - phi * strike * discount * normal_cdf(phi * (x - std_dev))
+    };
+    let reflected_term = |y: f64| {
+        phi * spot * dividend_discount * ratio.powf(2.0 * (mu + 1.0)) * normal_cdf(eta * y)
+            - phi * strike * discount * ratio.powf(2.0 * mu) * normal_cdf(eta * (y - std_dev))
+    };
+    let (a, b_term, c, d) = (
+        vanilla_term(x1),
+        vanilla_term(x2),
+        reflected_term(y1),
+        reflected_term(y2),
+    );
+
+    // The rebate of a knock-in option paid at expiry, and of a knock-out option paid on
+    // reaching the barrier.
+    let rebate_terms = |knock_in: bool| {
+        if rebate == 0.0 {
+            return 0.0;
+        }
+        if knock_in {
+            return rebate
+                * discount
+                * (normal_cdf(eta * (x2 - std_dev))
+                    - ratio.powf(2.0 * mu) * normal_cdf(eta * (y2 - std_dev)));
+        }
+
+        let lambda = (mu * mu + 2.0 * r / (sigma * sigma)).sqrt();
+        let z = (h / spot).ln() / std_dev + lambda * std_dev;
+        rebate
+            * (ratio.powf(mu + lambda) * normal_cdf(eta * z)
+                + ratio.powf(mu - lambda) * normal_cdf(eta * (z - 2.0 * lambda * std_dev)))
+    };
+    let e = rebate_terms(true);
+    let f = rebate_terms(false);
+
+    let above = strike >= h;
+    match (barrier_type, payoff.option_type(), above) {
+        (BarrierType::DownIn, OptionType::Call, true) => c + e,
+        (BarrierType::DownIn, OptionType::Call, false) => a - b_term + d + e,
+        (BarrierType::UpIn, OptionType::Call, true) => a + e,
+        (BarrierType::UpIn, OptionType::Call, false) => b_term - c + d + e,
+        (BarrierType::DownIn, OptionType::Put, true) => b_term - c + d + e,
+        (BarrierType::DownIn, OptionType::Put, false) => a + e,
+        (BarrierType::UpIn, OptionType::Put, true) => a - b_term + d + e,
+        (BarrierType::UpIn, OptionType::Put, false) => c + e,
+        (BarrierType::DownOut, OptionType::Call, true) => a - c + f,
+        (BarrierType::DownOut, OptionType::Call, false) => b_term - d + f,
+        (BarrierType::UpOut, OptionType::Call, true) => f,
+        (BarrierType::UpOut, OptionType::Call, false) => a - b_term + c - d + f,
+        (BarrierType::DownOut, OptionType::Put, true) => a - b_term + c - d + f,
+        (BarrierType::DownOut, OptionType::Put, false) => f,
+        (BarrierType::UpOut, OptionType::Put, true) => b_term - d + f,
+        (BarrierType::UpOut, OptionType::Put, false) => a - c + f,
+    }
+}
+
+/// The value of a double barrier option with its barriers at `barriers`, or `None` if they can
+/// no longer be reached, expiring in `time` years.
+fn double_barrier_value(
+    process: &GeneralizedBlackScholesProcess,
+    option: &DoubleBarrierOption,
+    barriers: Option<(f64, f64)>,
+    time: f64,
+) -> f64 {
+    let payoff = option.payoff();
+    let knock_in = option.barrier_type() == DoubleBarrierType::KnockIn;
+    let rebate = option.rebate();
+    let spot = process.spot();
+
+    let vanilla = || european_results(process, payoff, time).value;
+    let discount = process.risk_free_curve().discount_time(time);
+
+    let Some((lower, upper)) = barriers else {
+        return if knock_in {
+            rebate * discount
+        } else {
+            vanilla()
+        };
+    };
+    if spot <= option.lower_barrier() || spot >= option.upper_barrier() {
+        return if knock_in { vanilla() } else { rebate };
+    }
+    if time <= 0.0 {
+        return if knock_in { rebate } else { payoff.value(spot) };
+    }
+
+    let series = IkedaKunitomoSeries {
+        spot,
+        lower,
+        upper,
+        dividend_discount: process.dividend_curve().discount_time(time),
+        discount,
+        carry: process.risk_free_rate(time) - process.dividend_yield(time),
+        sigma: process.volatility(),
+        time,
+    };
+
+    let strike = payoff.strike();
+    let knock_out = match payoff.option_type() {
+        OptionType::Call if strike < upper => {
+            let (asset, cash) = series.integrals(strike.max(lower), upper);
+            asset - strike * cash
+        }
+        OptionType::Put if strike > lower => {
+            let (asset, cash) = series.integrals(lower, strike.min(upper));
+            strike * cash - asset
+        }
+        _ => 0.0,
+    };
+
+    match knock_in {
+        true => vanilla() - knock_out + rebate * series.integrals(lower, upper).1,
+        false => knock_out,
+    }
+}
+
+/// The inputs to the series of Ikeda and Kunitomo for an underlying between flat barriers.
+struct IkedaKunitomoSeries {
+    spot: f64,
+    lower: f64,
+    upper: f64,
+    dividend_discount: f64,
+    discount: f64,
+    carry: f64,
+    sigma: f64,
+    time: f64,
+}
+
+impl IkedaKunitomoSeries {
+    /// The values of receiving the underlying and one unit of cash at expiry if it finishes
+    /// between `low` and `high` without reaching either barrier.
+    fn integrals(&self, low: f64, high: f64) -> (f64, f64) {
+        let (spot, sigma, time) = (self.spot, self.sigma, self.time);
+        let std_dev = sigma * time.sqrt();
+        let drift = (self.carry + 0.5 * sigma * sigma) * time;
+        let mu = 2.0 * self.carry / (sigma * sigma) + 1.0;
+        let (ln_lower, ln_upper, ln_spot) = (self.lower.ln(), self.upper.ln(), spot.ln());
+
+        let mut asset = 0.0;
+        let mut cash = 0.0;
+        for n in -DOUBLE_BARRIER_TERMS..=DOUBLE_BARRIER_TERMS {
+            let n = n as f64;
+            let d = |k: f64| (ln_spot + 2.0 * n * (ln_upper - ln_lower) - k.ln() + drift) / std_dev;
+            let reflected_d = |k: f64| {
+                (2.0 * (n + 1.0) * ln_lower - 2.0 * n * ln_upper - ln_spot - k.ln() + drift)
+                    / std_dev
+            };
+            let ln_ratio = n * (ln_upper - ln_lower);
+            let ln_reflection = (n + 1.0) * ln_lower - n * ln_upper - ln_spot;
+
+            let between = |f: &dyn Fn(f64) -> f64, shift: f64| {
+                normal_cdf(f(low) - shift) - normal_cdf(f(high) - shift)
+            };
+
+            asset += (mu * ln_ratio).exp() * between(&d, 0.0)
+                - (mu * ln_reflection).exp() * between(&reflected_d, 0.0);
+            cash += ((mu - 2.0) * ln_ratio).exp() * between(&d, std_dev)
+                - ((mu - 2.0) * ln_reflection).exp() * between(&reflected_d, std_dev);
+        }
+
+        (spot * self.dividend_discount * asset, self.discount * cash)
+    }
+}
+
+/// The probability that a Brownian bridge in the logarithm of the price, from `start` to `end`
+/// with total variance `variance`, reaches `barrier` in between.
+fn crossing_probability(start: f64, end: f64, barrier: f64, variance: f64) -> f64 {
+    let (from, to) = ((start / barrier).ln(), (end / barrier).ln());
+
+    match from * to > 0.0 {
+        true => (-2.0 * from * to / variance).exp(),
+        false => 1.0,
+    }
+}
+
+/// The terms of a barrier option that the simulation needs besides its barriers.
+struct BarrierTerms<'a> {
+    payoff: &'a Payoff,
+    exercise: &'a Exercise,
+    monitoring_dates: &'a [NaiveDate],
+    knock_in: bool,
+    rebate: f64,
+}
+
+/// Simulates a barrier option, weighting each path by its probability of having reached the
+/// barriers.
+///
+/// # Arguments
+///
+/// * `process` - The process of the underlying.
+/// * `settings` - How the paths are simulated.
+/// * `terms` - The terms of the option.
+/// * `breached` - Whether the barriers have been reached at a price.
+/// * `crossing` - The probability that the barriers are reached between two prices on
+///   a continuously monitored path, given the variance of the logarithm of the price between
+///   them.
+fn simulate_barrier(
+    process: &GeneralizedBlackScholesProcess,
+    settings: &MonteCarloSettings,
+    terms: BarrierTerms,
+    breached: &dyn Fn(f64) -> bool,
+    crossing: &dyn Fn(f64, f64, f64) -> f64,
+) -> Result<Statistics, LibraError> {
+    let time = european_expiry(process, terms.exercise)?;
+    let sigma = process.volatility();
+    let continuous = terms.monitoring_dates.is_empty();
+
+    let mut mandatory = monitoring_times(process, terms.monitoring_dates, time)?;
+    mandatory.push(time);
+    let grid = TimeGrid::with_mandatory_times(&mandatory, settings.steps())?;
+
+    // Whether the barrier is checked at each time of the grid.
+    let monitored: Vec<bool> = grid
+        .times()
+        .iter()
+        .map(|t| {
+            continuous
+                || mandatory[..mandatory.len() - 1]
+                    .iter()
+                    .any(|m| (m - t).abs() < TIME_TOLERANCE)
+        })
+        .collect();
+    let discounts: Vec<f64> = grid
+        .times()
+        .iter()
+        .map(|t| process.risk_free_curve().discount_time(*t))
+        .collect();
+    let expiry_discount = discounts[discounts.len() - 1];
+
+    let pricer = |path: &Path| {
+        let mut survival = 1.0;
+        let mut rebate_value = 0.0;
+
+        for i in 0..path.len() - 1 {
+            let (start, end) = (path.value(i), path.value(i + 1));
+            let probability =
+                if (continuous && breached(start)) || (monitored[i + 1] && breached(end)) {
+                    1.0
+                } else if continuous {
+                    let dt = path.times()[i + 1] - path.times()[i];
+                    crossing(start, end, sigma * sigma * dt)
+                } else {
+                    0.0
+                };
+
+            rebate_value += survival * probability * discounts[i + 1];
+            survival *= 1.0 - probability;
+        }
+
+        let payoff = terms.payoff.value(path.value(path.len() - 1)) * expiry_discount;
+        match terms.knock_in {
+            true => (1.0 - survival) * payoff + terms.rebate * survival * expiry_discount,
+            false => survival * payoff + terms.rebate * rebate_value,
+        }
+    };
+
+    let mut simulation = settings.simulation(Rc::new(process.clone()), grid)?;
+    Ok(simulation.run(&pricer, settings.samples()))
+}
@@ -0,0 +1,118 @@
+use crate::{
+    instruments::OptionType,
+    math::distributions::{normal_cdf, normal_pdf},
+};
+
+/// The undiscounted value of an option on a forward, and its sensitivities.
+#[derive(Clone, Copy, Debug)]
+pub struct ForwardOptionResults {
+    pub value: f64,
+    /// The change in value per unit change in the forward.
+    pub delta: f64,
+    /// The change in delta per unit change in the forward.
+    pub gamma: f64,
+    /// The change in value per unit change in the volatility.
+    pub vega: f64,
+    /// The change in value per year of time passing, holding the forward fixed.
+    pub theta: f64,
+}
+
+impl ForwardOptionResults {
+    /// The value and sensitivities of an option which is worth its intrinsic value.
+    fn intrinsic(option_type: OptionType, forward: f64, strike: f64) -> Self {
+        let omega = option_type.sign();
+        let in_the_money = omega * (forward - strike) > 0.0;
+
+        ForwardOptionResults {
+            value: (omega * (forward - strike)).max(0.0),
+            delta: if in_the_money { omega } else { 0.0 },
+            gamma: 0.0,
+            vega: 0.0,
+            theta: 0.0,
+        }
+    }
+
+    /// Multiplies the value and sensitivities by `factor`, such as a discount factor or an
+    /// annuity.
+    pub fn scaled(&self, factor: f64) -> Self {
+        ForwardOptionResults {
+            value: self.value * factor,
+            delta: self.delta * factor,
+            gamma: self.gamma * factor,
+            vega: self.vega * factor,
+            theta: self.theta * factor,
+        }
+    }
+}
+
+/// The undiscounted value of an option on a lognormally distributed forward.
+///
+/// A shifted lognormal model, for forwards which may be negative, is priced by adding the shift
+/// to both `forward` and `strike`.
+///
+/// # Arguments
+///
+/// * `option_type` - Whether the option is a call or a put on the forward.
+/// * `forward` - The forward, which must be positive.
+/// * `strike` - The strike. A call with a non-positive strike is worth its intrinsic value.
+/// * `volatility` - The annualised volatility of the logarithm of the forward.
+/// * `time` - The time to expiry in years.
+pub fn black_formula(
+    option_type: OptionType,
+    forward: f64,
+    strike: f64,
+    volatility: f64,
+    time: f64,
+) -> ForwardOptionResults {
+    if time <= 0.0 || volatility <= 0.0 || strike <= 0.0 {
+        return ForwardOptionResults::intrinsic(option_type, forward, strike);
+    }
+
+    let omega = option_type.sign();
+    let std_dev = volatility * time.sqrt();
+    let d1 = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+    let d2 = d1 - std_dev;
+    let density = normal_pdf(d1);
+
+    ForwardOptionResults {
+        value: omega * (forward * normal_cdf(omega * d1) - strike * normal_cdf(omega * d2)),
+        delta: omega * normal_cdf(omega * d1),
+        gamma: density / (forward * std_dev),
+        vega: forward * density * time.sqrt(),
+        theta: -forward * density * volatility / (2.0 * time.sqrt()),
+    }
+}
+
+/// The undiscounted value of an option on a normally distributed forward.
+///
+/// # Arguments
+///
+/// * `option_type` - Whether the option is a call or a put on the forward.
+/// * `forward` - The forward.
+/// * `strike` - The strike.
+/// * `volatility` - The annualised volatility of the forward, in absolute terms.
+/// * `time` - The time to expiry in years.
+pub fn bachelier_formula(
+    option_type: OptionType,
+    forward: f64,
+    strike: f64,
+    volatility: f64,
+    time: f64,
+) -> ForwardOptionResults {
+    if time <= 0.0 || volatility <= 0.0 {
+        return ForwardOptionResults::intrinsic(option_type, forward, strike);
+    }
+
+    let omega = option_type.sign();
+    let std_dev = volatility * time.sqrt();
+    let d = (forward - strike) / std_dev;
+    let density = normal_pdf(d);
+
+    ForwardOptionResults {
+        value: omega * (forward - strike) * normal_cdf(omega * d) + std_dev * density,
+        delta: omega * normal_cdf(omega * d),
+        gamma: density / std_dev,
+        vega: time.sqrt() * density,
+        theta: -volatility * density / (2.0 * time.sqrt()),
+    }
+}
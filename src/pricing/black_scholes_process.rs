@@ -0,0 +1,133 @@
+use std::{fmt, rc::Rc};
+
+use chrono::NaiveDate;
+
+use crate::error::{InvalidDate, LibraError};
+
+use super::YieldTermStructure;
+
+/// The generalized Black-Scholes process for an equity, whose price grows at the risk-free rate
+/// less its dividend yield with constant lognormal volatility.
+///
+/// Time is measured in the day count convention of the risk-free curve, from its reference date.
+#[derive(Clone)]
+pub struct GeneralizedBlackScholesProcess {
+    spot: f64,
+    dividend_curve: Rc<dyn YieldTermStructure>,
+    risk_free_curve: Rc<dyn YieldTermStructure>,
+    volatility: f64,
+}
+
+impl GeneralizedBlackScholesProcess {
+    /// Creates the process.
+    ///
+    /// # Arguments
+    ///
+    /// * `spot` - The price of the underlying on the reference date of `risk_free_curve`.
+    /// * `dividend_curve` - The curve of the underlying's continuous dividend yield.
+    /// * `risk_free_curve` - The curve used to grow the underlying and discount payoffs.
+    /// * `volatility` - The annualised volatility of the logarithm of the underlying.
+    pub fn new(
+        spot: f64,
+        dividend_curve: Rc<dyn YieldTermStructure>,
+        risk_free_curve: Rc<dyn YieldTermStructure>,
+        volatility: f64,
+    ) -> Self {
+        GeneralizedBlackScholesProcess {
+            spot,
+            dividend_curve,
+            risk_free_curve,
+            volatility,
+        }
+    }
+
+    /// Starts the process from `spot` instead.
+    pub fn with_spot(mut self, spot: f64) -> Self {
+        self.spot = spot;
+        self
+    }
+
+    /// Uses `volatility` instead.
+    pub fn with_volatility(mut self, volatility: f64) -> Self {
+        self.volatility = volatility;
+        self
+    }
+
+    /// Takes dividends off `dividend_curve` instead.
+    pub fn with_dividend_curve(mut self, dividend_curve: Rc<dyn YieldTermStructure>) -> Self {
+        self.dividend_curve = dividend_curve;
+        self
+    }
+
+    /// Grows and discounts off `risk_free_curve` instead.
+    pub fn with_risk_free_curve(mut self, risk_free_curve: Rc<dyn YieldTermStructure>) -> Self {
+        self.risk_free_curve = risk_free_curve;
+        self
+    }
+
+    pub fn spot(&self) -> f64 {
+        self.spot
+    }
+
+    pub fn dividend_curve(&self) -> &Rc<dyn YieldTermStructure> {
+        &self.dividend_curve
+    }
+
+    pub fn risk_free_curve(&self) -> &Rc<dyn YieldTermStructure> {
+        &self.risk_free_curve
+    }
+
+    pub fn volatility(&self) -> f64 {
+        self.volatility
+    }
+
+    /// The date the process starts from, which is the reference date of the risk-free curve.
+    pub fn reference_date(&self) -> NaiveDate {
+        self.risk_free_curve.reference_date()
+    }
+
+    /// The time in years from the reference date to `date`.
+    ///
+    /// Returns an error if `date` is before the reference date.
+    pub fn time(&self, date: NaiveDate) -> Result<f64, LibraError> {
+        if date < self.reference_date() {
+            return Err(InvalidDate.into());
+        }
+
+        self.risk_free_curve.time_from_reference(date)
+    }
+
+    /// The forward price of the underlying for delivery at `time` years.
+    pub fn forward(&self, time: f64) -> f64 {
+        self.spot * self.dividend_curve.discount_time(time)
+            / self.risk_free_curve.discount_time(time)
+    }
+
+    /// The continuously compounded zero rate of the risk-free curve to `time` years, or zero if
+    /// `time` is zero.
+    pub fn risk_free_rate(&self, time: f64) -> f64 {
+        zero_rate(self.risk_free_curve.as_ref(), time)
+    }
+
+    /// The continuously compounded dividend yield to `time` years, or zero if `time` is zero.
+    pub fn dividend_yield(&self, time: f64) -> f64 {
+        zero_rate(self.dividend_curve.as_ref(), time)
+    }
+}
+
+/// The continuously compounded zero rate of `curve` to `time` years.
+fn zero_rate(curve: &dyn YieldTermStructure, time: f64) -> f64 {
+    match time > 0.0 {
+        true => -curve.discount_time(time).ln() / time,
+        false => 0.0,
+    }
+}
+
+impl fmt::Debug for GeneralizedBlackScholesProcess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GeneralizedBlackScholesProcess")
+            .field("spot", &self.spot)
+            .field("volatility", &self.volatility)
+            .finish_non_exhaustive()
+    }
+}
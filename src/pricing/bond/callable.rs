@@ -0,0 +1,250 @@
+use chrono::{Days, NaiveDate};
+
+use crate::{
+    error::LibraError,
+    instruments::{CallabilityType, CallableBond},
+    math::solvers::{bracket, Brent, Solver1D, DEFAULT_MAX_EVALUATIONS},
+    pricing::{
+        short_rate::{ShortRateModel, TrinomialTree},
+        YieldTermStructure,
+    },
+    time::DayCountConvention,
+};
+
+use super::{discounting::curve_discount, ONE_BASIS_POINT};
+
+/// The accuracy to which option-adjusted spreads are solved.
+const OAS_ACCURACY: f64 = 1.0e-10;
+
+/// Valuation measures of a callable or puttable bond.
+///
+/// Prices are per 100 of outstanding notional.
+#[derive(Clone, Copy, Debug)]
+pub struct CallableBondAnalytics {
+    /// The spread over the short rate at which the model reprices the market price.
+    pub option_adjusted_spread: f64,
+    pub dirty_price: f64,
+    pub clean_price: f64,
+    /// The relative fall in price for a parallel rise in the curve, holding the OAS fixed.
+    pub effective_duration: f64,
+    /// The relative curvature of price in a parallel curve shift, holding the OAS fixed.
+    pub effective_convexity: f64,
+}
+
+/// Prices callable and puttable bonds by backward induction on a short-rate trinomial tree
+/// fitted to a discount curve.
+///
+/// Spreads over the curve are continuously compounded over Actual/365 time.
+#[derive(Clone, Copy, Debug)]
+pub struct TreeCallableBondEngine {
+    model: ShortRateModel,
+    steps_per_year: usize,
+}
+
+impl TreeCallableBondEngine {
+    pub fn new(model: ShortRateModel, steps_per_year: usize) -> Self {
+        TreeCallableBondEngine {
+            model,
+            steps_per_year,
+        }
+    }
+
+    pub fn model(&self) -> ShortRateModel {
+        self.model
+    }
+
+    /// The dirty price of `bond` when every short rate in the tree is shifted by `oas`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bond` - The bond to price.
+    /// * `curve` - The curve the tree is fitted to.
+    /// * `settlement` - The settlement date to price for.
+    /// * `oas` - The option-adjusted spread added to the short rate when discounting.
+    ///
+    /// # Returns
+    ///
+    /// The dirty price per 100 of outstanding notional.
+    pub fn dirty_price(
+        &self,
+        bond: &CallableBond,
+        curve: &dyn YieldTermStructure,
+        settlement: NaiveDate,
+        oas: f64,
+    ) -> Result<f64, LibraError> {
+        self.price(bond, curve, settlement, oas, 0.0)
+    }
+
+    /// The clean price of `bond` when every short rate in the tree is shifted by `oas`.
+    pub fn clean_price(
+        &self,
+        bond: &CallableBond,
+        curve: &dyn YieldTermStructure,
+        settlement: NaiveDate,
+        oas: f64,
+    ) -> Result<f64, LibraError> {
+        Ok(self.dirty_price(bond, curve, settlement, oas)?
+            - bond.bond().accrued_amount(settlement)?)
+    }
+
+    /// The option-adjusted spread at which the model reprices `clean_price`.
+    pub fn option_adjusted_spread(
+        &self,
+        bond: &CallableBond,
+        clean_price: f64,
+        curve: &dyn YieldTermStructure,
+        settlement: NaiveDate,
+    ) -> Result<f64, LibraError> {
+        let dirty_price = bond
+            .bond()
+            .dirty_price_from_clean(clean_price, settlement)?;
+
+        let error = |oas: f64| {
+            self.price(bond, curve, settlement, oas, 0.0)
+                .map_or(f64::NAN, |p| p - dirty_price)
+        };
+
+        let (lower, upper) = bracket(&error, 0.0, 0.01, (-0.5, 1.0), DEFAULT_MAX_EVALUATIONS)?;
+
+        Brent::new().solve_bracketed(&error, OAS_ACCURACY, lower, upper)
+    }
+
+    /// The option-adjusted spread implied by `clean_price`, and the effective duration and
+    /// convexity found by shifting the curve by one basis point while holding that spread fixed.
+    pub fn analytics(
+        &self,
+        bond: &CallableBond,
+        clean_price: f64,
+        curve: &dyn YieldTermStructure,
+        settlement: NaiveDate,
+    ) -> Result<CallableBondAnalytics, LibraError> {
+        let oas = self.option_adjusted_spread(bond, clean_price, curve, settlement)?;
+
+        let price = self.price(bond, curve, settlement, oas, 0.0)?;
+        let up = self.price(bond, curve, settlement, oas, ONE_BASIS_POINT)?;
+        let down = self.price(bond, curve, settlement, oas, -ONE_BASIS_POINT)?;
+
+        Ok(CallableBondAnalytics {
+            option_adjusted_spread: oas,
+            dirty_price: price,
+            clean_price: price - bond.bond().accrued_amount(settlement)?,
+            effective_duration: (down - up) / (2.0 * price * ONE_BASIS_POINT),
+            effective_convexity: (up + down - 2.0 * price)
+                / (price * ONE_BASIS_POINT * ONE_BASIS_POINT),
+        })
+    }
+
+    /// The dirty price per 100 of outstanding notional, with the tree fitted to `curve` shifted
+    /// in parallel by `shift` and short rates spread by `oas`.
+    fn price(
+        &self,
+        callable: &CallableBond,
+        curve: &dyn YieldTermStructure,
+        settlement: NaiveDate,
+        oas: f64,
+        shift: f64,
+    ) -> Result<f64, LibraError> {
+        let bond = callable.bond();
+        let notional = bond.notional(settlement);
+        let day_count = DayCountConvention::Actual365Fixed;
+
+        let time = |date: NaiveDate| day_count.year_frac(&settlement, &date);
+        let date = |t: f64| settlement + Days::new((t * 365.0).round() as u64);
+        let discount = |t: f64, spread: f64| {
+            curve_discount(curve, shift + spread, settlement, date(t)).unwrap_or(f64::NAN)
+        };
+
+        let end = time(bond.maturity_date())?;
+        let steps = ((end * self.steps_per_year as f64).ceil() as usize).max(1);
+        let tree = TrinomialTree::new(self.model, &|t| discount(t, 0.0), end, steps)?;
+        let dt = tree.dt();
+        let step = |t: f64| ((t / dt).round() as usize).min(steps);
+
+        // Flows of the underlying bond, by the step they are paid at.
+        let flows = bond
+            .leg()
+            .flows()
+            .iter()
+            .filter(|f| f.date() > settlement)
+            .map(|f| Ok((step(time(f.date())?), time(f.date())?, f.amount()?)))
+            .collect::<Result<Vec<_>, LibraError>>()?;
+
+        // Exercise events, by the step exercise is notified at, with the value on exercise of
+        // the strike and any flows paid before it, discounted to that step along the curve.
+        let mut events = Vec::new();
+
+        for c in callable.callabilities() {
+            let notice = callable.notice_date(c);
+            if notice <= settlement || c.date() > bond.maturity_date() {
+                continue;
+            }
+
+            let (t_notice, t_exercise) = (time(notice)?, time(c.date())?);
+            let s = step(t_notice);
+            let forward = |t: f64| discount(t, oas) / discount(t_notice, oas);
+
+            let strike = c.price() / 100.0 * bond.notional(c.date().pred_opt().unwrap_or(c.date()))
+                + bond.leg().accrued_amount(&c.date())?;
+
+            let flows_to_exercise: f64 = flows
+                .iter()
+                .filter(|(i, t, _)| *i > s && *t <= t_exercise)
+                .map(|(_, t, amount)| amount * forward(*t))
+                .sum();
+
+            events.push((
+                s,
+                c.kind(),
+                strike * forward(t_exercise) + flows_to_exercise,
+            ));
+        }
+
+        let make_whole = callable.make_whole();
+        let mut values = vec![0.0; tree.size(steps)];
+        let mut make_whole_values = values.clone();
+
+        for i in (0..=steps).rev() {
+            if i < steps {
+                values = tree.rollback(i, &values, oas);
+
+                if let Some(mw) = make_whole {
+                    make_whole_values = tree.rollback(i, &make_whole_values, mw.spread());
+                }
+            }
+
+            for (_, kind, exercise) in events.iter().filter(|(s, _, _)| *s == i) {
+                for v in values.iter_mut() {
+                    *v = match kind {
+                        CallabilityType::Call => v.min(*exercise),
+                        CallabilityType::Put => v.max(*exercise),
+                    };
+                }
+            }
+
+            if let Some(mw) = make_whole {
+                let d = date(i as f64 * dt);
+
+                if d > settlement && d >= mw.start() && d <= mw.end() {
+                    let floor =
+                        mw.floor() / 100.0 * bond.notional(d) + bond.leg().accrued_amount(&d)?;
+
+                    for (v, pv) in values.iter_mut().zip(make_whole_values.iter()) {
+                        *v = v.min(pv.max(floor));
+                    }
+                }
+            }
+
+            let paid: f64 = flows
+                .iter()
+                .filter(|(s, _, _)| *s == i)
+                .map(|(_, _, amount)| amount)
+                .sum();
+
+            for v in values.iter_mut().chain(make_whole_values.iter_mut()) {
+                *v += paid;
+            }
+        }
+
+        Ok(values[0] / notional * 100.0)
+    }
+}
@@ -0,0 +1,295 @@
+use chrono::{Days, NaiveDate};
+
+use crate::{
+    error::{LibraError, NotInRange, NotPositive},
+    instruments::{CallabilityType, ConvertibleBond},
+    pricing::YieldTermStructure,
+    time::DayCountConvention,
+};
+
+use super::discounting::curve_discount;
+
+/// The equity and credit inputs used to price a convertible bond.
+#[derive(Clone, Copy, Debug)]
+pub struct ConvertibleBondMarket {
+    spot: f64,
+    volatility: f64,
+    credit_spread: f64,
+}
+
+impl ConvertibleBondMarket {
+    /// Creates the market for a convertible bond.
+    ///
+    /// # Arguments
+    ///
+    /// * `spot` - The current share price.
+    /// * `volatility` - The annualized volatility of the share price, net of expected dividends.
+    /// * `credit_spread` - The continuously compounded spread over the curve at which the
+    ///   issuer's cash payments are discounted.
+    pub fn new(spot: f64, volatility: f64, credit_spread: f64) -> Self {
+        ConvertibleBondMarket {
+            spot,
+            volatility,
+            credit_spread,
+        }
+    }
+
+    pub fn spot(&self) -> f64 {
+        self.spot
+    }
+
+    pub fn volatility(&self) -> f64 {
+        self.volatility
+    }
+
+    pub fn credit_spread(&self) -> f64 {
+        self.credit_spread
+    }
+}
+
+/// Valuation measures of a convertible bond.
+///
+/// Prices are per 100 of outstanding notional.
+#[derive(Clone, Copy, Debug)]
+pub struct ConvertibleBondAnalytics {
+    pub dirty_price: f64,
+    pub clean_price: f64,
+    /// The part of the dirty price paid in cash, discounted with the credit spread.
+    pub debt_component: f64,
+    /// The part of the dirty price received as shares, discounted at the risk-free rate.
+    pub equity_component: f64,
+    /// The value of converting immediately.
+    pub parity: f64,
+    /// The amount by which the clean price exceeds parity, as a fraction of parity, or `None` if
+    /// parity is zero because the share is worthless.
+    pub conversion_premium: Option<f64>,
+    /// The change in dirty price for a unit change in the share price.
+    pub delta: f64,
+}
+
+/// Prices convertible bonds on a Cox-Ross-Rubinstein binomial tree for the share price, splitting
+/// the value at each node into debt and equity components as in Tsiveriotis and Fernandes (1998).
+///
+/// Cash payments, including the bond's flows and call and put prices, are discounted at the curve
+/// plus the credit spread, while the value of shares received on conversion is discounted at the
+/// curve. Discrete dividends are handled by building the tree on the share price less the present
+/// value of the dividends to be paid before maturity.
+///
+/// Spreads over the curve are continuously compounded over Actual/365 time.
+#[derive(Clone, Copy, Debug)]
+pub struct BinomialConvertibleBondEngine {
+    steps_per_year: usize,
+}
+
+impl BinomialConvertibleBondEngine {
+    pub fn new(steps_per_year: usize) -> Self {
+        BinomialConvertibleBondEngine { steps_per_year }
+    }
+
+    /// The dirty price of `bond`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bond` - The bond to price.
+    /// * `market` - The share price, its volatility and the issuer's credit spread.
+    /// * `curve` - The risk-free curve.
+    /// * `settlement` - The settlement date to price for.
+    ///
+    /// # Returns
+    ///
+    /// The dirty price per 100 of outstanding notional, or an error if the tree cannot be built,
+    /// as for [`BinomialConvertibleBondEngine::analytics`].
+    pub fn dirty_price(
+        &self,
+        bond: &ConvertibleBond,
+        market: &ConvertibleBondMarket,
+        curve: &dyn YieldTermStructure,
+        settlement: NaiveDate,
+    ) -> Result<f64, LibraError> {
+        Ok(self.analytics(bond, market, curve, settlement)?.dirty_price)
+    }
+
+    /// The clean price of `bond`.
+    pub fn clean_price(
+        &self,
+        bond: &ConvertibleBond,
+        market: &ConvertibleBondMarket,
+        curve: &dyn YieldTermStructure,
+        settlement: NaiveDate,
+    ) -> Result<f64, LibraError> {
+        Ok(self.analytics(bond, market, curve, settlement)?.clean_price)
+    }
+
+    /// The price of `bond`, its split into debt and equity components, and its sensitivity to the
+    /// share price.
+    ///
+    /// # Returns
+    ///
+    /// The analytics, or an error if the dividends are worth at least the share, or the steps are
+    /// too long for the volatility to span the growth at the risk-free rate over a step.
+    pub fn analytics(
+        &self,
+        convertible: &ConvertibleBond,
+        market: &ConvertibleBondMarket,
+        curve: &dyn YieldTermStructure,
+        settlement: NaiveDate,
+    ) -> Result<ConvertibleBondAnalytics, LibraError> {
+        let bond = convertible.bond();
+        let notional = bond.notional(settlement);
+        let day_count = DayCountConvention::Actual365Fixed;
+
+        let time = |date: NaiveDate| day_count.year_frac(&settlement, &date);
+        let date = |t: f64| settlement + Days::new((t * 365.0).round() as u64);
+        let discount = |t: f64| curve_discount(curve, 0.0, settlement, date(t)).unwrap_or(f64::NAN);
+
+        let end = time(bond.maturity_date())?;
+        let steps = ((end * self.steps_per_year as f64).ceil() as usize).max(1);
+        let dt = end / steps as f64;
+        let step = |t: f64| ((t / dt).round() as usize).min(steps);
+
+        let flows = bond
+            .leg()
+            .flows()
+            .iter()
+            .filter(|f| f.date() > settlement)
+            .map(|f| Ok((step(time(f.date())?), f.amount()?)))
+            .collect::<Result<Vec<_>, LibraError>>()?;
+
+        let dividends = convertible
+            .dividends()
+            .iter()
+            .filter(|d| d.date() > settlement && d.date() <= bond.maturity_date())
+            .map(|d| Ok((time(d.date())?, d.amount())))
+            .collect::<Result<Vec<_>, LibraError>>()?;
+
+        // The value at time `t` of the dividends still to be paid.
+        let dividend_value = |t: f64| -> f64 {
+            dividends
+                .iter()
+                .filter(|(t_div, _)| *t_div > t)
+                .map(|(t_div, amount)| amount * discount(*t_div) / discount(t))
+                .sum()
+        };
+
+        // Calls and puts, by step, with the cash paid on exercise excluding flows due that day.
+        let mut events = Vec::new();
+
+        for c in convertible.callabilities() {
+            if c.date() <= settlement || c.date() >= bond.maturity_date() {
+                continue;
+            }
+
+            let strike = c.price() / 100.0 * bond.notional(c.date().pred_opt().unwrap_or(c.date()))
+                + bond.leg().accrued_amount(&c.date())?;
+
+            events.push((step(time(c.date())?), c.kind(), strike));
+        }
+
+        let u = (market.volatility() * dt.sqrt()).exp();
+        let d = 1.0 / u;
+        let escrowed_spot = market.spot() - dividend_value(0.0);
+        if escrowed_spot <= 0.0 {
+            return Err(NotPositive::new("spot less the value of dividends", escrowed_spot).into());
+        }
+        let trigger = convertible
+            .soft_call_trigger()
+            .map_or(0.0, |t| t / 100.0 * convertible.conversion_price());
+
+        let share_price = |i: usize, j: usize| {
+            escrowed_spot * u.powi(2 * j as i32 - i as i32) + dividend_value(i as f64 * dt)
+        };
+
+        let mut values = Vec::new();
+        let mut debt = Vec::new();
+        let mut step_one = (0.0, 0.0);
+
+        for i in (0..=steps).rev() {
+            let t = i as f64 * dt;
+
+            if i < steps {
+                let growth = discount(t) / discount(t + dt);
+                let p = (growth - d) / (u - d);
+                if !(0.0..=1.0).contains(&p) {
+                    return Err(NotInRange::new("probability of an up move", 0.0, 1.0, p).into());
+                }
+                let credit_discount = (-market.credit_spread() * dt).exp() / growth;
+
+                if i == 0 {
+                    step_one = (values[1] - values[0], share_price(1, 1) - share_price(1, 0));
+                }
+
+                let equity = |j: usize| values[j] - debt[j];
+
+                let next_values: Vec<f64> = (0..=i)
+                    .map(|j| {
+                        (p * equity(j + 1) + (1.0 - p) * equity(j)) / growth
+                            + (p * debt[j + 1] + (1.0 - p) * debt[j]) * credit_discount
+                    })
+                    .collect();
+                debt = (0..=i)
+                    .map(|j| (p * debt[j + 1] + (1.0 - p) * debt[j]) * credit_discount)
+                    .collect();
+                values = next_values;
+            } else {
+                values = vec![0.0; steps + 1];
+                debt = values.clone();
+            }
+
+            let paid: f64 = flows
+                .iter()
+                .filter(|(s, _)| *s == i)
+                .map(|(_, amount)| amount)
+                .sum();
+
+            let outstanding = bond.notional(date(t).pred_opt().unwrap_or(settlement));
+
+            for j in 0..=i {
+                let (v, b) = (&mut values[j], &mut debt[j]);
+                let share = share_price(i, j);
+                let conversion = convertible.parity(share) * outstanding / 100.0;
+
+                *v += paid;
+                *b += paid;
+
+                for (_, kind, strike) in events.iter().filter(|(s, _, _)| *s == i) {
+                    let exercise = strike + paid;
+
+                    match kind {
+                        CallabilityType::Call
+                            if share >= trigger && *v > exercise.max(conversion) =>
+                        {
+                            (*v, *b) = if conversion >= exercise {
+                                (conversion, 0.0)
+                            } else {
+                                (exercise, exercise)
+                            };
+                        }
+                        CallabilityType::Put if exercise > *v => {
+                            (*v, *b) = (exercise, exercise);
+                        }
+                        _ => {}
+                    }
+                }
+
+                if conversion > *v {
+                    (*v, *b) = (conversion, 0.0);
+                }
+            }
+        }
+
+        let scale = 100.0 / notional;
+        let dirty_price = values[0] * scale;
+        let clean_price = dirty_price - bond.accrued_amount(settlement)?;
+        let parity = convertible.parity(market.spot());
+
+        Ok(ConvertibleBondAnalytics {
+            dirty_price,
+            clean_price,
+            debt_component: debt[0] * scale,
+            equity_component: (values[0] - debt[0]) * scale,
+            parity,
+            conversion_premium: (parity > 0.0).then(|| clean_price / parity - 1.0),
+            delta: step_one.0 / step_one.1 * scale,
+        })
+    }
+}
@@ -0,0 +1,72 @@
+use chrono::NaiveDate;
+
+use crate::{
+    error::{InvalidDate, LibraError},
+    instruments::Bond,
+    pricing::YieldTermStructure,
+    time::DayCountConvention,
+};
+
+impl Bond {
+    /// The price including accrued interest when the bond's flows are discounted off `curve`.
+    ///
+    /// # Arguments
+    ///
+    /// * `curve` - The curve used to discount all flows after `settlement`.
+    /// * `settlement` - The settlement date to discount back to.
+    ///
+    /// # Returns
+    ///
+    /// The dirty price per 100 of outstanding notional.
+    pub fn dirty_price_on_curve(
+        &self,
+        curve: &dyn YieldTermStructure,
+        settlement: NaiveDate,
+    ) -> Result<f64, LibraError> {
+        curve_dirty_price(self, curve, 0.0, settlement)
+    }
+
+    /// The price excluding accrued interest when the bond's flows are discounted off `curve`.
+    pub fn clean_price_on_curve(
+        &self,
+        curve: &dyn YieldTermStructure,
+        settlement: NaiveDate,
+    ) -> Result<f64, LibraError> {
+        Ok(self.dirty_price_on_curve(curve, settlement)? - self.accrued_amount(settlement)?)
+    }
+}
+
+/// The discount factor from `date` back to `settlement` off `curve`, with a continuously
+/// compounded `spread` over Actual/365 time added to the curve's zero rates.
+pub(super) fn curve_discount(
+    curve: &dyn YieldTermStructure,
+    spread: f64,
+    settlement: NaiveDate,
+    date: NaiveDate,
+) -> Result<f64, LibraError> {
+    let time = DayCountConvention::Actual365Fixed.year_frac(&settlement, &date)?;
+
+    Ok(curve.discount(date)? / curve.discount(settlement)? * (-spread * time).exp())
+}
+
+/// The dirty price per 100 of outstanding notional of `bond`, discounting off `curve` with its
+/// zero rates shifted by `spread`.
+pub(super) fn curve_dirty_price(
+    bond: &Bond,
+    curve: &dyn YieldTermStructure,
+    spread: f64,
+    settlement: NaiveDate,
+) -> Result<f64, LibraError> {
+    let notional = bond.notional(settlement);
+    if notional == 0.0 || settlement >= bond.maturity_date() {
+        return Err(InvalidDate.into());
+    }
+
+    let mut pv = 0.0;
+
+    for flow in bond.leg().flows().iter().filter(|f| f.date() > settlement) {
+        pv += flow.amount()? * curve_discount(curve, spread, settlement, flow.date())?;
+    }
+
+    Ok(pv / notional * 100.0)
+}
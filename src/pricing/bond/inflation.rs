@@ -0,0 +1,142 @@
+use chrono::NaiveDate;
+
+use crate::{
+    cashflows::{CashFlow, Compounding, InterestRate, LegFlow},
+    error::{InvalidDate, LibraError},
+    instruments::Bond,
+    time::{DayCountConvention, Frequency},
+};
+
+use super::solve_yield;
+
+impl Bond {
+    /// The index ratio of the bond at `settlement`: the reference value of its inflation index
+    /// at `settlement` divided by the base value, or one if the bond is not inflation-linked.
+    pub fn index_ratio(&self, settlement: NaiveDate) -> Result<f64, LibraError> {
+        let indexation = self
+            .leg()
+            .flows()
+            .iter()
+            .filter(|f| f.date() > settlement)
+            .find_map(|f| match f {
+                LegFlow::CpiCoupon(coupon) => Some(coupon.indexation()),
+                LegFlow::CpiRedemption(redemption) => Some(redemption.indexation()),
+                _ => None,
+            });
+
+        match indexation {
+            Some(indexation) => indexation.index_ratio(settlement),
+            None => Ok(1.0),
+        }
+    }
+
+    /// The price including accrued interest in real terms, discounting the unindexed flows.
+    ///
+    /// # Arguments
+    ///
+    /// * `real_yield` - The real yield used to discount all flows after `settlement`.
+    /// * `settlement` - The settlement date to discount back to.
+    ///
+    /// # Returns
+    ///
+    /// The real dirty price per 100 of outstanding notional.
+    pub fn real_dirty_price(
+        &self,
+        real_yield: &InterestRate,
+        settlement: NaiveDate,
+    ) -> Result<f64, LibraError> {
+        let notional = self.notional(settlement);
+        if notional == 0.0 || settlement >= self.maturity_date() {
+            return Err(InvalidDate.into());
+        }
+
+        let mut pv = 0.0;
+
+        for flow in self.leg().flows().iter().filter(|f| f.date() > settlement) {
+            let real_amount = flow.amount()? / flow.index_ratio()?;
+            pv += CashFlow::new(real_amount, flow.date()).present_value(&settlement, real_yield)?;
+        }
+
+        Ok(pv / notional * 100.0)
+    }
+
+    /// The price excluding accrued interest in real terms.
+    pub fn real_clean_price(
+        &self,
+        real_yield: &InterestRate,
+        settlement: NaiveDate,
+    ) -> Result<f64, LibraError> {
+        Ok(
+            self.real_dirty_price(real_yield, settlement)?
+                - self.real_accrued_amount(settlement)?,
+        )
+    }
+
+    /// The interest accrued at `settlement` in real terms, per 100 of outstanding notional.
+    pub fn real_accrued_amount(&self, settlement: NaiveDate) -> Result<f64, LibraError> {
+        Ok(self.accrued_amount(settlement)? / self.index_ratio(settlement)?)
+    }
+
+    /// The nominal dirty price paid at `settlement` for a bond quoted at `real_clean_price`.
+    pub fn nominal_dirty_price_from_real_clean(
+        &self,
+        real_clean_price: f64,
+        settlement: NaiveDate,
+    ) -> Result<f64, LibraError> {
+        Ok((real_clean_price + self.real_accrued_amount(settlement)?)
+            * self.index_ratio(settlement)?)
+    }
+
+    /// The real yield, with the given conventions, at which the bond's real clean price is
+    /// `real_clean_price`.
+    ///
+    /// # Arguments
+    ///
+    /// * `real_clean_price` - The quoted real price, per 100 of outstanding notional.
+    /// * `settlement` - The settlement date the price applies to.
+    /// * `day_count` - The day count convention of the yield.
+    /// * `compounding` - The compounding of the yield.
+    /// * `frequency` - The compounding frequency of the yield.
+    ///
+    /// # Returns
+    ///
+    /// The real yield, or an error if no yield reproduces the price.
+    pub fn real_yield_from_clean_price(
+        &self,
+        real_clean_price: f64,
+        settlement: NaiveDate,
+        day_count: DayCountConvention,
+        compounding: Compounding,
+        frequency: Frequency,
+    ) -> Result<InterestRate, LibraError> {
+        let rate = |y: f64| InterestRate::new(y, day_count, compounding, frequency);
+        let dirty_price = real_clean_price + self.real_accrued_amount(settlement)?;
+        let min = self.minimum_yield(settlement, day_count, compounding, frequency)?;
+
+        let error = |y: f64| {
+            self.real_dirty_price(&rate(y), settlement)
+                .map_or(f64::NAN, |p| p - dirty_price)
+        };
+
+        Ok(rate(solve_yield(&error, min)?))
+    }
+
+    /// The nominal yield, with the given conventions, of the flows projected by the bond's
+    /// inflation index when it is quoted at `real_clean_price`.
+    pub fn nominal_yield_from_real_clean_price(
+        &self,
+        real_clean_price: f64,
+        settlement: NaiveDate,
+        day_count: DayCountConvention,
+        compounding: Compounding,
+        frequency: Frequency,
+    ) -> Result<InterestRate, LibraError> {
+        self.yield_from_dirty_price(
+            self.nominal_dirty_price_from_real_clean(real_clean_price, settlement)?,
+            settlement,
+            day_count,
+            compounding,
+            frequency,
+        )
+    }
+}
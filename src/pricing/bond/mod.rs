@@ -0,0 +1,18 @@
+mod callable;
+mod convertible;
+mod discounting;
+mod inflation;
+mod risk;
+mod spreads;
+mod yields;
+
+pub use callable::*;
+pub use convertible::*;
+pub use risk::*;
+pub use yields::*;
+
+use discounting::curve_dirty_price;
+use yields::solve_yield;
+
+/// One basis point, used as the bump size for sensitivities.
+pub const ONE_BASIS_POINT: f64 = 1.0e-4;
@@ -0,0 +1,168 @@
+use chrono::NaiveDate;
+
+use crate::{
+    cashflows::{Compounding, InterestRate},
+    error::LibraError,
+    instruments::Bond,
+    pricing::YieldTermStructure,
+};
+
+use super::{curve_dirty_price, ONE_BASIS_POINT};
+
+/// Interest rate risk measures of a bond.
+///
+/// Prices and price sensitivities are per 100 of outstanding notional, durations are in years.
+#[derive(Clone, Copy, Debug)]
+pub struct BondRiskReport {
+    pub dirty_price: f64,
+    pub clean_price: f64,
+    pub accrued_amount: f64,
+    /// The present value weighted average time to each flow.
+    pub macaulay_duration: f64,
+    /// The relative change in dirty price per unit change in yield.
+    pub modified_duration: f64,
+    /// The relative second derivative of the dirty price with respect to yield.
+    pub convexity: f64,
+    /// Modified duration estimated by bumping the yield up and down by one basis point.
+    pub effective_duration: f64,
+    /// Convexity estimated by bumping the yield up and down by one basis point.
+    pub effective_convexity: f64,
+    /// The fall in dirty price for a one basis point rise in yield.
+    pub dv01: f64,
+    /// The present value of receiving one basis point per annum on the notional of each coupon.
+    pub pv01: f64,
+    /// The fall in the value of the bond's face amount for a one basis point rise in yield.
+    pub basis_point_value: f64,
+    /// The change in yield for a 0.01 change in price.
+    pub yield_value_of_basis_point: f64,
+}
+
+impl Bond {
+    /// The risk measures of the bond when priced at `yield_rate`.
+    ///
+    /// # Arguments
+    ///
+    /// * `yield_rate` - The yield to maturity, whose conventions are used for all measures.
+    /// * `settlement` - The settlement date to measure risk at.
+    ///
+    /// # Returns
+    ///
+    /// The risk report, or an error if the bond has matured.
+    pub fn risk(
+        &self,
+        yield_rate: &InterestRate,
+        settlement: NaiveDate,
+    ) -> Result<BondRiskReport, LibraError> {
+        let day_count = yield_rate.day_count();
+        let y = yield_rate.rate();
+        let f = yield_rate.frequency().periods_per_year() as f64;
+        let notional = self.notional(settlement);
+
+        let mut price = 0.0;
+        let mut time_weighted = 0.0;
+        let mut first_derivative = 0.0;
+        let mut second_derivative = 0.0;
+
+        for flow in self.leg().flows().iter().filter(|f| f.date() > settlement) {
+            let t = day_count.year_frac(&settlement, &flow.date())?;
+            let amount = flow.amount()? / notional * 100.0;
+            let discount = yield_rate.discount_factor(t);
+
+            // The compounding actually applied over `t`, resolving the mixed conventions.
+            let compounding = match yield_rate.compounding() {
+                Compounding::SimpleThenCompounded if t <= 1.0 / f => Compounding::Simple,
+                Compounding::SimpleThenCompounded => Compounding::Compounded,
+                Compounding::CompoundedThenSimple if t <= 1.0 / f => Compounding::Compounded,
+                Compounding::CompoundedThenSimple => Compounding::Simple,
+                compounding => compounding,
+            };
+
+            let (d1, d2) = match compounding {
+                Compounding::Simple => (t * discount * discount, 2.0 * t * t * discount.powi(3)),
+                Compounding::Continuous => (t * discount, t * t * discount),
+                _ => (
+                    t * discount / (1.0 + y / f),
+                    t * (t + 1.0 / f) * discount / (1.0 + y / f).powi(2),
+                ),
+            };
+
+            price += amount * discount;
+            time_weighted += t * amount * discount;
+            first_derivative += amount * d1;
+            second_derivative += amount * d2;
+        }
+
+        let accrued_amount = self.accrued_amount(settlement)?;
+        let bumped = |shift: f64| {
+            let rate = InterestRate::new(
+                y + shift,
+                day_count,
+                yield_rate.compounding(),
+                yield_rate.frequency(),
+            );
+            self.dirty_price(&rate, settlement)
+        };
+
+        let up = bumped(ONE_BASIS_POINT)?;
+        let down = bumped(-ONE_BASIS_POINT)?;
+        let modified_duration = first_derivative / price;
+
+        Ok(BondRiskReport {
+            dirty_price: price,
+            clean_price: price - accrued_amount,
+            accrued_amount,
+            macaulay_duration: time_weighted / price,
+            modified_duration,
+            convexity: second_derivative / price,
+            effective_duration: (down - up) / (2.0 * price * ONE_BASIS_POINT),
+            effective_convexity: (up + down - 2.0 * price)
+                / (price * ONE_BASIS_POINT * ONE_BASIS_POINT),
+            dv01: first_derivative * ONE_BASIS_POINT,
+            pv01: self.pv01(yield_rate, settlement)?,
+            basis_point_value: first_derivative * ONE_BASIS_POINT * notional / 100.0,
+            yield_value_of_basis_point: 0.01 / first_derivative,
+        })
+    }
+
+    /// The effective duration and convexity of the bond when priced off `curve`, found by
+    /// shifting the curve's zero rates up and down by one basis point.
+    ///
+    /// # Arguments
+    ///
+    /// * `curve` - The curve used to discount the bond's flows.
+    /// * `settlement` - The settlement date to measure risk at.
+    ///
+    /// # Returns
+    ///
+    /// The `(effective_duration, effective_convexity)` of the bond.
+    pub fn curve_risk(
+        &self,
+        curve: &dyn YieldTermStructure,
+        settlement: NaiveDate,
+    ) -> Result<(f64, f64), LibraError> {
+        let price = curve_dirty_price(self, curve, 0.0, settlement)?;
+        let up = curve_dirty_price(self, curve, ONE_BASIS_POINT, settlement)?;
+        let down = curve_dirty_price(self, curve, -ONE_BASIS_POINT, settlement)?;
+
+        Ok((
+            (down - up) / (2.0 * price * ONE_BASIS_POINT),
+            (up + down - 2.0 * price) / (price * ONE_BASIS_POINT * ONE_BASIS_POINT),
+        ))
+    }
+
+    /// The present value, per 100 of outstanding notional, of one basis point per annum accrued
+    /// on the notional of each remaining coupon.
+    fn pv01(&self, yield_rate: &InterestRate, settlement: NaiveDate) -> Result<f64, LibraError> {
+        let notional = self.notional(settlement);
+        let mut pv01 = 0.0;
+
+        for coupon in self.leg().coupons().filter(|c| c.date() > settlement) {
+            if let Some((nominal, accrual)) = coupon.accrual()? {
+                let discount = yield_rate.discount_factor_between(&settlement, &coupon.date())?;
+                pv01 += nominal * accrual * discount;
+            }
+        }
+
+        Ok(pv01 / notional * 100.0 * ONE_BASIS_POINT)
+    }
+}
@@ -0,0 +1,135 @@
+use chrono::{Days, Months, NaiveDate};
+
+use crate::{
+    cashflows::InterestRate,
+    error::{InvalidDate, LibraError},
+    instruments::Bond,
+    math::solvers::{bracket, Brent, Solver1D, DEFAULT_MAX_EVALUATIONS},
+    pricing::YieldTermStructure,
+};
+
+use super::discounting::{curve_dirty_price, curve_discount};
+
+/// The accuracy to which spreads are solved.
+const SPREAD_ACCURACY: f64 = 1.0e-10;
+
+impl Bond {
+    /// The zero-volatility spread: the constant spread over the continuously compounded zero
+    /// rates of `curve` at which the discounted flows of the bond equal its dirty price.
+    ///
+    /// # Arguments
+    ///
+    /// * `clean_price` - The market clean price per 100 of outstanding notional.
+    /// * `curve` - The curve whose zero rates are spread.
+    /// * `settlement` - The settlement date the price applies to.
+    ///
+    /// # Returns
+    ///
+    /// The Z-spread as a continuously compounded rate.
+    pub fn z_spread(
+        &self,
+        clean_price: f64,
+        curve: &dyn YieldTermStructure,
+        settlement: NaiveDate,
+    ) -> Result<f64, LibraError> {
+        let dirty_price = self.dirty_price_from_clean(clean_price, settlement)?;
+
+        let error = |z: f64| {
+            curve_dirty_price(self, curve, z, settlement).map_or(f64::NAN, |p| p - dirty_price)
+        };
+
+        let (lower, upper) = bracket(&error, 0.0, 0.01, (-1.0, 10.0), DEFAULT_MAX_EVALUATIONS)?;
+
+        Brent::new().solve_bracketed(&error, SPREAD_ACCURACY, lower, upper)
+    }
+
+    /// The interpolated spread: the bond's yield less the par swap rate to its maturity off
+    /// `swap_curve`, with the fixed leg paying at the yield's frequency and day count.
+    pub fn i_spread(
+        &self,
+        yield_rate: &InterestRate,
+        swap_curve: &dyn YieldTermStructure,
+        settlement: NaiveDate,
+    ) -> Result<f64, LibraError> {
+        self.spread_to_par_rate(yield_rate, swap_curve, settlement)
+    }
+
+    /// The government spread: the bond's yield less the par yield to its maturity of a notional
+    /// government bond priced off `government_curve`, paying coupons at the yield's frequency and
+    /// day count.
+    pub fn g_spread(
+        &self,
+        yield_rate: &InterestRate,
+        government_curve: &dyn YieldTermStructure,
+        settlement: NaiveDate,
+    ) -> Result<f64, LibraError> {
+        self.spread_to_par_rate(yield_rate, government_curve, settlement)
+    }
+
+    /// The par-par asset swap spread: the spread over the floating rate which compensates for
+    /// the difference between the bond's value off `swap_curve` and its market dirty price.
+    ///
+    /// The floating leg of the asset swap is assumed to accrue over the bond's coupon periods.
+    pub fn asset_swap_spread(
+        &self,
+        clean_price: f64,
+        swap_curve: &dyn YieldTermStructure,
+        settlement: NaiveDate,
+    ) -> Result<f64, LibraError> {
+        let dirty_price = self.dirty_price_from_clean(clean_price, settlement)?;
+        let model_price = curve_dirty_price(self, swap_curve, 0.0, settlement)?;
+        let notional = self.notional(settlement);
+
+        let mut annuity = 0.0;
+
+        for coupon in self.leg().coupons().filter(|c| c.date() > settlement) {
+            if let Some((nominal, accrual)) = coupon.accrual()? {
+                let discount = curve_discount(swap_curve, 0.0, settlement, coupon.date())?;
+                annuity += nominal / notional * 100.0 * accrual * discount;
+            }
+        }
+
+        Ok((model_price - dirty_price) / annuity)
+    }
+
+    /// The bond's yield less the rate of a bullet instrument from `settlement` to the bond's
+    /// maturity, paying at the frequency and day count of `yield_rate`, which is priced at par
+    /// off `curve`.
+    ///
+    /// Payment dates step back from maturity, so the first period may be short.
+    fn spread_to_par_rate(
+        &self,
+        yield_rate: &InterestRate,
+        curve: &dyn YieldTermStructure,
+        settlement: NaiveDate,
+    ) -> Result<f64, LibraError> {
+        let maturity = self.maturity_date();
+        if maturity <= settlement {
+            return Err(InvalidDate.into());
+        }
+
+        let periods_per_year = yield_rate.frequency().periods_per_year();
+        let step_back = |date: NaiveDate| match 12 % periods_per_year {
+            0 => date.checked_sub_months(Months::new(12 / periods_per_year)),
+            _ => date.checked_sub_days(Days::new((365 / periods_per_year).max(1) as u64)),
+        };
+
+        let mut dates = vec![maturity];
+        while let Some(date) = step_back(dates[dates.len() - 1]).filter(|d| *d > settlement) {
+            dates.push(date);
+        }
+        dates.push(settlement);
+        dates.reverse();
+
+        let settlement_discount = curve.discount(settlement)?;
+        let mut annuity = 0.0;
+        for period in dates.windows(2) {
+            let accrual = yield_rate.day_count().year_frac(&period[0], &period[1])?;
+            annuity += accrual * curve.discount(period[1])? / settlement_discount;
+        }
+
+        let par_rate = (1.0 - curve.discount(maturity)? / settlement_discount) / annuity;
+
+        Ok(yield_rate.rate() - par_rate)
+    }
+}
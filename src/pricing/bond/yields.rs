@@ -0,0 +1,328 @@
+use chrono::{Months, NaiveDate};
+
+use crate::{
+    cashflows::{Compounding, InterestRate, LegFlow},
+    error::{DifferentVariant, InvalidDate, LibraError},
+    instruments::Bond,
+    math::solvers::{bracket, Brent, Solver1D, DEFAULT_MAX_EVALUATIONS},
+    time::{DayCountConvention, Frequency},
+};
+
+/// The accuracy to which yields are solved.
+const YIELD_ACCURACY: f64 = 1.0e-10;
+
+/// The number of business days before a coupon date on which a gilt goes ex-dividend.
+const GILT_EX_DIVIDEND_DAYS: i64 = 7;
+
+/// Market conventions for quoting the yield of a bond.
+///
+/// Apart from `JapaneseSimple`, yields are compounded semiannually and time is measured in
+/// quasi-coupon periods counted back from maturity, so that the yield is independent of holiday
+/// adjustments to payment dates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum YieldConvention {
+    /// Compounded over fractional periods, except in the final coupon period where simple
+    /// interest is used.
+    UsStreet,
+    /// Simple interest over the fractional first period and compounded thereafter.
+    UsTreasury,
+    /// The UK Debt Management Office formula for gilts: compounded over fractional periods, with
+    /// the next coupon going to the seller once the bond is ex-dividend.
+    UkDmo,
+    /// The Japanese simple yield: annual coupon plus straight-line pull to redemption, divided by
+    /// the clean price.
+    JapaneseSimple,
+}
+
+impl Bond {
+    /// The dirty price corresponding to `clean_price` for settlement on `settlement`.
+    pub fn dirty_price_from_clean(
+        &self,
+        clean_price: f64,
+        settlement: NaiveDate,
+    ) -> Result<f64, LibraError> {
+        Ok(clean_price + self.accrued_amount(settlement)?)
+    }
+
+    /// The clean price corresponding to `dirty_price` for settlement on `settlement`.
+    pub fn clean_price_from_dirty(
+        &self,
+        dirty_price: f64,
+        settlement: NaiveDate,
+    ) -> Result<f64, LibraError> {
+        Ok(dirty_price - self.accrued_amount(settlement)?)
+    }
+
+    /// The yield, with the given conventions, at which the bond's dirty price is `dirty_price`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dirty_price` - The price including accrued interest, per 100 of outstanding notional.
+    /// * `settlement` - The settlement date the price applies to.
+    /// * `day_count` - The day count convention of the yield.
+    /// * `compounding` - The compounding of the yield.
+    /// * `frequency` - The compounding frequency of the yield.
+    ///
+    /// # Returns
+    ///
+    /// The yield, or an error if no yield reproduces the price.
+    pub fn yield_from_dirty_price(
+        &self,
+        dirty_price: f64,
+        settlement: NaiveDate,
+        day_count: DayCountConvention,
+        compounding: Compounding,
+        frequency: Frequency,
+    ) -> Result<InterestRate, LibraError> {
+        let rate = |y: f64| InterestRate::new(y, day_count, compounding, frequency);
+
+        let min = self.minimum_yield(settlement, day_count, compounding, frequency)?;
+
+        let error = |y: f64| {
+            self.dirty_price(&rate(y), settlement)
+                .map_or(f64::NAN, |p| p - dirty_price)
+        };
+
+        Ok(rate(solve_yield(&error, min)?))
+    }
+
+    /// The yield, with the given conventions, at which the bond's clean price is `clean_price`.
+    pub fn yield_from_clean_price(
+        &self,
+        clean_price: f64,
+        settlement: NaiveDate,
+        day_count: DayCountConvention,
+        compounding: Compounding,
+        frequency: Frequency,
+    ) -> Result<InterestRate, LibraError> {
+        self.yield_from_dirty_price(
+            self.dirty_price_from_clean(clean_price, settlement)?,
+            settlement,
+            day_count,
+            compounding,
+            frequency,
+        )
+    }
+
+    /// The interest accrued at `settlement` under `convention`, per 100 of outstanding notional.
+    ///
+    /// This differs from `accrued_amount` only for gilts trading ex-dividend, where the accrued
+    /// interest is negative.
+    pub fn accrued_amount_by_convention(
+        &self,
+        settlement: NaiveDate,
+        convention: YieldConvention,
+    ) -> Result<f64, LibraError> {
+        let accrued = self.accrued_amount(settlement)?;
+
+        match (convention, self.ex_dividend_coupon(settlement)) {
+            (YieldConvention::UkDmo, Some(coupon)) => {
+                Ok(accrued - coupon.amount()? / self.notional(settlement) * 100.0)
+            }
+            _ => Ok(accrued),
+        }
+    }
+
+    /// The clean price implied by `yield_rate` quoted with `convention`.
+    ///
+    /// # Arguments
+    ///
+    /// * `yield_rate` - The annualized yield.
+    /// * `settlement` - The settlement date to price for.
+    /// * `convention` - The market convention the yield is quoted with.
+    ///
+    /// # Returns
+    ///
+    /// The clean price per 100 of outstanding notional.
+    pub fn clean_price_by_convention(
+        &self,
+        yield_rate: f64,
+        settlement: NaiveDate,
+        convention: YieldConvention,
+    ) -> Result<f64, LibraError> {
+        if convention == YieldConvention::JapaneseSimple {
+            let (coupon, redemption, time) = self.japanese_simple_terms(settlement)?;
+            return Ok((coupon + redemption / time) / (yield_rate + 1.0 / time));
+        }
+
+        Ok(
+            self.dirty_price_by_convention(yield_rate, settlement, convention)?
+                - self.accrued_amount_by_convention(settlement, convention)?,
+        )
+    }
+
+    /// The dirty price implied by `yield_rate` quoted with `convention`.
+    pub fn dirty_price_by_convention(
+        &self,
+        yield_rate: f64,
+        settlement: NaiveDate,
+        convention: YieldConvention,
+    ) -> Result<f64, LibraError> {
+        if convention == YieldConvention::JapaneseSimple {
+            return Ok(
+                self.clean_price_by_convention(yield_rate, settlement, convention)?
+                    + self.accrued_amount(settlement)?,
+            );
+        }
+
+        let notional = self.notional(settlement);
+        if notional == 0.0 || settlement >= self.maturity_date() {
+            return Err(InvalidDate.into());
+        }
+
+        let f = Frequency::Semiannual.periods_per_year() as f64;
+        let periods = QuasiCouponPeriods::new(self.maturity_date(), settlement)?;
+        let ex_dividend = match convention {
+            YieldConvention::UkDmo => self.ex_dividend_coupon(settlement),
+            _ => None,
+        };
+
+        let mut pv = 0.0;
+
+        for flow in self.leg().flows().iter().filter(|f| f.date() > settlement) {
+            if flow.is_coupon() && ex_dividend.is_some_and(|c| c.date() == flow.date()) {
+                continue;
+            }
+
+            let i = periods.whole_periods_to(flow.date()) as f64;
+            let w = periods.fraction;
+
+            let discount = match convention {
+                YieldConvention::UsStreet if periods.is_final() => 1.0 + w * yield_rate / f,
+                YieldConvention::UsTreasury => {
+                    (1.0 + w * yield_rate / f) * (1.0 + yield_rate / f).powf(i)
+                }
+                _ => (1.0 + yield_rate / f).powf(w + i),
+            };
+
+            pv += flow.amount()? / discount;
+        }
+
+        Ok(pv / notional * 100.0)
+    }
+
+    /// The yield, quoted with `convention`, at which the bond's clean price is `clean_price`.
+    pub fn yield_by_convention(
+        &self,
+        clean_price: f64,
+        settlement: NaiveDate,
+        convention: YieldConvention,
+    ) -> Result<f64, LibraError> {
+        if convention == YieldConvention::JapaneseSimple {
+            let (coupon, redemption, time) = self.japanese_simple_terms(settlement)?;
+            return Ok((coupon + (redemption - clean_price) / time) / clean_price);
+        }
+
+        let dirty_price =
+            clean_price + self.accrued_amount_by_convention(settlement, convention)?;
+
+        let error = |y: f64| {
+            self.dirty_price_by_convention(y, settlement, convention)
+                .map_or(f64::NAN, |p| p - dirty_price)
+        };
+
+        solve_yield(&error, -1.0)
+    }
+
+    /// The lowest yield, with the given conventions, for which every discount factor to
+    /// maturity is positive.
+    pub(super) fn minimum_yield(
+        &self,
+        settlement: NaiveDate,
+        day_count: DayCountConvention,
+        compounding: Compounding,
+        frequency: Frequency,
+    ) -> Result<f64, LibraError> {
+        let time = day_count.year_frac(&settlement, &self.maturity_date())?;
+        let min = match compounding {
+            Compounding::Continuous => -1.0,
+            Compounding::Compounded => -0.99 * frequency.periods_per_year() as f64,
+            _ => -0.99 / time.max(1.0),
+        };
+
+        Ok(min.max(-1.0))
+    }
+
+    /// The next coupon, if `settlement` falls in its ex-dividend period.
+    fn ex_dividend_coupon(&self, settlement: NaiveDate) -> Option<&LegFlow> {
+        let next = self.leg().coupons().find(|c| c.date() > settlement)?;
+        let ex_dividend_date = self.calendar().advance(next.date(), -GILT_EX_DIVIDEND_DAYS);
+
+        (settlement >= ex_dividend_date).then_some(next)
+    }
+
+    /// The annual coupon and redemption, as percentages, and the time to maturity in years used
+    /// by the Japanese simple yield.
+    fn japanese_simple_terms(&self, settlement: NaiveDate) -> Result<(f64, f64, f64), LibraError> {
+        let coupon = match self.leg().coupons().find(|c| c.date() > settlement) {
+            Some(LegFlow::FixedCoupon(coupon)) => coupon.rate().rate() * 100.0,
+            Some(_) => return Err(DifferentVariant.into()),
+            None => 0.0,
+        };
+
+        let redemption = self.redemption().map_or(100.0, |r| r.percentage());
+        let time =
+            DayCountConvention::Actual365Fixed.year_frac(&settlement, &self.maturity_date())?;
+
+        if time == 0.0 {
+            return Err(InvalidDate.into());
+        }
+
+        Ok((coupon, redemption, time))
+    }
+}
+
+/// Solves for the yield at which `error` is zero, starting from 5% and searching no lower than
+/// `min`.
+pub(super) fn solve_yield(error: &dyn Fn(f64) -> f64, min: f64) -> Result<f64, LibraError> {
+    let (lower, upper) = bracket(error, 0.05, 0.01, (min, 10.0), DEFAULT_MAX_EVALUATIONS)?;
+
+    Brent::new().solve_bracketed(error, YIELD_ACCURACY, lower, upper)
+}
+
+/// Semiannual quasi-coupon periods counted back from maturity, used to measure time in
+/// market yield conventions.
+struct QuasiCouponPeriods {
+    /// Quasi-coupon dates after settlement, from the next one to maturity.
+    dates: Vec<NaiveDate>,
+    /// The fraction of the current quasi-coupon period remaining after settlement.
+    fraction: f64,
+}
+
+impl QuasiCouponPeriods {
+    fn new(maturity: NaiveDate, settlement: NaiveDate) -> Result<Self, InvalidDate> {
+        let mut dates = Vec::new();
+        let mut previous = maturity;
+        let mut n = 0;
+
+        while previous > settlement {
+            dates.push(previous);
+            n += 6;
+            previous = maturity
+                .checked_sub_months(Months::new(n))
+                .ok_or(InvalidDate)?;
+        }
+
+        dates.reverse();
+
+        let next = dates[0];
+        let fraction = (next - settlement).num_days() as f64 / (next - previous).num_days() as f64;
+
+        Ok(QuasiCouponPeriods { dates, fraction })
+    }
+
+    /// Returns `true` if settlement falls in the final quasi-coupon period.
+    fn is_final(&self) -> bool {
+        self.dates.len() == 1
+    }
+
+    /// The number of whole periods between the next quasi-coupon date and the quasi-coupon date
+    /// nearest to `date`.
+    fn whole_periods_to(&self, date: NaiveDate) -> usize {
+        self.dates
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, d)| (**d - date).num_days().abs())
+            .map_or(0, |(i, _)| i)
+    }
+}
@@ -0,0 +1,302 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::{
+    cashflows::LegFlow,
+    error::{ArgumentRange, DifferentVariant, InvalidDate, LibraError},
+    instruments::{Bond, BondFuture, BondFutureExchange},
+    pricing::YieldConvention,
+};
+
+/// The economics of delivering one bond of the basket into a bond future, per 100 of face
+/// amount.
+#[derive(Clone, Copy, Debug)]
+pub struct DeliverableAnalytics {
+    /// The factor the futures price is scaled by to find the invoice price of the bond.
+    pub conversion_factor: f64,
+    /// The amount received on delivery, including accrued interest.
+    pub invoice_price: f64,
+    /// The clean price less the futures price scaled by the conversion factor.
+    pub gross_basis: f64,
+    /// The coupon income less the cost of financing the bond at the repo rate until delivery.
+    pub carry: f64,
+    /// The gross basis less the carry, which is the loss from buying the bond, selling the
+    /// future and delivering.
+    pub net_basis: f64,
+    /// The repo rate at which buying the bond and delivering it into the future breaks even.
+    pub implied_repo_rate: f64,
+}
+
+/// The economics of delivering each bond of a bond future's basket.
+#[derive(Clone, Debug)]
+pub struct BondFutureAnalytics {
+    /// The analytics of each deliverable bond, in basket order.
+    pub deliverables: Vec<DeliverableAnalytics>,
+    /// The position in the basket of the cheapest bond to deliver, which has the highest implied
+    /// repo rate.
+    pub cheapest_to_deliver: usize,
+}
+
+impl BondFuture {
+    /// The conversion factor of `bond` under the conventions of the future's exchange.
+    ///
+    /// CME factors use the exchange's closed-form formula, rounded to four decimal places, Eurex
+    /// factors discount the bond's remaining flows at the notional coupon compounded at the
+    /// coupon frequency, rounded to six, and ICE factors are the DMO clean price at the notional
+    /// coupon, rounded to seven.
+    ///
+    /// # Returns
+    ///
+    /// The conversion factor, or an error if the bond does not pay fixed coupons.
+    pub fn conversion_factor(&self, bond: &Bond) -> Result<f64, LibraError> {
+        let delivery_month =
+            NaiveDate::from_ymd_opt(self.delivery_date().year(), self.delivery_date().month(), 1)
+                .ok_or(InvalidDate)?;
+
+        match self.exchange() {
+            BondFutureExchange::Cme => {
+                let factor = cme_conversion_factor(bond, delivery_month, self.notional_coupon())?;
+                Ok(round(factor, 4))
+            }
+            BondFutureExchange::Eurex => {
+                let factor =
+                    eurex_conversion_factor(bond, self.delivery_date(), self.notional_coupon())?;
+                Ok(round(factor, 6))
+            }
+            BondFutureExchange::IceGilt => {
+                coupon_rate(bond)?;
+                let price = bond.clean_price_by_convention(
+                    self.notional_coupon(),
+                    delivery_month,
+                    YieldConvention::UkDmo,
+                )?;
+                Ok(round(price / 100.0, 7))
+            }
+        }
+    }
+
+    /// The amount paid on delivery of `bond`, per 100 of face amount, which is the futures price
+    /// scaled by the conversion factor plus the interest accrued at delivery.
+    pub fn invoice_price(&self, bond: &Bond, futures_price: f64) -> Result<f64, LibraError> {
+        Ok(futures_price * self.conversion_factor(bond)?
+            + bond.accrued_amount(self.delivery_date())?)
+    }
+
+    /// The repo rate at which buying `bond` at `clean_price` for settlement on `settlement` and
+    /// delivering it into the future breaks even, with coupons received before delivery
+    /// reinvested at the same rate.
+    ///
+    /// The rate is quoted in the repo day count convention of the exchange.
+    pub fn implied_repo_rate(
+        &self,
+        bond: &Bond,
+        futures_price: f64,
+        clean_price: f64,
+        settlement: NaiveDate,
+    ) -> Result<f64, LibraError> {
+        let carry = Carry::new(self, bond, clean_price, settlement)?;
+        let invoice_price = self.invoice_price(bond, futures_price)?;
+
+        Ok(carry.implied_repo_rate(invoice_price))
+    }
+
+    /// The basis of each deliverable bond and the cheapest to deliver.
+    ///
+    /// # Arguments
+    ///
+    /// * `futures_price` - The quoted price of the future.
+    /// * `clean_prices` - The clean price of each bond in the basket, in basket order.
+    /// * `settlement` - The date on which bonds bought at `clean_prices` settle.
+    /// * `repo_rate` - The rate at which bonds are financed until delivery, in the repo day
+    ///   count convention of the exchange.
+    ///
+    /// # Returns
+    ///
+    /// The analytics, or an error if there is not one price per bond or `settlement` is after
+    /// the delivery date.
+    pub fn analytics(
+        &self,
+        futures_price: f64,
+        clean_prices: &[f64],
+        settlement: NaiveDate,
+        repo_rate: f64,
+    ) -> Result<BondFutureAnalytics, LibraError> {
+        let n = self.deliverables().len();
+        if clean_prices.len() != n {
+            return Err(ArgumentRange::new(
+                "number of clean prices",
+                n as i64,
+                n as i64,
+                clean_prices.len() as i64,
+            )
+            .into());
+        }
+
+        let mut deliverables = Vec::with_capacity(n);
+
+        for (bond, &clean_price) in self.deliverables().iter().zip(clean_prices) {
+            let conversion_factor = self.conversion_factor(bond)?;
+            let carry = Carry::new(self, bond, clean_price, settlement)?;
+            let invoice_price = self.invoice_price(bond, futures_price)?;
+
+            let gross_basis = clean_price - futures_price * conversion_factor;
+            let income = carry.accrued_at_delivery - carry.accrued_at_settlement + carry.coupons;
+            let financing = repo_rate * (carry.dirty_price * carry.time - carry.coupon_times);
+
+            deliverables.push(DeliverableAnalytics {
+                conversion_factor,
+                invoice_price,
+                gross_basis,
+                carry: income - financing,
+                net_basis: gross_basis - (income - financing),
+                implied_repo_rate: carry.implied_repo_rate(invoice_price),
+            });
+        }
+
+        let cheapest_to_deliver = deliverables
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.implied_repo_rate.total_cmp(&b.implied_repo_rate))
+            .map_or(0, |(i, _)| i);
+
+        Ok(BondFutureAnalytics {
+            deliverables,
+            cheapest_to_deliver,
+        })
+    }
+}
+
+/// The flows from holding a bond between settlement and delivery, per 100 of face amount.
+struct Carry {
+    dirty_price: f64,
+    accrued_at_settlement: f64,
+    accrued_at_delivery: f64,
+    /// The coupons paid after settlement and up to delivery.
+    coupons: f64,
+    /// The time from settlement to delivery, in the repo day count convention.
+    time: f64,
+    /// The sum of each coupon paid before delivery times the time from its payment to delivery.
+    coupon_times: f64,
+}
+
+impl Carry {
+    fn new(
+        future: &BondFuture,
+        bond: &Bond,
+        clean_price: f64,
+        settlement: NaiveDate,
+    ) -> Result<Self, LibraError> {
+        let day_count = future.exchange().repo_day_count();
+        let delivery = future.delivery_date();
+        let notional = bond.notional(settlement);
+
+        let accrued_at_settlement = bond.accrued_amount(settlement)?;
+        let mut coupons = 0.0;
+        let mut coupon_times = 0.0;
+
+        for coupon in bond
+            .leg()
+            .coupons()
+            .filter(|c| c.date() > settlement && c.date() <= delivery)
+        {
+            let amount = coupon.amount()? / notional * 100.0;
+            coupons += amount;
+            coupon_times += amount * day_count.year_frac(&coupon.date(), &delivery)?;
+        }
+
+        Ok(Carry {
+            dirty_price: clean_price + accrued_at_settlement,
+            accrued_at_settlement,
+            accrued_at_delivery: bond.accrued_amount(delivery)?,
+            coupons,
+            time: day_count.year_frac(&settlement, &delivery)?,
+            coupon_times,
+        })
+    }
+
+    /// The repo rate at which financing the bond until delivery for `invoice_price` breaks even.
+    fn implied_repo_rate(&self, invoice_price: f64) -> f64 {
+        (invoice_price + self.coupons - self.dirty_price)
+            / (self.dirty_price * self.time - self.coupon_times)
+    }
+}
+
+/// The coupon rate of a bond paying fixed coupons.
+fn coupon_rate(bond: &Bond) -> Result<f64, LibraError> {
+    match bond.leg().coupons().last() {
+        Some(LegFlow::FixedCoupon(coupon)) => Ok(coupon.rate().rate()),
+        _ => Err(DifferentVariant.into()),
+    }
+}
+
+/// The CME conversion factor, with the time from the first day of the delivery month to
+/// maturity rounded down to whole quarters.
+fn cme_conversion_factor(
+    bond: &Bond,
+    delivery_month: NaiveDate,
+    notional_coupon: f64,
+) -> Result<f64, LibraError> {
+    let coupon = coupon_rate(bond)?;
+    let maturity = bond.maturity_date();
+
+    let months = (maturity.year() - delivery_month.year()) * 12 + maturity.month() as i32
+        - delivery_month.month() as i32;
+    let n = (months / 12) as f64;
+    let z = months % 12 / 3 * 3;
+    let v = if z < 7 { z } else { z - 6 } as f64;
+
+    let growth = 1.0 + notional_coupon / 2.0;
+    let a = growth.powf(-v / 6.0);
+    let b = coupon / 2.0 * (6.0 - v) / 6.0;
+    let c = if z < 7 {
+        growth.powf(-2.0 * n)
+    } else {
+        growth.powf(-2.0 * n - 1.0)
+    };
+    let d = coupon / notional_coupon * (1.0 - c);
+
+    Ok(a * (coupon / 2.0 + c + d) - b)
+}
+
+/// The Eurex conversion factor, discounting the remaining flows to the delivery date at
+/// `notional_coupon` compounded at the coupon frequency, over fractions of the current coupon
+/// period measured in actual days.
+fn eurex_conversion_factor(
+    bond: &Bond,
+    delivery: NaiveDate,
+    notional_coupon: f64,
+) -> Result<f64, LibraError> {
+    coupon_rate(bond)?;
+
+    let coupons: Vec<_> = bond
+        .leg()
+        .coupons()
+        .filter(|c| c.date() > delivery)
+        .collect();
+    let Some(LegFlow::FixedCoupon(next)) = coupons.first() else {
+        return Err(DifferentVariant.into());
+    };
+
+    let period = (next.accrual_end() - next.accrual_start()).num_days() as f64;
+    let fraction = (next.accrual_end() - delivery).num_days() as f64 / period;
+    let frequency = (365.25 / period).round().max(1.0);
+    let growth = 1.0 + notional_coupon / frequency;
+
+    let notional = bond.notional(delivery);
+    let mut dirty_price = 0.0;
+
+    for flow in bond.leg().flows().iter().filter(|f| f.date() > delivery) {
+        let i = coupons
+            .iter()
+            .position(|c| c.date() >= flow.date())
+            .unwrap_or(coupons.len() - 1) as f64;
+        dirty_price += flow.amount()? / notional * growth.powf(-(fraction + i));
+    }
+
+    Ok(dirty_price - bond.accrued_amount(delivery)? / 100.0)
+}
+
+/// `value` rounded to `decimals` decimal places.
+fn round(value: f64, decimals: i32) -> f64 {
+    let scale = 10f64.powi(decimals);
+    (value * scale).round() / scale
+}
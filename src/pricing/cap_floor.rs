@@ -0,0 +1,678 @@
+use std::rc::Rc;
+
+use chrono::NaiveDate;
+
+use crate::{
+    cashflows::{FloatingRateCoupon, FloatingRateLeg, Leg, LegFlow},
+    error::{ArgumentRange, InvalidDate, LibraError},
+    indexes::IborIndex,
+    instruments::{CapFloor, CapFloorType, OptionType},
+    math::solvers::{Brent, NewtonSafe, Solver1D},
+    pricing::{OptionletVolatilitySurface, VolatilityType, YieldTermStructure},
+    time::{DateAdjustment, DateGeneration, Schedule},
+};
+
+use super::{
+    bachelier_formula, black_formula, implied_bachelier_volatility, implied_black_volatility,
+    ForwardOptionResults,
+};
+
+/// The accuracy to which flat and optionlet volatilities are solved.
+const VOLATILITY_ACCURACY: f64 = 1.0e-10;
+
+/// The value and sensitivities of a cap, floor or collar.
+#[derive(Clone, Debug)]
+pub struct CapFloorResults {
+    pub npv: f64,
+    /// The value of each optionlet, or of each caplet less floorlet for collars, in order of
+    /// payment. Optionlets paid on or before the reference date are worth nothing.
+    pub optionlet_npvs: Vec<f64>,
+    /// The index rate of each coupon, fixed or forecast.
+    pub forward_rates: Vec<f64>,
+    /// The fixed rate at which a swap against the coupons, paid on the same nominals and
+    /// accrual periods, has no value, at which a cap and floor have the same value.
+    pub atm_rate: f64,
+    /// The change in value per unit parallel rise in the forward rates, holding discount
+    /// factors fixed.
+    pub delta: f64,
+    /// The change in value per unit parallel rise in the optionlet volatilities.
+    pub vega: f64,
+}
+
+/// Prices caps, floors and collars by valuing each optionlet with Black's formula, for shifted
+/// lognormal volatilities, or Bachelier's, for normal volatilities.
+#[derive(Clone)]
+pub struct CapFloorEngine {
+    discount_curve: Rc<dyn YieldTermStructure>,
+    volatility: Rc<OptionletVolatilitySurface>,
+}
+
+impl CapFloorEngine {
+    /// Creates an engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `discount_curve` - The curve discounting to its reference date, which is today.
+    /// * `volatility` - The volatility of each optionlet by its fixing date and strike.
+    pub fn new(
+        discount_curve: Rc<dyn YieldTermStructure>,
+        volatility: Rc<OptionletVolatilitySurface>,
+    ) -> Self {
+        CapFloorEngine {
+            discount_curve,
+            volatility,
+        }
+    }
+
+    pub fn discount_curve(&self) -> &Rc<dyn YieldTermStructure> {
+        &self.discount_curve
+    }
+
+    pub fn volatility(&self) -> &Rc<OptionletVolatilitySurface> {
+        &self.volatility
+    }
+
+    /// Values `cap_floor` and its sensitivities.
+    ///
+    /// Each optionlet is on the coupon's rate, which is the index fixing times the gearing plus
+    /// the spread, and expires on the fixing date. Optionlets fixed on or before the reference
+    /// date are worth their intrinsic value.
+    ///
+    /// # Returns
+    ///
+    /// The results, or an error if an index rate cannot be forecast or fixed.
+    pub fn calculate(&self, cap_floor: &CapFloor) -> Result<CapFloorResults, LibraError> {
+        let curve = &*self.discount_curve;
+        let today = curve.reference_date();
+        let surface = &*self.volatility;
+
+        let mut optionlet_npvs = Vec::with_capacity(cap_floor.coupons().len());
+        let mut forward_rates = Vec::with_capacity(optionlet_npvs.capacity());
+        let (mut delta, mut vega) = (0.0, 0.0);
+        let (mut rates, mut annuity) = (0.0, 0.0);
+
+        // Collars are short their floorlets.
+        let floor_sign = match cap_floor.kind() {
+            CapFloorType::Collar => -1.0,
+            _ => 1.0,
+        };
+
+        for (i, coupon) in cap_floor.coupons().iter().enumerate() {
+            forward_rates.push(coupon.index_fixing()?);
+
+            if coupon.payment_date() <= today {
+                optionlet_npvs.push(0.0);
+                continue;
+            }
+
+            let bps = coupon.nominal()
+                * coupon.accrual_period()?
+                * curve.discount(coupon.payment_date())?;
+            rates += coupon.rate()? * bps;
+            annuity += bps;
+
+            let mut optionlets = Vec::with_capacity(2);
+
+            if let Some(rate) = cap_floor.cap_rates().get(i) {
+                optionlets.push((
+                    1.0,
+                    optionlet(coupon, OptionType::Call, *rate, surface, curve)?,
+                ));
+            }
+            if let Some(rate) = cap_floor.floor_rates().get(i) {
+                let floorlet = optionlet(coupon, OptionType::Put, *rate, surface, curve)?;
+                optionlets.push((floor_sign, floorlet));
+            }
+
+            let value = optionlets.iter().map(|(sign, o)| sign * o.value).sum();
+            delta += optionlets
+                .iter()
+                .map(|(sign, o)| sign * o.delta)
+                .sum::<f64>();
+            vega += optionlets
+                .iter()
+                .map(|(sign, o)| sign * o.vega)
+                .sum::<f64>();
+
+            optionlet_npvs.push(value);
+        }
+
+        Ok(CapFloorResults {
+            npv: optionlet_npvs.iter().sum(),
+            optionlet_npvs,
+            forward_rates,
+            atm_rate: rates / annuity,
+            delta,
+            vega,
+        })
+    }
+
+    /// The flat volatility, of the type of the engine's surface, at which every optionlet of
+    /// `cap_floor` must be valued for it to be worth `npv`.
+    ///
+    /// When a single optionlet has yet to fix, as for a caplet or floorlet, its price is inverted
+    /// directly. Otherwise the volatility is solved for with a safeguarded Newton solver.
+    ///
+    /// # Returns
+    ///
+    /// The volatility, or an error if an index rate cannot be forecast or fixed, or no
+    /// volatility reprices `npv`. With several optionlets left to fix, the volatility is only
+    /// searched for up to 500% for shifted lognormal or 10,000 basis points for normal
+    /// volatilities.
+    pub fn implied_volatility(&self, cap_floor: &CapFloor, npv: f64) -> Result<f64, LibraError> {
+        let volatility_type = self.volatility.volatility_type();
+        let reference_date = self.volatility.reference_date();
+        let curve = &*self.discount_curve;
+
+        let flat = |volatility: f64| {
+            OptionletVolatilitySurface::constant(reference_date, volatility, volatility_type)
+        };
+        let engine = |volatility: f64| {
+            CapFloorEngine::new(self.discount_curve.clone(), Rc::new(flat(volatility)))
+        };
+
+        // Collars are short their floorlets.
+        let floor_sign = match cap_floor.kind() {
+            CapFloorType::Collar => -1.0,
+            _ => 1.0,
+        };
+
+        // The optionlets whose value depends on the volatility.
+        let surface = flat(0.0);
+        let mut live = Vec::new();
+
+        for (i, coupon) in cap_floor.coupons().iter().enumerate() {
+            if coupon.payment_date() <= curve.reference_date() {
+                continue;
+            }
+
+            let strikes = [
+                (1.0, OptionType::Call, cap_floor.cap_rates().get(i)),
+                (floor_sign, OptionType::Put, cap_floor.floor_rates().get(i)),
+            ];
+
+            for (sign, option_type, strike) in strikes {
+                if let Some(strike) = strike {
+                    let optionlet = Optionlet::new(coupon, option_type, *strike, &surface, curve)?;
+                    if optionlet.expiry.is_some() {
+                        live.push((sign, optionlet));
+                    }
+                }
+            }
+        }
+
+        if let [(sign, optionlet)] = live.as_slice() {
+            let fixed = engine(0.0).calculate(cap_floor)?.npv
+                - sign * optionlet.value(0.0, volatility_type).value;
+
+            return optionlet.implied_volatility(sign * (npv - fixed), volatility_type);
+        }
+
+        let value = |volatility: f64| {
+            engine(volatility)
+                .calculate(cap_floor)
+                .map_or(f64::NAN, |r| r.npv - npv)
+        };
+        let vega = |volatility: f64| {
+            engine(volatility)
+                .calculate(cap_floor)
+                .map_or(f64::NAN, |r| r.vega)
+        };
+
+        NewtonSafe::new().solve_bracketed(
+            &value,
+            &vega,
+            VOLATILITY_ACCURACY,
+            0.0,
+            maximum_volatility(volatility_type),
+        )
+    }
+}
+
+/// The value today of an optionlet on the rate of `coupon`, and its sensitivities to the index
+/// rate and volatility.
+///
+/// # Arguments
+///
+/// * `coupon` - The coupon whose rate the optionlet is on.
+/// * `option_type` - A call for caplets or a put for floorlets.
+/// * `strike` - The cap or floor rate on the coupon's rate.
+/// * `surface` - The volatility surface, by expiry and strike on the index rate.
+/// * `curve` - The discount curve.
+fn optionlet(
+    coupon: &FloatingRateCoupon,
+    option_type: OptionType,
+    strike: f64,
+    surface: &OptionletVolatilitySurface,
+    curve: &dyn YieldTermStructure,
+) -> Result<ForwardOptionResults, LibraError> {
+    let optionlet = Optionlet::new(coupon, option_type, strike, surface, curve)?;
+    let sigma = match optionlet.expiry {
+        Some((expiry, _)) => surface.volatility(expiry, optionlet.strike)?,
+        None => 0.0,
+    };
+
+    Ok(optionlet.value(sigma, surface.volatility_type()))
+}
+
+/// An optionlet on the rate of a coupon, as an option on the index rate.
+struct Optionlet {
+    option_type: OptionType,
+    forward: f64,
+    strike: f64,
+    /// The fixing date and its time from the reference date of the surface, or `None` if the
+    /// optionlet has already fixed.
+    expiry: Option<(NaiveDate, f64)>,
+    /// The discounted amount paid per unit of the option on the index rate.
+    scale: f64,
+}
+
+impl Optionlet {
+    fn new(
+        coupon: &FloatingRateCoupon,
+        option_type: OptionType,
+        strike: f64,
+        surface: &OptionletVolatilitySurface,
+        curve: &dyn YieldTermStructure,
+    ) -> Result<Self, LibraError> {
+        let forward = coupon.index_fixing()?;
+        let gearing = coupon.gearing();
+
+        let expiry = coupon.fixing_date();
+        let expiry = match expiry > surface.reference_date() {
+            true => Some((expiry, surface.time_from_reference(expiry)?)),
+            false => None,
+        };
+
+        let bps =
+            coupon.nominal() * coupon.accrual_period()? * curve.discount(coupon.payment_date())?;
+
+        Ok(Optionlet {
+            option_type,
+            forward,
+            // The optionlet on the coupon rate is one on the index rate at this strike.
+            strike: (strike - coupon.spread()) / gearing,
+            expiry,
+            scale: gearing * bps,
+        })
+    }
+
+    /// The value today at volatility `sigma`, which is ignored once the optionlet has fixed.
+    fn value(&self, sigma: f64, volatility_type: VolatilityType) -> ForwardOptionResults {
+        let (time, sigma) = self.expiry.map_or((0.0, 0.0), |(_, time)| (time, sigma));
+
+        let value = match volatility_type {
+            VolatilityType::ShiftedLognormal { shift } => black_formula(
+                self.option_type,
+                self.forward + shift,
+                self.strike + shift,
+                sigma,
+                time,
+            ),
+            VolatilityType::Normal => {
+                bachelier_formula(self.option_type, self.forward, self.strike, sigma, time)
+            }
+        };
+
+        value.scaled(self.scale)
+    }
+
+    /// The volatility at which the optionlet is worth `value` today, or an error if it has
+    /// fixed or `value` is outside the range of values of the option.
+    fn implied_volatility(
+        &self,
+        value: f64,
+        volatility_type: VolatilityType,
+    ) -> Result<f64, LibraError> {
+        let (_, time) = self.expiry.ok_or(InvalidDate)?;
+        let price = value / self.scale;
+
+        match volatility_type {
+            VolatilityType::ShiftedLognormal { shift } => implied_black_volatility(
+                self.option_type,
+                price,
+                self.forward + shift,
+                self.strike + shift,
+                time,
+            ),
+            VolatilityType::Normal => implied_bachelier_volatility(
+                self.option_type,
+                price,
+                self.forward,
+                self.strike,
+                time,
+            ),
+        }
+    }
+}
+
+/// The upper end of the range searched for volatilities of `volatility_type`.
+fn maximum_volatility(volatility_type: VolatilityType) -> f64 {
+    match volatility_type {
+        VolatilityType::ShiftedLognormal { .. } => 5.0,
+        VolatilityType::Normal => 1.0,
+    }
+}
+
+/// Strips the volatilities of caplets from quoted flat volatilities of caps.
+///
+/// Each quoted cap starts on a common date, with caplets on the periods of the index's tenor
+/// after the first, whose rate is usually fixed already. The caplets each cap adds to the one
+/// before it are taken to share a single volatility, which is solved so that the stripped
+/// caplets reprice each cap at its flat volatility.
+#[derive(Clone)]
+pub struct OptionletStripper {
+    index: IborIndex,
+    discount_curve: Rc<dyn YieldTermStructure>,
+    start: NaiveDate,
+    maturities: Vec<NaiveDate>,
+    strikes: Vec<f64>,
+    flat_volatilities: Vec<Vec<f64>>,
+    volatility_type: VolatilityType,
+}
+
+impl OptionletStripper {
+    /// Creates a stripper for a grid of flat cap volatilities.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index capped, whose tenor is the length of each caplet.
+    /// * `discount_curve` - The curve discounting to its reference date, which is today.
+    /// * `start` - The start date of every cap.
+    /// * `maturities` - The strictly increasing maturity dates of the caps.
+    /// * `strikes` - The cap rates quoted.
+    /// * `flat_volatilities` - The flat volatilities, with a row for each maturity holding the
+    ///   volatility at each strike.
+    /// * `volatility_type` - The model the volatilities are quoted for.
+    pub fn new(
+        index: IborIndex,
+        discount_curve: Rc<dyn YieldTermStructure>,
+        start: NaiveDate,
+        maturities: Vec<NaiveDate>,
+        strikes: Vec<f64>,
+        flat_volatilities: Vec<Vec<f64>>,
+        volatility_type: VolatilityType,
+    ) -> Self {
+        OptionletStripper {
+            index,
+            discount_curve,
+            start,
+            maturities,
+            strikes,
+            flat_volatilities,
+            volatility_type,
+        }
+    }
+
+    pub fn maturities(&self) -> &[NaiveDate] {
+        &self.maturities
+    }
+
+    pub fn strikes(&self) -> &[f64] {
+        &self.strikes
+    }
+
+    pub fn flat_volatilities(&self) -> &[Vec<f64>] {
+        &self.flat_volatilities
+    }
+
+    /// The caplets of the longest cap, on a nominal of one.
+    pub fn caplets(&self) -> Result<Leg, LibraError> {
+        let last = self.maturities.last().copied().unwrap_or(self.start);
+        let schedule = Schedule::new(
+            self.start,
+            last,
+            self.index.tenor(),
+            self.index.calendar(),
+            DateAdjustment::ModifiedFollowing,
+            DateGeneration::Forward,
+        )?;
+
+        let leg = FloatingRateLeg::new(schedule, self.index.clone(), self.index.day_count())
+            .with_notional(1.0)
+            .without_redemptions()
+            .build()?;
+
+        Ok(Leg::new(leg.flows().iter().skip(1).cloned().collect()))
+    }
+
+    /// Strips the caplet volatilities.
+    ///
+    /// # Returns
+    ///
+    /// A surface with the volatility of each caplet by its fixing date and each quoted strike,
+    /// or an error if the quotes do not match the maturities and strikes, a cap has no caplets
+    /// beyond the previous one, or no caplet volatility reprices a cap.
+    pub fn strip(&self) -> Result<OptionletVolatilitySurface, LibraError> {
+        if let Some(row) = self
+            .flat_volatilities
+            .iter()
+            .find(|row| row.len() != self.strikes.len())
+        {
+            let n = self.strikes.len() as i64;
+            return Err(
+                ArgumentRange::new("number of volatilities", n, n, row.len() as i64).into(),
+            );
+        }
+        if self.flat_volatilities.len() != self.maturities.len() {
+            let n = self.maturities.len() as i64;
+            return Err(ArgumentRange::new(
+                "number of maturities",
+                n,
+                n,
+                self.flat_volatilities.len() as i64,
+            )
+            .into());
+        }
+
+        let curve = &*self.discount_curve;
+        let today = curve.reference_date();
+        let caplets: Vec<FloatingRateCoupon> = self
+            .caplets()?
+            .flows()
+            .iter()
+            .filter_map(|flow| match flow {
+                LegFlow::FloatingCoupon(coupon) => Some(coupon.clone()),
+                _ => None,
+            })
+            .collect();
+
+        // The number of caplets in each cap, which are those ending by its maturity.
+        let counts: Vec<usize> = self
+            .maturities
+            .iter()
+            .map(|m| {
+                let adjusted = self
+                    .index
+                    .calendar()
+                    .adjust(*m, DateAdjustment::ModifiedFollowing);
+                caplets
+                    .iter()
+                    .filter(|c| c.accrual_end() <= adjusted)
+                    .count()
+            })
+            .collect();
+
+        let expiries: Vec<NaiveDate> = caplets.iter().map(|c| c.fixing_date()).collect();
+        let flat = |volatility: f64| {
+            OptionletVolatilitySurface::constant(today, volatility, self.volatility_type)
+        };
+        let mut volatilities = vec![vec![0.0; self.strikes.len()]; caplets.len()];
+
+        for (j, strike) in self.strikes.iter().enumerate() {
+            let mut stripped = 0;
+            let mut stripped_value = 0.0;
+
+            for (m, count) in counts.iter().enumerate() {
+                if *count <= stripped {
+                    return Err(ArgumentRange::new(
+                        "number of caplets",
+                        stripped as i64 + 1,
+                        i64::MAX,
+                        *count as i64,
+                    )
+                    .into());
+                }
+
+                let surface = flat(self.flat_volatilities[m][j]);
+                let cap_value = caplets[..*count]
+                    .iter()
+                    .map(|c| caplet_value(c, *strike, &surface, curve))
+                    .sum::<Result<f64, LibraError>>()?;
+
+                let added = &caplets[stripped..*count];
+                let target = cap_value - stripped_value;
+                let error = |volatility: f64| {
+                    let surface = flat(volatility);
+                    added
+                        .iter()
+                        .map(|c| caplet_value(c, *strike, &surface, curve))
+                        .sum::<Result<f64, LibraError>>()
+                        .map_or(f64::NAN, |v| v - target)
+                };
+
+                let volatility = Brent::new().solve_bracketed(
+                    &error,
+                    VOLATILITY_ACCURACY,
+                    0.0,
+                    maximum_volatility(self.volatility_type),
+                )?;
+
+                for row in volatilities[stripped..*count].iter_mut() {
+                    row[j] = volatility;
+                }
+
+                stripped = *count;
+                stripped_value = cap_value;
+            }
+        }
+
+        // Caplets beyond the last cap are not covered by the quotes.
+        let covered = counts.last().copied().unwrap_or(0);
+
+        OptionletVolatilitySurface::new(
+            today,
+            expiries[..covered].to_vec(),
+            self.strikes.clone(),
+            volatilities[..covered].to_vec(),
+            self.volatility_type,
+        )
+    }
+}
+
+/// The value today of a caplet on `coupon` at `strike`, valued off a constant surface.
+fn caplet_value(
+    coupon: &FloatingRateCoupon,
+    strike: f64,
+    surface: &OptionletVolatilitySurface,
+    curve: &dyn YieldTermStructure,
+) -> Result<f64, LibraError> {
+    if coupon.payment_date() <= curve.reference_date() {
+        return Ok(0.0);
+    }
+
+    Ok(optionlet(coupon, OptionType::Call, strike, surface, curve)?.value)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use chrono::{Months, NaiveDate};
+
+    use crate::{
+        cashflows::{Compounding, FloatingRateLeg, InterestRate},
+        indexes::IborIndex,
+        instruments::CapFloor,
+        pricing::{FlatForward, OptionletVolatilitySurface, VolatilityType, YieldTermStructure},
+        time::{Calendar, DateAdjustment, DateGeneration, DayCountConvention, Frequency, Schedule},
+    };
+
+    use super::CapFloorEngine;
+
+    fn reference_date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 1, 2).unwrap()
+    }
+
+    fn cap_floors(periods: u32) -> Vec<CapFloor> {
+        let curve: Rc<dyn YieldTermStructure> = Rc::new(FlatForward::new(
+            reference_date(),
+            InterestRate::new(
+                0.04,
+                DayCountConvention::Actual365Fixed,
+                Compounding::Continuous,
+                Frequency::Annual,
+            ),
+        ));
+        let index = IborIndex::new(
+            "EURIBOR",
+            Months::new(3),
+            2,
+            Calendar::UnitedKingdom,
+            DayCountConvention::Actual360,
+            curve,
+        );
+        let start = reference_date() + Months::new(1);
+        let schedule = Schedule::new(
+            start,
+            start + Months::new(3 * periods),
+            Months::new(3),
+            Calendar::UnitedKingdom,
+            DateAdjustment::ModifiedFollowing,
+            DateGeneration::Forward,
+        )
+        .unwrap();
+        let leg = FloatingRateLeg::new(schedule, index, DayCountConvention::Actual360)
+            .with_notional(1.0e6)
+            .without_redemptions()
+            .build()
+            .unwrap();
+
+        vec![
+            CapFloor::cap(&leg, vec![0.045]).unwrap(),
+            CapFloor::floor(&leg, vec![0.035]).unwrap(),
+            CapFloor::collar(&leg, vec![0.05], vec![0.03]).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn implied_volatilities_reprice_caps_and_floors() {
+        let cases = [
+            (VolatilityType::ShiftedLognormal { shift: 0.0 }, 0.3),
+            (VolatilityType::ShiftedLognormal { shift: 0.01 }, 0.2),
+            (VolatilityType::Normal, 0.01),
+        ];
+        let discount_curve: Rc<dyn YieldTermStructure> = Rc::new(FlatForward::new(
+            reference_date(),
+            InterestRate::new(
+                0.035,
+                DayCountConvention::Actual365Fixed,
+                Compounding::Continuous,
+                Frequency::Annual,
+            ),
+        ));
+
+        for (volatility_type, volatility) in cases {
+            let surface =
+                OptionletVolatilitySurface::constant(reference_date(), volatility, volatility_type);
+            let engine = CapFloorEngine::new(discount_curve.clone(), Rc::new(surface));
+
+            // A single caplet or floorlet is inverted directly, and longer caps by a solver.
+            for periods in [1, 8] {
+                for cap_floor in cap_floors(periods) {
+                    let npv = engine.calculate(&cap_floor).unwrap().npv;
+                    let implied = engine.implied_volatility(&cap_floor, npv).unwrap();
+
+                    assert!(
+                        (implied - volatility).abs() < 1.0e-8,
+                        "{} with {} periods: {} against {}",
+                        volatility_type,
+                        periods,
+                        implied,
+                        volatility
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use std::rc::Rc;
+
+use crate::{
+    error::{DifferentVariant, InvalidDate, LibraError},
+    instruments::{CliquetOption, Exercise},
+};
+
+use super::{
+    black_formula, bumped_results, european_expiry,
+    montecarlo::{MonteCarloSettings, Path, Statistics, TimeGrid},
+    GeneralizedBlackScholesProcess, OptionResults,
+};
+
+/// Prices cliquet options without global floors or caps, with European exercise, under the
+/// generalized Black-Scholes process.
+///
+/// The returns over each period are independent and lognormal, so each coupon is the local floor
+/// plus an option struck at the floor less one struck at any cap, on the growth of the forward
+/// over the period, priced with the Black formula. Delta and gamma are found by bumping the spot,
+/// and theta is implied by the Black-Scholes equation.
+#[derive(Clone, Debug)]
+pub struct AnalyticCliquetEngine {
+    process: GeneralizedBlackScholesProcess,
+}
+
+impl AnalyticCliquetEngine {
+    pub fn new(process: GeneralizedBlackScholesProcess) -> Self {
+        AnalyticCliquetEngine { process }
+    }
+
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    /// Prices `option`.
+    ///
+    /// # Returns
+    ///
+    /// The value, delta, gamma and theta, or an error if the option has a global floor or cap,
+    /// is not European, has expired, or has a reset before the reference date or after expiry.
+    pub fn calculate(&self, option: &CliquetOption) -> Result<OptionResults, LibraError> {
+        if option.global_floor().is_some() || option.global_cap().is_some() {
+            return Err(DifferentVariant.into());
+        }
+
+        let process = &self.process;
+        let periods = period_times(process, option)?;
+        let time = periods[periods.len() - 1];
+
+        bumped_results(process, time, &|process| {
+            Ok(cliquet_value(process, option, &periods))
+        })
+    }
+}
+
+/// Prices cliquet options with European exercise under the generalized Black-Scholes process by
+/// Monte Carlo simulation.
+///
+/// The reset dates are placed on the grid of each path, so that the coupons are found from the
+/// simulated prices on them.
+#[derive(Clone, Debug)]
+pub struct McCliquetEngine {
+    process: GeneralizedBlackScholesProcess,
+    settings: MonteCarloSettings,
+}
+
+impl McCliquetEngine {
+    pub fn new(process: GeneralizedBlackScholesProcess, settings: MonteCarloSettings) -> Self {
+        McCliquetEngine { process, settings }
+    }
+
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    pub fn settings(&self) -> &MonteCarloSettings {
+        &self.settings
+    }
+
+    /// Prices `option`.
+    ///
+    /// # Returns
+    ///
+    /// The statistics of the discounted payoff, whose mean is the value, or an error if the
+    /// option is not European, has expired, has a reset before the reference date or after
+    /// expiry, or the paths cannot be simulated.
+    pub fn calculate(&self, option: &CliquetOption) -> Result<Statistics, LibraError> {
+        let process = &self.process;
+        let periods = period_times(process, option)?;
+        let time = periods[periods.len() - 1];
+
+        let grid = TimeGrid::with_mandatory_times(&periods, self.settings.steps())?;
+        let indices: Vec<usize> = periods.iter().map(|t| grid.closest_index(*t)).collect();
+        let discount = process.risk_free_curve().discount_time(time);
+
+        let pricer = |path: &Path| {
+            let total = indices
+                .windows(2)
+                .map(|pair| option.coupon(path.value(pair[1]) / path.value(pair[0])))
+                .sum();
+
+            option.value(total) * discount
+        };
+
+        let mut simulation = self.settings.simulation(Rc::new(process.clone()), grid)?;
+        Ok(simulation.run(&pricer, self.settings.samples()))
+    }
+}
+
+/// The times of the resets of `option` followed by its expiry.
+///
+/// # Returns
+///
+/// The times, or an error if the option is not European, has expired, or has a reset before the
+/// reference date or after expiry.
+fn period_times(
+    process: &GeneralizedBlackScholesProcess,
+    option: &CliquetOption,
+) -> Result<Vec<f64>, LibraError> {
+    let time = european_expiry(process, option.exercise())?;
+    if let Exercise::European(expiry) = option.exercise() {
+        if option.reset_dates().iter().any(|date| date > expiry) {
+            return Err(InvalidDate.into());
+        }
+    }
+
+    let mut times = option
+        .reset_dates()
+        .iter()
+        .map(|date| process.time(*date))
+        .collect::<Result<Vec<f64>, LibraError>>()?;
+    times.push(time);
+
+    Ok(times)
+}
+
+/// The value of `option` with periods between consecutive `periods`.
+fn cliquet_value(
+    process: &GeneralizedBlackScholesProcess,
+    option: &CliquetOption,
+    periods: &[f64],
+) -> f64 {
+    let option_type = option.option_type();
+    let omega = option_type.sign();
+    let moneyness = option.moneyness();
+    let floor = option.local_floor();
+    let sigma = process.volatility();
+
+    let coupons: f64 = periods
+        .windows(2)
+        .map(|pair| {
+            let growth = process.forward(pair[1]) / process.forward(pair[0]);
+            let length = pair[1] - pair[0];
+
+            // The expected excess of the coupon before flooring and capping over `level`.
+            let excess = |level: f64| {
+                black_formula(
+                    option_type,
+                    growth,
+                    moneyness + omega * level,
+                    sigma,
+                    length,
+                )
+                .value
+            };
+
+            floor + excess(floor) - option.local_cap().map_or(0.0, excess)
+        })
+        .sum();
+
+    process
+        .risk_free_curve()
+        .discount_time(periods[periods.len() - 1])
+        * coupons
+}
@@ -0,0 +1,208 @@
+use std::rc::Rc;
+
+use chrono::NaiveDate;
+
+use crate::{
+    error::LibraError,
+    instruments::{CrossCurrencySwap, NotionalReset},
+    pricing::YieldTermStructure,
+    time::DayCountConvention,
+};
+
+use super::{leg_bps, leg_npv, ONE_BASIS_POINT};
+
+/// The value and spread sensitivities of a cross-currency swap, in the domestic currency and
+/// signed from the holder's side.
+#[derive(Clone, Debug)]
+pub struct CrossCurrencySwapResults {
+    pub npv: f64,
+    /// The value of the domestic leg, including its notional exchanges.
+    pub domestic_leg_npv: f64,
+    /// The value of the foreign leg, including its notional exchanges, converted at spot.
+    pub foreign_leg_npv: f64,
+    /// The change in value of the domestic leg for a one basis point rise in its spread.
+    pub domestic_leg_bps: f64,
+    /// The change in value of the foreign leg for a one basis point rise in its spread.
+    pub foreign_leg_bps: f64,
+    /// The spread over the index of the domestic leg at which the swap has no value.
+    pub fair_domestic_spread: f64,
+    /// The spread over the index of the foreign leg at which the swap has no value, which is
+    /// the cross-currency basis.
+    pub fair_foreign_spread: f64,
+    /// The notional of the domestic leg over each period, fixed or forecast.
+    pub domestic_notionals: Vec<f64>,
+}
+
+/// Prices cross-currency swaps by discounting the flows of each leg off the discount curve of
+/// its currency, with the foreign curve adjusted by a cross-currency basis curve.
+#[derive(Clone)]
+pub struct CrossCurrencySwapEngine {
+    domestic_curve: Rc<dyn YieldTermStructure>,
+    foreign_curve: Rc<dyn YieldTermStructure>,
+    basis_curve: Option<Rc<dyn YieldTermStructure>>,
+    spot: f64,
+}
+
+impl CrossCurrencySwapEngine {
+    /// Creates an engine with no cross-currency basis.
+    ///
+    /// # Arguments
+    ///
+    /// * `domestic_curve` - The discount curve of the domestic currency, to whose reference
+    ///   date flows are discounted.
+    /// * `foreign_curve` - The discount curve of the foreign currency, with the same reference
+    ///   date.
+    /// * `spot` - The exchange rate on the reference date, in units of the domestic currency per
+    ///   unit of the foreign currency.
+    pub fn new(
+        domestic_curve: Rc<dyn YieldTermStructure>,
+        foreign_curve: Rc<dyn YieldTermStructure>,
+        spot: f64,
+    ) -> Self {
+        CrossCurrencySwapEngine {
+            domestic_curve,
+            foreign_curve,
+            basis_curve: None,
+            spot,
+        }
+    }
+
+    /// Multiplies the discount factors of the foreign curve by those of `basis_curve`, such as
+    /// a `ZeroSpreadedTermStructure` over a flat zero curve, to account for the cross-currency
+    /// basis.
+    pub fn with_basis_curve(mut self, basis_curve: Rc<dyn YieldTermStructure>) -> Self {
+        self.basis_curve = Some(basis_curve);
+        self
+    }
+
+    pub fn spot(&self) -> f64 {
+        self.spot
+    }
+
+    /// Values `swap` and its sensitivities.
+    ///
+    /// Only flows paid after the reference date of the domestic curve are included. For
+    /// mark-to-market swaps, the domestic notional of each period after the first is the
+    /// foreign notional at the exchange rate fixed on the start of the period, or forecast by
+    /// covered interest parity if it is not yet fixed.
+    ///
+    /// # Returns
+    ///
+    /// The results, or an error if a coupon or exchange rate fixing before the reference date
+    /// has not been recorded.
+    pub fn calculate(
+        &self,
+        swap: &CrossCurrencySwap,
+    ) -> Result<CrossCurrencySwapResults, LibraError> {
+        let domestic = &*self.domestic_curve;
+        let foreign = BasisAdjustedCurve {
+            curve: self.foreign_curve.clone(),
+            basis: self.basis_curve.clone(),
+        };
+        let today = domestic.reference_date();
+        let schedule = swap.schedule();
+        let (start, end) = (schedule.start_date(), schedule.end_date());
+
+        let domestic_notionals = match swap.notional_reset() {
+            NotionalReset::Constant => vec![swap.domestic_nominal()],
+            NotionalReset::MarkToMarket => schedule
+                .periods()
+                .enumerate()
+                .map(|(i, (reset, _))| match i {
+                    0 => Ok(swap.domestic_nominal()),
+                    _ => Ok(swap.foreign_nominal()
+                        * swap.fx_index().rate(reset, self.spot, domestic, &foreign)?),
+                })
+                .collect::<Result<_, LibraError>>()?,
+        };
+
+        // The value of an amount paid on `date` if it is after today.
+        let pv = |curve: &dyn YieldTermStructure, amount: f64, date: NaiveDate| {
+            if date > today {
+                curve.discount(date).map(|d| amount * d)
+            } else {
+                Ok(0.0)
+            }
+        };
+
+        let foreign_leg = swap.foreign_index().leg(
+            schedule,
+            vec![swap.foreign_nominal()],
+            swap.foreign_spread(),
+            0,
+        )?;
+        let mut foreign_npv = leg_npv(&foreign_leg, &foreign)?;
+        if swap.initial_exchange() {
+            foreign_npv -= pv(&foreign, swap.foreign_nominal(), start)?;
+        }
+        if swap.final_exchange() {
+            foreign_npv += pv(&foreign, swap.foreign_nominal(), end)?;
+        }
+
+        let domestic_leg = swap.domestic_index().leg(
+            schedule,
+            domestic_notionals.clone(),
+            swap.domestic_spread(),
+            0,
+        )?;
+        let mut domestic_npv = -leg_npv(&domestic_leg, domestic)?;
+        if swap.initial_exchange() {
+            domestic_npv += pv(domestic, domestic_notionals[0], start)?;
+        }
+        for (i, (reset, _)) in schedule.periods().enumerate().skip(1) {
+            if let Some(notional) = domestic_notionals.get(i) {
+                domestic_npv += pv(domestic, notional - domestic_notionals[i - 1], reset)?;
+            }
+        }
+        if swap.final_exchange() {
+            let last = domestic_notionals[domestic_notionals.len() - 1];
+            domestic_npv -= pv(domestic, last, end)?;
+        }
+
+        let foreign_leg_npv = self.spot * foreign_npv;
+        let domestic_leg_npv = domestic_npv;
+        let foreign_leg_bps = self.spot * leg_bps(&foreign_leg, &foreign)?;
+        let domestic_leg_bps = -leg_bps(&domestic_leg, domestic)?;
+        let npv = foreign_leg_npv + domestic_leg_npv;
+
+        Ok(CrossCurrencySwapResults {
+            npv,
+            domestic_leg_npv,
+            foreign_leg_npv,
+            domestic_leg_bps,
+            foreign_leg_bps,
+            fair_domestic_spread: swap.domestic_spread() - npv / domestic_leg_bps * ONE_BASIS_POINT,
+            fair_foreign_spread: swap.foreign_spread() - npv / foreign_leg_bps * ONE_BASIS_POINT,
+            domestic_notionals,
+        })
+    }
+}
+
+/// A foreign discount curve whose discount factors are multiplied by those of a basis curve.
+struct BasisAdjustedCurve {
+    curve: Rc<dyn YieldTermStructure>,
+    basis: Option<Rc<dyn YieldTermStructure>>,
+}
+
+impl YieldTermStructure for BasisAdjustedCurve {
+    fn reference_date(&self) -> NaiveDate {
+        self.curve.reference_date()
+    }
+
+    fn day_count(&self) -> DayCountConvention {
+        self.curve.day_count()
+    }
+
+    fn discount_time(&self, time: f64) -> f64 {
+        self.curve.discount_time(time) * self.basis.as_ref().map_or(1.0, |b| b.discount_time(time))
+    }
+
+    fn discount(&self, date: NaiveDate) -> Result<f64, LibraError> {
+        let basis = match &self.basis {
+            Some(basis) => basis.discount(date)?,
+            None => 1.0,
+        };
+
+        Ok(self.curve.discount(date)? * basis)
+    }
+}
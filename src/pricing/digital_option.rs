@@ -0,0 +1,143 @@
+use crate::{
+    error::{DifferentVariant, InvalidDate, LibraError, NotPositive},
+    instruments::{Exercise, Payoff, VanillaOption},
+    math::distributions::normal_cdf,
+};
+
+use super::{bumped_results, GeneralizedBlackScholesProcess, OptionResults};
+
+/// Prices American digital options, or one-touch options, under the generalized Black-Scholes
+/// process, using the closed forms of Reiner and Rubinstein (1991).
+///
+/// A call pays as soon as the underlying rises to the strike and a put as soon as it falls to it:
+/// cash-or-nothing options pay their cash and asset-or-nothing options the underlying, which is
+/// then at the strike. If the payment is instead deferred to expiry, asset-or-nothing options pay
+/// the underlying at expiry. The option must be exercisable from the reference date, and the zero
+/// rates and dividend yield to expiry are used. Delta and gamma are found by bumping the spot,
+/// and theta is implied by the Black-Scholes equation.
+#[derive(Clone, Debug)]
+pub struct AnalyticDigitalAmericanEngine {
+    process: GeneralizedBlackScholesProcess,
+    payment_at_expiry: bool,
+}
+
+impl AnalyticDigitalAmericanEngine {
+    /// Creates an engine for options which pay when the strike is reached.
+    pub fn new(process: GeneralizedBlackScholesProcess) -> Self {
+        AnalyticDigitalAmericanEngine {
+            process,
+            payment_at_expiry: false,
+        }
+    }
+
+    /// Defers the payment of options to their expiry.
+    pub fn with_payment_at_expiry(mut self) -> Self {
+        self.payment_at_expiry = true;
+        self
+    }
+
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    pub fn payment_at_expiry(&self) -> bool {
+        self.payment_at_expiry
+    }
+
+    /// Prices `option`.
+    ///
+    /// # Returns
+    ///
+    /// The value, delta, gamma and theta, or an error if the option is not a cash-or-nothing or
+    /// asset-or-nothing option with American exercise, is not exercisable from the reference
+    /// date, has expired, or the volatility is not positive.
+    pub fn calculate(&self, option: &VanillaOption) -> Result<OptionResults, LibraError> {
+        let process = &self.process;
+        let payoff = option.payoff();
+        if !matches!(
+            payoff,
+            Payoff::CashOrNothing { .. } | Payoff::AssetOrNothing { .. }
+        ) {
+            return Err(DifferentVariant.into());
+        }
+
+        let Exercise::American { earliest, latest } = option.exercise() else {
+            return Err(DifferentVariant.into());
+        };
+        if *earliest > process.reference_date() {
+            return Err(InvalidDate.into());
+        }
+        let time = process.time(*latest)?;
+
+        let sigma = process.volatility();
+        if sigma <= 0.0 {
+            return Err(NotPositive::new("volatility", sigma).into());
+        }
+
+        bumped_results(process, time, &|process| {
+            Ok(self.touch_value(process, payoff, time))
+        })
+    }
+
+    /// The value of a one-touch option paying `payoff` with `time` years to expiry.
+    fn touch_value(
+        &self,
+        process: &GeneralizedBlackScholesProcess,
+        payoff: &Payoff,
+        time: f64,
+    ) -> f64 {
+        let spot = process.spot();
+        let barrier = payoff.strike();
+        let discount = process.risk_free_curve().discount_time(time);
+        let dividend_discount = process.dividend_curve().discount_time(time);
+
+        // The amount paid at the barrier, which is the underlying at expiry if an asset is paid
+        // then.
+        let cash = match payoff {
+            Payoff::CashOrNothing { cash, .. } => *cash,
+            _ => barrier,
+        };
+        let asset_at_expiry =
+            self.payment_at_expiry && matches!(payoff, Payoff::AssetOrNothing { .. });
+
+        // A call touches from below and a put from above.
+        let eta = -payoff.option_type().sign();
+        if eta * (spot - barrier) <= 0.0 {
+            return match (self.payment_at_expiry, asset_at_expiry) {
+                (false, _) => cash,
+                (true, false) => cash * discount,
+                (true, true) => spot * dividend_discount,
+            };
+        }
+        if time <= 0.0 {
+            return 0.0;
+        }
+
+        let sigma = process.volatility();
+        let std_dev = sigma * time.sqrt();
+        let r = process.risk_free_rate(time);
+        let carry = r - process.dividend_yield(time);
+        let mu = (carry - 0.5 * sigma * sigma) / (sigma * sigma);
+        let ratio = barrier / spot;
+
+        let x2 = (spot / barrier).ln() / std_dev + (1.0 + mu) * std_dev;
+        let y2 = (barrier / spot).ln() / std_dev + (1.0 + mu) * std_dev;
+
+        if asset_at_expiry {
+            return spot
+                * dividend_discount
+                * (normal_cdf(-eta * x2) + ratio.powf(2.0 * (mu + 1.0)) * normal_cdf(eta * y2));
+        }
+        if self.payment_at_expiry {
+            return cash
+                * discount
+                * (normal_cdf(-eta * (x2 - std_dev))
+                    + ratio.powf(2.0 * mu) * normal_cdf(eta * (y2 - std_dev)));
+        }
+
+        let lambda = (mu * mu + 2.0 * r / (sigma * sigma)).sqrt();
+        let z = (barrier / spot).ln() / std_dev + lambda * std_dev;
+        cash * (ratio.powf(mu + lambda) * normal_cdf(eta * z)
+            + ratio.powf(mu - lambda) * normal_cdf(eta * (z - 2.0 * lambda * std_dev)))
+    }
+}
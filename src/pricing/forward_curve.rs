@@ -1,7 +1,45 @@
 use chrono::NaiveDate;
 
-use crate::error::LibraError;
+use crate::{cashflows::Compounding, error::LibraError, time::Frequency};
 
+use super::YieldTermStructure;
+
+/// A curve of forward rates, kept so that code written against it can move to
+/// [`YieldTermStructure`].
+///
+/// Every [`YieldTermStructure`] is a forward curve, whose forward rates are continuously
+/// compounded with the day count convention of the term structure. The rate is named differently
+/// from [`YieldTermStructure::forward_rate`] so that both traits can be in scope together.
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use libra::{
+///     cashflows::{Compounding, InterestRate},
+///     pricing::*,
+///     time::{DayCountConvention, Frequency},
+/// };
+///
+/// let reference_date = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+/// let day_count = DayCountConvention::Actual365Fixed;
+/// let rate = InterestRate::new(0.05, day_count, Compounding::Continuous, Frequency::Annual);
+/// let curve = FlatForward::new(reference_date, rate);
+///
+/// let from = NaiveDate::from_ymd_opt(2025, 1, 2).unwrap();
+/// let to = NaiveDate::from_ymd_opt(2026, 1, 2).unwrap();
+/// let forward = curve
+///     .forward_rate(from, to, day_count, Compounding::Continuous, Frequency::Annual)
+///     .unwrap();
+///
+/// assert!((forward.rate() - 0.05).abs() < 1.0e-12);
+/// # #[allow(deprecated)]
+/// # {
+/// assert!((curve.forward(from, to).unwrap() - 0.05).abs() < 1.0e-12);
+/// # }
+/// ```
+#[deprecated(
+    note = "use `YieldTermStructure::forward_rate`, which also takes the day count, \
+            compounding and frequency of the rate"
+)]
 pub trait ForwardCurve {
     /// Returns the constant forward rate for the period between two dates.
     ///
@@ -13,27 +51,20 @@ pub trait ForwardCurve {
     /// # Returns
     ///
     /// The forward rate.
-    fn forward_rate(&self, from: NaiveDate, to: NaiveDate) -> Result<f64, LibraError>;
-}
-
-/// A forward yield term structure with a fixed rate.
-pub struct FlatForward {
-    rate: f64,
+    fn forward(&self, from: NaiveDate, to: NaiveDate) -> Result<f64, LibraError>;
 }
 
-impl FlatForward {
-    pub fn new(rate: f64) -> Self {
-        Self { rate }
-    }
-
-    pub fn rate(&self) -> f64 {
-        self.rate
+#[allow(deprecated)]
+impl<T: YieldTermStructure + ?Sized> ForwardCurve for T {
+    fn forward(&self, from: NaiveDate, to: NaiveDate) -> Result<f64, LibraError> {
+        Ok(self
+            .forward_rate(
+                from,
+                to,
+                self.day_count(),
+                Compounding::Continuous,
+                Frequency::Annual,
+            )?
+            .rate())
     }
 }
-
-impl ForwardCurve for FlatForward {
-    fn forward_rate(&self, _from: NaiveDate, _to: NaiveDate) -> Result<f64, LibraError> {
-        Ok(self.rate)
-    }
-}
-
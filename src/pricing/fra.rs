@@ -0,0 +1,76 @@
+use std::rc::Rc;
+
+use crate::{
+    error::LibraError,
+    indexes::IborIndex,
+    instruments::{Fra, FraSettlement},
+    pricing::{YieldTermStructure, ZeroSpreadedTermStructure},
+};
+
+use super::ONE_BASIS_POINT;
+
+impl Fra {
+    /// The rate of the index over the FRA's period, which is its fixing if one is recorded and
+    /// otherwise the simple forward rate of the index's forward curve.
+    pub fn forward_rate(&self) -> Result<f64, LibraError> {
+        self.index().rate(self.value_date(), self.maturity_date())
+    }
+
+    /// The strike at which the FRA has no value, which is the forward rate of the index.
+    pub fn par_rate(&self) -> Result<f64, LibraError> {
+        self.forward_rate()
+    }
+
+    /// The amount received on the payment date when the index fixes at its forward rate.
+    ///
+    /// For discounted settlement, this is the interest difference discounted from the maturity
+    /// date to the value date at the index rate.
+    pub fn settlement_amount(&self) -> Result<f64, LibraError> {
+        settlement_amount(self, self.index())
+    }
+
+    /// The present value of the FRA, discounting the settlement amount off `discount_curve`.
+    ///
+    /// # Arguments
+    ///
+    /// * `discount_curve` - The curve used to discount the settlement amount to its reference
+    ///   date.
+    ///
+    /// # Returns
+    ///
+    /// The present value, or an error if the settlement amount has been paid.
+    pub fn npv(&self, discount_curve: &dyn YieldTermStructure) -> Result<f64, LibraError> {
+        Ok(self.settlement_amount()? * discount_curve.discount(self.payment_date())?)
+    }
+
+    /// The fall in present value for a one basis point parallel rise in the zero rates of both
+    /// `discount_curve` and the forward curve of the index, estimated by bumping the curves up
+    /// and down.
+    pub fn dv01(&self, discount_curve: Rc<dyn YieldTermStructure>) -> Result<f64, LibraError> {
+        let bumped_npv = |shift: f64| -> Result<f64, LibraError> {
+            let forward_curve =
+                ZeroSpreadedTermStructure::new(self.index().forward_curve().clone(), shift);
+            let index = self
+                .index()
+                .clone()
+                .with_forward_curve(Rc::new(forward_curve));
+            let discount_curve = ZeroSpreadedTermStructure::new(discount_curve.clone(), shift);
+
+            Ok(settlement_amount(self, &index)? * discount_curve.discount(self.payment_date())?)
+        };
+
+        Ok((bumped_npv(-ONE_BASIS_POINT)? - bumped_npv(ONE_BASIS_POINT)?) / 2.0)
+    }
+}
+
+/// The settlement amount of `fra` when its rate is projected off `index`.
+fn settlement_amount(fra: &Fra, index: &IborIndex) -> Result<f64, LibraError> {
+    let rate = index.rate(fra.value_date(), fra.maturity_date())?;
+    let accrual = fra.accrual_fraction()?;
+    let amount = fra.position().sign() * fra.notional() * (rate - fra.strike()) * accrual;
+
+    Ok(match fra.settlement() {
+        FraSettlement::Discounted => amount / (1.0 + rate * accrual),
+        FraSettlement::InArrears => amount,
+    })
+}
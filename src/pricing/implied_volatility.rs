@@ -0,0 +1,645 @@
+use std::f64::consts::{PI, SQRT_2};
+
+use crate::{
+    error::{AboveMaximumValue, BelowIntrinsicValue, LibraError, NotPositive},
+    instruments::OptionType,
+    math::{
+        distributions::{erfcx, inverse_normal_cdf, normal_cdf, normal_pdf},
+        solvers::NewtonSafe,
+    },
+};
+
+use super::bachelier_formula;
+
+/// The accuracy to which implied volatilities are solved by the fallback solvers.
+const IMPLIED_VOLATILITY_ACCURACY: f64 = 1.0e-12;
+
+/// The tolerance, relative to the larger of the forward and strike, by which a price may be
+/// below the intrinsic value, as prices of deep in-the-money options round to just below it.
+const INTRINSIC_VALUE_TOLERANCE: f64 = 8.0 * f64::EPSILON;
+
+/// The relative change in total volatility at which the Householder iterations stop, by when the
+/// error is far below machine precision as they converge cubically.
+const HOUSEHOLDER_ACCURACY: f64 = 1.0e-11;
+
+/// The maximum number of Householder iterations before falling back to a safeguarded Newton
+/// solver.
+const MAX_HOUSEHOLDER_ITERATIONS: usize = 10;
+
+/// Half the total volatility below which the normalised Black price is found by its Taylor
+/// expansion.
+const SMALL_HALF_TOTAL_VOLATILITY: f64 = 0.21;
+
+/// The smallest control parameter of a rational cubic, just above the value of minus one at
+/// which its denominator can vanish.
+const MINIMUM_RATIONAL_CUBIC_CONTROL: f64 = -(1.0 - 1.490_116_119_384_765_6e-8);
+
+/// The control parameter above which a rational cubic is taken to be the straight line it tends
+/// to.
+const MAXIMUM_RATIONAL_CUBIC_CONTROL: f64 = 2.0 / (f64::EPSILON * f64::EPSILON);
+
+/// The Black implied volatility of an option on a forward, found with a simplification of
+/// Jäckel's "Let's Be Rational" method.
+///
+/// The price is normalised and reduced to that of an out-of-the-money call. Its total volatility
+/// is first estimated on one of four branches of the normalised Black function, split at its
+/// inflection point and where the tangent there meets zero and the maximum. On the two central
+/// branches the inverse function is approximated by a rational cubic matching its value and slope
+/// at both ends and its vanishing second derivative at the inflection point. On the lowest and
+/// highest branches a rational cubic approximates instead a map of the price which is exact for
+/// the asymptotic forms for small and large volatilities. The estimate is then refined by
+/// Householder iterations of third order on an objective transformed for the branch. A
+/// safeguarded Newton solver is used should the iterations fail to converge.
+///
+/// # Arguments
+///
+/// * `option_type` - Whether the option is a call or a put on the forward.
+/// * `price` - The undiscounted price of the option.
+/// * `forward` - The forward, which must be positive.
+/// * `strike` - The strike, which must be positive.
+/// * `time` - The time to expiry in years, which must be positive.
+///
+/// # Returns
+///
+/// The volatility, which is zero if the price is the intrinsic value, or an error if the price is
+/// below the intrinsic value or not below the forward for a call or the strike for a put.
+pub fn implied_black_volatility(
+    option_type: OptionType,
+    price: f64,
+    forward: f64,
+    strike: f64,
+    time: f64,
+) -> Result<f64, LibraError> {
+    for (name, value) in [("forward", forward), ("strike", strike), ("time", time)] {
+        if value <= 0.0 || value.is_nan() {
+            return Err(NotPositive::new(name, value).into());
+        }
+    }
+
+    let omega = option_type.sign();
+    let intrinsic_value = (omega * (forward - strike)).max(0.0);
+    let maximum_value = match option_type {
+        OptionType::Call => forward,
+        OptionType::Put => strike,
+    };
+
+    if price < intrinsic_value - INTRINSIC_VALUE_TOLERANCE * forward.max(strike) || price.is_nan() {
+        return Err(BelowIntrinsicValue::new(price, intrinsic_value).into());
+    }
+    if price >= maximum_value {
+        return Err(AboveMaximumValue::new(price, maximum_value).into());
+    }
+
+    // By put-call parity, an in-the-money option is worth its intrinsic value plus the
+    // out-of-the-money option of the other type, whose price is solved for instead.
+    let out_of_the_money_price = (price - intrinsic_value).max(0.0);
+
+    // Normalise by the geometric mean of forward and strike. By put-call symmetry, an
+    // out-of-the-money put on log-moneyness x is worth the out-of-the-money call on -x.
+    let scale = (forward * strike).sqrt();
+    let x = (forward / strike).ln();
+    let beta = out_of_the_money_price / scale;
+
+    if beta <= 0.0 {
+        return Ok(0.0);
+    }
+
+    let x = -x.abs();
+    let total_volatility = match lets_be_rational(beta, x) {
+        Some(s) => s,
+        None => fallback_total_volatility(beta, x)?,
+    };
+
+    Ok(total_volatility / time.sqrt())
+}
+
+/// The Bachelier implied volatility of an option on a forward, found with a safeguarded Newton
+/// solver.
+///
+/// # Arguments
+///
+/// * `option_type` - Whether the option is a call or a put on the forward.
+/// * `price` - The undiscounted price of the option.
+/// * `forward` - The forward.
+/// * `strike` - The strike.
+/// * `time` - The time to expiry in years, which must be positive.
+///
+/// # Returns
+///
+/// The volatility, in absolute terms, which is zero if the price is the intrinsic value, or an
+/// error if the price is below the intrinsic value.
+pub fn implied_bachelier_volatility(
+    option_type: OptionType,
+    price: f64,
+    forward: f64,
+    strike: f64,
+    time: f64,
+) -> Result<f64, LibraError> {
+    if time <= 0.0 || time.is_nan() {
+        return Err(NotPositive::new("time", time).into());
+    }
+
+    let intrinsic_value = (option_type.sign() * (forward - strike)).max(0.0);
+    let tolerance = INTRINSIC_VALUE_TOLERANCE * forward.abs().max(strike.abs());
+
+    if price < intrinsic_value - tolerance || price.is_nan() {
+        return Err(BelowIntrinsicValue::new(price, intrinsic_value).into());
+    }
+    if price <= intrinsic_value {
+        return Ok(0.0);
+    }
+    if price.is_infinite() {
+        return Err(AboveMaximumValue::new(price, f64::MAX).into());
+    }
+
+    // By put-call parity, an in-the-money option is worth its intrinsic value plus the
+    // out-of-the-money option of the other type, whose price is solved for instead.
+    let (option_type, price) = match intrinsic_value > 0.0 {
+        true => match option_type {
+            OptionType::Call => (OptionType::Put, price - intrinsic_value),
+            OptionType::Put => (OptionType::Call, price - intrinsic_value),
+        },
+        false => (option_type, price),
+    };
+
+    let value = |v: f64| bachelier_formula(option_type, forward, strike, v, time).value - price;
+    let vega = |v: f64| bachelier_formula(option_type, forward, strike, v, time).vega;
+
+    // The at-the-money price is the standard deviation over the square root of 2π, and no
+    // option is worth more than the at-the-money straddle less the distance to the strike.
+    let mut upper = (2.0 * PI / time).sqrt() * (price + (forward - strike).abs());
+    while value(upper) < 0.0 {
+        upper *= 2.0;
+    }
+
+    NewtonSafe::new().solve_bracketed(&value, &vega, IMPLIED_VOLATILITY_ACCURACY, 0.0, upper)
+}
+
+/// The normalised price of a call on a forward at log-moneyness `x` with total volatility `s`,
+/// which is its undiscounted price divided by the geometric mean of forward and strike.
+pub(crate) fn normalised_black_call(x: f64, s: f64) -> f64 {
+    if x > 0.0 {
+        return 2.0 * (0.5 * x).sinh() + normalised_black_call(-x, s);
+    }
+    if s <= 0.0 {
+        return 0.0;
+    }
+
+    let h = x / s;
+    let t = 0.5 * s;
+
+    if t < SMALL_HALF_TOTAL_VOLATILITY {
+        small_t_expansion(h, t)
+    } else if h + t > 0.85 {
+        (0.5 * x).exp() * normal_cdf(h + t) - (-0.5 * x).exp() * normal_cdf(h - t)
+    } else {
+        0.5 * (-0.5 * (h * h + t * t)).exp() * (erfcx(-(h + t) / SQRT_2) - erfcx(-(h - t) / SQRT_2))
+    }
+}
+
+/// The Taylor expansion of the normalised Black call price in `t`, half the total volatility,
+/// which avoids cancellation between the two terms of the Black formula for small volatilities.
+fn small_t_expansion(h: f64, t: f64) -> f64 {
+    let a = 1.0 + h * (0.5 * PI).sqrt() * erfcx(-h / SQRT_2);
+    let w = t * t;
+    let h2 = h * h;
+
+    let terms = [
+        a,
+        (-1.0 + 3.0 * a + a * h2) / 6.0,
+        (-7.0 + 15.0 * a + h2 * (-1.0 + 10.0 * a + a * h2)) / 120.0,
+        (-57.0 + 105.0 * a + h2 * (-18.0 + 105.0 * a + h2 * (-1.0 + 21.0 * a + a * h2))) / 5040.0,
+        (-561.0
+            + 945.0 * a
+            + h2 * (-285.0
+                + 1260.0 * a
+                + h2 * (-33.0 + 378.0 * a + h2 * (-1.0 + 36.0 * a + a * h2))))
+            / 362880.0,
+        (-6555.0
+            + 10395.0 * a
+            + h2 * (-4680.0
+                + 17325.0 * a
+                + h2 * (-840.0
+                    + 6930.0 * a
+                    + h2 * (-52.0 + 990.0 * a + h2 * (-1.0 + 55.0 * a + a * h2)))))
+            / 39916800.0,
+        (-89055.0
+            + 135135.0 * a
+            + h2 * (-82845.0
+                + 270270.0 * a
+                + h2 * (-20370.0
+                    + 135135.0 * a
+                    + h2 * (-1926.0
+                        + 25740.0 * a
+                        + h2 * (-75.0 + 2145.0 * a + h2 * (-1.0 + 78.0 * a + a * h2))))))
+            / 6227020800.0,
+    ];
+
+    let expansion = terms.iter().rev().fold(0.0, |sum, term| sum * w + term);
+
+    2.0 * t * expansion * (-0.5 * (h * h + t * t)).exp() / (2.0 * PI).sqrt()
+}
+
+/// The derivative of the normalised Black call price with respect to the total volatility `s`,
+/// and its second and third derivatives relative to it.
+fn normalised_vega(x: f64, s: f64) -> (f64, f64, f64) {
+    let h = x / s;
+    let t = 0.5 * s;
+    let vega = (-0.5 * (h * h + t * t)).exp() / (2.0 * PI).sqrt();
+
+    let exponent = x * x / (s * s * s) - 0.25 * s;
+    let second = exponent;
+    let third = exponent * exponent - 3.0 * x * x / (s * s * s * s) - 0.25;
+
+    (vega, second, third)
+}
+
+/// The branch of the normalised Black function the price lies on, which sets the objective the
+/// Householder iterations solve.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Branch {
+    /// Below the tangent at the inflection point meets zero, where the price is exponentially
+    /// small and its logarithm is solved for.
+    Lowest,
+    /// Between the lowest and highest branches, where the price itself is solved for.
+    Central,
+    /// Above the tangent at the inflection point meets the maximum, where the distance of the
+    /// price from its maximum is solved for in logarithms.
+    Highest,
+}
+
+/// The total volatility at which the out-of-the-money normalised call price at log-moneyness
+/// `x <= 0` is `beta`, or `None` if the iterations fail to converge.
+fn lets_be_rational(beta: f64, x: f64) -> Option<f64> {
+    let maximum = (0.5 * x).exp();
+
+    if x == 0.0 {
+        // At the money, the price is 1 - 2 N(-s / 2), which inverts in closed form.
+        return Some(-2.0 * inverse_normal_cdf(0.5 * (1.0 - beta)));
+    }
+
+    // The inflection point of the price in the total volatility, and the points at which the
+    // tangent there crosses zero and the maximum. The second derivative of the inverse function
+    // vanishes at the inflection point.
+    let s_c = (2.0 * x.abs()).sqrt();
+    let b_c = normalised_black_call(x, s_c);
+    let (v_c, _, _) = normalised_vega(x, s_c);
+
+    let (branch, guess) = if beta < b_c {
+        let s_l = s_c - b_c / v_c;
+        let b_l = normalised_black_call(x, s_l);
+
+        if s_l <= 0.0 {
+            (Branch::Lowest, inverse_lower_map(x, beta).min(s_c))
+        } else if beta < b_l {
+            let (f_l, d_f_l, d2_f_l) = lower_map(x, s_l);
+            let cubic = RationalCubic::new((0.0, 0.0, 1.0), (b_l, f_l, d_f_l));
+            let mut f = cubic.value(beta, cubic.control_for_right_curvature(d2_f_l, true));
+
+            if f.is_nan() || f <= 0.0 {
+                let t = beta / b_l;
+                f = (f_l * t + b_l * (1.0 - t)) * t;
+            }
+
+            (Branch::Lowest, inverse_lower_map(x, f))
+        } else {
+            let (v_l, _, _) = normalised_vega(x, s_l);
+            let cubic = RationalCubic::new((b_l, s_l, 1.0 / v_l), (b_c, s_c, 1.0 / v_c));
+
+            (
+                Branch::Central,
+                cubic.value(beta, cubic.control_for_right_curvature(0.0, false)),
+            )
+        }
+    } else {
+        let s_h = s_c + (maximum - b_c) / v_c;
+        let b_h = normalised_black_call(x, s_h);
+
+        if beta <= b_h {
+            let (v_h, _, _) = normalised_vega(x, s_h);
+            let cubic = RationalCubic::new((b_c, s_c, 1.0 / v_c), (b_h, s_h, 1.0 / v_h));
+
+            (
+                Branch::Central,
+                cubic.value(beta, cubic.control_for_left_curvature(0.0, false)),
+            )
+        } else {
+            let (f_h, d_f_h, d2_f_h) = upper_map(x, s_h);
+            let cubic = RationalCubic::new((b_h, f_h, d_f_h), (maximum, 0.0, -0.5));
+            let mut f = cubic.value(beta, cubic.control_for_left_curvature(d2_f_h, true));
+
+            if f.is_nan() || f <= 0.0 {
+                let h = maximum - b_h;
+                let t = (beta - b_h) / h;
+                f = (f_h * (1.0 - t) + 0.5 * h * t) * (1.0 - t);
+            }
+
+            (Branch::Highest, -2.0 * inverse_normal_cdf(f))
+        }
+    };
+
+    householder(beta, x, branch, guess)
+}
+
+/// The map `2 pi |x| / (3 sqrt(3)) N(-|x| / (sqrt(3) s))^3` of the lowest branch, which is the
+/// leading term of the asymptotic expansion of the normalised price for small volatilities, and
+/// its first two derivatives with respect to the price at total volatility `s`.
+fn lower_map(x: f64, s: f64) -> (f64, f64, f64) {
+    let ax = x.abs();
+    let z = ax / (3f64.sqrt() * s);
+    let y = z * z;
+    let s2 = s * s;
+    let cdf = normal_cdf(-z);
+    let pdf = normal_pdf(z);
+
+    let f = 2.0 * PI / 27f64.sqrt() * ax * cdf * cdf * cdf;
+    let d_f = 2.0 * PI * y * cdf * cdf * (y + 0.125 * s2).exp();
+    let d2_f = PI / 6.0 * y / (s2 * s)
+        * cdf
+        * (8.0 * 3f64.sqrt() * s * ax + (3.0 * s2 * (s2 - 8.0) - 8.0 * x * x) * cdf / pdf)
+        * (2.0 * y + 0.25 * s2).exp();
+
+    (f, d_f, d2_f)
+}
+
+/// The total volatility at which the map of the lowest branch is `f`.
+fn inverse_lower_map(x: f64, f: f64) -> f64 {
+    let scale = 2.0 * PI * x.abs() / 27f64.sqrt();
+    let p = (f / scale).cbrt().min(0.5 - f64::EPSILON);
+
+    -x.abs() / (3f64.sqrt() * inverse_normal_cdf(p))
+}
+
+/// The map `N(-s / 2)` of the highest branch, which is the distance of the normalised price from
+/// its maximum for large volatilities relative to its value at the money, and its first two
+/// derivatives with respect to the price at total volatility `s`.
+fn upper_map(x: f64, s: f64) -> (f64, f64, f64) {
+    let w = (x / s) * (x / s);
+
+    let f = normal_cdf(-0.5 * s);
+    let d_f = -0.5 * (0.5 * w).exp();
+    let d2_f = (0.5 * PI).sqrt() * (w + 0.125 * s * s).exp() * w / s;
+
+    (f, d_f, d2_f)
+}
+
+/// The rational cubic of Delbourgo and Gregory between two points with given slopes, as used by
+/// Jäckel. A control parameter of three gives the Hermite cubic, and the curve tends to the
+/// straight line between the points as it grows.
+#[derive(Clone, Copy)]
+struct RationalCubic {
+    x_l: f64,
+    y_l: f64,
+    d_l: f64,
+    x_r: f64,
+    y_r: f64,
+    d_r: f64,
+}
+
+impl RationalCubic {
+    /// Creates a curve through the `(x, y, slope)` triples `left` and `right`.
+    fn new(left: (f64, f64, f64), right: (f64, f64, f64)) -> Self {
+        RationalCubic {
+            x_l: left.0,
+            y_l: left.1,
+            d_l: left.2,
+            x_r: right.0,
+            y_r: right.1,
+            d_r: right.2,
+        }
+    }
+
+    /// The value of the curve at `x` with control parameter `r`.
+    fn value(&self, x: f64, r: f64) -> f64 {
+        let h = self.x_r - self.x_l;
+        if h.abs() <= 0.0 {
+            return 0.5 * (self.y_l + self.y_r);
+        }
+
+        let t = (x - self.x_l) / h;
+        let omt = 1.0 - t;
+
+        if r >= MAXIMUM_RATIONAL_CUBIC_CONTROL {
+            return self.y_r * t + self.y_l * omt;
+        }
+
+        let (t2, omt2) = (t * t, omt * omt);
+        (self.y_r * t2 * t
+            + (r * self.y_r - h * self.d_r) * t2 * omt
+            + (r * self.y_l + h * self.d_l) * t * omt2
+            + self.y_l * omt2 * omt)
+            / (1.0 + (r - 3.0) * t * omt)
+    }
+
+    /// The control parameter giving the curve `second_derivative` at the left point, raised if
+    /// needed to keep the curve monotonic and convex or concave where the data are.
+    fn control_for_left_curvature(&self, second_derivative: f64, prefer_shape: bool) -> f64 {
+        let h = self.x_r - self.x_l;
+        let numerator = 0.5 * h * second_derivative + (self.d_r - self.d_l);
+        let denominator = (self.y_r - self.y_l) / h - self.d_l;
+
+        self.fitted_control(numerator, denominator)
+            .max(self.minimum_control(prefer_shape))
+    }
+
+    /// The control parameter giving the curve `second_derivative` at the right point, raised if
+    /// needed to keep the curve monotonic and convex or concave where the data are.
+    fn control_for_right_curvature(&self, second_derivative: f64, prefer_shape: bool) -> f64 {
+        let h = self.x_r - self.x_l;
+        let numerator = 0.5 * h * second_derivative + (self.d_r - self.d_l);
+        let denominator = self.d_r - (self.y_r - self.y_l) / h;
+
+        self.fitted_control(numerator, denominator)
+            .max(self.minimum_control(prefer_shape))
+    }
+
+    fn fitted_control(&self, numerator: f64, denominator: f64) -> f64 {
+        if numerator.abs() < f64::MIN_POSITIVE {
+            0.0
+        } else if denominator.abs() < f64::MIN_POSITIVE {
+            match numerator > 0.0 {
+                true => MAXIMUM_RATIONAL_CUBIC_CONTROL,
+                false => MINIMUM_RATIONAL_CUBIC_CONTROL,
+            }
+        } else {
+            numerator / denominator
+        }
+    }
+
+    /// The smallest control parameter for which the curve is monotonic, convex or concave
+    /// wherever the slopes at its ends and of the chord between them are.
+    fn minimum_control(&self, prefer_shape: bool) -> f64 {
+        let is_zero = |v: f64| v.abs() < f64::MIN_POSITIVE;
+        let (d_l, d_r) = (self.d_l, self.d_r);
+        let s = (self.y_r - self.y_l) / (self.x_r - self.x_l);
+
+        let monotonic = d_l * s >= 0.0 && d_r * s >= 0.0;
+        let convex = d_l <= s && s <= d_r;
+        let concave = d_l >= s && s >= d_r;
+
+        if !monotonic && !convex && !concave {
+            return MINIMUM_RATIONAL_CUBIC_CONTROL;
+        }
+
+        let mut r1 = f64::MIN;
+        let mut r2 = f64::MIN;
+
+        if monotonic {
+            if !is_zero(s) {
+                r1 = (d_r + d_l) / s;
+            } else if prefer_shape {
+                r1 = MAXIMUM_RATIONAL_CUBIC_CONTROL;
+            }
+        }
+
+        if convex || concave {
+            if !(is_zero(s - d_l) || is_zero(d_r - s)) {
+                r2 = ((d_r - d_l) / (d_r - s))
+                    .abs()
+                    .max(((d_r - d_l) / (s - d_l)).abs());
+            } else if prefer_shape {
+                r2 = MAXIMUM_RATIONAL_CUBIC_CONTROL;
+            }
+        } else if monotonic && prefer_shape {
+            r2 = MAXIMUM_RATIONAL_CUBIC_CONTROL;
+        }
+
+        MINIMUM_RATIONAL_CUBIC_CONTROL.max(r1).max(r2)
+    }
+}
+
+/// Refines `guess` with Householder iterations of third order on the objective for `branch`.
+fn householder(beta: f64, x: f64, branch: Branch, guess: f64) -> Option<f64> {
+    let maximum = (0.5 * x).exp();
+    let mut s = guess;
+
+    for _ in 0..MAX_HOUSEHOLDER_ITERATIONS {
+        if !s.is_finite() || s <= 0.0 {
+            return None;
+        }
+
+        let b = normalised_black_call(x, s);
+
+        // Close to the maximum the price is too flat in the volatility for the steps to settle,
+        // so a volatility reproducing the price to machine precision is accepted.
+        if (b - beta).abs() <= f64::EPSILON * beta {
+            return Some(s);
+        }
+
+        let (vega, second, third) = normalised_vega(x, s);
+
+        // The objective and its first three derivatives, the higher two relative to the first.
+        let (g, g1, g2, g3) = match branch {
+            Branch::Central => (b - beta, vega, second, third),
+            Branch::Lowest => {
+                let (ln_b, ln_beta) = (b.ln(), beta.ln());
+                let l1 = vega / b;
+                let l2 = vega * second / b - l1 * l1;
+                let l3 = vega * third / b - 3.0 * l1 * vega * second / b + 2.0 * l1 * l1 * l1;
+
+                let g1 = -l1 / (ln_b * ln_b);
+                let g2 = -l2 / (ln_b * ln_b) + 2.0 * l1 * l1 / (ln_b * ln_b * ln_b);
+                let g3 = -l3 / (ln_b * ln_b) + 6.0 * l1 * l2 / (ln_b * ln_b * ln_b)
+                    - 6.0 * l1 * l1 * l1 / (ln_b * ln_b * ln_b * ln_b);
+
+                (1.0 / ln_b - 1.0 / ln_beta, g1, g2 / g1, g3 / g1)
+            }
+            Branch::Highest => {
+                let m = maximum - b;
+                let g1 = vega / m;
+                let g2 = vega * second / m + g1 * g1;
+                let g3 = vega * third / m + 3.0 * g1 * vega * second / m + 2.0 * g1 * g1 * g1;
+
+                ((maximum - beta).ln() - m.ln(), g1, g2 / g1, g3 / g1)
+            }
+        };
+
+        let nu = -g / g1;
+        let step = nu * (1.0 + 0.5 * g2 * nu) / (1.0 + nu * (g2 + g3 * nu / 6.0));
+        let next = if s + step > 0.0 { s + step } else { 0.5 * s };
+
+        if (next - s).abs() <= HOUSEHOLDER_ACCURACY * next {
+            return Some(next);
+        }
+        s = next;
+    }
+
+    None
+}
+
+/// The total volatility at which the normalised call price is `beta`, found with a safeguarded
+/// Newton solver.
+fn fallback_total_volatility(beta: f64, x: f64) -> Result<f64, LibraError> {
+    let value = |s: f64| normalised_black_call(x, s) - beta;
+    let vega = |s: f64| normalised_vega(x, s).0;
+
+    let mut upper = (2.0 * x.abs()).sqrt().max(1.0);
+    while value(upper) < 0.0 {
+        upper *= 2.0;
+    }
+
+    NewtonSafe::new().solve_bracketed(&value, &vega, IMPLIED_VOLATILITY_ACCURACY, 0.0, upper)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{instruments::OptionType, pricing::black_formula};
+
+    use super::{implied_black_volatility, lets_be_rational, normalised_black_call};
+
+    const LOG_MONEYNESS: [f64; 7] = [-20.0, -5.0, -1.0, -0.3, -0.05, -1.0e-3, -1.0e-6];
+
+    #[test]
+    fn every_branch_converges_without_the_fallback_solver() {
+        for x in LOG_MONEYNESS {
+            for s in [1.0e-3, 0.01, 0.05, 0.1, 0.3, 0.7, 1.0, 2.0, 4.0, 8.0, 15.0] {
+                let beta = normalised_black_call(x, s);
+                if beta < 1.0e-280 {
+                    continue;
+                }
+
+                let implied = lets_be_rational(beta, x).unwrap();
+                let repriced = normalised_black_call(x, implied);
+                assert!(
+                    (repriced - beta).abs() <= 1.0e-12 * beta,
+                    "x = {}, s = {}: {} reprices {} as {}",
+                    x,
+                    s,
+                    implied,
+                    beta,
+                    repriced
+                );
+
+                // Far from the maximum the volatility itself is recovered.
+                if s <= 8.0 {
+                    assert!(((implied - s) / s).abs() < 1.0e-9, "x = {}, s = {}", x, s);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn implied_volatilities_reprice_black_prices() {
+        let (forward, time) = (100.0, 2.0);
+
+        for option_type in [OptionType::Call, OptionType::Put] {
+            for strike in [60.0, 90.0, 99.0, 100.0, 101.0, 110.0, 150.0] {
+                for volatility in [0.1, 0.2, 0.6, 1.5] {
+                    let price = black_formula(option_type, forward, strike, volatility, time).value;
+                    let implied =
+                        implied_black_volatility(option_type, price, forward, strike, time)
+                            .unwrap();
+
+                    assert!(
+                        (implied - volatility).abs() < 1.0e-9,
+                        "{:?} at {}: {} against {}",
+                        option_type,
+                        strike,
+                        implied,
+                        volatility
+                    );
+                }
+            }
+        }
+    }
+}
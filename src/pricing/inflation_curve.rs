@@ -0,0 +1,194 @@
+use chrono::{Datelike, Months, NaiveDate};
+
+use crate::{
+    error::{ArgumentRange, InvalidDate, LibraError},
+    math::interpolation::{Extrapolation, Interpolation, Interpolator},
+    time::DayCountConvention,
+};
+
+/// A term structure of zero-coupon inflation swap rates, quoted as annually compounded rates of
+/// growth of an index from its base month.
+///
+/// Rates are interpolated linearly in time between pillars and extrapolated flat. Time is
+/// measured in Actual/365 years from the base month.
+#[derive(Clone, Debug)]
+pub struct ZeroInflationCurve {
+    base_date: NaiveDate,
+    pillars: Vec<(NaiveDate, f64)>,
+    rates: Interpolator,
+}
+
+impl ZeroInflationCurve {
+    /// Creates a curve of zero-coupon inflation rates.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_date` - A date in the month whose index fixing the rates grow from.
+    /// * `pillars` - The `(date, rate)` pairs of the curve.
+    ///
+    /// # Returns
+    ///
+    /// The curve, or an error if no pillars are given, two pillars share a date or a pillar
+    /// precedes the base month.
+    pub fn new(
+        base_date: NaiveDate,
+        mut pillars: Vec<(NaiveDate, f64)>,
+    ) -> Result<Self, LibraError> {
+        let base_date = first_of_month(base_date);
+
+        if pillars.is_empty() {
+            return Err(ArgumentRange::new("number of pillars", 1, i64::MAX, 0).into());
+        }
+
+        pillars.sort_by_key(|(date, _)| *date);
+
+        if pillars[0].0 <= base_date {
+            return Err(InvalidDate.into());
+        }
+
+        Ok(ZeroInflationCurve {
+            rates: pillar_interpolator(base_date, &pillars)?,
+            base_date,
+            pillars,
+        })
+    }
+
+    /// The first day of the base month.
+    pub fn base_date(&self) -> NaiveDate {
+        self.base_date
+    }
+
+    pub fn pillars(&self) -> &[(NaiveDate, f64)] {
+        &self.pillars
+    }
+
+    /// The zero-coupon inflation rate from the base month to `date`.
+    pub fn zero_rate(&self, date: NaiveDate) -> Result<f64, LibraError> {
+        Ok(self.rates.value(self.time(date)?))
+    }
+
+    /// The ratio of the index fixing for the month of `date` to the base fixing.
+    pub fn index_growth(&self, date: NaiveDate) -> Result<f64, LibraError> {
+        let date = first_of_month(date);
+
+        Ok((1.0 + self.zero_rate(date)?).powf(self.time(date)?))
+    }
+
+    fn time(&self, date: NaiveDate) -> Result<f64, InvalidDate> {
+        DayCountConvention::Actual365Fixed.year_frac(&self.base_date, &date)
+    }
+}
+
+/// A term structure of year-on-year inflation rates, each the growth of an index over the year
+/// ending on the pillar date.
+///
+/// Rates are interpolated linearly in time between pillars and extrapolated flat.
+#[derive(Clone, Debug)]
+pub struct YoYInflationCurve {
+    base_date: NaiveDate,
+    pillars: Vec<(NaiveDate, f64)>,
+    rates: Interpolator,
+}
+
+impl YoYInflationCurve {
+    /// Creates a curve of year-on-year inflation rates.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_date` - A date in the base month of the index.
+    /// * `pillars` - The `(date, rate)` pairs of the curve.
+    ///
+    /// # Returns
+    ///
+    /// The curve, or an error if no pillars are given, two pillars share a date or a pillar
+    /// precedes the base month.
+    pub fn new(
+        base_date: NaiveDate,
+        mut pillars: Vec<(NaiveDate, f64)>,
+    ) -> Result<Self, LibraError> {
+        let base_date = first_of_month(base_date);
+
+        if pillars.is_empty() {
+            return Err(ArgumentRange::new("number of pillars", 1, i64::MAX, 0).into());
+        }
+
+        pillars.sort_by_key(|(date, _)| *date);
+
+        if pillars[0].0 <= base_date {
+            return Err(InvalidDate.into());
+        }
+
+        Ok(YoYInflationCurve {
+            rates: pillar_interpolator(base_date, &pillars)?,
+            base_date,
+            pillars,
+        })
+    }
+
+    /// The first day of the base month.
+    pub fn base_date(&self) -> NaiveDate {
+        self.base_date
+    }
+
+    pub fn pillars(&self) -> &[(NaiveDate, f64)] {
+        &self.pillars
+    }
+
+    /// The year-on-year inflation rate for the year ending on `date`.
+    pub fn yoy_rate(&self, date: NaiveDate) -> Result<f64, LibraError> {
+        Ok(self
+            .rates
+            .value(DayCountConvention::Actual365Fixed.year_frac(&self.base_date, &date)?))
+    }
+
+    /// The zero-coupon curve implied by compounding the year-on-year rates annually from the
+    /// base month, ignoring the convexity between the two.
+    pub fn zero_curve(&self) -> Result<ZeroInflationCurve, LibraError> {
+        let last = self.pillars.last().map_or(self.base_date, |(d, _)| *d);
+        let mut growth = 1.0;
+        let mut pillars = Vec::new();
+
+        for year in 1.. {
+            let date = self
+                .base_date
+                .checked_add_months(Months::new(12 * year))
+                .ok_or(InvalidDate)?;
+
+            growth *= 1.0 + self.yoy_rate(date)?;
+            pillars.push((date, growth.powf(1.0 / year as f64) - 1.0));
+
+            if date >= last {
+                break;
+            }
+        }
+
+        ZeroInflationCurve::new(self.base_date, pillars)
+    }
+}
+
+/// The first day of the month containing `date`.
+pub(crate) fn first_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+/// Interpolates the rates of `pillars` sorted by date linearly in Actual/365 time from
+/// `base_date`, extrapolating flat.
+///
+/// The rate of the first pillar is also given at the base date, so that a single pillar gives a
+/// flat curve.
+fn pillar_interpolator(
+    base_date: NaiveDate,
+    pillars: &[(NaiveDate, f64)],
+) -> Result<Interpolator, LibraError> {
+    let day_count = DayCountConvention::Actual365Fixed;
+    let mut times = vec![0.0];
+    let mut rates = vec![pillars[0].1];
+
+    for (date, rate) in pillars {
+        times.push(day_count.year_frac(&base_date, date)?);
+        rates.push(*rate);
+    }
+
+    Ok(Interpolator::new(Interpolation::Linear, times, rates)?
+        .with_extrapolation(Extrapolation::Flat))
+}
@@ -0,0 +1,182 @@
+use std::rc::Rc;
+
+use crate::{
+    error::{LibraError, NotPositive},
+    instruments::{LookbackOption, LookbackType},
+    math::distributions::{normal_cdf, normal_pdf},
+};
+
+use super::{
+    bumped_results, european_expiry,
+    montecarlo::{MonteCarloSettings, Path, Statistics, TimeGrid},
+    GeneralizedBlackScholesProcess, OptionResults,
+};
+
+/// The constant of the continuity correction of Broadie, Glasserman and Kou, by which many
+/// standard deviations over a step the extremum of a discretely sampled path is moved outwards to
+/// approximate the extremum of the continuous path.
+const DISCRETE_MONITORING_SHIFT: f64 = 0.582_597_157_939_010_6;
+
+/// The cost of carry below which the limit of the lookback premium as the cost of carry tends to
+/// zero is used.
+const CARRY_TOLERANCE: f64 = 1.0e-8;
+
+/// Prices continuously monitored lookback options with European exercise under the generalized
+/// Black-Scholes process, using the formulas of Goldman, Sosin and Gatto (1979) for floating
+/// strikes and of Conze and Viswanathan (1991) for fixed strikes.
+///
+/// The zero rates and dividend yield to expiry are used. Delta and gamma are found by bumping the
+/// spot, and theta is implied by the Black-Scholes equation.
+#[derive(Clone, Debug)]
+pub struct AnalyticLookbackEngine {
+    process: GeneralizedBlackScholesProcess,
+}
+
+impl AnalyticLookbackEngine {
+    pub fn new(process: GeneralizedBlackScholesProcess) -> Self {
+        AnalyticLookbackEngine { process }
+    }
+
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    /// Prices `option`.
+    ///
+    /// # Returns
+    ///
+    /// The value, delta, gamma and theta, or an error if the option is not European, has
+    /// expired, or the volatility is not positive.
+    pub fn calculate(&self, option: &LookbackOption) -> Result<OptionResults, LibraError> {
+        let process = &self.process;
+        let time = european_expiry(process, option.exercise())?;
+
+        let sigma = process.volatility();
+        if sigma <= 0.0 {
+            return Err(NotPositive::new("volatility", sigma).into());
+        }
+
+        bumped_results(process, time, &|process| {
+            Ok(lookback_value(process, option, time))
+        })
+    }
+}
+
+/// Prices lookback options with European exercise under the generalized Black-Scholes process by
+/// Monte Carlo simulation.
+///
+/// The extremum of each path is taken over the times of its grid and moved outwards by the
+/// continuity correction of Broadie, Glasserman and Kou, which approximates the extremum of the
+/// continuously monitored path far better than the discrete one.
+#[derive(Clone, Debug)]
+pub struct McLookbackEngine {
+    process: GeneralizedBlackScholesProcess,
+    settings: MonteCarloSettings,
+}
+
+impl McLookbackEngine {
+    pub fn new(process: GeneralizedBlackScholesProcess, settings: MonteCarloSettings) -> Self {
+        McLookbackEngine { process, settings }
+    }
+
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    pub fn settings(&self) -> &MonteCarloSettings {
+        &self.settings
+    }
+
+    /// Prices `option`.
+    ///
+    /// # Returns
+    ///
+    /// The statistics of the discounted payoff, whose mean is the value, or an error if the
+    /// option is not European, has expired, or the paths cannot be simulated.
+    pub fn calculate(&self, option: &LookbackOption) -> Result<Statistics, LibraError> {
+        let process = &self.process;
+        let time = european_expiry(process, option.exercise())?;
+        let grid = TimeGrid::new(time, self.settings.steps())?;
+
+        let minimum = option.depends_on_minimum();
+        let direction = if minimum { -1.0 } else { 1.0 };
+        let step = time / self.settings.steps() as f64;
+        let shift =
+            (direction * DISCRETE_MONITORING_SHIFT * process.volatility() * step.sqrt()).exp();
+
+        let running = option.extremum(process.spot());
+        let discount = process.risk_free_curve().discount_time(time);
+
+        let pricer = |path: &Path| {
+            let values = path.values();
+            let extremum = match minimum {
+                true => values.iter().copied().fold(f64::INFINITY, f64::min) * shift,
+                false => values.iter().copied().fold(f64::NEG_INFINITY, f64::max) * shift,
+            };
+            let extremum = match minimum {
+                true => extremum.min(running),
+                false => extremum.max(running),
+            };
+
+            option.value(values[values.len() - 1], extremum) * discount
+        };
+
+        let mut simulation = self.settings.simulation(Rc::new(process.clone()), grid)?;
+        Ok(simulation.run(&pricer, self.settings.samples()))
+    }
+}
+
+/// The value of `option` expiring in `time` years.
+fn lookback_value(
+    process: &GeneralizedBlackScholesProcess,
+    option: &LookbackOption,
+    time: f64,
+) -> f64 {
+    let spot = process.spot();
+    let extremum = option.extremum(spot);
+    if time <= 0.0 {
+        return option.value(spot, extremum);
+    }
+
+    let sigma = process.volatility();
+    let std_dev = sigma * time.sqrt();
+    let carry = process.risk_free_rate(time) - process.dividend_yield(time);
+    let discount = process.risk_free_curve().discount_time(time);
+    let dividend_discount = process.dividend_curve().discount_time(time);
+    let omega = option.option_type().sign();
+
+    // The value of the option to receive the extremum beyond `level` over receiving the final
+    // price, relative to the spot, with `d` the first Black-Scholes term at `level`.
+    let premium = |omega: f64, level: f64, d: f64| {
+        let ratio = spot / level;
+        if carry.abs() < CARRY_TOLERANCE {
+            return std_dev * normal_pdf(d)
+                + omega * (ratio.ln() + 0.5 * std_dev * std_dev) * normal_cdf(omega * d);
+        }
+
+        let exponent = 2.0 * carry / (sigma * sigma);
+        omega / exponent
+            * (-ratio.powf(-exponent) * normal_cdf(omega * (d - 2.0 * carry * time.sqrt() / sigma))
+                + (carry * time).exp() * normal_cdf(omega * d))
+    };
+    // The value of an option struck at `level` on the final price, and of the premium above.
+    let terms = |level: f64, premium_sign: f64| {
+        let d1 = (spot / level).ln() / std_dev + 0.5 * std_dev + carry * time / std_dev;
+        let d2 = d1 - std_dev;
+
+        omega
+            * (spot * dividend_discount * normal_cdf(omega * d1)
+                - level * discount * normal_cdf(omega * d2))
+            + spot * discount * premium(premium_sign * omega, level, d1)
+    };
+
+    match option.lookback_type() {
+        LookbackType::FloatingStrike => terms(extremum, -1.0),
+        LookbackType::FixedStrike { strike } => {
+            let intrinsic = (omega * (extremum - strike)).max(0.0);
+            let level = if intrinsic > 0.0 { extremum } else { strike };
+
+            discount * intrinsic + terms(level, 1.0)
+        }
+    }
+}
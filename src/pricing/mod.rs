@@ -1,3 +1,51 @@
+mod american_approximations;
+mod asian_option;
+mod barrier_option;
+mod black_formula;
+mod black_scholes_process;
+mod bond;
+mod bond_future;
+mod cap_floor;
+mod cliquet_option;
+mod cross_currency_swap;
+mod digital_option;
 mod forward_curve;
+mod fra;
+mod implied_volatility;
+mod inflation_curve;
+mod lookback_option;
+pub mod montecarlo;
+mod optionlet_volatility;
+pub mod short_rate;
+mod stir_future;
+mod swap;
+mod swaption;
+mod term_structures;
+mod vanilla_finite_difference;
+mod vanilla_lattice;
+mod vanilla_option;
 
+pub use american_approximations::*;
+pub use asian_option::*;
+pub use barrier_option::*;
+pub use black_formula::*;
+pub use black_scholes_process::*;
+pub use bond::*;
+pub use bond_future::*;
+pub use cap_floor::*;
+pub use cliquet_option::*;
+pub use cross_currency_swap::*;
+pub use digital_option::*;
 pub use forward_curve::*;
+pub use implied_volatility::*;
+pub use inflation_curve::*;
+pub use lookback_option::*;
+pub use optionlet_volatility::*;
+pub use swap::*;
+pub use swaption::*;
+pub use term_structures::*;
+pub use vanilla_finite_difference::*;
+pub use vanilla_lattice::*;
+pub use vanilla_option::*;
+
+use swap::{leg_bps, leg_npv};
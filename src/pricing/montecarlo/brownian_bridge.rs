@@ -0,0 +1,125 @@
+use crate::error::{ArgumentRange, LibraError, NotPositive};
+
+/// Builds the path of a Brownian motion on a time grid by bisection, drawing its value at the
+/// last time first and then filling in the times in between conditional on their neighbours.
+///
+/// Used with a low-discrepancy sequence, this gives the first and most uniform coordinates of
+/// each point to the largest scale features of the path, which greatly reduces the effective
+/// dimension of the simulation.
+#[derive(Clone, Debug)]
+pub struct BrownianBridge {
+    times: Vec<f64>,
+    bridge_index: Vec<usize>,
+    left_index: Vec<usize>,
+    right_index: Vec<usize>,
+    left_weight: Vec<f64>,
+    right_weight: Vec<f64>,
+    std_dev: Vec<f64>,
+}
+
+impl BrownianBridge {
+    /// Creates a bridge over the steps ending at `times`, measured from time zero.
+    ///
+    /// # Returns
+    ///
+    /// The bridge, or an error if `times` is empty or not strictly increasing from zero.
+    pub fn new(times: &[f64]) -> Result<Self, LibraError> {
+        let n = times.len();
+        if n == 0 {
+            return Err(ArgumentRange::new("number of times", 1, i64::MAX, 0).into());
+        }
+
+        let mut previous = 0.0;
+        for time in times {
+            if time - previous <= 0.0 {
+                return Err(NotPositive::new("time step", time - previous).into());
+            }
+            previous = *time;
+        }
+
+        let mut bridge = BrownianBridge {
+            times: times.to_vec(),
+            bridge_index: vec![0; n],
+            left_index: vec![0; n],
+            right_index: vec![0; n],
+            left_weight: vec![0.0; n],
+            right_weight: vec![0.0; n],
+            std_dev: vec![0.0; n],
+        };
+
+        // Which step each time is filled in at, counted from one, or zero if not yet filled.
+        let mut filled = vec![0; n];
+        filled[n - 1] = 1;
+        bridge.bridge_index[0] = n - 1;
+        bridge.std_dev[0] = times[n - 1].sqrt();
+
+        let mut j = 0;
+        for i in 1..n {
+            // Find the next gap between filled times and bisect it.
+            while filled[j] != 0 {
+                j += 1;
+            }
+            let mut k = j;
+            while filled[k] == 0 {
+                k += 1;
+            }
+            let l = j + ((k - 1 - j) >> 1);
+            filled[l] = i + 1;
+
+            // The gap runs from the time before j, or zero, to the time at k.
+            let start = if j == 0 { 0.0 } else { times[j - 1] };
+            let (middle, end) = (times[l], times[k]);
+
+            bridge.bridge_index[i] = l;
+            bridge.left_index[i] = j;
+            bridge.right_index[i] = k;
+            bridge.left_weight[i] = (end - middle) / (end - start);
+            bridge.right_weight[i] = (middle - start) / (end - start);
+            bridge.std_dev[i] = ((middle - start) * (end - middle) / (end - start)).sqrt();
+
+            j = k + 1;
+            if j >= n {
+                j = 0;
+            }
+        }
+
+        Ok(bridge)
+    }
+
+    pub fn times(&self) -> &[f64] {
+        &self.times
+    }
+
+    /// Builds a path of the Brownian motion from independent standard normal draws, one for
+    /// each time.
+    ///
+    /// # Returns
+    ///
+    /// The increments of the path over each step divided by the square root of its length, which
+    /// are themselves independent standard normal variables.
+    pub fn transform(&self, normals: &[f64]) -> Vec<f64> {
+        let n = self.times.len();
+        let mut path = vec![0.0; n];
+        path[n - 1] = self.std_dev[0] * normals[0];
+
+        for (i, normal) in normals.iter().enumerate().take(n).skip(1) {
+            let (j, k, l) = (
+                self.left_index[i],
+                self.right_index[i],
+                self.bridge_index[i],
+            );
+            let left = if j == 0 { 0.0 } else { path[j - 1] };
+
+            path[l] = self.left_weight[i] * left
+                + self.right_weight[i] * path[k]
+                + self.std_dev[i] * normal;
+        }
+
+        for i in (1..n).rev() {
+            path[i] = (path[i] - path[i - 1]) / (self.times[i] - self.times[i - 1]).sqrt();
+        }
+        path[0] /= self.times[0].sqrt();
+
+        path
+    }
+}
@@ -0,0 +1,255 @@
+use crate::error::{ArgumentRange, LibraError};
+
+use super::{
+    random::u32_to_uniform, MersenneTwister, UniformRandomGenerator, UniformSequenceGenerator,
+};
+
+/// The number of bits of each coordinate of a Sobol point.
+const SOBOL_BITS: usize = 32;
+
+/// The coefficients `a` of the primitive polynomials and the initial direction numbers `m` of
+/// the Sobol sequence for dimensions two onwards, from the `new-joe-kuo-6.21201` table of Joe
+/// and Kuo (2008). The degree of each polynomial is the number of direction numbers.
+const JOE_KUO_DIRECTIONS: [(u32, &[u32]); 52] = [
+    (0, &[1]),
+    (1, &[1, 3]),
+    (1, &[1, 3, 1]),
+    (2, &[1, 1, 1]),
+    (1, &[1, 1, 3, 3]),
+    (4, &[1, 3, 5, 13]),
+    (2, &[1, 1, 5, 5, 17]),
+    (4, &[1, 1, 5, 5, 5]),
+    (7, &[1, 1, 7, 11, 19]),
+    (11, &[1, 1, 5, 1, 1]),
+    (13, &[1, 1, 1, 3, 11]),
+    (14, &[1, 3, 5, 5, 31]),
+    (1, &[1, 3, 3, 9, 7, 49]),
+    (13, &[1, 1, 1, 15, 21, 21]),
+    (16, &[1, 3, 1, 13, 27, 49]),
+    (19, &[1, 1, 1, 15, 7, 5]),
+    (22, &[1, 3, 1, 15, 13, 25]),
+    (25, &[1, 1, 5, 5, 19, 61]),
+    (1, &[1, 3, 7, 11, 23, 15, 103]),
+    (4, &[1, 3, 7, 13, 13, 15, 69]),
+    (7, &[1, 1, 3, 13, 7, 35, 63]),
+    (8, &[1, 3, 5, 9, 1, 25, 53]),
+    (14, &[1, 3, 1, 13, 9, 35, 107]),
+    (19, &[1, 3, 1, 5, 27, 61, 31]),
+    (21, &[1, 1, 5, 11, 19, 41, 61]),
+    (28, &[1, 3, 5, 3, 3, 13, 69]),
+    (31, &[1, 1, 7, 13, 1, 19, 1]),
+    (32, &[1, 3, 7, 5, 13, 19, 59]),
+    (37, &[1, 1, 3, 9, 25, 29, 41]),
+    (41, &[1, 3, 5, 13, 23, 1, 55]),
+    (42, &[1, 3, 7, 3, 13, 59, 17]),
+    (50, &[1, 3, 1, 3, 5, 53, 69]),
+    (55, &[1, 1, 5, 5, 23, 33, 13]),
+    (56, &[1, 1, 7, 7, 1, 61, 123]),
+    (59, &[1, 1, 7, 9, 13, 61, 49]),
+    (62, &[1, 3, 3, 5, 3, 55, 33]),
+    (14, &[1, 3, 1, 15, 31, 13, 49, 245]),
+    (21, &[1, 3, 5, 15, 31, 59, 63, 97]),
+    (22, &[1, 3, 1, 11, 11, 11, 77, 249]),
+    (38, &[1, 3, 1, 11, 27, 43, 71, 9]),
+    (47, &[1, 1, 7, 15, 21, 11, 81, 45]),
+    (49, &[1, 3, 7, 3, 25, 31, 65, 79]),
+    (50, &[1, 3, 1, 1, 19, 11, 3, 205]),
+    (52, &[1, 1, 5, 9, 19, 21, 29, 157]),
+    (56, &[1, 3, 7, 11, 1, 33, 89, 185]),
+    (67, &[1, 3, 3, 3, 15, 9, 79, 71]),
+    (70, &[1, 3, 7, 11, 15, 39, 119, 27]),
+    (84, &[1, 1, 3, 1, 11, 31, 97, 225]),
+    (97, &[1, 1, 1, 3, 23, 43, 57, 177]),
+    (103, &[1, 3, 7, 7, 17, 17, 37, 71]),
+    (115, &[1, 3, 1, 5, 27, 63, 123, 213]),
+    (122, &[1, 1, 3, 5, 11, 43, 53, 133]),
+];
+
+/// The largest dimension of the Sobol sequences available, which is one more than the number of
+/// primitive polynomials taken from the table of Joe and Kuo.
+pub const MAX_SOBOL_DIMENSION: usize = JOE_KUO_DIRECTIONS.len() + 1;
+
+/// The Sobol low-discrepancy sequence, generated in Gray code order.
+///
+/// The first dimension is the van der Corput sequence in base two, and the others use the
+/// direction numbers of Joe and Kuo.
+///
+/// The point at the origin is skipped, so the sequence starts at the centre of the hypercube.
+/// The points can be randomised with a digital shift, which keeps their low discrepancy while
+/// allowing independent randomised sequences to be used to estimate the integration error.
+#[derive(Clone, Debug)]
+pub struct SobolSequence {
+    directions: Vec<[u32; SOBOL_BITS]>,
+    shift: Vec<u32>,
+    integers: Vec<u32>,
+    index: u32,
+}
+
+impl SobolSequence {
+    /// Creates a sequence of points with `dimension` coordinates.
+    ///
+    /// # Returns
+    ///
+    /// The sequence, or an error if `dimension` is zero or more than [`MAX_SOBOL_DIMENSION`].
+    pub fn new(dimension: usize) -> Result<Self, LibraError> {
+        if !(1..=MAX_SOBOL_DIMENSION).contains(&dimension) {
+            return Err(ArgumentRange::new(
+                "dimension",
+                1,
+                MAX_SOBOL_DIMENSION as i64,
+                dimension as i64,
+            )
+            .into());
+        }
+
+        let mut directions = vec![std::array::from_fn(|k| 1 << (SOBOL_BITS - 1 - k))];
+
+        for (a, initial) in &JOE_KUO_DIRECTIONS[..dimension - 1] {
+            let (a, degree) = (*a, initial.len());
+            let mut m = initial.to_vec();
+
+            // The recurrence of the primitive polynomial, on the direction numbers as integers.
+            for k in degree..SOBOL_BITS {
+                let mut next = m[k - degree] ^ (m[k - degree] << degree);
+                for i in 1..degree {
+                    if (a >> (degree - 1 - i)) & 1 == 1 {
+                        next ^= m[k - i] << i;
+                    }
+                }
+                m.push(next);
+            }
+
+            directions.push(std::array::from_fn(|k| m[k] << (SOBOL_BITS - 1 - k)));
+        }
+
+        Ok(SobolSequence {
+            directions,
+            shift: vec![0; dimension],
+            integers: vec![0; dimension],
+            index: 0,
+        })
+    }
+
+    /// Randomises the sequence by a digital shift, adding a random bit vector drawn from `seed`
+    /// to each coordinate without carry.
+    pub fn with_digital_shift(mut self, seed: u32) -> Self {
+        let mut generator = MersenneTwister::new(seed);
+        self.shift = (0..self.directions.len())
+            .map(|_| generator.next_u32())
+            .collect();
+        self
+    }
+
+    /// Skips the next `points` points of the sequence.
+    pub fn skip(&mut self, points: usize) {
+        for _ in 0..points {
+            self.advance();
+        }
+    }
+
+    /// Moves the integer coordinates on to the next point, flipping the direction number of the
+    /// bit which changes in the Gray code of the index.
+    fn advance(&mut self) {
+        self.index += 1;
+        let bit = self.index.trailing_zeros() as usize;
+
+        for (integer, directions) in self.integers.iter_mut().zip(&self.directions) {
+            *integer ^= directions[bit];
+        }
+    }
+}
+
+impl UniformSequenceGenerator for SobolSequence {
+    fn dimension(&self) -> usize {
+        self.directions.len()
+    }
+
+    fn next_sequence(&mut self) -> Vec<f64> {
+        self.advance();
+
+        self.integers
+            .iter()
+            .zip(&self.shift)
+            .map(|(integer, shift)| u32_to_uniform(integer ^ shift))
+            .collect()
+    }
+}
+
+/// The Halton low-discrepancy sequence, whose coordinates are the radical inverses of the index
+/// of each point in successive prime bases.
+///
+/// The sequence starts from the first point after the origin. Its uniformity degrades in high
+/// dimensions, for which the Sobol sequence is preferred. The points can be randomised by a
+/// random shift modulo one.
+#[derive(Clone, Debug)]
+pub struct HaltonSequence {
+    bases: Vec<u64>,
+    shift: Vec<f64>,
+    index: u64,
+}
+
+impl HaltonSequence {
+    /// Creates a sequence of points with `dimension` coordinates.
+    pub fn new(dimension: usize) -> Self {
+        let mut bases = Vec::with_capacity(dimension);
+        let mut candidate = 2;
+        while bases.len() < dimension {
+            if bases.iter().all(|p| candidate % p != 0) {
+                bases.push(candidate);
+            }
+            candidate += 1;
+        }
+
+        HaltonSequence {
+            bases,
+            shift: vec![0.0; dimension],
+            index: 0,
+        }
+    }
+
+    /// Randomises the sequence by adding a uniform random vector drawn from `seed` to each point,
+    /// modulo one.
+    pub fn with_random_shift(mut self, seed: u32) -> Self {
+        let mut generator = MersenneTwister::new(seed);
+        self.shift = (0..self.bases.len())
+            .map(|_| generator.next_uniform())
+            .collect();
+        self
+    }
+
+    /// Skips the next `points` points of the sequence.
+    pub fn skip(&mut self, points: usize) {
+        self.index += points as u64;
+    }
+}
+
+impl UniformSequenceGenerator for HaltonSequence {
+    fn dimension(&self) -> usize {
+        self.bases.len()
+    }
+
+    fn next_sequence(&mut self) -> Vec<f64> {
+        self.index += 1;
+
+        self.bases
+            .iter()
+            .zip(&self.shift)
+            .map(|(base, shift)| (radical_inverse(self.index, *base) + shift).fract())
+            .collect()
+    }
+}
+
+/// The number whose digits after the point in `base` are those of `n` reversed.
+fn radical_inverse(mut n: u64, base: u64) -> f64 {
+    let inverse_base = 1.0 / base as f64;
+    let mut scale = inverse_base;
+    let mut value = 0.0;
+
+    while n > 0 {
+        value += (n % base) as f64 * scale;
+        n /= base;
+        scale *= inverse_base;
+    }
+
+    value
+}
@@ -0,0 +1,48 @@
+mod brownian_bridge;
+mod low_discrepancy;
+mod path_generator;
+mod processes;
+mod random;
+mod settings;
+mod simulation;
+mod statistics;
+mod time_grid;
+
+pub use brownian_bridge::*;
+pub use low_discrepancy::*;
+pub use path_generator::*;
+pub use processes::*;
+pub use random::*;
+pub use settings::*;
+pub use simulation::*;
+pub use statistics::*;
+pub use time_grid::*;
+
+/// A Markov process driven by independent Brownian motions, which can be stepped forwards in
+/// time to simulate its paths.
+///
+/// Time is measured in years from the start of the process.
+pub trait StochasticProcess {
+    /// The number of variables in the state of the process.
+    fn size(&self) -> usize;
+
+    /// The number of independent Brownian motions driving the process.
+    fn factors(&self) -> usize;
+
+    /// The state of the process at time zero.
+    fn initial_values(&self) -> Vec<f64>;
+
+    /// Steps the process forwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time of the start of the step.
+    /// * `state` - The state at the start of the step.
+    /// * `dt` - The length of the step in years.
+    /// * `normals` - One independent standard normal draw for each factor.
+    ///
+    /// # Returns
+    ///
+    /// The state at the end of the step.
+    fn evolve(&self, time: f64, state: &[f64], dt: f64, normals: &[f64]) -> Vec<f64>;
+}
@@ -0,0 +1,183 @@
+use std::{fmt, rc::Rc};
+
+use crate::{
+    error::{ArgumentRange, LibraError},
+    math::distributions::inverse_normal_cdf,
+};
+
+use super::{BrownianBridge, StochasticProcess, TimeGrid, UniformSequenceGenerator};
+
+/// A simulated path of a stochastic process.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Path {
+    times: Rc<[f64]>,
+    states: Vec<Vec<f64>>,
+}
+
+impl Path {
+    /// The times of the path, starting from zero.
+    pub fn times(&self) -> &[f64] {
+        &self.times
+    }
+
+    /// The states of the process at each time.
+    pub fn states(&self) -> &[Vec<f64>] {
+        &self.states
+    }
+
+    /// The value of the first variable of the state at time `i`, such as the price of an
+    /// equity.
+    pub fn value(&self, i: usize) -> f64 {
+        self.states[i][0]
+    }
+
+    /// The values of the first variable of the state at every time.
+    pub fn values(&self) -> Vec<f64> {
+        self.states.iter().map(|s| s[0]).collect()
+    }
+
+    /// The number of times on the path.
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Whether the path has no times, which is never the case for a simulated path.
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+}
+
+/// Generates paths of a stochastic process on a time grid, from points of a uniform sequence
+/// transformed to standard normal draws.
+///
+/// Each point drives a whole path, so the sequence must have one coordinate for each factor of
+/// the process at each step. The antithetic path of the last one, driven by the negated draws,
+/// can also be generated.
+pub struct PathGenerator {
+    process: Rc<dyn StochasticProcess>,
+    grid: TimeGrid,
+    times: Rc<[f64]>,
+    generator: Box<dyn UniformSequenceGenerator>,
+    bridge: Option<BrownianBridge>,
+    normals: Vec<f64>,
+}
+
+impl PathGenerator {
+    /// Creates a generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `process` - The process to simulate.
+    /// * `grid` - The times to simulate the process at.
+    /// * `generator` - The uniform sequence driving the paths.
+    ///
+    /// # Returns
+    ///
+    /// The generator, or an error if the dimension of the sequence is not the number of steps
+    /// times the number of factors of the process.
+    pub fn new(
+        process: Rc<dyn StochasticProcess>,
+        grid: TimeGrid,
+        generator: Box<dyn UniformSequenceGenerator>,
+    ) -> Result<Self, LibraError> {
+        let dimension = grid.steps() * process.factors();
+        if generator.dimension() != dimension {
+            return Err(ArgumentRange::new(
+                "generator dimension",
+                dimension as i64,
+                dimension as i64,
+                generator.dimension() as i64,
+            )
+            .into());
+        }
+
+        Ok(PathGenerator {
+            process,
+            times: grid.times().into(),
+            grid,
+            generator,
+            bridge: None,
+            normals: Vec::new(),
+        })
+    }
+
+    /// Builds each path with a Brownian bridge, so that the first coordinates of each point of the
+    /// sequence set the values of the factors at the end of the path.
+    pub fn with_brownian_bridge(mut self) -> Self {
+        // The grid has increasing positive times after zero, so the bridge can always be built.
+        self.bridge = BrownianBridge::new(&self.grid.times()[1..]).ok();
+        self
+    }
+
+    pub fn process(&self) -> &Rc<dyn StochasticProcess> {
+        &self.process
+    }
+
+    pub fn grid(&self) -> &TimeGrid {
+        &self.grid
+    }
+
+    /// Generates the next path.
+    pub fn next_path(&mut self) -> Path {
+        let uniforms = self.generator.next_sequence();
+        let normals: Vec<f64> = uniforms.into_iter().map(inverse_normal_cdf).collect();
+
+        self.normals = match &self.bridge {
+            Some(bridge) => self.bridge_normals(bridge, &normals),
+            None => normals,
+        };
+
+        self.build_path(1.0)
+    }
+
+    /// Generates the antithetic path of the last path, driven by the negated draws.
+    pub fn antithetic_path(&self) -> Path {
+        self.build_path(-1.0)
+    }
+
+    /// Reorders the draws through the bridge of each factor, which takes the coordinates of the
+    /// point for that factor in turn.
+    fn bridge_normals(&self, bridge: &BrownianBridge, normals: &[f64]) -> Vec<f64> {
+        let factors = self.process.factors();
+        let steps = self.grid.steps();
+        let mut ordered = vec![0.0; normals.len()];
+
+        for factor in 0..factors {
+            let draws: Vec<f64> = (0..steps).map(|i| normals[i * factors + factor]).collect();
+            for (i, draw) in bridge.transform(&draws).into_iter().enumerate() {
+                ordered[i * factors + factor] = draw;
+            }
+        }
+
+        ordered
+    }
+
+    /// Steps the process along the grid with the current draws multiplied by `sign`.
+    fn build_path(&self, sign: f64) -> Path {
+        let factors = self.process.factors();
+        let mut states = Vec::with_capacity(self.times.len());
+        states.push(self.process.initial_values());
+
+        for (i, draws) in self.normals.chunks(factors).enumerate() {
+            let draws: Vec<f64> = draws.iter().map(|z| sign * z).collect();
+            let next = self
+                .process
+                .evolve(self.times[i], &states[i], self.grid.dt(i), &draws);
+            states.push(next);
+        }
+
+        Path {
+            times: self.times.clone(),
+            states,
+        }
+    }
+}
+
+impl fmt::Debug for PathGenerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PathGenerator")
+            .field("grid", &self.grid)
+            .field("brownian_bridge", &self.bridge.is_some())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,506 @@
+use std::{fmt, rc::Rc};
+
+use crate::{
+    error::{LibraError, NotInRange, NotPositive},
+    math::distributions::normal_cdf,
+};
+
+use super::{
+    super::{GeneralizedBlackScholesProcess, YieldTermStructure},
+    StochasticProcess,
+};
+
+/// The variance over `dt` years of a variable mean reverting at `speed` with unit volatility.
+fn mean_reverting_variance(speed: f64, dt: f64) -> f64 {
+    match speed == 0.0 {
+        true => dt,
+        false => -(-2.0 * speed * dt).exp_m1() / (2.0 * speed),
+    }
+}
+
+/// The logarithm of the growth of the forward price of an equity between two times, from its
+/// dividend and risk-free curves.
+fn log_forward_growth(
+    dividend_curve: &dyn YieldTermStructure,
+    risk_free_curve: &dyn YieldTermStructure,
+    time: f64,
+    dt: f64,
+) -> f64 {
+    (dividend_curve.discount_time(time + dt) / dividend_curve.discount_time(time)).ln()
+        - (risk_free_curve.discount_time(time + dt) / risk_free_curve.discount_time(time)).ln()
+}
+
+/// Geometric Brownian motion with constant drift and volatility,
+/// `dS = mu S dt + sigma S dW`, which is stepped exactly.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GeometricBrownianMotion {
+    initial_value: f64,
+    drift: f64,
+    volatility: f64,
+}
+
+impl GeometricBrownianMotion {
+    /// Creates the process.
+    ///
+    /// # Arguments
+    ///
+    /// * `initial_value` - The value at time zero.
+    /// * `drift` - The annualised drift `mu`.
+    /// * `volatility` - The annualised volatility `sigma`.
+    pub fn new(initial_value: f64, drift: f64, volatility: f64) -> Self {
+        GeometricBrownianMotion {
+            initial_value,
+            drift,
+            volatility,
+        }
+    }
+
+    pub fn initial_value(&self) -> f64 {
+        self.initial_value
+    }
+
+    pub fn drift(&self) -> f64 {
+        self.drift
+    }
+
+    pub fn volatility(&self) -> f64 {
+        self.volatility
+    }
+}
+
+impl StochasticProcess for GeometricBrownianMotion {
+    fn size(&self) -> usize {
+        1
+    }
+
+    fn factors(&self) -> usize {
+        1
+    }
+
+    fn initial_values(&self) -> Vec<f64> {
+        vec![self.initial_value]
+    }
+
+    fn evolve(&self, _time: f64, state: &[f64], dt: f64, normals: &[f64]) -> Vec<f64> {
+        let sigma = self.volatility;
+
+        vec![
+            state[0]
+                * ((self.drift - 0.5 * sigma * sigma) * dt + sigma * dt.sqrt() * normals[0]).exp(),
+        ]
+    }
+}
+
+/// The generalized Black-Scholes process is stepped exactly, growing the price of the underlying
+/// along its forward curve.
+impl StochasticProcess for GeneralizedBlackScholesProcess {
+    fn size(&self) -> usize {
+        1
+    }
+
+    fn factors(&self) -> usize {
+        1
+    }
+
+    fn initial_values(&self) -> Vec<f64> {
+        vec![self.spot()]
+    }
+
+    fn evolve(&self, time: f64, state: &[f64], dt: f64, normals: &[f64]) -> Vec<f64> {
+        let sigma = self.volatility();
+        let growth = log_forward_growth(
+            self.dividend_curve().as_ref(),
+            self.risk_free_curve().as_ref(),
+            time,
+            dt,
+        );
+
+        vec![state[0] * (growth - 0.5 * sigma * sigma * dt + sigma * dt.sqrt() * normals[0]).exp()]
+    }
+}
+
+/// The parameters of the Heston model, in which the variance of an equity follows
+/// `dv = kappa (theta - v) dt + sigma sqrt(v) dW`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HestonParameters {
+    v0: f64,
+    kappa: f64,
+    theta: f64,
+    sigma: f64,
+    rho: f64,
+}
+
+impl HestonParameters {
+    /// Creates the parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `v0` - The positive variance at time zero.
+    /// * `kappa` - The positive speed at which the variance reverts to its long-run level.
+    /// * `theta` - The positive long-run level of the variance.
+    /// * `sigma` - The positive volatility of the variance.
+    /// * `rho` - The correlation between the price and its variance, from -1 to 1.
+    ///
+    /// # Returns
+    ///
+    /// The parameters, or an error if any but the correlation is not positive or the correlation
+    /// is not from -1 to 1.
+    pub fn new(v0: f64, kappa: f64, theta: f64, sigma: f64, rho: f64) -> Result<Self, LibraError> {
+        for (name, value) in [
+            ("v0", v0),
+            ("kappa", kappa),
+            ("theta", theta),
+            ("sigma", sigma),
+        ] {
+            if value <= 0.0 || value.is_nan() {
+                return Err(NotPositive::new(name, value).into());
+            }
+        }
+        if !(-1.0..=1.0).contains(&rho) {
+            return Err(NotInRange::new("rho", -1.0, 1.0, rho).into());
+        }
+
+        Ok(HestonParameters {
+            v0,
+            kappa,
+            theta,
+            sigma,
+            rho,
+        })
+    }
+
+    pub fn v0(&self) -> f64 {
+        self.v0
+    }
+
+    pub fn kappa(&self) -> f64 {
+        self.kappa
+    }
+
+    pub fn theta(&self) -> f64 {
+        self.theta
+    }
+
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    pub fn rho(&self) -> f64 {
+        self.rho
+    }
+
+    /// Whether `2 kappa theta >= sigma^2`, under which the variance never reaches zero.
+    pub fn satisfies_feller_condition(&self) -> bool {
+        2.0 * self.kappa * self.theta >= self.sigma * self.sigma
+    }
+}
+
+impl fmt::Display for HestonParameters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Heston (v0 = {}, kappa = {}, theta = {}, sigma = {}, rho = {})",
+            self.v0, self.kappa, self.theta, self.sigma, self.rho
+        )
+    }
+}
+
+/// The Heston stochastic volatility process for an equity, whose state is its price and
+/// variance.
+///
+/// The variance is stepped with the quadratic-exponential scheme of Andersen (2008), which keeps
+/// it non-negative and matches its first two moments over each step, and the logarithm of the
+/// price is integrated with the central discretisation of the variance in the same scheme. The
+/// process takes one normal draw for the variance and one for the price.
+#[derive(Clone)]
+pub struct HestonProcess {
+    spot: f64,
+    dividend_curve: Rc<dyn YieldTermStructure>,
+    risk_free_curve: Rc<dyn YieldTermStructure>,
+    parameters: HestonParameters,
+}
+
+impl HestonProcess {
+    /// Creates the process.
+    ///
+    /// # Arguments
+    ///
+    /// * `spot` - The price of the underlying at time zero.
+    /// * `dividend_curve` - The curve of the underlying's continuous dividend yield.
+    /// * `risk_free_curve` - The curve used to grow the underlying.
+    /// * `parameters` - The parameters of the variance.
+    pub fn new(
+        spot: f64,
+        dividend_curve: Rc<dyn YieldTermStructure>,
+        risk_free_curve: Rc<dyn YieldTermStructure>,
+        parameters: HestonParameters,
+    ) -> Self {
+        HestonProcess {
+            spot,
+            dividend_curve,
+            risk_free_curve,
+            parameters,
+        }
+    }
+
+    pub fn spot(&self) -> f64 {
+        self.spot
+    }
+
+    pub fn dividend_curve(&self) -> &Rc<dyn YieldTermStructure> {
+        &self.dividend_curve
+    }
+
+    pub fn risk_free_curve(&self) -> &Rc<dyn YieldTermStructure> {
+        &self.risk_free_curve
+    }
+
+    pub fn parameters(&self) -> &HestonParameters {
+        &self.parameters
+    }
+}
+
+impl StochasticProcess for HestonProcess {
+    fn size(&self) -> usize {
+        2
+    }
+
+    fn factors(&self) -> usize {
+        2
+    }
+
+    fn initial_values(&self) -> Vec<f64> {
+        vec![self.spot, self.parameters.v0]
+    }
+
+    fn evolve(&self, time: f64, state: &[f64], dt: f64, normals: &[f64]) -> Vec<f64> {
+        // The switching value of the ratio of the variance to the squared mean of the next
+        // variance, above which the exponential branch of the scheme is used.
+        const CRITICAL_PSI: f64 = 1.5;
+
+        let HestonParameters {
+            kappa,
+            theta,
+            sigma,
+            rho,
+            ..
+        } = self.parameters;
+        let (spot, variance) = (state[0], state[1].max(0.0));
+
+        let decay = (-kappa * dt).exp();
+        let mean = theta + (variance - theta) * decay;
+        let second_moment = variance * sigma * sigma * decay * (1.0 - decay) / kappa
+            + theta * sigma * sigma * (1.0 - decay).powi(2) / (2.0 * kappa);
+        let psi = second_moment / (mean * mean);
+
+        let next_variance = if psi <= CRITICAL_PSI {
+            let inverse = 2.0 / psi;
+            let b_squared = inverse - 1.0 + inverse.sqrt() * (inverse - 1.0).sqrt();
+            let a = mean / (1.0 + b_squared);
+            a * (b_squared.sqrt() + normals[0]).powi(2)
+        } else {
+            let p = (psi - 1.0) / (psi + 1.0);
+            let beta = (1.0 - p) / mean;
+            let u = normal_cdf(normals[0]);
+            match u <= p {
+                true => 0.0,
+                false => ((1.0 - p) / (1.0 - u)).ln() / beta,
+            }
+        };
+
+        // The coefficients of the scheme for the logarithm of the price, weighting the variance
+        // equally at the start and end of the step.
+        let k0 = -rho * kappa * theta * dt / sigma;
+        let k1 = 0.5 * dt * (kappa * rho / sigma - 0.5) - rho / sigma;
+        let k2 = 0.5 * dt * (kappa * rho / sigma - 0.5) + rho / sigma;
+        let k3 = 0.5 * dt * (1.0 - rho * rho);
+
+        let growth = log_forward_growth(
+            self.dividend_curve.as_ref(),
+            self.risk_free_curve.as_ref(),
+            time,
+            dt,
+        );
+        let log_return = growth
+            + k0
+            + k1 * variance
+            + k2 * next_variance
+            + (k3 * (variance + next_variance)).sqrt() * normals[1];
+
+        vec![spot * log_return.exp(), next_variance]
+    }
+}
+
+impl fmt::Debug for HestonProcess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HestonProcess")
+            .field("spot", &self.spot)
+            .field("parameters", &self.parameters)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The Hull-White short-rate process `dr = (theta(t) - a r) dt + sigma dW`, with `theta` fitted
+/// to an initial term structure, whose state is the short rate. It is stepped exactly.
+#[derive(Clone)]
+pub struct HullWhiteProcess {
+    curve: Rc<dyn YieldTermStructure>,
+    mean_reversion: f64,
+    volatility: f64,
+}
+
+impl HullWhiteProcess {
+    /// Creates the process.
+    ///
+    /// # Arguments
+    ///
+    /// * `curve` - The initial term structure the process is fitted to.
+    /// * `mean_reversion` - The speed of mean reversion `a`.
+    /// * `volatility` - The volatility of the short rate `sigma`.
+    pub fn new(curve: Rc<dyn YieldTermStructure>, mean_reversion: f64, volatility: f64) -> Self {
+        HullWhiteProcess {
+            curve,
+            mean_reversion,
+            volatility,
+        }
+    }
+
+    pub fn curve(&self) -> &Rc<dyn YieldTermStructure> {
+        &self.curve
+    }
+
+    pub fn mean_reversion(&self) -> f64 {
+        self.mean_reversion
+    }
+
+    pub fn volatility(&self) -> f64 {
+        self.volatility
+    }
+
+    /// The price at `time` of a zero-coupon bond maturing at `maturity` when the short rate is
+    /// `rate`.
+    pub fn discount_bond(&self, time: f64, maturity: f64, rate: f64) -> f64 {
+        let a = self.mean_reversion;
+        let sigma = self.volatility;
+        let b = self.b(maturity - time);
+        let short_rate_variance = sigma * sigma * mean_reverting_variance(a, time);
+
+        self.curve.discount_time(maturity) / self.curve.discount_time(time)
+            * (b * self.curve.instantaneous_forward(time)
+                - 0.5 * short_rate_variance * b * b
+                - b * rate)
+                .exp()
+    }
+
+    /// The sensitivity of the logarithm of a zero-coupon bond with `tenor` years to run to the
+    /// short rate.
+    fn b(&self, tenor: f64) -> f64 {
+        match self.mean_reversion == 0.0 {
+            true => tenor,
+            false => -(-self.mean_reversion * tenor).exp_m1() / self.mean_reversion,
+        }
+    }
+
+    /// The expected short rate at `time` when its shock is zero, about which it mean reverts.
+    fn alpha(&self, time: f64) -> f64 {
+        let sigma = self.volatility;
+        let b = self.b(time);
+
+        self.curve.instantaneous_forward(time) + 0.5 * sigma * sigma * b * b
+    }
+}
+
+impl StochasticProcess for HullWhiteProcess {
+    fn size(&self) -> usize {
+        1
+    }
+
+    fn factors(&self) -> usize {
+        1
+    }
+
+    fn initial_values(&self) -> Vec<f64> {
+        vec![self.curve.instantaneous_forward(0.0)]
+    }
+
+    fn evolve(&self, time: f64, state: &[f64], dt: f64, normals: &[f64]) -> Vec<f64> {
+        let decay = (-self.mean_reversion * dt).exp();
+        let std_dev = self.volatility * mean_reverting_variance(self.mean_reversion, dt).sqrt();
+
+        vec![(state[0] - self.alpha(time)) * decay + self.alpha(time + dt) + std_dev * normals[0]]
+    }
+}
+
+impl fmt::Debug for HullWhiteProcess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HullWhiteProcess")
+            .field("mean_reversion", &self.mean_reversion)
+            .field("volatility", &self.volatility)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The Ornstein-Uhlenbeck process `dx = a (level - x) dt + sigma dW`, which is stepped exactly.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OrnsteinUhlenbeckProcess {
+    initial_value: f64,
+    speed: f64,
+    level: f64,
+    volatility: f64,
+}
+
+impl OrnsteinUhlenbeckProcess {
+    /// Creates the process.
+    ///
+    /// # Arguments
+    ///
+    /// * `initial_value` - The value at time zero.
+    /// * `speed` - The speed of mean reversion `a`.
+    /// * `level` - The level the process reverts to.
+    /// * `volatility` - The volatility `sigma`.
+    pub fn new(initial_value: f64, speed: f64, level: f64, volatility: f64) -> Self {
+        OrnsteinUhlenbeckProcess {
+            initial_value,
+            speed,
+            level,
+            volatility,
+        }
+    }
+
+    pub fn initial_value(&self) -> f64 {
+        self.initial_value
+    }
+
+    pub fn speed(&self) -> f64 {
+        self.speed
+    }
+
+    pub fn level(&self) -> f64 {
+        self.level
+    }
+
+    pub fn volatility(&self) -> f64 {
+        self.volatility
+    }
+}
+
+impl StochasticProcess for OrnsteinUhlenbeckProcess {
+    fn size(&self) -> usize {
+        1
+    }
+
+    fn factors(&self) -> usize {
+        1
+    }
+
+    fn initial_values(&self) -> Vec<f64> {
+        vec![self.initial_value]
+    }
+
+    fn evolve(&self, _time: f64, state: &[f64], dt: f64, normals: &[f64]) -> Vec<f64> {
+        let decay = (-self.speed * dt).exp();
+        let std_dev = self.volatility * mean_reverting_variance(self.speed, dt).sqrt();
+
+        vec![self.level + (state[0] - self.level) * decay + std_dev * normals[0]]
+    }
+}
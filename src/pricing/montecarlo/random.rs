@@ -0,0 +1,199 @@
+/// The number of values a 32-bit generator can produce.
+const TWO_POW_32: f64 = 4_294_967_296.0;
+
+/// A generator of pseudo-random 32-bit integers, which reproduces the same sequence from the
+/// same seed.
+pub trait UniformRandomGenerator {
+    /// The next integer, uniform over all 32-bit values.
+    fn next_u32(&mut self) -> u32;
+
+    /// The next number, uniform on the open interval (0, 1), with 53 bits of resolution taken
+    /// from two integers.
+    fn next_uniform(&mut self) -> f64 {
+        let high = (self.next_u32() >> 5) as f64;
+        let low = (self.next_u32() >> 6) as f64;
+
+        (high * 67_108_864.0 + low + 0.5) / 9_007_199_254_740_992.0
+    }
+}
+
+/// A generator of points uniform on the open unit hypercube of a fixed dimension, either
+/// pseudo-random or low-discrepancy.
+pub trait UniformSequenceGenerator {
+    /// The number of coordinates of each point.
+    fn dimension(&self) -> usize;
+
+    /// The next point of the sequence.
+    fn next_sequence(&mut self) -> Vec<f64>;
+}
+
+/// The Mersenne Twister MT19937 of Matsumoto and Nishimura, with a period of `2^19937 - 1`.
+#[derive(Clone, Debug)]
+pub struct MersenneTwister {
+    state: Box<[u32; MersenneTwister::N]>,
+    index: usize,
+}
+
+impl MersenneTwister {
+    const N: usize = 624;
+    const M: usize = 397;
+    const MATRIX_A: u32 = 0x9908_b0df;
+    const UPPER_MASK: u32 = 0x8000_0000;
+    const LOWER_MASK: u32 = 0x7fff_ffff;
+
+    /// Creates a generator, initialised from `seed` as in the reference implementation.
+    pub fn new(seed: u32) -> Self {
+        let mut state = Box::new([0; Self::N]);
+        state[0] = seed;
+        for i in 1..Self::N {
+            let previous = state[i - 1];
+            state[i] = 1_812_433_253_u32
+                .wrapping_mul(previous ^ (previous >> 30))
+                .wrapping_add(i as u32);
+        }
+
+        MersenneTwister {
+            state,
+            index: Self::N,
+        }
+    }
+
+    /// Regenerates the whole state once all of it has been used.
+    fn twist(&mut self) {
+        let state = &mut self.state;
+        for i in 0..Self::N {
+            let y = (state[i] & Self::UPPER_MASK) | (state[(i + 1) % Self::N] & Self::LOWER_MASK);
+            let mag = if y & 1 == 1 { Self::MATRIX_A } else { 0 };
+            state[i] = state[(i + Self::M) % Self::N] ^ (y >> 1) ^ mag;
+        }
+        self.index = 0;
+    }
+}
+
+impl UniformRandomGenerator for MersenneTwister {
+    fn next_u32(&mut self) -> u32 {
+        if self.index >= Self::N {
+            self.twist();
+        }
+
+        let mut y = self.state[self.index];
+        self.index += 1;
+
+        y ^= y >> 11;
+        y ^= (y << 7) & 0x9d2c_5680;
+        y ^= (y << 15) & 0xefc6_0000;
+        y ^ (y >> 18)
+    }
+}
+
+/// The permuted congruential generator PCG32 of O'Neill, the XSH-RR output of a 64-bit linear
+/// congruential generator, which can produce `2^63` independent streams.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pcg32 {
+    state: u64,
+    increment: u64,
+}
+
+impl Pcg32 {
+    const MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+
+    /// Creates a generator for the first stream, initialised from `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self::with_stream(seed, 0)
+    }
+
+    /// Creates a generator for `stream`, initialised from `seed`. Generators for different
+    /// streams produce independent sequences from the same seed.
+    pub fn with_stream(seed: u64, stream: u64) -> Self {
+        let mut generator = Pcg32 {
+            state: 0,
+            increment: (stream << 1) | 1,
+        };
+        generator.step();
+        generator.state = generator.state.wrapping_add(seed);
+        generator.step();
+        generator
+    }
+
+    fn step(&mut self) {
+        self.state = self
+            .state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(self.increment);
+    }
+}
+
+impl UniformRandomGenerator for Pcg32 {
+    fn next_u32(&mut self) -> u32 {
+        let old = self.state;
+        self.step();
+
+        let shifted = (((old >> 18) ^ old) >> 27) as u32;
+        shifted.rotate_right((old >> 59) as u32)
+    }
+}
+
+/// Points whose coordinates are independent draws from a pseudo-random generator.
+#[derive(Clone, Debug)]
+pub struct PseudoRandomSequence<R: UniformRandomGenerator> {
+    generator: R,
+    dimension: usize,
+}
+
+impl<R: UniformRandomGenerator> PseudoRandomSequence<R> {
+    /// Creates a sequence of points with `dimension` coordinates drawn from `generator`.
+    pub fn new(generator: R, dimension: usize) -> Self {
+        PseudoRandomSequence {
+            generator,
+            dimension,
+        }
+    }
+
+    pub fn generator(&self) -> &R {
+        &self.generator
+    }
+}
+
+impl<R: UniformRandomGenerator> UniformSequenceGenerator for PseudoRandomSequence<R> {
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn next_sequence(&mut self) -> Vec<f64> {
+        (0..self.dimension)
+            .map(|_| self.generator.next_uniform())
+            .collect()
+    }
+}
+
+/// The number `(n + 0.5) / 2^32`, uniform on the open interval (0, 1) when `n` is a uniform
+/// 32-bit integer.
+pub(crate) fn u32_to_uniform(n: u32) -> f64 {
+    (n as f64 + 0.5) / TWO_POW_32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MersenneTwister, UniformRandomGenerator};
+
+    #[test]
+    fn mersenne_twister_matches_the_reference_outputs() {
+        let mut generator = MersenneTwister::new(5489);
+
+        let first: Vec<u32> = (0..5).map(|_| generator.next_u32()).collect();
+        assert_eq!(
+            first,
+            [
+                3_499_211_612,
+                581_869_302,
+                3_890_346_734,
+                3_586_334_585,
+                545_404_204
+            ]
+        );
+
+        // The 10000th output of the default-seeded generator, as required by the C++ standard.
+        let last = (5..10_000).map(|_| generator.next_u32()).last().unwrap();
+        assert_eq!(last, 4_123_659_995);
+    }
+}
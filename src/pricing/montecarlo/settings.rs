@@ -0,0 +1,169 @@
+use std::{fmt, rc::Rc};
+
+use crate::error::LibraError;
+
+use super::{
+    HaltonSequence, MersenneTwister, MonteCarloSimulation, PathGenerator, Pcg32,
+    PseudoRandomSequence, SobolSequence, StochasticProcess, TimeGrid, UniformSequenceGenerator,
+};
+
+/// The seed of pseudo-random sequences when none is given.
+pub const DEFAULT_SEED: u32 = 42;
+
+/// The kind of uniform sequence that drives a simulation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SequenceType {
+    /// Pseudo-random numbers from the Mersenne Twister.
+    MersenneTwister,
+    /// Pseudo-random numbers from PCG32.
+    Pcg,
+    /// The Sobol low-discrepancy sequence.
+    Sobol,
+    /// The Halton low-discrepancy sequence.
+    Halton,
+}
+
+impl fmt::Display for SequenceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SequenceType::MersenneTwister => write!(f, "Mersenne Twister"),
+            SequenceType::Pcg => write!(f, "PCG32"),
+            SequenceType::Sobol => write!(f, "Sobol"),
+            SequenceType::Halton => write!(f, "Halton"),
+        }
+    }
+}
+
+/// How Monte Carlo engines simulate paths: the number of samples and time steps, the sequence
+/// driving the paths and its seed, and the variance reduction used.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MonteCarloSettings {
+    samples: usize,
+    steps: usize,
+    sequence: SequenceType,
+    seed: Option<u32>,
+    brownian_bridge: bool,
+    antithetic: bool,
+}
+
+impl MonteCarloSettings {
+    /// Creates settings for pseudo-random paths from the Mersenne Twister, without variance
+    /// reduction.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - The number of samples, each of which is over two paths with antithetic
+    ///   variates.
+    /// * `steps` - The number of time steps to the last time the engine needs, to which other
+    ///   times it needs are added.
+    pub fn new(samples: usize, steps: usize) -> Self {
+        MonteCarloSettings {
+            samples,
+            steps,
+            sequence: SequenceType::MersenneTwister,
+            seed: None,
+            brownian_bridge: false,
+            antithetic: false,
+        }
+    }
+
+    /// Drives the paths with `sequence` instead.
+    pub fn with_sequence(mut self, sequence: SequenceType) -> Self {
+        self.sequence = sequence;
+        self
+    }
+
+    /// Seeds pseudo-random sequences with `seed` instead of [`DEFAULT_SEED`], and randomises
+    /// low-discrepancy sequences with a shift drawn from it, which are otherwise unrandomised.
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Builds the paths with a Brownian bridge.
+    pub fn with_brownian_bridge(mut self) -> Self {
+        self.brownian_bridge = true;
+        self
+    }
+
+    /// Uses antithetic variates.
+    pub fn with_antithetic_variates(mut self) -> Self {
+        self.antithetic = true;
+        self
+    }
+
+    pub fn samples(&self) -> usize {
+        self.samples
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn sequence(&self) -> SequenceType {
+        self.sequence
+    }
+
+    pub fn seed(&self) -> Option<u32> {
+        self.seed
+    }
+
+    pub fn brownian_bridge(&self) -> bool {
+        self.brownian_bridge
+    }
+
+    pub fn antithetic(&self) -> bool {
+        self.antithetic
+    }
+
+    /// Sets up a simulation of `process` on `grid` with these settings.
+    ///
+    /// # Returns
+    ///
+    /// The simulation, or an error if the Sobol sequence is used and there are more draws per
+    /// path than its largest dimension.
+    pub fn simulation(
+        &self,
+        process: Rc<dyn StochasticProcess>,
+        grid: TimeGrid,
+    ) -> Result<MonteCarloSimulation, LibraError> {
+        let dimension = grid.steps() * process.factors();
+        let seed = self.seed.unwrap_or(DEFAULT_SEED);
+
+        let sequence: Box<dyn UniformSequenceGenerator> = match self.sequence {
+            SequenceType::MersenneTwister => Box::new(PseudoRandomSequence::new(
+                MersenneTwister::new(seed),
+                dimension,
+            )),
+            SequenceType::Pcg => Box::new(PseudoRandomSequence::new(
+                Pcg32::new(seed as u64),
+                dimension,
+            )),
+            SequenceType::Sobol => {
+                let sobol = SobolSequence::new(dimension)?;
+                match self.seed {
+                    Some(seed) => Box::new(sobol.with_digital_shift(seed)),
+                    None => Box::new(sobol),
+                }
+            }
+            SequenceType::Halton => {
+                let halton = HaltonSequence::new(dimension);
+                match self.seed {
+                    Some(seed) => Box::new(halton.with_random_shift(seed)),
+                    None => Box::new(halton),
+                }
+            }
+        };
+
+        let mut generator = PathGenerator::new(process, grid, sequence)?;
+        if self.brownian_bridge {
+            generator = generator.with_brownian_bridge();
+        }
+
+        let simulation = MonteCarloSimulation::new(generator);
+        Ok(match self.antithetic {
+            true => simulation.with_antithetic_variates(),
+            false => simulation,
+        })
+    }
+}
@@ -0,0 +1,184 @@
+use crate::error::{ArgumentRange, LibraError};
+
+use super::{Path, PathGenerator, Statistics};
+
+/// Estimates the expectation of a function of the paths of a stochastic process, such as the
+/// discounted payoff of a path-dependent option, by averaging it over simulated paths.
+///
+/// The variance of the estimate can be reduced with antithetic variates, which average each path
+/// with its mirror image, and with a control variate, a function of the path with a known
+/// expectation whose error on each path is used to correct the estimate.
+#[derive(Debug)]
+pub struct MonteCarloSimulation {
+    generator: PathGenerator,
+    antithetic: bool,
+}
+
+impl MonteCarloSimulation {
+    /// Creates a simulation of the paths of `generator`.
+    pub fn new(generator: PathGenerator) -> Self {
+        MonteCarloSimulation {
+            generator,
+            antithetic: false,
+        }
+    }
+
+    /// Takes each sample as the average over a path and its antithetic path.
+    pub fn with_antithetic_variates(mut self) -> Self {
+        self.antithetic = true;
+        self
+    }
+
+    pub fn generator(&self) -> &PathGenerator {
+        &self.generator
+    }
+
+    pub fn antithetic(&self) -> bool {
+        self.antithetic
+    }
+
+    /// Simulates `samples` samples of `pricer`, continuing from the paths already simulated.
+    ///
+    /// # Returns
+    ///
+    /// The statistics of the samples, each of which is over two paths with antithetic variates.
+    pub fn run(&mut self, pricer: &dyn Fn(&Path) -> f64, samples: usize) -> Statistics {
+        let mut statistics = Statistics::new();
+        for _ in 0..samples {
+            statistics.add(self.sample(pricer));
+        }
+
+        statistics
+    }
+
+    /// Simulates samples of `pricer` until the standard error of their mean is at most
+    /// `tolerance`.
+    ///
+    /// # Arguments
+    ///
+    /// * `pricer` - The function to find the expectation of.
+    /// * `tolerance` - The target standard error.
+    /// * `min_samples` - The number of samples taken before the error is first checked, of
+    ///   which there are at least two.
+    /// * `max_samples` - The largest number of samples taken.
+    ///
+    /// # Returns
+    ///
+    /// The statistics of the samples, which may not reach the tolerance if `max_samples` are
+    /// taken first.
+    pub fn run_to_tolerance(
+        &mut self,
+        pricer: &dyn Fn(&Path) -> f64,
+        tolerance: f64,
+        min_samples: usize,
+        max_samples: usize,
+    ) -> Statistics {
+        let mut statistics = Statistics::new();
+        let mut next_check = min_samples.max(2);
+
+        while statistics.samples() < max_samples {
+            statistics.add(self.sample(pricer));
+
+            if statistics.samples() == next_check {
+                let error = statistics.standard_error();
+                if error <= tolerance {
+                    break;
+                }
+
+                // The error falls with the square root of the number of samples, so aim for
+                // the number expected to reach the tolerance, growing by at most a factor of two.
+                let target = statistics.samples() as f64 * (error / tolerance).powi(2);
+                next_check = (target.ceil() as usize).clamp(next_check + 1, 2 * next_check);
+            }
+        }
+
+        statistics
+    }
+
+    /// Simulates `samples` samples of `pricer` corrected by a control variate.
+    ///
+    /// Each sample of `pricer` has the error of `control` on the same path, scaled by the
+    /// coefficient which minimises the variance of the result, subtracted from it. The
+    /// coefficient is estimated from the same samples, which biases the estimate by an amount
+    /// negligible beside its standard error.
+    ///
+    /// # Arguments
+    ///
+    /// * `pricer` - The function to find the expectation of.
+    /// * `control` - The control variate, a function correlated with `pricer`.
+    /// * `control_value` - The known expectation of `control`.
+    /// * `samples` - The number of samples, of which there are at least two.
+    ///
+    /// # Returns
+    ///
+    /// The statistics of the corrected samples, or an error if there are fewer than two samples.
+    pub fn run_with_control_variate(
+        &mut self,
+        pricer: &dyn Fn(&Path) -> f64,
+        control: &dyn Fn(&Path) -> f64,
+        control_value: f64,
+        samples: usize,
+    ) -> Result<Statistics, LibraError> {
+        if samples < 2 {
+            return Err(ArgumentRange::new("samples", 2, i64::MAX, samples as i64).into());
+        }
+
+        let pairs: Vec<(f64, f64)> = (0..samples)
+            .map(|_| self.paired_sample(pricer, control))
+            .collect();
+
+        let n = samples as f64;
+        let mean_value = pairs.iter().map(|(v, _)| v).sum::<f64>() / n;
+        let mean_control = pairs.iter().map(|(_, c)| c).sum::<f64>() / n;
+        let (covariance, control_variance) =
+            pairs
+                .iter()
+                .fold((0.0, 0.0), |(covariance, variance), (v, c)| {
+                    (
+                        covariance + (v - mean_value) * (c - mean_control),
+                        variance + (c - mean_control).powi(2),
+                    )
+                });
+        let beta = match control_variance > 0.0 {
+            true => covariance / control_variance,
+            false => 0.0,
+        };
+
+        let mut statistics = Statistics::new();
+        for (value, control) in pairs {
+            statistics.add(value - beta * (control - control_value));
+        }
+
+        Ok(statistics)
+    }
+
+    /// One sample of `pricer`, averaged with the antithetic path if required.
+    fn sample(&mut self, pricer: &dyn Fn(&Path) -> f64) -> f64 {
+        let path = self.generator.next_path();
+
+        match self.antithetic {
+            true => 0.5 * (pricer(&path) + pricer(&self.generator.antithetic_path())),
+            false => pricer(&path),
+        }
+    }
+
+    /// One sample each of `pricer` and `control` on the same paths.
+    fn paired_sample(
+        &mut self,
+        pricer: &dyn Fn(&Path) -> f64,
+        control: &dyn Fn(&Path) -> f64,
+    ) -> (f64, f64) {
+        let path = self.generator.next_path();
+
+        match self.antithetic {
+            true => {
+                let antithetic = self.generator.antithetic_path();
+                (
+                    0.5 * (pricer(&path) + pricer(&antithetic)),
+                    0.5 * (control(&path) + control(&antithetic)),
+                )
+            }
+            false => (pricer(&path), control(&path)),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::math::distributions::inverse_normal_cdf;
+
+/// Accumulates samples of a Monte Carlo estimate, updating their mean and variance with the
+/// method of Welford so that no samples need to be kept.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Statistics {
+    samples: usize,
+    mean: f64,
+    sum_squared_deviations: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for Statistics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Statistics {
+    /// Creates an accumulator with no samples.
+    pub fn new() -> Self {
+        Statistics {
+            samples: 0,
+            mean: 0.0,
+            sum_squared_deviations: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Adds a sample.
+    pub fn add(&mut self, value: f64) {
+        self.samples += 1;
+        let deviation = value - self.mean;
+        self.mean += deviation / self.samples as f64;
+        self.sum_squared_deviations += deviation * (value - self.mean);
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    /// The number of samples added.
+    pub fn samples(&self) -> usize {
+        self.samples
+    }
+
+    /// The mean of the samples, which is the Monte Carlo estimate.
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// The unbiased variance of the samples, or zero if there are fewer than two.
+    pub fn variance(&self) -> f64 {
+        match self.samples {
+            0 | 1 => 0.0,
+            n => self.sum_squared_deviations / (n - 1) as f64,
+        }
+    }
+
+    pub fn standard_deviation(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// The standard error of the mean, the standard deviation of the estimate.
+    pub fn standard_error(&self) -> f64 {
+        match self.samples {
+            0 => 0.0,
+            n => (self.variance() / n as f64).sqrt(),
+        }
+    }
+
+    /// The two-sided confidence interval for the expectation at `level`, such as 0.95, from the
+    /// normal approximation to the distribution of the mean.
+    ///
+    /// # Returns
+    ///
+    /// The lower and upper ends of the interval.
+    pub fn confidence_interval(&self, level: f64) -> (f64, f64) {
+        let half_width = inverse_normal_cdf(0.5 + 0.5 * level) * self.standard_error();
+
+        (self.mean - half_width, self.mean + half_width)
+    }
+
+    /// The smallest sample, or infinity if there are none.
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// The largest sample, or negative infinity if there are none.
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+}
@@ -0,0 +1,152 @@
+use chrono::NaiveDate;
+
+use crate::{
+    error::{ArgumentRange, InvalidDate, LibraError, NotPositive},
+    time::{DayCountConvention, Schedule},
+};
+
+/// The gap below which two times are treated as the same.
+const TIME_TOLERANCE: f64 = 1.0e-10;
+
+/// The times at which paths are simulated, starting from zero, which include a set of mandatory
+/// times such as fixing or exercise dates.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeGrid {
+    times: Vec<f64>,
+    mandatory_indices: Vec<usize>,
+}
+
+impl TimeGrid {
+    /// Creates a grid of `steps` equal steps to `end`, which is its only mandatory time.
+    ///
+    /// # Returns
+    ///
+    /// The grid, or an error if `end` is not positive or `steps` is zero.
+    pub fn new(end: f64, steps: usize) -> Result<Self, LibraError> {
+        Self::with_mandatory_times(&[end], steps)
+    }
+
+    /// Creates a grid through `mandatory_times`, splitting the gap between each mandatory time and
+    /// the one before into equal steps no longer than the last time over `steps`.
+    ///
+    /// # Returns
+    ///
+    /// The grid, or an error if `steps` is zero or there is no positive mandatory time. Times at
+    /// or before zero are ignored.
+    pub fn with_mandatory_times(mandatory_times: &[f64], steps: usize) -> Result<Self, LibraError> {
+        if steps == 0 {
+            return Err(ArgumentRange::new("steps", 1, i64::MAX, 0).into());
+        }
+
+        let mut mandatory: Vec<f64> = mandatory_times
+            .iter()
+            .copied()
+            .filter(|t| *t > TIME_TOLERANCE)
+            .collect();
+        mandatory.sort_by(f64::total_cmp);
+        mandatory.dedup_by(|a, b| (*a - *b).abs() < TIME_TOLERANCE);
+
+        let end = match mandatory.last() {
+            Some(end) if end.is_finite() => *end,
+            _ => {
+                let latest = mandatory_times.iter().copied().fold(0.0, f64::max);
+                return Err(NotPositive::new("last mandatory time", latest).into());
+            }
+        };
+        let max_step = end / steps as f64;
+
+        let mut times = vec![0.0];
+        let mut mandatory_indices = Vec::with_capacity(mandatory.len());
+        for time in mandatory {
+            let start = times[times.len() - 1];
+            let gap_steps = ((time - start) / max_step - TIME_TOLERANCE).ceil().max(1.0) as usize;
+
+            times.extend(
+                (1..=gap_steps).map(|i| start + (time - start) * i as f64 / gap_steps as f64),
+            );
+            mandatory_indices.push(times.len() - 1);
+        }
+
+        Ok(TimeGrid {
+            times,
+            mandatory_indices,
+        })
+    }
+
+    /// Creates a grid through the dates of `schedule` after `reference_date`.
+    ///
+    /// # Arguments
+    ///
+    /// * `schedule` - The schedule whose dates are the mandatory times.
+    /// * `reference_date` - The date of time zero. Schedule dates on or before it are ignored.
+    /// * `day_count` - The day count convention used to convert dates to times.
+    /// * `steps` - The number of steps to the last date if the dates were evenly spaced.
+    ///
+    /// # Returns
+    ///
+    /// The grid, or an error if no schedule date is after the reference date or `steps` is zero.
+    pub fn from_schedule(
+        schedule: &Schedule,
+        reference_date: NaiveDate,
+        day_count: DayCountConvention,
+        steps: usize,
+    ) -> Result<Self, LibraError> {
+        let times = schedule
+            .dates()
+            .iter()
+            .filter(|d| **d > reference_date)
+            .map(|d| day_count.year_frac(&reference_date, d))
+            .collect::<Result<Vec<f64>, InvalidDate>>()?;
+
+        if times.is_empty() {
+            return Err(InvalidDate.into());
+        }
+
+        Self::with_mandatory_times(&times, steps)
+    }
+
+    /// The times of the grid, starting from zero.
+    pub fn times(&self) -> &[f64] {
+        &self.times
+    }
+
+    /// The number of steps in the grid, which is one fewer than the number of times.
+    pub fn steps(&self) -> usize {
+        self.times.len() - 1
+    }
+
+    /// The length of step `i`, which ends at time `i + 1`.
+    pub fn dt(&self, i: usize) -> f64 {
+        self.times[i + 1] - self.times[i]
+    }
+
+    /// The indices in the grid of the mandatory times, in increasing order.
+    pub fn mandatory_indices(&self) -> &[usize] {
+        &self.mandatory_indices
+    }
+
+    /// The mandatory times, in increasing order.
+    pub fn mandatory_times(&self) -> Vec<f64> {
+        self.mandatory_indices
+            .iter()
+            .map(|i| self.times[*i])
+            .collect()
+    }
+
+    /// The last time of the grid.
+    pub fn end(&self) -> f64 {
+        self.times[self.times.len() - 1]
+    }
+
+    /// The index of the time in the grid closest to `time`.
+    pub fn closest_index(&self, time: f64) -> usize {
+        let position = self.times.partition_point(|t| *t < time);
+
+        match position {
+            0 => 0,
+            p if p == self.times.len() => p - 1,
+            p if time - self.times[p - 1] <= self.times[p] - time => p - 1,
+            p => p,
+        }
+    }
+}
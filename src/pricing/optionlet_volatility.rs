@@ -0,0 +1,193 @@
+use std::fmt;
+
+use chrono::NaiveDate;
+
+use crate::{
+    error::{ArgumentRange, LibraError},
+    math::interpolation::{
+        Extrapolation, Interpolation, Interpolation2D, Interpolator, Interpolator2D,
+    },
+    time::DayCountConvention,
+};
+
+/// The distribution of rates a volatility is quoted for.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VolatilityType {
+    /// Black's model, in which the rate plus a shift is lognormal. A shift of zero is the
+    /// unshifted model.
+    ShiftedLognormal { shift: f64 },
+    /// Bachelier's model, in which the rate is normal.
+    Normal,
+}
+
+impl fmt::Display for VolatilityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VolatilityType::ShiftedLognormal { shift } if *shift == 0.0 => write!(f, "Lognormal"),
+            VolatilityType::ShiftedLognormal { shift } => {
+                write!(f, "Shifted lognormal ({})", shift)
+            }
+            VolatilityType::Normal => write!(f, "Normal"),
+        }
+    }
+}
+
+/// The interpolation of a surface along whichever of its axes have more than one point.
+#[derive(Clone, Debug)]
+enum Surface {
+    Constant(f64),
+    Expiries(Interpolator),
+    Strikes(Interpolator),
+    Grid(Interpolator2D),
+}
+
+/// The volatilities of optionlets on a rate, such as caplets and floorlets, by expiry and
+/// strike.
+///
+/// Volatilities are interpolated linearly in expiry time and strike, and extrapolated flat.
+/// Time is measured in Actual/365 years from the reference date.
+#[derive(Clone, Debug)]
+pub struct OptionletVolatilitySurface {
+    reference_date: NaiveDate,
+    volatility_type: VolatilityType,
+    expiries: Vec<NaiveDate>,
+    strikes: Vec<f64>,
+    volatilities: Vec<Vec<f64>>,
+    surface: Surface,
+}
+
+impl OptionletVolatilitySurface {
+    /// Creates a surface from volatilities on a grid of expiries and strikes.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference_date` - The date volatilities are measured from.
+    /// * `expiries` - The strictly increasing expiry dates of the grid.
+    /// * `strikes` - The strictly increasing strikes of the grid.
+    /// * `volatilities` - The volatilities, with a row for each expiry holding the volatility
+    ///   at each strike.
+    /// * `volatility_type` - The model the volatilities are quoted for.
+    ///
+    /// # Returns
+    ///
+    /// The surface, or an error if no expiries or strikes are given, the shape of
+    /// `volatilities` does not match them, or an expiry is before the reference date.
+    pub fn new(
+        reference_date: NaiveDate,
+        expiries: Vec<NaiveDate>,
+        strikes: Vec<f64>,
+        volatilities: Vec<Vec<f64>>,
+        volatility_type: VolatilityType,
+    ) -> Result<Self, LibraError> {
+        if expiries.is_empty() {
+            return Err(ArgumentRange::new("number of expiries", 1, i64::MAX, 0).into());
+        }
+        if strikes.is_empty() {
+            return Err(ArgumentRange::new("number of strikes", 1, i64::MAX, 0).into());
+        }
+        if let Some(row) = volatilities.iter().find(|row| row.len() != strikes.len()) {
+            let n = strikes.len() as i64;
+            return Err(
+                ArgumentRange::new("number of volatilities", n, n, row.len() as i64).into(),
+            );
+        }
+        if volatilities.len() != expiries.len() {
+            let n = expiries.len() as i64;
+            return Err(
+                ArgumentRange::new("number of expiries", n, n, volatilities.len() as i64).into(),
+            );
+        }
+
+        let day_count = DayCountConvention::Actual365Fixed;
+        let times = expiries
+            .iter()
+            .map(|e| day_count.year_frac(&reference_date, e))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let linear = |xs: Vec<f64>, ys: Vec<f64>| -> Result<Interpolator, LibraError> {
+            Ok(Interpolator::new(Interpolation::Linear, xs, ys)?
+                .with_extrapolation(Extrapolation::Flat))
+        };
+
+        let surface = match (expiries.len(), strikes.len()) {
+            (1, 1) => Surface::Constant(volatilities[0][0]),
+            (1, _) => Surface::Strikes(linear(strikes.clone(), volatilities[0].clone())?),
+            (_, 1) => Surface::Expiries(linear(
+                times,
+                volatilities.iter().map(|row| row[0]).collect(),
+            )?),
+            _ => Surface::Grid(
+                Interpolator2D::new(
+                    Interpolation2D::Bilinear,
+                    times,
+                    strikes.clone(),
+                    volatilities.clone(),
+                )?
+                .with_extrapolation(Extrapolation::Flat),
+            ),
+        };
+
+        Ok(OptionletVolatilitySurface {
+            reference_date,
+            volatility_type,
+            expiries,
+            strikes,
+            volatilities,
+            surface,
+        })
+    }
+
+    /// Creates a surface with the same volatility at every expiry and strike.
+    pub fn constant(
+        reference_date: NaiveDate,
+        volatility: f64,
+        volatility_type: VolatilityType,
+    ) -> Self {
+        OptionletVolatilitySurface {
+            reference_date,
+            volatility_type,
+            expiries: vec![reference_date],
+            strikes: vec![0.0],
+            volatilities: vec![vec![volatility]],
+            surface: Surface::Constant(volatility),
+        }
+    }
+
+    pub fn reference_date(&self) -> NaiveDate {
+        self.reference_date
+    }
+
+    pub fn volatility_type(&self) -> VolatilityType {
+        self.volatility_type
+    }
+
+    pub fn expiries(&self) -> &[NaiveDate] {
+        &self.expiries
+    }
+
+    pub fn strikes(&self) -> &[f64] {
+        &self.strikes
+    }
+
+    /// The volatilities on the grid, with a row for each expiry.
+    pub fn volatilities(&self) -> &[Vec<f64>] {
+        &self.volatilities
+    }
+
+    /// The time in years from the reference date to `date`.
+    pub fn time_from_reference(&self, date: NaiveDate) -> Result<f64, LibraError> {
+        Ok(DayCountConvention::Actual365Fixed.year_frac(&self.reference_date, &date)?)
+    }
+
+    /// The volatility of an optionlet expiring on `expiry` with strike `strike`.
+    pub fn volatility(&self, expiry: NaiveDate, strike: f64) -> Result<f64, LibraError> {
+        let time = self.time_from_reference(expiry)?;
+
+        Ok(match &self.surface {
+            Surface::Constant(volatility) => *volatility,
+            Surface::Expiries(interpolator) => interpolator.value(time),
+            Surface::Strikes(interpolator) => interpolator.value(strike),
+            Surface::Grid(interpolator) => interpolator.value(time, strike),
+        })
+    }
+}
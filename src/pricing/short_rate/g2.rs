@@ -0,0 +1,290 @@
+use std::fmt;
+
+use crate::error::{ArgumentRange, LibraError, NotInRange};
+
+use super::{
+    tree::{branches, geometry},
+    ShortRateLattice,
+};
+
+/// The two-factor additive Gaussian model G2++, in which the short rate is
+/// `r(t) = x(t) + y(t) + phi(t)` for correlated Ornstein-Uhlenbeck factors
+/// `dx = -a x dt + sigma dW1` and `dy = -b y dt + eta dW2`, with `phi` fitted to an initial term
+/// structure.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct G2Model {
+    a: f64,
+    sigma: f64,
+    b: f64,
+    eta: f64,
+    rho: f64,
+}
+
+impl G2Model {
+    /// Creates a model.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The mean reversion of the first factor.
+    /// * `sigma` - The volatility of the first factor.
+    /// * `b` - The mean reversion of the second factor.
+    /// * `eta` - The volatility of the second factor.
+    /// * `rho` - The correlation between the factors, from -1 to 1.
+    ///
+    /// # Returns
+    ///
+    /// The model, or an error if the correlation is not from -1 to 1.
+    pub fn new(a: f64, sigma: f64, b: f64, eta: f64, rho: f64) -> Result<Self, LibraError> {
+        if !(-1.0..=1.0).contains(&rho) {
+            return Err(NotInRange::new("correlation", -1.0, 1.0, rho).into());
+        }
+
+        Ok(G2Model {
+            a,
+            sigma,
+            b,
+            eta,
+            rho,
+        })
+    }
+
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+
+    pub fn eta(&self) -> f64 {
+        self.eta
+    }
+
+    pub fn rho(&self) -> f64 {
+        self.rho
+    }
+}
+
+impl fmt::Display for G2Model {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "G2++ (a = {}, sigma = {}, b = {}, eta = {}, rho = {})",
+            self.a, self.sigma, self.b, self.eta, self.rho
+        )
+    }
+}
+
+/// The Hull-White grid of one factor of a two-factor tree.
+#[derive(Clone, Copy, Debug)]
+struct Factor {
+    dx: f64,
+    m: f64,
+    j_max: usize,
+}
+
+impl Factor {
+    fn width(&self, i: usize) -> usize {
+        i.min(self.j_max)
+    }
+
+    fn size(&self, i: usize) -> usize {
+        2 * self.width(i) + 1
+    }
+}
+
+/// A two-dimensional trinomial tree for the G2++ model, built as the product of a Hull-White
+/// tree for each factor with the branching probabilities adjusted for their correlation, as
+/// described by Hull and White (1994), and fitted so that it reprices an initial discount
+/// curve exactly.
+///
+/// The node `(j, k)` of step `i`, for `j` and `k` from `-width` to `width` of each factor, is
+/// stored at `(j + width_x) * size_y + k + width_y`.
+#[derive(Clone, Debug)]
+pub struct G2Tree {
+    model: G2Model,
+    dt: f64,
+    x: Factor,
+    y: Factor,
+    alphas: Vec<f64>,
+}
+
+impl G2Tree {
+    /// Builds a tree and fits it to a discount curve.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model.
+    /// * `discount` - The discount factor for a time in years.
+    /// * `end` - The time in years of the last step of the tree.
+    /// * `steps` - The number of time steps.
+    pub fn new(
+        model: G2Model,
+        discount: &dyn Fn(f64) -> f64,
+        end: f64,
+        steps: usize,
+    ) -> Result<Self, LibraError> {
+        if steps == 0 {
+            return Err(ArgumentRange::new("steps", 1, i64::MAX, 0).into());
+        }
+
+        let dt = end / steps as f64;
+        let factor = |a: f64, sigma: f64| {
+            let (m, dx, j_max) = geometry(a, sigma, dt, steps);
+            Factor { dx, m, j_max }
+        };
+
+        let mut tree = G2Tree {
+            model,
+            dt,
+            x: factor(model.a, model.sigma),
+            y: factor(model.b, model.eta),
+            alphas: Vec::with_capacity(steps),
+        };
+
+        // Arrow-Debreu prices of the nodes at the current step.
+        let mut prices = vec![1.0];
+
+        for i in 0..steps {
+            let sum: f64 = prices
+                .iter()
+                .enumerate()
+                .map(|(n, q)| q * (-tree.state(i, n) * dt).exp())
+                .sum();
+            tree.alphas
+                .push((sum / discount((i + 1) as f64 * dt)).ln() / dt);
+
+            let mut next = vec![0.0; tree.size(i + 1)];
+
+            for (n, price) in prices.iter().enumerate() {
+                let growth = price * (-(tree.alphas[i] + tree.state(i, n)) * dt).exp();
+
+                for (target, p) in tree.transitions(i, n) {
+                    next[target] += growth * p;
+                }
+            }
+
+            prices = next;
+        }
+
+        Ok(tree)
+    }
+
+    pub fn model(&self) -> G2Model {
+        self.model
+    }
+
+    /// The short rate at node `(j, k)` of step `i`, which applies until step `i + 1`.
+    pub fn short_rate(&self, i: usize, j: i64, k: i64) -> f64 {
+        self.alphas[i] + j as f64 * self.x.dx + k as f64 * self.y.dx
+    }
+
+    /// The sum of the factors at the node stored at `n` in step `i`.
+    fn state(&self, i: usize, n: usize) -> f64 {
+        let (j, k) = self.node(i, n);
+        j as f64 * self.x.dx + k as f64 * self.y.dx
+    }
+
+    /// The indexes of each factor of the node stored at `n` in step `i`.
+    fn node(&self, i: usize, n: usize) -> (i64, i64) {
+        let size_y = self.y.size(i);
+
+        (
+            (n / size_y) as i64 - self.x.width(i) as i64,
+            (n % size_y) as i64 - self.y.width(i) as i64,
+        )
+    }
+
+    /// The nodes of step `i + 1` reached from the node stored at `n` in step `i`, and the
+    /// probabilities of reaching them.
+    fn transitions(&self, i: usize, n: usize) -> impl Iterator<Item = (usize, f64)> + '_ {
+        // Hull and White's adjustment to the product probabilities for a positive correlation,
+        // by the moves of the first and second factors to the node below, at and above. For a
+        // negative correlation the moves of the second factor are mirrored.
+        const CORRELATION: [[f64; 3]; 3] =
+            [[5.0, -4.0, -1.0], [-4.0, 8.0, -4.0], [-1.0, -4.0, 5.0]];
+
+        let (j, k) = self.node(i, n);
+        let (cx, px) = branches(self.x.m, self.x.j_max, j);
+        let (cy, py) = branches(self.y.m, self.y.j_max, k);
+        let (width_x, width_y) = (self.x.width(i + 1) as i64, self.y.width(i + 1) as i64);
+        let size_y = self.y.size(i + 1);
+        let rho = self.model.rho;
+        let epsilon = rho.abs() / 36.0;
+
+        (0..3).flat_map(move |bx| {
+            (0..3).map(move |by| {
+                let row = (cx + bx as i64 - 1 + width_x) as usize;
+                let column = (cy + by as i64 - 1 + width_y) as usize;
+
+                (
+                    row * size_y + column,
+                    px[bx] * py[by]
+                        + epsilon * CORRELATION[bx][if rho < 0.0 { 2 - by } else { by }],
+                )
+            })
+        })
+    }
+}
+
+impl ShortRateLattice for G2Tree {
+    fn dt(&self) -> f64 {
+        self.dt
+    }
+
+    fn steps(&self) -> usize {
+        self.alphas.len()
+    }
+
+    fn size(&self, i: usize) -> usize {
+        self.x.size(i) * self.y.size(i)
+    }
+
+    fn rollback(&self, i: usize, next: &[f64], spread: f64) -> Vec<f64> {
+        (0..self.size(i))
+            .map(|n| {
+                let expected: f64 = self.transitions(i, n).map(|(m, p)| p * next[m]).sum();
+
+                expected * (-(self.alphas[i] + self.state(i, n) + spread) * self.dt).exp()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{G2Model, G2Tree, ShortRateLattice};
+
+    fn discount(t: f64) -> f64 {
+        (-(0.03 + 0.01 * t) * t).exp()
+    }
+
+    #[test]
+    fn fitted_trees_reprice_the_discount_curve() {
+        for rho in [-0.75, 0.0, 0.5] {
+            let model = G2Model::new(0.1, 0.01, 0.3, 0.015, rho).unwrap();
+            let tree = G2Tree::new(model, &discount, 10.0, 40).unwrap();
+
+            for step in [1, 20, 40] {
+                let mut values = vec![1.0; tree.size(step)];
+                for i in (0..step).rev() {
+                    values = tree.rollback(i, &values, 0.0);
+                }
+
+                let t = step as f64 * tree.dt();
+                assert!(
+                    (values[0] - discount(t)).abs() < 1.0e-10,
+                    "{}: {} against {} at t = {}",
+                    model,
+                    values[0],
+                    discount(t),
+                    t
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+mod g2;
+mod tree;
+
+pub use g2::*;
+pub use tree::*;
+
+use std::fmt;
+
+/// A recombining lattice of short rates, on which values are found by backward induction.
+///
+/// The nodes of each step are stored in a vector whose layout is particular to the lattice.
+pub trait ShortRateLattice {
+    /// The length of each time step in years.
+    fn dt(&self) -> f64;
+
+    /// The number of time steps.
+    fn steps(&self) -> usize;
+
+    /// The number of nodes at step `i`.
+    fn size(&self, i: usize) -> usize;
+
+    /// Rolls values at step `i + 1` back to step `i`, discounting at the short rate plus
+    /// `spread`.
+    fn rollback(&self, i: usize, next: &[f64], spread: f64) -> Vec<f64>;
+}
+
+/// A one-factor short-rate model whose drift is fitted to an initial term structure.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShortRateModel {
+    /// Hull-White: `dr = (theta(t) - a r) dt + sigma dW`, allowing negative rates.
+    HullWhite {
+        mean_reversion: f64,
+        volatility: f64,
+    },
+    /// Black-Karasinski: `d ln r = (theta(t) - a ln r) dt + sigma dW`, keeping rates positive.
+    BlackKarasinski {
+        mean_reversion: f64,
+        volatility: f64,
+    },
+}
+
+impl ShortRateModel {
+    /// The speed of mean reversion of the model's state variable.
+    pub fn mean_reversion(&self) -> f64 {
+        match self {
+            ShortRateModel::HullWhite { mean_reversion, .. }
+            | ShortRateModel::BlackKarasinski { mean_reversion, .. } => *mean_reversion,
+        }
+    }
+
+    /// The volatility of the model's state variable.
+    pub fn volatility(&self) -> f64 {
+        match self {
+            ShortRateModel::HullWhite { volatility, .. }
+            | ShortRateModel::BlackKarasinski { volatility, .. } => *volatility,
+        }
+    }
+
+    /// The short rate at state `x` when the fitted drift term is `alpha`.
+    pub(crate) fn short_rate(&self, alpha: f64, x: f64) -> f64 {
+        match self {
+            ShortRateModel::HullWhite { .. } => alpha + x,
+            ShortRateModel::BlackKarasinski { .. } => (alpha + x).exp(),
+        }
+    }
+}
+
+impl fmt::Display for ShortRateModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShortRateModel::HullWhite {
+                mean_reversion,
+                volatility,
+            } => write!(
+                f,
+                "Hull-White (a = {}, sigma = {})",
+                mean_reversion, volatility
+            ),
+            ShortRateModel::BlackKarasinski {
+                mean_reversion,
+                volatility,
+            } => write!(
+                f,
+                "Black-Karasinski (a = {}, sigma = {})",
+                mean_reversion, volatility
+            ),
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use crate::{
+    error::{ArgumentRange, LibraError},
+    math::solvers::{bracket, Brent, Solver1D, DEFAULT_MAX_EVALUATIONS},
+};
+
+use super::{ShortRateLattice, ShortRateModel};
+
+/// The accuracy to which the drift of non-linear models is fitted at each step.
+const FIT_ACCURACY: f64 = 1.0e-12;
+
+/// A recombining trinomial tree for a short-rate model, built with the Hull-White procedure and
+/// fitted so that it reprices an initial discount curve exactly.
+///
+/// Nodes at step `i` are indexed from `-width(i)` to `width(i)` and are stored in vectors with an
+/// offset of `width(i)`.
+#[derive(Clone, Debug)]
+pub struct TrinomialTree {
+    model: ShortRateModel,
+    dt: f64,
+    dx: f64,
+    m: f64,
+    j_max: usize,
+    alphas: Vec<f64>,
+}
+
+impl TrinomialTree {
+    /// Builds a tree and fits it to a discount curve.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The short-rate model.
+    /// * `discount` - The discount factor for a time in years.
+    /// * `end` - The time in years of the last step of the tree.
+    /// * `steps` - The number of time steps.
+    ///
+    /// # Returns
+    ///
+    /// The fitted tree, or an error if the drift cannot be fitted at some step.
+    pub fn new(
+        model: ShortRateModel,
+        discount: &dyn Fn(f64) -> f64,
+        end: f64,
+        steps: usize,
+    ) -> Result<Self, LibraError> {
+        if steps == 0 {
+            return Err(ArgumentRange::new("steps", 1, i64::MAX, 0).into());
+        }
+
+        let dt = end / steps as f64;
+        let (m, dx, j_max) = geometry(model.mean_reversion(), model.volatility(), dt, steps);
+
+        let mut tree = TrinomialTree {
+            model,
+            dt,
+            dx,
+            m,
+            j_max,
+            alphas: Vec::with_capacity(steps),
+        };
+
+        // Arrow-Debreu prices of the nodes at the current step.
+        let mut prices = vec![1.0];
+
+        for i in 0..steps {
+            let target = discount((i + 1) as f64 * dt);
+            let alpha = tree.fit_alpha(i, &prices, target)?;
+            tree.alphas.push(alpha);
+
+            let mut next = vec![0.0; 2 * tree.width(i + 1) + 1];
+            let width = tree.width(i) as i64;
+            let next_width = tree.width(i + 1) as i64;
+
+            for (n, price) in prices.iter().enumerate() {
+                let j = n as i64 - width;
+                let growth = price * (-tree.short_rate(i, j) * dt).exp();
+                let (k, probabilities) = tree.branches(j);
+
+                for (b, p) in probabilities.iter().enumerate() {
+                    next[(k + b as i64 - 1 + next_width) as usize] += growth * p;
+                }
+            }
+
+            prices = next;
+        }
+
+        Ok(tree)
+    }
+
+    pub fn model(&self) -> ShortRateModel {
+        self.model
+    }
+
+    /// The length of each time step in years.
+    pub fn dt(&self) -> f64 {
+        self.dt
+    }
+
+    /// The number of time steps.
+    pub fn steps(&self) -> usize {
+        self.alphas.len()
+    }
+
+    /// The index of the widest node at step `i`.
+    pub fn width(&self, i: usize) -> usize {
+        i.min(self.j_max)
+    }
+
+    /// The number of nodes at step `i`.
+    pub fn size(&self, i: usize) -> usize {
+        2 * self.width(i) + 1
+    }
+
+    /// The short rate at node `j` of step `i`, which applies until step `i + 1`.
+    pub fn short_rate(&self, i: usize, j: i64) -> f64 {
+        self.model.short_rate(self.alphas[i], j as f64 * self.dx)
+    }
+
+    /// Rolls values at step `i + 1` back to step `i`, discounting at the short rate plus
+    /// `spread`.
+    pub fn rollback(&self, i: usize, next: &[f64], spread: f64) -> Vec<f64> {
+        let width = self.width(i) as i64;
+        let next_width = self.width(i + 1) as i64;
+
+        (-width..=width)
+            .map(|j| {
+                let (k, probabilities) = self.branches(j);
+                let expected: f64 = probabilities
+                    .iter()
+                    .enumerate()
+                    .map(|(b, p)| p * next[(k + b as i64 - 1 + next_width) as usize])
+                    .sum();
+
+                expected * (-(self.short_rate(i, j) + spread) * self.dt).exp()
+            })
+            .collect()
+    }
+
+    /// The central node reached from node `j`, and the probabilities of moving to the nodes
+    /// below, at and above it.
+    fn branches(&self, j: i64) -> (i64, [f64; 3]) {
+        branches(self.m, self.j_max, j)
+    }
+
+    /// Finds the drift at step `i` for which the nodes reprice the discount factor `target` to
+    /// the next step.
+    fn fit_alpha(&self, i: usize, prices: &[f64], target: f64) -> Result<f64, LibraError> {
+        let width = self.width(i) as i64;
+
+        match self.model {
+            ShortRateModel::HullWhite { .. } => {
+                let sum: f64 = prices
+                    .iter()
+                    .enumerate()
+                    .map(|(n, q)| q * (-((n as i64 - width) as f64) * self.dx * self.dt).exp())
+                    .sum();
+
+                Ok((sum / target).ln() / self.dt)
+            }
+            ShortRateModel::BlackKarasinski { .. } => {
+                let error = |alpha: f64| {
+                    prices
+                        .iter()
+                        .enumerate()
+                        .map(|(n, q)| {
+                            let x = (n as i64 - width) as f64 * self.dx;
+                            q * (-self.model.short_rate(alpha, x) * self.dt).exp()
+                        })
+                        .sum::<f64>()
+                        - target
+                };
+
+                // Start from the log of the forward rate implied by the target.
+                let total: f64 = prices.iter().sum();
+                let forward = ((total / target).ln() / self.dt).max(1.0e-4);
+                let (lower, upper) = bracket(
+                    &error,
+                    forward.ln(),
+                    0.5,
+                    (-30.0, 5.0),
+                    DEFAULT_MAX_EVALUATIONS,
+                )?;
+
+                Brent::new().solve_bracketed(&error, FIT_ACCURACY, lower, upper)
+            }
+        }
+    }
+}
+
+impl ShortRateLattice for TrinomialTree {
+    fn dt(&self) -> f64 {
+        self.dt
+    }
+
+    fn steps(&self) -> usize {
+        self.alphas.len()
+    }
+
+    fn size(&self, i: usize) -> usize {
+        TrinomialTree::size(self, i)
+    }
+
+    fn rollback(&self, i: usize, next: &[f64], spread: f64) -> Vec<f64> {
+        TrinomialTree::rollback(self, i, next, spread)
+    }
+}
+
+/// The spacing of the Hull-White grid for an Ornstein-Uhlenbeck factor with mean reversion `a`
+/// and volatility `sigma`.
+///
+/// # Returns
+///
+/// The expected relative change `m` in the factor over a step of `dt`, the spacing of the nodes,
+/// and the index of the widest node, beyond which the tree stops growing.
+pub(super) fn geometry(a: f64, sigma: f64, dt: f64, steps: usize) -> (f64, f64, usize) {
+    let (m, variance) = if a > 0.0 {
+        (
+            (-a * dt).exp() - 1.0,
+            sigma * sigma * (1.0 - (-2.0 * a * dt).exp()) / (2.0 * a),
+        )
+    } else {
+        (0.0, sigma * sigma * dt)
+    };
+
+    let j_max = if m < 0.0 {
+        ((-0.184 / m).ceil() as usize).max(1)
+    } else {
+        steps
+    };
+
+    (m, (3.0 * variance).sqrt(), j_max)
+}
+
+/// The central node reached from node `j` of a grid with relative drift `m` and widest node
+/// `j_max`, and the probabilities of moving to the nodes below, at and above it.
+pub(super) fn branches(m: f64, j_max: usize, j: i64) -> (i64, [f64; 3]) {
+    let jm = j as f64 * m;
+    let jm2 = jm * jm;
+
+    if j == j_max as i64 {
+        (
+            j - 1,
+            [
+                1.0 / 6.0 + (jm2 + jm) / 2.0,
+                -1.0 / 3.0 - jm2 - 2.0 * jm,
+                7.0 / 6.0 + (jm2 + 3.0 * jm) / 2.0,
+            ],
+        )
+    } else if j == -(j_max as i64) {
+        (
+            j + 1,
+            [
+                7.0 / 6.0 + (jm2 - 3.0 * jm) / 2.0,
+                -1.0 / 3.0 - jm2 + 2.0 * jm,
+                1.0 / 6.0 + (jm2 - jm) / 2.0,
+            ],
+        )
+    } else {
+        (
+            j,
+            [
+                1.0 / 6.0 + (jm2 - jm) / 2.0,
+                2.0 / 3.0 - jm2,
+                1.0 / 6.0 + (jm2 + jm) / 2.0,
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ShortRateLattice, ShortRateModel, TrinomialTree};
+
+    fn discount(t: f64) -> f64 {
+        (-(0.03 + 0.01 * t) * t).exp()
+    }
+
+    fn zero_coupon_price(lattice: &dyn ShortRateLattice, step: usize) -> f64 {
+        let mut values = vec![1.0; lattice.size(step)];
+        for i in (0..step).rev() {
+            values = lattice.rollback(i, &values, 0.0);
+        }
+        values[0]
+    }
+
+    #[test]
+    fn fitted_trees_reprice_the_discount_curve() {
+        let models = [
+            ShortRateModel::HullWhite {
+                mean_reversion: 0.1,
+                volatility: 0.01,
+            },
+            ShortRateModel::BlackKarasinski {
+                mean_reversion: 0.1,
+                volatility: 0.2,
+            },
+        ];
+
+        for model in models {
+            let tree = TrinomialTree::new(model, &discount, 10.0, 100).unwrap();
+
+            for step in [1, 25, 50, 100] {
+                let t = step as f64 * tree.dt();
+                let price = zero_coupon_price(&tree, step);
+                assert!(
+                    (price - discount(t)).abs() < 1.0e-10,
+                    "{}: {} against {} at t = {}",
+                    model,
+                    price,
+                    discount(t),
+                    t
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use chrono::NaiveDate;
+
+use crate::{
+    error::LibraError,
+    indexes::{FloatingIndex, OvernightIndex},
+    instruments::StirFuture,
+    pricing::{hull_white_convexity_adjustment, FuturesRateHelper},
+    time::DateAdjustment,
+};
+
+impl StirFuture {
+    /// The rate of the index over the reference period, using recorded fixings and forecasting
+    /// the rest off the index's forward curve.
+    ///
+    /// Euribor contracts use the index fixing for the period, three-month SOFR and SONIA
+    /// contracts the daily compounded rate and one-month SOFR contracts the average daily rate,
+    /// with each rate applying until the next business day.
+    pub fn forward_rate(&self) -> Result<f64, LibraError> {
+        let (start, end) = (self.reference_start(), self.reference_end());
+
+        match self.index() {
+            FloatingIndex::Ibor(index) => index.rate(start, end),
+            FloatingIndex::Overnight(index) if self.contract().is_averaged() => {
+                averaged_rate(index, start, end)
+            }
+            FloatingIndex::Overnight(index) => index.compounded_rate(start, end),
+        }
+    }
+
+    /// The amount by which the futures rate exceeds the forward rate under the Hull-White model,
+    /// measuring time off the reference date of the index's forward curve.
+    ///
+    /// # Arguments
+    ///
+    /// * `volatility` - The volatility of the short rate.
+    /// * `mean_reversion` - The speed of mean reversion of the short rate.
+    ///
+    /// # Returns
+    ///
+    /// The convexity adjustment, which is zero once the reference period has started.
+    pub fn convexity_adjustment(
+        &self,
+        volatility: f64,
+        mean_reversion: f64,
+    ) -> Result<f64, LibraError> {
+        let curve = self.index().forward_curve();
+        if self.reference_start() <= curve.reference_date() {
+            return Ok(0.0);
+        }
+
+        let start = curve.time_from_reference(self.reference_start())?;
+        let end = curve.time_from_reference(self.reference_end())?;
+
+        Ok(hull_white_convexity_adjustment(
+            volatility,
+            mean_reversion,
+            start,
+            end,
+        ))
+    }
+
+    /// The futures rate, which is the forward rate plus `convexity_adjustment`.
+    pub fn futures_rate(&self, convexity_adjustment: f64) -> Result<f64, LibraError> {
+        Ok(self.forward_rate()? + convexity_adjustment)
+    }
+
+    /// The price of the future, 100 less the futures rate in percent.
+    pub fn fair_price(&self, convexity_adjustment: f64) -> Result<f64, LibraError> {
+        Ok(100.0 * (1.0 - self.futures_rate(convexity_adjustment)?))
+    }
+
+    /// The variation margin received on `contracts` contracts, or paid if negative, when the
+    /// price moves from `trade_price` to `price`.
+    pub fn variation_margin(&self, trade_price: f64, price: f64, contracts: f64) -> f64 {
+        (price - trade_price) * 100.0 * self.contract().basis_point_value() * contracts
+    }
+
+    /// A helper for bootstrapping a curve to the quoted `price` of the future.
+    pub fn rate_helper(&self, price: f64, convexity_adjustment: f64) -> FuturesRateHelper {
+        FuturesRateHelper::new(
+            price,
+            self.reference_start(),
+            self.reference_end(),
+            self.index().day_count(),
+        )
+        .with_convexity_adjustment(convexity_adjustment)
+    }
+}
+
+/// The average of the daily rates of `index` over each calendar day between `start` and `end`,
+/// taking the rate of the previous business day on holidays.
+fn averaged_rate(
+    index: &OvernightIndex,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Result<f64, LibraError> {
+    let calendar = index.calendar();
+    let mut sum = 0.0;
+    let mut date = start;
+
+    while date < end {
+        let fixing_date = calendar.adjust(date, DateAdjustment::Preceding);
+        let next_business_day = calendar.advance(fixing_date, 1);
+        let next = next_business_day.min(end);
+
+        let rate = index.compounded_rate(fixing_date, next_business_day)?;
+        sum += rate * (next - date).num_days() as f64;
+        date = next;
+    }
+
+    Ok(sum / (end - start).num_days() as f64)
+}
@@ -0,0 +1,185 @@
+use std::rc::Rc;
+
+use crate::{
+    cashflows::Leg,
+    error::LibraError,
+    instruments::{BasisSwap, FixedFloatSwap},
+    pricing::{YieldTermStructure, ZeroSpreadedTermStructure},
+};
+
+use super::ONE_BASIS_POINT;
+
+/// The value and rate sensitivities of a swap.
+///
+/// Values are signed from the holder's side, so paid legs have negative values and basis point
+/// sensitivities.
+#[derive(Clone, Copy, Debug)]
+pub struct SwapResults {
+    pub npv: f64,
+    pub fixed_leg_npv: f64,
+    pub floating_leg_npv: f64,
+    /// The change in value of the fixed leg for a one basis point rise in its rate.
+    pub fixed_leg_bps: f64,
+    /// The change in value of the floating leg for a one basis point rise in its spread.
+    pub floating_leg_bps: f64,
+    /// The fixed rate at which the swap has no value.
+    pub fair_rate: f64,
+    /// The spread over the index at which the swap has no value.
+    pub fair_spread: f64,
+    /// The fall in value for a one basis point parallel rise in the zero rates of the discount
+    /// and forward curves.
+    pub dv01: f64,
+}
+
+/// The value and spread sensitivities of a basis swap, signed from the holder's side.
+#[derive(Clone, Copy, Debug)]
+pub struct BasisSwapResults {
+    pub npv: f64,
+    pub pay_leg_npv: f64,
+    pub receive_leg_npv: f64,
+    /// The change in value of the paid leg for a one basis point rise in its spread.
+    pub pay_leg_bps: f64,
+    /// The change in value of the received leg for a one basis point rise in its spread.
+    pub receive_leg_bps: f64,
+    /// The spread over the index of the paid leg at which the swap has no value.
+    pub fair_pay_spread: f64,
+    /// The spread over the index of the received leg at which the swap has no value.
+    pub fair_receive_spread: f64,
+    /// The fall in value for a one basis point parallel rise in the zero rates of the discount
+    /// curve and the forward curves of both indexes.
+    pub dv01: f64,
+}
+
+/// Prices swaps by discounting the flows of each leg off a discount curve, with floating
+/// coupons forecast off a separate forward curve.
+#[derive(Clone)]
+pub struct DiscountingSwapEngine {
+    discount_curve: Rc<dyn YieldTermStructure>,
+    forward_curve: Option<Rc<dyn YieldTermStructure>>,
+}
+
+impl DiscountingSwapEngine {
+    /// Creates an engine discounting off `discount_curve` to its reference date, which
+    /// forecasts floating coupons off the forward curve of each swap's index.
+    pub fn new(discount_curve: Rc<dyn YieldTermStructure>) -> Self {
+        DiscountingSwapEngine {
+            discount_curve,
+            forward_curve: None,
+        }
+    }
+
+    /// Forecasts the floating coupons of fixed-float swaps off `forward_curve` instead of the
+    /// curve of the index.
+    pub fn with_forward_curve(mut self, forward_curve: Rc<dyn YieldTermStructure>) -> Self {
+        self.forward_curve = Some(forward_curve);
+        self
+    }
+
+    pub fn discount_curve(&self) -> &Rc<dyn YieldTermStructure> {
+        &self.discount_curve
+    }
+
+    /// Values `swap` and its sensitivities.
+    ///
+    /// Only flows paid after the reference date of the discount curve are included.
+    ///
+    /// # Returns
+    ///
+    /// The results, or an error if a coupon cannot be forecast, such as when a fixing before
+    /// the reference date of the forward curve has not been recorded.
+    pub fn calculate(&self, swap: &dyn FixedFloatSwap) -> Result<SwapResults, LibraError> {
+        let forward_curve = self
+            .forward_curve
+            .clone()
+            .unwrap_or_else(|| swap.forward_curve());
+        let discount = &*self.discount_curve;
+
+        let fixed_sign = swap.swap_type().fixed_sign();
+        let fixed_leg = swap.fixed_leg()?;
+        let floating_leg = swap.floating_leg_on(forward_curve.clone())?;
+
+        let fixed_leg_npv = fixed_sign * leg_npv(&fixed_leg, discount)?;
+        let floating_leg_npv = -fixed_sign * leg_npv(&floating_leg, discount)?;
+        let fixed_leg_bps = fixed_sign * leg_bps(&fixed_leg, discount)?;
+        let floating_leg_bps = -fixed_sign * leg_bps(&floating_leg, discount)?;
+        let npv = fixed_leg_npv + floating_leg_npv;
+
+        let bumped_npv = |shift: f64| -> Result<f64, LibraError> {
+            let discount = ZeroSpreadedTermStructure::new(self.discount_curve.clone(), shift);
+            let forward = ZeroSpreadedTermStructure::new(forward_curve.clone(), shift);
+            let floating_leg = swap.floating_leg_on(Rc::new(forward))?;
+
+            Ok(fixed_sign * (leg_npv(&fixed_leg, &discount)? - leg_npv(&floating_leg, &discount)?))
+        };
+
+        Ok(SwapResults {
+            npv,
+            fixed_leg_npv,
+            floating_leg_npv,
+            fixed_leg_bps,
+            floating_leg_bps,
+            fair_rate: swap.fixed_rate() - npv / fixed_leg_bps * ONE_BASIS_POINT,
+            fair_spread: swap.spread() - npv / floating_leg_bps * ONE_BASIS_POINT,
+            dv01: (bumped_npv(-ONE_BASIS_POINT)? - bumped_npv(ONE_BASIS_POINT)?) / 2.0,
+        })
+    }
+
+    /// Values the basis swap `swap` and its sensitivities, forecasting each leg off the forward
+    /// curve of its own index.
+    ///
+    /// Only flows paid after the reference date of the discount curve are included.
+    pub fn calculate_basis(&self, swap: &BasisSwap) -> Result<BasisSwapResults, LibraError> {
+        let discount = &*self.discount_curve;
+
+        let pay_leg = swap.pay_leg()?;
+        let receive_leg = swap.receive_leg()?;
+
+        let pay_leg_npv = -leg_npv(&pay_leg, discount)?;
+        let receive_leg_npv = leg_npv(&receive_leg, discount)?;
+        let pay_leg_bps = -leg_bps(&pay_leg, discount)?;
+        let receive_leg_bps = leg_bps(&receive_leg, discount)?;
+        let npv = pay_leg_npv + receive_leg_npv;
+
+        let bumped_npv = |shift: f64| -> Result<f64, LibraError> {
+            let bump = |curve: &Rc<dyn YieldTermStructure>| -> Rc<dyn YieldTermStructure> {
+                Rc::new(ZeroSpreadedTermStructure::new(curve.clone(), shift))
+            };
+            let discount = bump(&self.discount_curve);
+            let pay_leg = swap.pay_leg_on(bump(swap.pay_index().forward_curve()))?;
+            let receive_leg = swap.receive_leg_on(bump(swap.receive_index().forward_curve()))?;
+
+            Ok(leg_npv(&receive_leg, &*discount)? - leg_npv(&pay_leg, &*discount)?)
+        };
+
+        Ok(BasisSwapResults {
+            npv,
+            pay_leg_npv,
+            receive_leg_npv,
+            pay_leg_bps,
+            receive_leg_bps,
+            fair_pay_spread: swap.pay_spread() - npv / pay_leg_bps * ONE_BASIS_POINT,
+            fair_receive_spread: swap.receive_spread() - npv / receive_leg_bps * ONE_BASIS_POINT,
+            dv01: (bumped_npv(-ONE_BASIS_POINT)? - bumped_npv(ONE_BASIS_POINT)?) / 2.0,
+        })
+    }
+}
+
+/// The value of the flows of `leg` paid after the reference date of `curve`.
+pub(super) fn leg_npv(leg: &Leg, curve: &dyn YieldTermStructure) -> Result<f64, LibraError> {
+    leg.npv(curve, &curve.reference_date())
+}
+
+/// The value of one basis point per annum on the nominal of each coupon of `leg` paid after the
+/// reference date of `curve`.
+pub(super) fn leg_bps(leg: &Leg, curve: &dyn YieldTermStructure) -> Result<f64, LibraError> {
+    let today = curve.reference_date();
+
+    leg.coupons()
+        .filter(|c| c.date() > today)
+        .try_fold(0.0, |sum, coupon| match coupon.accrual()? {
+            Some((nominal, accrual)) => {
+                Ok(sum + nominal * accrual * ONE_BASIS_POINT * curve.discount(coupon.date())?)
+            }
+            None => Ok(sum),
+        })
+}
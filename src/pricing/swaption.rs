@@ -0,0 +1,653 @@
+use std::rc::Rc;
+
+use chrono::NaiveDate;
+
+use crate::{
+    cashflows::Leg,
+    error::{DifferentVariant, LibraError},
+    instruments::{
+        CashSettlementMethod, Exercise, FixedFloatSwap, OptionType, SwapType, Swaption,
+        SwaptionSettlement,
+    },
+    pricing::{
+        short_rate::{G2Model, G2Tree, ShortRateLattice, ShortRateModel, TrinomialTree},
+        YieldTermStructure, ZeroSpreadedTermStructure,
+    },
+    time::DayCountConvention,
+};
+
+use super::{
+    bachelier_formula, black_formula, implied_bachelier_volatility, implied_black_volatility,
+    DiscountingSwapEngine, ForwardOptionResults, ONE_BASIS_POINT,
+};
+
+/// The parallel shift in zero rates used to find the delta and gamma of lattice engines, which
+/// is wide enough to smooth over the kinks in values at the nodes.
+const RATE_BUMP: f64 = 1.0e-3;
+
+/// The change in model volatility used to find the vega of lattice engines.
+const VOLATILITY_BUMP: f64 = 1.0e-4;
+
+/// The premium and sensitivities of a European swaption priced off a quoted volatility.
+///
+/// Sensitivities to the forward swap rate and the volatility hold the annuity fixed.
+#[derive(Clone, Copy, Debug)]
+pub struct SwaptionResults {
+    pub premium: f64,
+    /// The par rate of the underlying swap.
+    pub forward_rate: f64,
+    /// The value today of one unit of rate per annum paid on the fixed leg, by which the
+    /// undiscounted option on the swap rate is multiplied. For par yield cash settlement, this
+    /// is the cash annuity at the forward rate, discounted from the start of the swap.
+    pub annuity: f64,
+    /// The change in premium per unit rise in the forward swap rate.
+    pub delta: f64,
+    /// The change in delta per unit rise in the forward swap rate.
+    pub gamma: f64,
+    /// The change in premium per unit rise in the volatility.
+    pub vega: f64,
+    /// The change in premium per year of time passing, holding the forward swap rate fixed.
+    pub theta: f64,
+}
+
+/// The premium and sensitivities of a swaption priced on a short-rate lattice.
+#[derive(Clone, Copy, Debug)]
+pub struct LatticeSwaptionResults {
+    pub premium: f64,
+    /// The change in premium per unit parallel rise in the zero rates of the discount and
+    /// forward curves.
+    pub delta: f64,
+    /// The change in delta per unit parallel rise in the zero rates of the discount and forward
+    /// curves.
+    pub gamma: f64,
+    /// The change in premium per unit rise in the volatility of each factor of the model.
+    pub vega: f64,
+}
+
+/// Prices European swaptions with Black's model on the forward swap rate, which may be shifted
+/// so that negative rates have a lognormal distribution.
+#[derive(Clone)]
+pub struct BlackSwaptionEngine {
+    discount_curve: Rc<dyn YieldTermStructure>,
+    volatility: f64,
+    shift: f64,
+}
+
+impl BlackSwaptionEngine {
+    /// Creates an engine with no shift.
+    ///
+    /// # Arguments
+    ///
+    /// * `discount_curve` - The curve discounting to its reference date, which is today.
+    /// * `volatility` - The annualised lognormal volatility of the forward swap rate.
+    pub fn new(discount_curve: Rc<dyn YieldTermStructure>, volatility: f64) -> Self {
+        BlackSwaptionEngine {
+            discount_curve,
+            volatility,
+            shift: 0.0,
+        }
+    }
+
+    /// Models the forward swap rate plus `shift` as lognormal, as quoted for currencies with
+    /// negative rates.
+    pub fn with_shift(mut self, shift: f64) -> Self {
+        self.shift = shift;
+        self
+    }
+
+    pub fn volatility(&self) -> f64 {
+        self.volatility
+    }
+
+    pub fn shift(&self) -> f64 {
+        self.shift
+    }
+
+    /// Values `swaption` and its sensitivities.
+    ///
+    /// # Returns
+    ///
+    /// The results, or an error if the swaption is not European or its underlying swap cannot
+    /// be valued.
+    pub fn calculate(&self, swaption: &Swaption) -> Result<SwaptionResults, LibraError> {
+        let underlying = Underlying::new(swaption, &self.discount_curve)?;
+
+        Ok(underlying.results(self.black(&underlying, self.volatility)))
+    }
+
+    /// The shifted lognormal volatility at which the premium of `swaption` is `premium`.
+    ///
+    /// # Returns
+    ///
+    /// The volatility, or an error if the swaption is not European, the shifted forward or
+    /// strike is not positive, or `premium` is below the intrinsic value or not below the most
+    /// the swaption can be worth.
+    pub fn implied_volatility(&self, swaption: &Swaption, premium: f64) -> Result<f64, LibraError> {
+        let underlying = Underlying::new(swaption, &self.discount_curve)?;
+
+        implied_black_volatility(
+            underlying.option_type,
+            premium / underlying.annuity,
+            underlying.forward + self.shift,
+            underlying.strike + self.shift,
+            underlying.time,
+        )
+    }
+
+    fn black(&self, underlying: &Underlying, volatility: f64) -> ForwardOptionResults {
+        black_formula(
+            underlying.option_type,
+            underlying.forward + self.shift,
+            underlying.strike + self.shift,
+            volatility,
+            underlying.time,
+        )
+        .scaled(underlying.annuity)
+    }
+}
+
+/// Prices European swaptions with Bachelier's model, in which the forward swap rate is normally
+/// distributed.
+#[derive(Clone)]
+pub struct BachelierSwaptionEngine {
+    discount_curve: Rc<dyn YieldTermStructure>,
+    volatility: f64,
+}
+
+impl BachelierSwaptionEngine {
+    /// Creates an engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `discount_curve` - The curve discounting to its reference date, which is today.
+    /// * `volatility` - The annualised normal volatility of the forward swap rate, such as
+    ///   0.01 for 100 basis points.
+    pub fn new(discount_curve: Rc<dyn YieldTermStructure>, volatility: f64) -> Self {
+        BachelierSwaptionEngine {
+            discount_curve,
+            volatility,
+        }
+    }
+
+    pub fn volatility(&self) -> f64 {
+        self.volatility
+    }
+
+    /// Values `swaption` and its sensitivities.
+    ///
+    /// # Returns
+    ///
+    /// The results, or an error if the swaption is not European or its underlying swap cannot
+    /// be valued.
+    pub fn calculate(&self, swaption: &Swaption) -> Result<SwaptionResults, LibraError> {
+        let underlying = Underlying::new(swaption, &self.discount_curve)?;
+
+        Ok(underlying.results(Self::bachelier(&underlying, self.volatility)))
+    }
+
+    /// The normal volatility at which the premium of `swaption` is `premium`.
+    ///
+    /// # Returns
+    ///
+    /// The volatility, or an error if the swaption is not European or `premium` is below the
+    /// intrinsic value.
+    pub fn implied_volatility(&self, swaption: &Swaption, premium: f64) -> Result<f64, LibraError> {
+        let underlying = Underlying::new(swaption, &self.discount_curve)?;
+
+        implied_bachelier_volatility(
+            underlying.option_type,
+            premium / underlying.annuity,
+            underlying.forward,
+            underlying.strike,
+            underlying.time,
+        )
+    }
+
+    fn bachelier(underlying: &Underlying, volatility: f64) -> ForwardOptionResults {
+        bachelier_formula(
+            underlying.option_type,
+            underlying.forward,
+            underlying.strike,
+            volatility,
+            underlying.time,
+        )
+        .scaled(underlying.annuity)
+    }
+}
+
+/// The swap rate option a European swaption is equivalent to.
+struct Underlying {
+    option_type: OptionType,
+    forward: f64,
+    strike: f64,
+    annuity: f64,
+    time: f64,
+}
+
+impl Underlying {
+    fn new(
+        swaption: &Swaption,
+        discount_curve: &Rc<dyn YieldTermStructure>,
+    ) -> Result<Self, LibraError> {
+        let expiry = match swaption.exercise() {
+            Exercise::European(date) => *date,
+            _ => return Err(DifferentVariant.into()),
+        };
+
+        let swap = swaption.swap();
+        let results = DiscountingSwapEngine::new(discount_curve.clone()).calculate(swap)?;
+        let forward = results.fair_rate;
+
+        let annuity = match swaption.settlement() {
+            SwaptionSettlement::Physical
+            | SwaptionSettlement::Cash(CashSettlementMethod::CollateralizedCashPrice) => {
+                results.fixed_leg_bps.abs() / ONE_BASIS_POINT
+            }
+            SwaptionSettlement::Cash(CashSettlementMethod::ParYieldCurve) => {
+                let start = swap.fixed_schedule().start_date();
+                let accruals = fixed_accruals(&swap.fixed_leg()?, start)?;
+
+                cash_annuity(&accruals, forward) * discount_curve.discount(start)?
+            }
+        };
+
+        let today = discount_curve.reference_date();
+
+        Ok(Underlying {
+            option_type: option_type(swaption.swap_type()),
+            forward,
+            strike: swaption.strike(),
+            annuity,
+            time: DayCountConvention::Actual365Fixed
+                .year_frac(&today, &expiry)?
+                .max(0.0),
+        })
+    }
+
+    fn results(&self, option: ForwardOptionResults) -> SwaptionResults {
+        SwaptionResults {
+            premium: option.value,
+            forward_rate: self.forward,
+            annuity: self.annuity,
+            delta: option.delta,
+            gamma: option.gamma,
+            vega: option.vega,
+            theta: option.theta,
+        }
+    }
+}
+
+/// Prices swaptions with European or Bermudan exercise by backward induction on a trinomial
+/// tree for a one-factor short-rate model, such as Hull-White, fitted to the discount curve.
+#[derive(Clone)]
+pub struct TreeSwaptionEngine {
+    discount_curve: Rc<dyn YieldTermStructure>,
+    model: ShortRateModel,
+    steps_per_year: usize,
+}
+
+impl TreeSwaptionEngine {
+    /// Creates an engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `discount_curve` - The curve the tree is fitted to, discounting to its reference date.
+    /// * `model` - The short-rate model.
+    /// * `steps_per_year` - The number of time steps in the tree per year.
+    pub fn new(
+        discount_curve: Rc<dyn YieldTermStructure>,
+        model: ShortRateModel,
+        steps_per_year: usize,
+    ) -> Self {
+        TreeSwaptionEngine {
+            discount_curve,
+            model,
+            steps_per_year,
+        }
+    }
+
+    pub fn model(&self) -> ShortRateModel {
+        self.model
+    }
+
+    /// Values `swaption` and its sensitivities.
+    ///
+    /// Floating coupons are valued off the tree as the notional on their start date less the
+    /// notional on their payment date, plus their deterministic basis to the discount curve.
+    pub fn calculate(&self, swaption: &Swaption) -> Result<LatticeSwaptionResults, LibraError> {
+        let build = |bump: f64, discount: &dyn Fn(f64) -> f64, end: f64, steps: usize| {
+            let model = match self.model {
+                ShortRateModel::HullWhite {
+                    mean_reversion,
+                    volatility,
+                } => ShortRateModel::HullWhite {
+                    mean_reversion,
+                    volatility: volatility + bump,
+                },
+                ShortRateModel::BlackKarasinski {
+                    mean_reversion,
+                    volatility,
+                } => ShortRateModel::BlackKarasinski {
+                    mean_reversion,
+                    volatility: volatility + bump,
+                },
+            };
+
+            Ok(Box::new(TrinomialTree::new(model, discount, end, steps)?)
+                as Box<dyn ShortRateLattice>)
+        };
+
+        lattice_results(swaption, &self.discount_curve, self.steps_per_year, &build)
+    }
+}
+
+/// Prices swaptions with European or Bermudan exercise by backward induction on a
+/// two-dimensional trinomial tree for the G2++ model fitted to the discount curve.
+#[derive(Clone)]
+pub struct G2SwaptionEngine {
+    discount_curve: Rc<dyn YieldTermStructure>,
+    model: G2Model,
+    steps_per_year: usize,
+}
+
+impl G2SwaptionEngine {
+    /// Creates an engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `discount_curve` - The curve the tree is fitted to, discounting to its reference date.
+    /// * `model` - The model.
+    /// * `steps_per_year` - The number of time steps in the tree per year. The number of nodes
+    ///   grows with its square, so this should be kept modest.
+    pub fn new(
+        discount_curve: Rc<dyn YieldTermStructure>,
+        model: G2Model,
+        steps_per_year: usize,
+    ) -> Self {
+        G2SwaptionEngine {
+            discount_curve,
+            model,
+            steps_per_year,
+        }
+    }
+
+    pub fn model(&self) -> G2Model {
+        self.model
+    }
+
+    /// Values `swaption` and its sensitivities, with vega found by raising the volatilities of
+    /// both factors together.
+    ///
+    /// Floating coupons are valued off the tree as the notional on their start date less the
+    /// notional on their payment date, plus their deterministic basis to the discount curve.
+    pub fn calculate(&self, swaption: &Swaption) -> Result<LatticeSwaptionResults, LibraError> {
+        let build = |bump: f64, discount: &dyn Fn(f64) -> f64, end: f64, steps: usize| {
+            let m = self.model;
+            let model = G2Model::new(m.a(), m.sigma() + bump, m.b(), m.eta() + bump, m.rho())?;
+
+            Ok(Box::new(G2Tree::new(model, discount, end, steps)?) as Box<dyn ShortRateLattice>)
+        };
+
+        lattice_results(swaption, &self.discount_curve, self.steps_per_year, &build)
+    }
+}
+
+/// A lattice fitted to a discount curve.
+type FittedLattice = Result<Box<dyn ShortRateLattice>, LibraError>;
+
+/// Builds a lattice with its volatilities raised by a bump, fitted to a discount function over
+/// a number of steps to an end time.
+type LatticeBuilder<'a> = dyn Fn(f64, &dyn Fn(f64) -> f64, f64, usize) -> FittedLattice + 'a;
+
+/// The premium of `swaption` on lattices fitted to the discount curve, and its sensitivities to
+/// shifts in the curves and volatilities.
+fn lattice_results(
+    swaption: &Swaption,
+    discount_curve: &Rc<dyn YieldTermStructure>,
+    steps_per_year: usize,
+    build: &LatticeBuilder,
+) -> Result<LatticeSwaptionResults, LibraError> {
+    let premium = |shift: f64, bump: f64| {
+        let bumped = |curve: &Rc<dyn YieldTermStructure>| -> Rc<dyn YieldTermStructure> {
+            Rc::new(ZeroSpreadedTermStructure::new(curve.clone(), shift))
+        };
+
+        lattice_premium(
+            swaption,
+            &*bumped(discount_curve),
+            bumped(&swaption.swap().forward_curve()),
+            steps_per_year,
+            build,
+            bump,
+        )
+    };
+
+    let value = premium(0.0, 0.0)?;
+    let up = premium(RATE_BUMP, 0.0)?;
+    let down = premium(-RATE_BUMP, 0.0)?;
+
+    Ok(LatticeSwaptionResults {
+        premium: value,
+        delta: (up - down) / (2.0 * RATE_BUMP),
+        gamma: (up + down - 2.0 * value) / (RATE_BUMP * RATE_BUMP),
+        vega: (premium(0.0, VOLATILITY_BUMP)? - premium(0.0, -VOLATILITY_BUMP)?)
+            / (2.0 * VOLATILITY_BUMP),
+    })
+}
+
+/// A period of the underlying swap, valued on the lattice by rolling back a zero coupon bond
+/// paying one on its payment date to its start.
+struct LatticePeriod {
+    start: usize,
+    payment: usize,
+    /// The amount received on the payment date per unit of the bond, negative when paid,
+    /// discounted to the step nearest that date.
+    paid: f64,
+    /// The amount received on the start date, discounted to the step nearest that date.
+    received: f64,
+    /// The nominal and accrual period of a fixed coupon, or zeros for a floating coupon.
+    accrual: (f64, f64),
+    start_date: NaiveDate,
+}
+
+/// The premium of `swaption` found by backward induction on a lattice.
+///
+/// The underlying swap on each exercise date is made up of the periods starting on or after
+/// it. Floating coupons are valued as the notional on their start date less the notional on
+/// their payment date, plus the deterministic difference between the coupon forecast off the
+/// forward curve and that implied by the discount curve. Cash settlement is paid on the
+/// exercise date.
+///
+/// # Arguments
+///
+/// * `swaption` - The swaption to price.
+/// * `discount` - The curve the lattice is fitted to, discounting to its reference date.
+/// * `forward` - The curve floating coupons are forecast off.
+/// * `steps_per_year` - The number of time steps per year.
+/// * `build` - Builds the lattice.
+/// * `bump` - The change in the model's volatilities.
+fn lattice_premium(
+    swaption: &Swaption,
+    discount: &dyn YieldTermStructure,
+    forward: Rc<dyn YieldTermStructure>,
+    steps_per_year: usize,
+    build: &LatticeBuilder,
+    bump: f64,
+) -> Result<f64, LibraError> {
+    let today = discount.reference_date();
+    let swap = swaption.swap();
+    let time = |date: NaiveDate| discount.time_from_reference(date);
+
+    let fixed_leg = swap.fixed_leg()?;
+    let floating_leg = swap.floating_leg_on(forward)?;
+
+    let end = fixed_leg
+        .coupons()
+        .chain(floating_leg.coupons())
+        .map(|c| time(c.date()))
+        .try_fold(0.0, |end: f64, t| t.map(|t| end.max(t)))?;
+    let steps = ((end * steps_per_year as f64).ceil() as usize).max(1);
+    let lattice = build(bump, &|t| discount.discount_time(t), end, steps)?;
+    let dt = lattice.dt();
+    // The step nearest to `date`, and the discount factor from `date` back to that step, by
+    // which amounts on `date` are moved onto the lattice.
+    let step = |date: NaiveDate| -> Result<(usize, f64), LibraError> {
+        let i = ((time(date)? / dt).round().max(0.0) as usize).min(steps);
+        Ok((
+            i,
+            discount.discount(date)? / discount.discount_time(i as f64 * dt),
+        ))
+    };
+
+    let mut periods = Vec::new();
+
+    for (coupon, (start, _)) in fixed_leg.coupons().zip(swap.fixed_schedule().periods()) {
+        if start < today {
+            continue;
+        }
+
+        let (payment, to_payment) = step(coupon.date())?;
+        periods.push(LatticePeriod {
+            start: step(start)?.0,
+            payment,
+            paid: -coupon.amount()? * to_payment,
+            received: 0.0,
+            accrual: coupon.accrual()?.unwrap_or((0.0, 0.0)),
+            start_date: start,
+        });
+    }
+
+    for (coupon, (start, _)) in floating_leg
+        .coupons()
+        .zip(swap.floating_schedule().periods())
+    {
+        if start < today {
+            continue;
+        }
+
+        let (nominal, _) = coupon.accrual()?.unwrap_or((0.0, 0.0));
+        let implied =
+            nominal * (discount.discount(start)? / discount.discount(coupon.date())? - 1.0);
+        let ((first, to_start), (payment, to_payment)) = (step(start)?, step(coupon.date())?);
+        periods.push(LatticePeriod {
+            start: first,
+            payment,
+            paid: (coupon.amount()? - implied - nominal) * to_payment,
+            received: nominal * to_start,
+            accrual: (0.0, 0.0),
+            start_date: start,
+        });
+    }
+
+    if matches!(swaption.exercise(), Exercise::American { .. }) {
+        return Err(DifferentVariant.into());
+    }
+
+    let exercises = swaption
+        .exercise()
+        .dates()
+        .into_iter()
+        .filter(|d| *d >= today)
+        .map(|d| Ok((step(d)?.0, d)))
+        .collect::<Result<Vec<_>, LibraError>>()?;
+
+    let omega = option_type(swaption.swap_type()).sign();
+    let strike = swaption.strike();
+    let settlement = swaption.settlement();
+
+    // The value of the payer swap made up of the periods started so far, going backwards, and
+    // of one unit of rate paid on its fixed leg.
+    let mut swap_values = vec![0.0; lattice.size(steps)];
+    let mut annuities = swap_values.clone();
+    let mut option = swap_values.clone();
+    // The zero coupon bonds of the periods paid but not yet started, by period.
+    let mut bonds: Vec<(usize, Vec<f64>)> = Vec::new();
+
+    for i in (0..=steps).rev() {
+        if i < steps {
+            swap_values = lattice.rollback(i, &swap_values, 0.0);
+            annuities = lattice.rollback(i, &annuities, 0.0);
+            option = lattice.rollback(i, &option, 0.0);
+
+            for (_, bond) in bonds.iter_mut() {
+                *bond = lattice.rollback(i, bond, 0.0);
+            }
+        }
+
+        for (n, period) in periods.iter().enumerate() {
+            if period.payment == i {
+                bonds.push((n, vec![1.0; lattice.size(i)]));
+            }
+        }
+
+        bonds.retain(|(n, bond)| {
+            let period = &periods[*n];
+            if period.start != i {
+                return true;
+            }
+
+            for ((value, annuity), z) in swap_values.iter_mut().zip(annuities.iter_mut()).zip(bond)
+            {
+                *value += period.paid * z + period.received;
+                *annuity += period.accrual.0 * period.accrual.1 * z;
+            }
+
+            false
+        });
+
+        for (_, date) in exercises.iter().filter(|(s, _)| *s == i) {
+            let accruals: Vec<(f64, f64)> = periods
+                .iter()
+                .filter(|p| p.accrual.1 != 0.0 && p.start_date >= *date)
+                .map(|p| p.accrual)
+                .collect();
+
+            for ((v, value), annuity) in option.iter_mut().zip(&swap_values).zip(&annuities) {
+                let exercised = match settlement {
+                    SwaptionSettlement::Cash(CashSettlementMethod::ParYieldCurve)
+                        if *annuity > 0.0 =>
+                    {
+                        let rate = strike + value / annuity;
+                        omega * (rate - strike) * cash_annuity(&accruals, rate)
+                    }
+                    _ => omega * value,
+                };
+
+                *v = v.max(exercised);
+            }
+        }
+    }
+
+    Ok(option[0])
+}
+
+/// The option on the swap rate that a swaption entering a swap of `swap_type` is.
+fn option_type(swap_type: SwapType) -> OptionType {
+    match swap_type {
+        SwapType::Payer => OptionType::Call,
+        SwapType::Receiver => OptionType::Put,
+    }
+}
+
+/// The nominal and accrual period of each coupon of the fixed leg `leg` paid after `start`.
+fn fixed_accruals(leg: &Leg, start: NaiveDate) -> Result<Vec<(f64, f64)>, LibraError> {
+    leg.coupons()
+        .filter(|c| c.date() > start)
+        .filter_map(|c| c.accrual().transpose())
+        .collect()
+}
+
+/// The value on the start of a swap of one unit of rate per annum paid on coupons with the
+/// given nominals and accrual periods, discounted at the swap rate `rate` as for par yield
+/// cash settlement.
+fn cash_annuity(accruals: &[(f64, f64)], rate: f64) -> f64 {
+    let mut discount = 1.0;
+
+    accruals
+        .iter()
+        .map(|(nominal, accrual)| {
+            discount /= 1.0 + rate * accrual;
+            nominal * accrual * discount
+        })
+        .sum()
+}
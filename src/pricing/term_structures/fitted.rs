@@ -0,0 +1,374 @@
+use chrono::NaiveDate;
+
+use crate::{
+    cashflows::Compounding,
+    error::{ArgumentRange, LibraError},
+    instruments::Bond,
+    math::solvers::{Constraint, LevenbergMarquardt, Minimum},
+    time::{DayCountConvention, Frequency},
+};
+
+use super::YieldTermStructure;
+
+/// The number of exponentials in the exponential splines method.
+const EXPONENTIALS: usize = 9;
+
+/// A parametric form for the discount function of a fitted bond curve.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FittingMethod {
+    /// The Nelson-Siegel zero curve, with parameters `[b0, b1, b2, tau]`, where `b0` is the long
+    /// rate, `b0 + b1` the short rate, `b2` the size of the hump and `tau` its position.
+    NelsonSiegel,
+    /// The Svensson extension of Nelson-Siegel with a second hump, with parameters
+    /// `[b0, b1, b2, b3, tau1, tau2]`.
+    Svensson,
+    /// The exponential splines of Li et al., where the discount function is a sum of nine
+    /// exponentials `c_i * exp(-kappa * i * t)`, with parameters `[kappa, c1, ..., c9]`. The
+    /// coefficients sum to one, so that the discount factor at time zero is one.
+    ExponentialSplines,
+    /// A discount function which is a cubic B-spline, with parameters giving the coefficient of
+    /// each basis spline. The first coefficient is one, so that the discount factor at time zero
+    /// is one.
+    CubicBSplines {
+        /// The increasing times at which the spline's pieces join, starting at zero and ending
+        /// at or after the longest bond. The discount function is extrapolated beyond the last
+        /// with a flat forward rate.
+        knots: Vec<f64>,
+    },
+}
+
+impl FittingMethod {
+    /// The full parameters of the method given the parameters that are fitted.
+    fn expand(&self, free: &[f64]) -> Vec<f64> {
+        match self {
+            FittingMethod::NelsonSiegel | FittingMethod::Svensson => free.to_vec(),
+            FittingMethod::ExponentialSplines => {
+                let mut parameters = free.to_vec();
+                parameters.push(1.0 - free[1..].iter().sum::<f64>());
+                parameters
+            }
+            FittingMethod::CubicBSplines { .. } => {
+                let mut parameters = vec![1.0];
+                parameters.extend_from_slice(free);
+                parameters
+            }
+        }
+    }
+
+    /// The starting points of the fit for a curve whose zero rates are near `rate`, and the
+    /// constraint on the fitted parameters.
+    fn initial_guesses(&self, rate: f64) -> (Vec<Vec<f64>>, Constraint) {
+        let unbounded = (f64::NEG_INFINITY, f64::INFINITY);
+        let decay = (0.05, 30.0);
+
+        match self {
+            FittingMethod::NelsonSiegel => (
+                [0.5, 2.0, 5.0]
+                    .iter()
+                    .map(|tau| vec![rate, 0.0, 0.0, *tau])
+                    .collect(),
+                Constraint::bounded(vec![unbounded, unbounded, unbounded, decay]),
+            ),
+            FittingMethod::Svensson => (
+                [(0.5, 3.0), (1.0, 5.0), (2.0, 10.0)]
+                    .iter()
+                    .map(|(tau1, tau2)| vec![rate, 0.0, 0.0, 0.0, *tau1, *tau2])
+                    .collect(),
+                Constraint::bounded(vec![
+                    unbounded, unbounded, unbounded, unbounded, decay, decay,
+                ]),
+            ),
+            FittingMethod::ExponentialSplines => {
+                // A single exponential decaying at the rate gives a flat curve.
+                let mut guess = vec![0.0; EXPONENTIALS];
+                guess[0] = rate.max(1.0e-3);
+                guess[1] = 1.0;
+
+                let mut bounds = vec![unbounded; EXPONENTIALS];
+                bounds[0] = (1.0e-4, 1.0);
+
+                (vec![guess], Constraint::bounded(bounds))
+            }
+            FittingMethod::CubicBSplines { knots } => {
+                // The coefficients of a spline close to a flat curve are its values at the
+                // averages of the knots of each basis spline.
+                let extended = clamped_knots(knots);
+                let guess = (1..extended.len() - 4)
+                    .map(|i| {
+                        let t = (extended[i + 1] + extended[i + 2] + extended[i + 3]) / 3.0;
+                        (-rate * t).exp()
+                    })
+                    .collect();
+
+                (vec![guess], Constraint::none())
+            }
+        }
+    }
+
+    /// The discount factor at time `t` given the full `parameters`.
+    fn discount(&self, parameters: &[f64], t: f64) -> f64 {
+        // The Nelson-Siegel loadings of the slope and of a hump decaying with `tau`.
+        let loadings = |tau: f64| {
+            let x = t / tau;
+            let slope = if x < 1.0e-8 {
+                1.0 - x / 2.0
+            } else {
+                (1.0 - (-x).exp()) / x
+            };
+            (slope, slope - (-x).exp())
+        };
+
+        match self {
+            FittingMethod::NelsonSiegel => {
+                let (slope, hump) = loadings(parameters[3]);
+                let zero = parameters[0] + parameters[1] * slope + parameters[2] * hump;
+                (-zero * t).exp()
+            }
+            FittingMethod::Svensson => {
+                let (slope, hump) = loadings(parameters[4]);
+                let (_, second_hump) = loadings(parameters[5]);
+                let zero = parameters[0]
+                    + parameters[1] * slope
+                    + parameters[2] * hump
+                    + parameters[3] * second_hump;
+                (-zero * t).exp()
+            }
+            FittingMethod::ExponentialSplines => {
+                let kappa = parameters[0];
+                parameters[1..]
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| c * (-kappa * (i + 1) as f64 * t).exp())
+                    .sum()
+            }
+            FittingMethod::CubicBSplines { knots } => {
+                let last = knots[knots.len() - 1];
+                let spline = |t: f64| b_spline(&clamped_knots(knots), parameters, t);
+
+                if t <= last {
+                    spline(t)
+                } else {
+                    const STEP: f64 = 1.0e-4;
+                    let end = spline(last);
+                    let forward = (spline(last - STEP) / end).ln() / STEP;
+                    end * (-forward * (t - last)).exp()
+                }
+            }
+        }
+    }
+}
+
+/// The knots of a cubic B-spline with `knots` as breakpoints, with the first and last repeated
+/// so that the spline is clamped to its first and last coefficients at either end.
+fn clamped_knots(knots: &[f64]) -> Vec<f64> {
+    let (first, last) = (knots[0], knots[knots.len() - 1]);
+    let mut extended = vec![first; 3];
+    extended.extend_from_slice(knots);
+    extended.extend([last; 3]);
+    extended
+}
+
+/// The value at `t` of the cubic B-spline on `knots` with `coefficients`, by the Cox-de Boor
+/// recursion.
+fn b_spline(knots: &[f64], coefficients: &[f64], t: f64) -> f64 {
+    let n = knots.len() - 4;
+    // The interval containing t, with the last interval closed on the right.
+    let span = (3..n)
+        .rev()
+        .find(|i| knots[*i] <= t && knots[*i] < knots[*i + 1])
+        .unwrap_or(3);
+
+    let mut d: Vec<f64> = (0..4).map(|j| coefficients[j + span - 3]).collect();
+
+    for r in 1..4 {
+        for j in (r..4).rev() {
+            let i = j + span - 3;
+            let alpha = (t - knots[i]) / (knots[i + 4 - r] - knots[i]);
+            d[j] = (1.0 - alpha) * d[j - 1] + alpha * d[j];
+        }
+    }
+
+    d[3]
+}
+
+/// A discount curve of parametric form fitted to the clean prices of a set of bonds by least
+/// squares, as used by central banks to publish government curves and to find rich and cheap
+/// bonds relative to the curve.
+///
+/// Price errors are weighted by the inverse of each bond's dollar duration, so that the fit
+/// approximately minimizes errors in yield.
+#[derive(Clone, Debug)]
+pub struct FittedBondDiscountCurve {
+    reference_date: NaiveDate,
+    day_count: DayCountConvention,
+    method: FittingMethod,
+    parameters: Vec<f64>,
+    fitted_prices: Vec<f64>,
+    residuals: Vec<f64>,
+}
+
+impl FittedBondDiscountCurve {
+    /// Fits a curve.
+    ///
+    /// # Arguments
+    ///
+    /// * `settlement` - The settlement date of the prices, which is the reference date of the
+    ///   curve.
+    /// * `bonds` - The bonds to fit to.
+    /// * `clean_prices` - The quoted clean price of each bond, per 100 of outstanding notional.
+    /// * `day_count` - The day count convention used to convert dates to times.
+    /// * `method` - The form of the discount function.
+    ///
+    /// # Returns
+    ///
+    /// The curve, or an error if the number of prices differs from the number of bonds, a bond
+    /// has matured, fewer bonds are given than the method has parameters, or the fit does not
+    /// converge.
+    pub fn new(
+        settlement: NaiveDate,
+        bonds: &[Bond],
+        clean_prices: &[f64],
+        day_count: DayCountConvention,
+        method: FittingMethod,
+    ) -> Result<Self, LibraError> {
+        if clean_prices.len() != bonds.len() {
+            return Err(ArgumentRange::new(
+                "number of prices",
+                bonds.len() as i64,
+                bonds.len() as i64,
+                clean_prices.len() as i64,
+            )
+            .into());
+        }
+
+        if let FittingMethod::CubicBSplines { knots } = &method {
+            if knots.len() < 2 {
+                return Err(
+                    ArgumentRange::new("number of knots", 2, i64::MAX, knots.len() as i64).into(),
+                );
+            }
+        }
+
+        // Each bond's continuously compounded yield and the change in its dirty price for a
+        // unit change in yield.
+        let mut yields = Vec::new();
+        let mut weights = Vec::new();
+
+        for (bond, price) in bonds.iter().zip(clean_prices) {
+            let yield_rate = bond.yield_from_clean_price(
+                *price,
+                settlement,
+                day_count,
+                Compounding::Continuous,
+                Frequency::Annual,
+            )?;
+            let risk = bond.risk(&yield_rate, settlement)?;
+
+            yields.push(yield_rate.rate());
+            weights.push(1.0 / (risk.modified_duration * risk.dirty_price));
+        }
+
+        let rate = yields.iter().sum::<f64>() / yields.len().max(1) as f64;
+        let (guesses, constraint) = method.initial_guesses(rate);
+
+        if bonds.len() < guesses[0].len() {
+            return Err(ArgumentRange::new(
+                "number of bonds",
+                guesses[0].len() as i64,
+                i64::MAX,
+                bonds.len() as i64,
+            )
+            .into());
+        }
+
+        let curve = |free: &[f64]| FittedBondDiscountCurve {
+            reference_date: settlement,
+            day_count,
+            method: method.clone(),
+            parameters: method.expand(free),
+            fitted_prices: Vec::new(),
+            residuals: Vec::new(),
+        };
+
+        let residuals = |free: &[f64]| -> Vec<f64> {
+            let trial = curve(free);
+
+            bonds
+                .iter()
+                .zip(clean_prices)
+                .zip(&weights)
+                .map(|((bond, price), weight)| {
+                    bond.clean_price_on_curve(&trial, settlement)
+                        .map_or(f64::NAN, |p| (p - price) * weight)
+                })
+                .collect()
+        };
+
+        // Keep the best fit from each starting point.
+        let mut best: Option<Minimum> = None;
+        let mut error = None;
+
+        for guess in guesses {
+            match LevenbergMarquardt::new().minimize(&residuals, &guess, &constraint) {
+                Ok(minimum) if best.as_ref().is_none_or(|b| minimum.value < b.value) => {
+                    best = Some(minimum)
+                }
+                Ok(_) => {}
+                Err(e) => error = Some(e),
+            }
+        }
+
+        let minimum = match (best, error) {
+            (Some(minimum), _) => minimum,
+            (None, Some(error)) => return Err(error),
+            (None, None) => unreachable!("every method has a starting point"),
+        };
+
+        let mut fitted = curve(&minimum.parameters);
+        fitted.fitted_prices = bonds
+            .iter()
+            .map(|bond| bond.clean_price_on_curve(&fitted, settlement))
+            .collect::<Result<_, _>>()?;
+        fitted.residuals = clean_prices
+            .iter()
+            .zip(&fitted.fitted_prices)
+            .map(|(price, fitted)| price - fitted)
+            .collect();
+
+        Ok(fitted)
+    }
+
+    pub fn method(&self) -> &FittingMethod {
+        &self.method
+    }
+
+    /// The fitted parameters, in the order described by the fitting method.
+    pub fn parameters(&self) -> &[f64] {
+        &self.parameters
+    }
+
+    /// The clean price of each bond on the fitted curve.
+    pub fn fitted_prices(&self) -> &[f64] {
+        &self.fitted_prices
+    }
+
+    /// The quoted clean price of each bond less its fitted price. Bonds with positive residuals
+    /// are rich to the curve, and those with negative residuals cheap.
+    pub fn residuals(&self) -> &[f64] {
+        &self.residuals
+    }
+}
+
+impl YieldTermStructure for FittedBondDiscountCurve {
+    fn reference_date(&self) -> NaiveDate {
+        self.reference_date
+    }
+
+    fn day_count(&self) -> DayCountConvention {
+        self.day_count
+    }
+
+    fn discount_time(&self, time: f64) -> f64 {
+        self.method.discount(&self.parameters, time)
+    }
+}
@@ -0,0 +1,40 @@
+use chrono::NaiveDate;
+
+use crate::{cashflows::InterestRate, time::DayCountConvention};
+
+use super::YieldTermStructure;
+
+/// A term structure with the same rate for every period, measured with the rate's day count,
+/// compounding and frequency.
+#[derive(Clone, Copy, Debug)]
+pub struct FlatForward {
+    reference_date: NaiveDate,
+    rate: InterestRate,
+}
+
+impl FlatForward {
+    pub fn new(reference_date: NaiveDate, rate: InterestRate) -> Self {
+        Self {
+            reference_date,
+            rate,
+        }
+    }
+
+    pub fn rate(&self) -> InterestRate {
+        self.rate
+    }
+}
+
+impl YieldTermStructure for FlatForward {
+    fn reference_date(&self) -> NaiveDate {
+        self.reference_date
+    }
+
+    fn day_count(&self) -> DayCountConvention {
+        self.rate.day_count()
+    }
+
+    fn discount_time(&self, time: f64) -> f64 {
+        self.rate.discount_factor(time)
+    }
+}
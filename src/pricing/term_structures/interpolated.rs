@@ -0,0 +1,219 @@
+use chrono::NaiveDate;
+
+use crate::{
+    error::{InvalidDate, LibraError},
+    math::interpolation::{Interpolation, Interpolator},
+    time::DayCountConvention,
+};
+
+use super::YieldTermStructure;
+
+/// Converts `dates` to times from the first of them, checking that they are strictly increasing.
+pub(super) fn pillar_times(
+    dates: &[NaiveDate],
+    day_count: DayCountConvention,
+) -> Result<Vec<f64>, LibraError> {
+    if dates.windows(2).any(|w| w[0] >= w[1]) {
+        return Err(InvalidDate.into());
+    }
+
+    dates
+        .iter()
+        .map(|d| Ok(day_count.year_frac(&dates[0], d)?))
+        .collect()
+}
+
+/// A term structure interpolating discount factors given at a set of dates.
+///
+/// The first date is the reference date, whose discount factor should be one.
+#[derive(Clone, Debug)]
+pub struct InterpolatedDiscountCurve {
+    dates: Vec<NaiveDate>,
+    day_count: DayCountConvention,
+    interpolator: Interpolator,
+}
+
+impl InterpolatedDiscountCurve {
+    /// Creates a discount curve.
+    ///
+    /// # Arguments
+    ///
+    /// * `dates` - The strictly increasing pillar dates, starting with the reference date.
+    /// * `discounts` - The discount factor at each date.
+    /// * `day_count` - The day count convention used to convert dates to times.
+    /// * `interpolation` - How discount factors are interpolated in time, usually `LogLinear`
+    ///   for piecewise constant forward rates.
+    ///
+    /// # Returns
+    ///
+    /// The curve, or an error if fewer than two dates are given, the number of discount factors
+    /// differs, or the dates are not increasing.
+    pub fn new(
+        dates: Vec<NaiveDate>,
+        discounts: Vec<f64>,
+        day_count: DayCountConvention,
+        interpolation: Interpolation,
+    ) -> Result<Self, LibraError> {
+        let times = pillar_times(&dates, day_count)?;
+
+        Ok(InterpolatedDiscountCurve {
+            interpolator: Interpolator::new(interpolation, times, discounts)?,
+            dates,
+            day_count,
+        })
+    }
+
+    pub fn dates(&self) -> &[NaiveDate] {
+        &self.dates
+    }
+
+    pub fn discounts(&self) -> &[f64] {
+        self.interpolator.ys()
+    }
+}
+
+impl YieldTermStructure for InterpolatedDiscountCurve {
+    fn reference_date(&self) -> NaiveDate {
+        self.dates[0]
+    }
+
+    fn day_count(&self) -> DayCountConvention {
+        self.day_count
+    }
+
+    fn discount_time(&self, time: f64) -> f64 {
+        self.interpolator.value(time)
+    }
+}
+
+/// A term structure interpolating continuously compounded zero rates given at a set of dates.
+///
+/// Zero rates are measured with the curve's day count convention. The first date is the
+/// reference date, whose rate is used as the short end of the curve.
+#[derive(Clone, Debug)]
+pub struct InterpolatedZeroCurve {
+    dates: Vec<NaiveDate>,
+    day_count: DayCountConvention,
+    interpolator: Interpolator,
+}
+
+impl InterpolatedZeroCurve {
+    /// Creates a zero curve.
+    ///
+    /// # Arguments
+    ///
+    /// * `dates` - The strictly increasing pillar dates, starting with the reference date.
+    /// * `rates` - The continuously compounded zero rate to each date.
+    /// * `day_count` - The day count convention used to convert dates to times.
+    /// * `interpolation` - How zero rates are interpolated in time, usually `Linear`.
+    ///
+    /// # Returns
+    ///
+    /// The curve, or an error if fewer than two dates are given, the number of rates differs,
+    /// or the dates are not increasing.
+    pub fn new(
+        dates: Vec<NaiveDate>,
+        rates: Vec<f64>,
+        day_count: DayCountConvention,
+        interpolation: Interpolation,
+    ) -> Result<Self, LibraError> {
+        let times = pillar_times(&dates, day_count)?;
+
+        Ok(InterpolatedZeroCurve {
+            interpolator: Interpolator::new(interpolation, times, rates)?,
+            dates,
+            day_count,
+        })
+    }
+
+    pub fn dates(&self) -> &[NaiveDate] {
+        &self.dates
+    }
+
+    pub fn rates(&self) -> &[f64] {
+        self.interpolator.ys()
+    }
+}
+
+impl YieldTermStructure for InterpolatedZeroCurve {
+    fn reference_date(&self) -> NaiveDate {
+        self.dates[0]
+    }
+
+    fn day_count(&self) -> DayCountConvention {
+        self.day_count
+    }
+
+    fn discount_time(&self, time: f64) -> f64 {
+        (-self.interpolator.value(time) * time).exp()
+    }
+}
+
+/// A term structure interpolating continuously compounded instantaneous forward rates given at a
+/// set of dates.
+///
+/// Discount factors are found by integrating the interpolated forward rates from the reference
+/// date, which is the first date.
+#[derive(Clone, Debug)]
+pub struct InterpolatedForwardCurve {
+    dates: Vec<NaiveDate>,
+    day_count: DayCountConvention,
+    interpolator: Interpolator,
+}
+
+impl InterpolatedForwardCurve {
+    /// Creates a forward curve.
+    ///
+    /// # Arguments
+    ///
+    /// * `dates` - The strictly increasing pillar dates, starting with the reference date.
+    /// * `forwards` - The instantaneous forward rate at each date.
+    /// * `day_count` - The day count convention used to convert dates to times.
+    /// * `interpolation` - How forward rates are interpolated in time, usually `BackwardFlat`
+    ///   so that each rate applies to the period ending at its date.
+    ///
+    /// # Returns
+    ///
+    /// The curve, or an error if fewer than two dates are given, the number of rates differs,
+    /// or the dates are not increasing.
+    pub fn new(
+        dates: Vec<NaiveDate>,
+        forwards: Vec<f64>,
+        day_count: DayCountConvention,
+        interpolation: Interpolation,
+    ) -> Result<Self, LibraError> {
+        let times = pillar_times(&dates, day_count)?;
+
+        Ok(InterpolatedForwardCurve {
+            interpolator: Interpolator::new(interpolation, times, forwards)?,
+            dates,
+            day_count,
+        })
+    }
+
+    pub fn dates(&self) -> &[NaiveDate] {
+        &self.dates
+    }
+
+    pub fn forwards(&self) -> &[f64] {
+        self.interpolator.ys()
+    }
+}
+
+impl YieldTermStructure for InterpolatedForwardCurve {
+    fn reference_date(&self) -> NaiveDate {
+        self.dates[0]
+    }
+
+    fn day_count(&self) -> DayCountConvention {
+        self.day_count
+    }
+
+    fn discount_time(&self, time: f64) -> f64 {
+        (-self.interpolator.primitive(time)).exp()
+    }
+
+    fn instantaneous_forward(&self, time: f64) -> f64 {
+        self.interpolator.value(time)
+    }
+}
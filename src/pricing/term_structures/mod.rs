@@ -0,0 +1,130 @@
+mod fitted;
+mod flat;
+mod interpolated;
+mod piecewise;
+mod rate_helpers;
+mod relinkable;
+mod spreaded;
+
+pub use fitted::*;
+pub use flat::*;
+pub use interpolated::*;
+pub use piecewise::*;
+pub use rate_helpers::*;
+pub use relinkable::*;
+pub use spreaded::*;
+
+use chrono::NaiveDate;
+
+use crate::{
+    cashflows::{Compounding, InterestRate},
+    error::{InvalidDate, LibraError},
+    time::{DayCountConvention, Frequency},
+};
+
+/// A term structure of interest rates, described by the discount factor to each date from its
+/// reference date.
+///
+/// Implementors only need to give discount factors in terms of time; dates are converted to
+/// times from the reference date with the term structure's day count convention.
+pub trait YieldTermStructure {
+    /// The date at which discount factors are one.
+    fn reference_date(&self) -> NaiveDate;
+
+    /// The day count convention used to convert dates to times.
+    fn day_count(&self) -> DayCountConvention;
+
+    /// The discount factor for `time` years after the reference date.
+    fn discount_time(&self, time: f64) -> f64;
+
+    /// The time in years from the reference date to `date`.
+    ///
+    /// Returns an error if `date` is before the reference date.
+    fn time_from_reference(&self, date: NaiveDate) -> Result<f64, LibraError> {
+        Ok(self.day_count().year_frac(&self.reference_date(), &date)?)
+    }
+
+    /// The discount factor from `date` back to the reference date.
+    fn discount(&self, date: NaiveDate) -> Result<f64, LibraError> {
+        Ok(self.discount_time(self.time_from_reference(date)?))
+    }
+
+    /// The zero rate from the reference date to `date`.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The end of the period. The rate over the first day is returned if this is the
+    ///   reference date.
+    /// * `day_count` - The day count convention of the rate.
+    /// * `compounding` - The compounding of the rate.
+    /// * `frequency` - The compounding frequency of the rate.
+    ///
+    /// # Returns
+    ///
+    /// The zero rate, or an error if `date` is before the reference date.
+    fn zero_rate(
+        &self,
+        date: NaiveDate,
+        day_count: DayCountConvention,
+        compounding: Compounding,
+        frequency: Frequency,
+    ) -> Result<InterestRate, LibraError> {
+        self.forward_rate(
+            self.reference_date(),
+            date,
+            day_count,
+            compounding,
+            frequency,
+        )
+    }
+
+    /// The forward rate between `from` and `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The start of the forward period.
+    /// * `to` - The end of the forward period. The rate over the day after `from` is returned if
+    ///   this is `from`.
+    /// * `day_count` - The day count convention of the rate.
+    /// * `compounding` - The compounding of the rate.
+    /// * `frequency` - The compounding frequency of the rate.
+    ///
+    /// # Returns
+    ///
+    /// The forward rate, or an error if either date is before the reference date or `to` is
+    /// before `from`.
+    fn forward_rate(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        day_count: DayCountConvention,
+        compounding: Compounding,
+        frequency: Frequency,
+    ) -> Result<InterestRate, LibraError> {
+        let to = if to == from {
+            from.succ_opt().ok_or(InvalidDate)?
+        } else {
+            to
+        };
+
+        Ok(InterestRate::implied_rate_between(
+            self.discount(from)? / self.discount(to)?,
+            day_count,
+            compounding,
+            frequency,
+            &from,
+            &to,
+        )?)
+    }
+
+    /// The continuously compounded instantaneous forward rate `time` years after the reference
+    /// date.
+    fn instantaneous_forward(&self, time: f64) -> f64 {
+        const STEP: f64 = 1.0e-4;
+
+        let start = (time - STEP).max(0.0);
+        let end = start + 2.0 * STEP;
+
+        (self.discount_time(start) / self.discount_time(end)).ln() / (end - start)
+    }
+}
@@ -0,0 +1,372 @@
+use chrono::NaiveDate;
+
+use crate::{
+    error::{LibraError, NoConvergence},
+    math::{
+        interpolation::{Interpolation, Interpolator},
+        solvers::{bracket, Brent, Solver1D, DEFAULT_MAX_EVALUATIONS},
+    },
+    time::DayCountConvention,
+};
+
+use super::{interpolated::pillar_times, RateHelper, YieldTermStructure};
+
+/// The accuracy to which each node is solved, and to which nodes must settle between passes.
+const BOOTSTRAP_ACCURACY: f64 = 1.0e-12;
+
+/// The maximum number of passes over the nodes for interpolations where each node affects the
+/// curve before the previous one.
+const MAX_PASSES: usize = 50;
+
+/// The quantity interpolated between the nodes of a bootstrapped curve, and how.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PiecewiseInterpolation {
+    /// Discount factors interpolated log-linearly, giving piecewise flat forward rates.
+    LogLinearDiscount,
+    /// Continuously compounded zero rates interpolated with a natural cubic spline.
+    CubicZero,
+    /// Continuously compounded zero rates interpolated with the monotone convex method of Hagan
+    /// and West, giving continuous and, where possible, positive forward rates.
+    MonotoneConvex,
+}
+
+impl PiecewiseInterpolation {
+    fn interpolation(&self) -> Interpolation {
+        match self {
+            PiecewiseInterpolation::LogLinearDiscount => Interpolation::LogLinear,
+            PiecewiseInterpolation::CubicZero => Interpolation::CubicNatural,
+            PiecewiseInterpolation::MonotoneConvex => Interpolation::MonotoneConvex,
+        }
+    }
+
+    fn is_local(&self) -> bool {
+        matches!(self, PiecewiseInterpolation::LogLinearDiscount)
+    }
+}
+
+/// A yield curve bootstrapped so that each of a set of instruments reprices to its quote.
+///
+/// The curve has a node at the reference date and at the pillar date of each instrument, solved
+/// in order of maturity. Interpolations where later nodes move the curve before earlier pillars
+/// are solved repeatedly until the nodes settle.
+#[derive(Clone, Debug)]
+pub struct PiecewiseYieldCurve {
+    dates: Vec<NaiveDate>,
+    day_count: DayCountConvention,
+    interpolation: PiecewiseInterpolation,
+    interpolator: Interpolator,
+    repricing_errors: Vec<f64>,
+}
+
+impl PiecewiseYieldCurve {
+    /// Bootstraps a curve.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference_date` - The date at which discount factors are one.
+    /// * `helpers` - The instruments the curve must reprice, in any order. Each must have a
+    ///   different pillar date after `reference_date`.
+    /// * `day_count` - The day count convention used to convert dates to times.
+    /// * `interpolation` - The quantity interpolated between nodes, and how.
+    ///
+    /// # Returns
+    ///
+    /// The curve, or an error if no helpers are given, two share a pillar date, or no curve
+    /// reprices them.
+    pub fn new(
+        reference_date: NaiveDate,
+        helpers: &[Box<dyn RateHelper>],
+        day_count: DayCountConvention,
+        interpolation: PiecewiseInterpolation,
+    ) -> Result<Self, LibraError> {
+        let mut order: Vec<usize> = (0..helpers.len()).collect();
+        order.sort_by_key(|i| helpers[*i].pillar_date());
+
+        let mut dates = vec![reference_date];
+        dates.extend(order.iter().map(|i| helpers[*i].pillar_date()));
+        let times = pillar_times(&dates, day_count)?;
+
+        let initial = match interpolation {
+            PiecewiseInterpolation::LogLinearDiscount => 1.0,
+            _ => 0.0,
+        };
+        let mut values = vec![initial; dates.len()];
+
+        for pass in 0..MAX_PASSES {
+            let mut change: f64 = 0.0;
+
+            for (k, i) in order.iter().enumerate().map(|(k, i)| (k + 1, *i)) {
+                // The first pass only uses the nodes solved so far.
+                let nodes = if pass == 0 { k + 1 } else { dates.len() };
+                let helper = &helpers[i];
+
+                let error = |value: f64| {
+                    let mut trial = values[..nodes].to_vec();
+                    trial[k] = value;
+                    if !interpolation.is_local() && k == 1 {
+                        trial[0] = value;
+                    }
+
+                    Self::from_nodes(
+                        &dates[..nodes],
+                        &times[..nodes],
+                        &trial,
+                        day_count,
+                        interpolation,
+                    )
+                    .and_then(|c| c.implied_quote_error(helper.as_ref()))
+                    .unwrap_or(f64::NAN)
+                };
+
+                let (guess, step, domain) = match interpolation {
+                    PiecewiseInterpolation::LogLinearDiscount => {
+                        (values[k - 1], 0.05, (1.0e-6, 10.0))
+                    }
+                    _ => (values[k - 1], 0.01, (-1.0, 1.0)),
+                };
+                let guess = if pass == 0 { guess } else { values[k] };
+
+                let (lower, upper) = bracket(&error, guess, step, domain, DEFAULT_MAX_EVALUATIONS)?;
+                let value =
+                    Brent::new().solve_bracketed(&error, BOOTSTRAP_ACCURACY, lower, upper)?;
+
+                change = change.max((value - values[k]).abs());
+                values[k] = value;
+                if !interpolation.is_local() && k == 1 {
+                    values[0] = value;
+                }
+            }
+
+            let mut curve = Self::from_nodes(&dates, &times, &values, day_count, interpolation)?;
+
+            if interpolation.is_local() || (pass > 0 && change < BOOTSTRAP_ACCURACY) {
+                curve.repricing_errors = helpers
+                    .iter()
+                    .map(|h| curve.implied_quote_error(h.as_ref()))
+                    .collect::<Result<_, _>>()?;
+
+                return Ok(curve);
+            }
+        }
+
+        Err(NoConvergence::new("piecewise bootstrap", MAX_PASSES).into())
+    }
+
+    /// Creates the curve through the node `values` at `dates`, with no repricing errors.
+    fn from_nodes(
+        dates: &[NaiveDate],
+        times: &[f64],
+        values: &[f64],
+        day_count: DayCountConvention,
+        interpolation: PiecewiseInterpolation,
+    ) -> Result<Self, LibraError> {
+        Ok(PiecewiseYieldCurve {
+            dates: dates.to_vec(),
+            day_count,
+            interpolation,
+            interpolator: Interpolator::new(
+                interpolation.interpolation(),
+                times.to_vec(),
+                values.to_vec(),
+            )?,
+            repricing_errors: Vec::new(),
+        })
+    }
+
+    /// The quote of `helper` implied by this curve less its market quote.
+    fn implied_quote_error(&self, helper: &dyn RateHelper) -> Result<f64, LibraError> {
+        Ok(helper.implied_quote(self)? - helper.quote())
+    }
+
+    /// The dates of the nodes, starting with the reference date and followed by the pillar
+    /// date of each instrument in order.
+    pub fn dates(&self) -> &[NaiveDate] {
+        &self.dates
+    }
+
+    pub fn interpolation(&self) -> PiecewiseInterpolation {
+        self.interpolation
+    }
+
+    /// The discount factor at each node.
+    pub fn discounts(&self) -> Vec<f64> {
+        self.interpolator
+            .xs()
+            .iter()
+            .map(|t| self.discount_time(*t))
+            .collect()
+    }
+
+    /// The quote implied by the curve less the market quote of each instrument, in the order
+    /// the instruments were given.
+    pub fn repricing_errors(&self) -> &[f64] {
+        &self.repricing_errors
+    }
+}
+
+impl YieldTermStructure for PiecewiseYieldCurve {
+    fn reference_date(&self) -> NaiveDate {
+        self.dates[0]
+    }
+
+    fn day_count(&self) -> DayCountConvention {
+        self.day_count
+    }
+
+    fn discount_time(&self, time: f64) -> f64 {
+        match self.interpolation {
+            PiecewiseInterpolation::LogLinearDiscount => self.interpolator.value(time),
+            _ => (-self.interpolator.value(time) * time).exp(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use chrono::{Months, NaiveDate};
+
+    use crate::{
+        pricing::{
+            DepositRateHelper, FraRateHelper, OisRateHelper, RateHelper, SwapRateHelper,
+            YieldTermStructure,
+        },
+        time::{Calendar, DateAdjustment, DateGeneration, DayCountConvention, Schedule},
+    };
+
+    use super::{PiecewiseInterpolation, PiecewiseYieldCurve};
+
+    const INTERPOLATIONS: [PiecewiseInterpolation; 3] = [
+        PiecewiseInterpolation::LogLinearDiscount,
+        PiecewiseInterpolation::CubicZero,
+        PiecewiseInterpolation::MonotoneConvex,
+    ];
+
+    fn reference_date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 1, 2).unwrap()
+    }
+
+    fn schedule(years: u32, tenor: u32) -> Schedule {
+        Schedule::new(
+            reference_date(),
+            reference_date() + Months::new(12 * years),
+            Months::new(tenor),
+            Calendar::UnitedKingdom,
+            DateAdjustment::ModifiedFollowing,
+            DateGeneration::Backward,
+        )
+        .unwrap()
+    }
+
+    fn swap(rate: f64, years: u32) -> SwapRateHelper {
+        SwapRateHelper::new(
+            rate,
+            schedule(years, 12),
+            DayCountConvention::Thirty360,
+            schedule(years, 6),
+            DayCountConvention::Actual360,
+        )
+    }
+
+    fn assert_reprices(curve: &dyn YieldTermStructure, helpers: &[Box<dyn RateHelper>]) {
+        for helper in helpers {
+            let implied = helper.implied_quote(curve).unwrap();
+            assert!(
+                (implied - helper.quote()).abs() < 1.0e-10,
+                "{} implied for a quote of {}",
+                implied,
+                helper.quote()
+            );
+        }
+    }
+
+    #[test]
+    fn bootstrapped_curves_reprice_their_helpers() {
+        let months = |n: u32| reference_date() + Months::new(n);
+        let helpers: Vec<Box<dyn RateHelper>> = vec![
+            Box::new(DepositRateHelper::new(
+                0.0520,
+                reference_date(),
+                months(3),
+                DayCountConvention::Actual360,
+            )),
+            Box::new(FraRateHelper::new(
+                0.0505,
+                months(3),
+                months(9),
+                DayCountConvention::Actual360,
+            )),
+            Box::new(swap(0.0480, 2)),
+            Box::new(swap(0.0440, 5)),
+            Box::new(swap(0.0455, 10)),
+            Box::new(swap(0.0430, 30)),
+        ];
+
+        for interpolation in INTERPOLATIONS {
+            let curve = PiecewiseYieldCurve::new(
+                reference_date(),
+                &helpers,
+                DayCountConvention::Actual365Fixed,
+                interpolation,
+            )
+            .unwrap();
+
+            assert_eq!(curve.repricing_errors().len(), helpers.len());
+            assert!(curve.repricing_errors().iter().all(|e| e.abs() < 1.0e-10));
+            assert_reprices(&curve, &helpers);
+        }
+    }
+
+    #[test]
+    fn swaps_discounted_on_an_ois_curve_reprice() {
+        let ois: Vec<Box<dyn RateHelper>> = [(0.0400, 1), (0.0370, 3), (0.0360, 10)]
+            .into_iter()
+            .map(|(rate, years)| {
+                Box::new(OisRateHelper::new(
+                    rate,
+                    schedule(years, 12),
+                    DayCountConvention::Actual360,
+                )) as Box<dyn RateHelper>
+            })
+            .collect();
+
+        for interpolation in INTERPOLATIONS {
+            let discount_curve: Rc<dyn YieldTermStructure> = Rc::new(
+                PiecewiseYieldCurve::new(
+                    reference_date(),
+                    &ois,
+                    DayCountConvention::Actual365Fixed,
+                    interpolation,
+                )
+                .unwrap(),
+            );
+            assert_reprices(discount_curve.as_ref(), &ois);
+
+            let swaps: Vec<Box<dyn RateHelper>> = [(0.0450, 2), (0.0420, 5), (0.0410, 10)]
+                .into_iter()
+                .map(|(rate, years)| {
+                    Box::new(swap(rate, years).with_discount_curve(discount_curve.clone()))
+                        as Box<dyn RateHelper>
+                })
+                .collect();
+            let projection_curve = PiecewiseYieldCurve::new(
+                reference_date(),
+                &swaps,
+                DayCountConvention::Actual365Fixed,
+                interpolation,
+            )
+            .unwrap();
+
+            assert!(projection_curve
+                .repricing_errors()
+                .iter()
+                .all(|e| e.abs() < 1.0e-10));
+            assert_reprices(&projection_curve, &swaps);
+
+            // Discounting on the projection curve instead changes the implied swap rates.
+            let single_curve = swap(0.0410, 10).implied_quote(&projection_curve).unwrap();
+            assert!((single_curve - 0.0410).abs() > 1.0e-6);
+        }
+    }
+}
@@ -0,0 +1,516 @@
+use std::rc::Rc;
+
+use chrono::NaiveDate;
+
+use crate::{
+    error::LibraError,
+    instruments::Fra,
+    time::{DayCountConvention, Schedule},
+};
+
+use super::YieldTermStructure;
+
+/// A quoted instrument used to bootstrap a yield curve, which can find the quote implied by a
+/// trial curve.
+pub trait RateHelper {
+    /// The market quote of the instrument.
+    fn quote(&self) -> f64;
+
+    /// The date of the last discount factor the instrument depends on, at which the curve has a
+    /// node fixed by this instrument.
+    fn pillar_date(&self) -> NaiveDate;
+
+    /// The quote implied by `curve`, which is the curve being bootstrapped.
+    fn implied_quote(&self, curve: &dyn YieldTermStructure) -> Result<f64, LibraError>;
+}
+
+/// The sum of the accrual fractions of each period of `schedule` weighted by the discount factor
+/// at the end of the period.
+fn annuity(
+    schedule: &Schedule,
+    day_count: DayCountConvention,
+    discount: &dyn YieldTermStructure,
+) -> Result<f64, LibraError> {
+    schedule.periods().try_fold(0.0, |sum, (start, end)| {
+        Ok(sum + day_count.year_frac(&start, &end)? * discount.discount(end)?)
+    })
+}
+
+/// The value of a unit notional leg paying the simple forward rate of `projection` over each
+/// period of `schedule`, discounted on `discount`.
+fn floating_leg(
+    schedule: &Schedule,
+    projection: &dyn YieldTermStructure,
+    discount: &dyn YieldTermStructure,
+) -> Result<f64, LibraError> {
+    schedule.periods().try_fold(0.0, |sum, (start, end)| {
+        let growth = projection.discount(start)? / projection.discount(end)?;
+        Ok(sum + (growth - 1.0) * discount.discount(end)?)
+    })
+}
+
+/// The simple rate implied by `curve` between `start` and `end`.
+fn simple_forward(
+    curve: &dyn YieldTermStructure,
+    start: NaiveDate,
+    end: NaiveDate,
+    day_count: DayCountConvention,
+) -> Result<f64, LibraError> {
+    Ok(
+        (curve.discount(start)? / curve.discount(end)? - 1.0)
+            / day_count.year_frac(&start, &end)?,
+    )
+}
+
+/// The difference between the futures rate and the forward rate for the period between `start`
+/// and `end` years from today, in the Hull-White model.
+///
+/// # Arguments
+///
+/// * `volatility` - The volatility of the short rate.
+/// * `mean_reversion` - The speed of mean reversion of the short rate. The Ho-Lee adjustment
+///   `volatility^2 * start * end / 2` is used if this is zero.
+/// * `start` - The time in years to the start of the futures period.
+/// * `end` - The time in years to the end of the futures period.
+///
+/// # Returns
+///
+/// The convexity adjustment, to be subtracted from the futures rate.
+pub fn hull_white_convexity_adjustment(
+    volatility: f64,
+    mean_reversion: f64,
+    start: f64,
+    end: f64,
+) -> f64 {
+    if mean_reversion.abs() < 1.0e-8 {
+        return volatility * volatility * start * end / 2.0;
+    }
+
+    let b = |t: f64| (1.0 - (-mean_reversion * t).exp()) / mean_reversion;
+    let period = b(end - start);
+
+    period / (end - start)
+        * (period * (1.0 - (-2.0 * mean_reversion * start).exp())
+            + 2.0 * mean_reversion * b(start).powi(2))
+        * volatility
+        * volatility
+        / (4.0 * mean_reversion)
+}
+
+/// A deposit paying a simple rate between two dates.
+#[derive(Clone, Copy, Debug)]
+pub struct DepositRateHelper {
+    rate: f64,
+    start: NaiveDate,
+    end: NaiveDate,
+    day_count: DayCountConvention,
+}
+
+impl DepositRateHelper {
+    /// Creates a deposit helper.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate` - The quoted simple rate.
+    /// * `start` - The date the deposit is made, no earlier than the curve's reference date.
+    /// * `end` - The date the deposit is repaid.
+    /// * `day_count` - The day count convention of the rate.
+    pub fn new(rate: f64, start: NaiveDate, end: NaiveDate, day_count: DayCountConvention) -> Self {
+        DepositRateHelper {
+            rate,
+            start,
+            end,
+            day_count,
+        }
+    }
+}
+
+impl RateHelper for DepositRateHelper {
+    fn quote(&self) -> f64 {
+        self.rate
+    }
+
+    fn pillar_date(&self) -> NaiveDate {
+        self.end
+    }
+
+    fn implied_quote(&self, curve: &dyn YieldTermStructure) -> Result<f64, LibraError> {
+        simple_forward(curve, self.start, self.end, self.day_count)
+    }
+}
+
+/// A forward rate agreement, quoted as the simple rate fixed for a future period.
+#[derive(Clone, Copy, Debug)]
+pub struct FraRateHelper {
+    rate: f64,
+    start: NaiveDate,
+    end: NaiveDate,
+    day_count: DayCountConvention,
+}
+
+impl FraRateHelper {
+    /// Creates a FRA helper.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate` - The quoted simple rate.
+    /// * `start` - The start of the period the rate applies to.
+    /// * `end` - The end of the period the rate applies to.
+    /// * `day_count` - The day count convention of the rate.
+    pub fn new(rate: f64, start: NaiveDate, end: NaiveDate, day_count: DayCountConvention) -> Self {
+        FraRateHelper {
+            rate,
+            start,
+            end,
+            day_count,
+        }
+    }
+
+    /// Creates a helper quoting the strike of `fra`, so that a curve bootstrapped from it
+    /// forecasts the FRA at par.
+    pub fn from_fra(fra: &Fra) -> Self {
+        Self::new(
+            fra.strike(),
+            fra.value_date(),
+            fra.maturity_date(),
+            fra.index().day_count(),
+        )
+    }
+}
+
+impl RateHelper for FraRateHelper {
+    fn quote(&self) -> f64 {
+        self.rate
+    }
+
+    fn pillar_date(&self) -> NaiveDate {
+        self.end
+    }
+
+    fn implied_quote(&self, curve: &dyn YieldTermStructure) -> Result<f64, LibraError> {
+        simple_forward(curve, self.start, self.end, self.day_count)
+    }
+}
+
+/// A short-term interest rate future, quoted as 100 minus the futures rate in percent.
+///
+/// The futures rate exceeds the forward rate by a convexity adjustment, as futures are settled
+/// daily.
+#[derive(Clone, Copy, Debug)]
+pub struct FuturesRateHelper {
+    price: f64,
+    start: NaiveDate,
+    end: NaiveDate,
+    day_count: DayCountConvention,
+    convexity_adjustment: f64,
+}
+
+impl FuturesRateHelper {
+    /// Creates a futures helper with no convexity adjustment.
+    ///
+    /// # Arguments
+    ///
+    /// * `price` - The quoted futures price, such as 96.5 for a rate of 3.5%.
+    /// * `start` - The start of the period the underlying rate applies to.
+    /// * `end` - The end of the period the underlying rate applies to.
+    /// * `day_count` - The day count convention of the underlying rate.
+    pub fn new(
+        price: f64,
+        start: NaiveDate,
+        end: NaiveDate,
+        day_count: DayCountConvention,
+    ) -> Self {
+        FuturesRateHelper {
+            price,
+            start,
+            end,
+            day_count,
+            convexity_adjustment: 0.0,
+        }
+    }
+
+    /// Sets the amount by which the futures rate exceeds the forward rate, as found by
+    /// [`hull_white_convexity_adjustment`].
+    pub fn with_convexity_adjustment(mut self, convexity_adjustment: f64) -> Self {
+        self.convexity_adjustment = convexity_adjustment;
+        self
+    }
+
+    pub fn convexity_adjustment(&self) -> f64 {
+        self.convexity_adjustment
+    }
+}
+
+impl RateHelper for FuturesRateHelper {
+    fn quote(&self) -> f64 {
+        self.price
+    }
+
+    fn pillar_date(&self) -> NaiveDate {
+        self.end
+    }
+
+    fn implied_quote(&self, curve: &dyn YieldTermStructure) -> Result<f64, LibraError> {
+        let forward = simple_forward(curve, self.start, self.end, self.day_count)?;
+        Ok(100.0 * (1.0 - forward - self.convexity_adjustment))
+    }
+}
+
+/// An overnight indexed swap, exchanging a fixed rate for the compounded overnight rate over each
+/// period. The curve being bootstrapped both projects and discounts the overnight rate.
+#[derive(Clone, Debug)]
+pub struct OisRateHelper {
+    rate: f64,
+    schedule: Schedule,
+    day_count: DayCountConvention,
+}
+
+impl OisRateHelper {
+    /// Creates an OIS helper.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate` - The quoted fixed rate.
+    /// * `schedule` - The payment periods of both legs.
+    /// * `day_count` - The day count convention of the fixed rate.
+    pub fn new(rate: f64, schedule: Schedule, day_count: DayCountConvention) -> Self {
+        OisRateHelper {
+            rate,
+            schedule,
+            day_count,
+        }
+    }
+}
+
+impl RateHelper for OisRateHelper {
+    fn quote(&self) -> f64 {
+        self.rate
+    }
+
+    fn pillar_date(&self) -> NaiveDate {
+        self.schedule.end_date()
+    }
+
+    fn implied_quote(&self, curve: &dyn YieldTermStructure) -> Result<f64, LibraError> {
+        Ok(floating_leg(&self.schedule, curve, curve)?
+            / annuity(&self.schedule, self.day_count, curve)?)
+    }
+}
+
+/// A vanilla interest rate swap, exchanging a fixed rate for a floating rate projected from the
+/// curve being bootstrapped.
+///
+/// Flows are discounted on the curve being bootstrapped unless a separate discount curve is
+/// given, in which case the bootstrapped curve is only used to project the floating rate.
+#[derive(Clone)]
+pub struct SwapRateHelper {
+    rate: f64,
+    fixed_schedule: Schedule,
+    fixed_day_count: DayCountConvention,
+    floating_schedule: Schedule,
+    floating_day_count: DayCountConvention,
+    spread: f64,
+    discount_curve: Option<Rc<dyn YieldTermStructure>>,
+}
+
+impl SwapRateHelper {
+    /// Creates a swap helper with no spread on the floating leg.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate` - The quoted fixed rate.
+    /// * `fixed_schedule` - The periods of the fixed leg.
+    /// * `fixed_day_count` - The day count convention of the fixed rate.
+    /// * `floating_schedule` - The periods of the floating leg, over each of which the floating
+    ///   rate is the simple forward rate.
+    /// * `floating_day_count` - The day count convention of the floating rate.
+    pub fn new(
+        rate: f64,
+        fixed_schedule: Schedule,
+        fixed_day_count: DayCountConvention,
+        floating_schedule: Schedule,
+        floating_day_count: DayCountConvention,
+    ) -> Self {
+        SwapRateHelper {
+            rate,
+            fixed_schedule,
+            fixed_day_count,
+            floating_schedule,
+            floating_day_count,
+            spread: 0.0,
+            discount_curve: None,
+        }
+    }
+
+    /// Adds `spread` to the floating rate.
+    pub fn with_spread(mut self, spread: f64) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Discounts flows on `discount_curve`, such as an OIS curve, rather than on the curve being
+    /// bootstrapped.
+    pub fn with_discount_curve(mut self, discount_curve: Rc<dyn YieldTermStructure>) -> Self {
+        self.discount_curve = Some(discount_curve);
+        self
+    }
+}
+
+impl RateHelper for SwapRateHelper {
+    fn quote(&self) -> f64 {
+        self.rate
+    }
+
+    fn pillar_date(&self) -> NaiveDate {
+        self.fixed_schedule
+            .end_date()
+            .max(self.floating_schedule.end_date())
+    }
+
+    fn implied_quote(&self, curve: &dyn YieldTermStructure) -> Result<f64, LibraError> {
+        let discount = self.discount_curve.as_deref().unwrap_or(curve);
+        let floating = floating_leg(&self.floating_schedule, curve, discount)?
+            + self.spread * annuity(&self.floating_schedule, self.floating_day_count, discount)?;
+
+        Ok(floating / annuity(&self.fixed_schedule, self.fixed_day_count, discount)?)
+    }
+}
+
+/// A single currency basis swap, exchanging a floating rate projected from the curve being
+/// bootstrapped plus a quoted spread for a floating rate projected from a known curve, such as
+/// 3 month against 6 month EURIBOR.
+#[derive(Clone)]
+pub struct BasisSwapRateHelper {
+    spread: f64,
+    schedule: Schedule,
+    day_count: DayCountConvention,
+    base_schedule: Schedule,
+    base_curve: Rc<dyn YieldTermStructure>,
+    discount_curve: Option<Rc<dyn YieldTermStructure>>,
+}
+
+impl BasisSwapRateHelper {
+    /// Creates a basis swap helper.
+    ///
+    /// # Arguments
+    ///
+    /// * `spread` - The quoted spread on the leg projected from the curve being bootstrapped.
+    /// * `schedule` - The periods of the leg projected from the curve being bootstrapped.
+    /// * `day_count` - The day count convention of the spread.
+    /// * `base_schedule` - The periods of the leg projected from `base_curve`.
+    /// * `base_curve` - The known projection curve of the other leg.
+    pub fn new(
+        spread: f64,
+        schedule: Schedule,
+        day_count: DayCountConvention,
+        base_schedule: Schedule,
+        base_curve: Rc<dyn YieldTermStructure>,
+    ) -> Self {
+        BasisSwapRateHelper {
+            spread,
+            schedule,
+            day_count,
+            base_schedule,
+            base_curve,
+            discount_curve: None,
+        }
+    }
+
+    /// Discounts flows on `discount_curve` rather than on the curve being bootstrapped.
+    pub fn with_discount_curve(mut self, discount_curve: Rc<dyn YieldTermStructure>) -> Self {
+        self.discount_curve = Some(discount_curve);
+        self
+    }
+}
+
+impl RateHelper for BasisSwapRateHelper {
+    fn quote(&self) -> f64 {
+        self.spread
+    }
+
+    fn pillar_date(&self) -> NaiveDate {
+        self.schedule.end_date().max(self.base_schedule.end_date())
+    }
+
+    fn implied_quote(&self, curve: &dyn YieldTermStructure) -> Result<f64, LibraError> {
+        let discount = self.discount_curve.as_deref().unwrap_or(curve);
+        let base = floating_leg(&self.base_schedule, self.base_curve.as_ref(), discount)?;
+        let projected = floating_leg(&self.schedule, curve, discount)?;
+
+        Ok((base - projected) / annuity(&self.schedule, self.day_count, discount)?)
+    }
+}
+
+/// A constant notional cross currency basis swap, exchanging a foreign floating rate plus a
+/// quoted basis for a domestic floating rate, with notionals exchanged at the spot FX rate at the
+/// start and returned at the end.
+///
+/// The curve being bootstrapped discounts foreign flows for collateral posted in the domestic
+/// currency. As notionals are exchanged at spot, the FX rate itself does not affect the basis.
+#[derive(Clone)]
+pub struct CrossCurrencyBasisRateHelper {
+    basis: f64,
+    schedule: Schedule,
+    foreign_day_count: DayCountConvention,
+    foreign_projection: Rc<dyn YieldTermStructure>,
+    domestic_discount: Rc<dyn YieldTermStructure>,
+    domestic_projection: Rc<dyn YieldTermStructure>,
+}
+
+impl CrossCurrencyBasisRateHelper {
+    /// Creates a cross currency basis helper.
+    ///
+    /// # Arguments
+    ///
+    /// * `basis` - The quoted spread on the foreign leg.
+    /// * `schedule` - The periods of both legs.
+    /// * `foreign_day_count` - The day count convention of the basis.
+    /// * `foreign_projection` - The curve projecting the foreign floating rate.
+    /// * `domestic_discount` - The curve discounting domestic flows.
+    /// * `domestic_projection` - The curve projecting the domestic floating rate.
+    pub fn new(
+        basis: f64,
+        schedule: Schedule,
+        foreign_day_count: DayCountConvention,
+        foreign_projection: Rc<dyn YieldTermStructure>,
+        domestic_discount: Rc<dyn YieldTermStructure>,
+        domestic_projection: Rc<dyn YieldTermStructure>,
+    ) -> Self {
+        CrossCurrencyBasisRateHelper {
+            basis,
+            schedule,
+            foreign_day_count,
+            foreign_projection,
+            domestic_discount,
+            domestic_projection,
+        }
+    }
+}
+
+impl RateHelper for CrossCurrencyBasisRateHelper {
+    fn quote(&self) -> f64 {
+        self.basis
+    }
+
+    fn pillar_date(&self) -> NaiveDate {
+        self.schedule.end_date()
+    }
+
+    fn implied_quote(&self, curve: &dyn YieldTermStructure) -> Result<f64, LibraError> {
+        let (start, end) = (self.schedule.start_date(), self.schedule.end_date());
+
+        // The value of each leg per unit of its own notional, including the notional exchanges.
+        let domestic_discount = self.domestic_discount.as_ref();
+        let domestic = floating_leg(
+            &self.schedule,
+            self.domestic_projection.as_ref(),
+            domestic_discount,
+        )? + domestic_discount.discount(end)?
+            - domestic_discount.discount(start)?;
+        let foreign = floating_leg(&self.schedule, self.foreign_projection.as_ref(), curve)?
+            + curve.discount(end)?
+            - curve.discount(start)?;
+
+        Ok((domestic - foreign) / annuity(&self.schedule, self.foreign_day_count, curve)?)
+    }
+}
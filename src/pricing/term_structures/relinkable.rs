@@ -0,0 +1,91 @@
+use std::{cell::RefCell, fmt, rc::Rc};
+
+use chrono::NaiveDate;
+
+use crate::time::DayCountConvention;
+
+use super::YieldTermStructure;
+
+/// A handle to a term structure which can be relinked to another one.
+///
+/// Clones of a handle share its link, so anything built on a clone, such as a spreaded curve or
+/// an index, follows the handle when it is relinked to a new curve, for example after the market
+/// moves or a curve is bootstrapped again.
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use chrono::NaiveDate;
+/// use libra::{
+///     cashflows::{Compounding, InterestRate},
+///     pricing::*,
+///     time::{DayCountConvention, Frequency},
+/// };
+///
+/// let reference_date = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+/// let flat = |rate: f64| {
+///     let rate = InterestRate::new(
+///         rate,
+///         DayCountConvention::Actual365Fixed,
+///         Compounding::Continuous,
+///         Frequency::Annual,
+///     );
+///     Rc::new(FlatForward::new(reference_date, rate))
+/// };
+///
+/// let handle = RelinkableYieldTermStructure::new(flat(0.03));
+/// let spreaded = ZeroSpreadedTermStructure::new(Rc::new(handle.clone()), 0.01);
+/// assert!((spreaded.discount_time(1.0) - (-0.04_f64).exp()).abs() < 1.0e-12);
+///
+/// handle.link_to(flat(0.05));
+/// assert!((spreaded.discount_time(1.0) - (-0.06_f64).exp()).abs() < 1.0e-12);
+/// ```
+#[derive(Clone)]
+pub struct RelinkableYieldTermStructure {
+    link: Rc<RefCell<Rc<dyn YieldTermStructure>>>,
+}
+
+impl RelinkableYieldTermStructure {
+    /// Creates a handle linked to `curve`.
+    pub fn new(curve: Rc<dyn YieldTermStructure>) -> Self {
+        RelinkableYieldTermStructure {
+            link: Rc::new(RefCell::new(curve)),
+        }
+    }
+
+    /// Links this handle, and every clone of it, to `curve`.
+    pub fn link_to(&self, curve: Rc<dyn YieldTermStructure>) {
+        *self.link.borrow_mut() = curve;
+    }
+
+    /// The curve the handle is currently linked to.
+    pub fn current_link(&self) -> Rc<dyn YieldTermStructure> {
+        self.link.borrow().clone()
+    }
+}
+
+impl YieldTermStructure for RelinkableYieldTermStructure {
+    fn reference_date(&self) -> NaiveDate {
+        self.link.borrow().reference_date()
+    }
+
+    fn day_count(&self) -> DayCountConvention {
+        self.link.borrow().day_count()
+    }
+
+    fn discount_time(&self, time: f64) -> f64 {
+        self.link.borrow().discount_time(time)
+    }
+
+    fn instantaneous_forward(&self, time: f64) -> f64 {
+        self.link.borrow().instantaneous_forward(time)
+    }
+}
+
+impl fmt::Debug for RelinkableYieldTermStructure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RelinkableYieldTermStructure")
+            .field("reference_date", &self.reference_date())
+            .finish_non_exhaustive()
+    }
+}
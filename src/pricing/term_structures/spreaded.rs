@@ -0,0 +1,302 @@
+use std::{fmt, rc::Rc};
+
+use chrono::NaiveDate;
+
+use crate::{
+    cashflows::{Compounding, InterestRate},
+    error::LibraError,
+    math::interpolation::{Extrapolation, Interpolation, Interpolator},
+    time::{DayCountConvention, Frequency},
+};
+
+use super::YieldTermStructure;
+
+/// A term structure whose zero rates are those of a base curve plus a constant spread.
+///
+/// The spread is added to zero rates with the given compounding, measured with the base curve's
+/// day count convention. To have the spreaded curve follow a base curve which is replaced
+/// later, build it on a [`RelinkableYieldTermStructure`](super::RelinkableYieldTermStructure).
+#[derive(Clone)]
+pub struct ZeroSpreadedTermStructure {
+    base: Rc<dyn YieldTermStructure>,
+    spread: f64,
+    compounding: Compounding,
+    frequency: Frequency,
+}
+
+impl ZeroSpreadedTermStructure {
+    /// Creates a curve adding `spread` to the continuously compounded zero rates of `base`.
+    pub fn new(base: Rc<dyn YieldTermStructure>, spread: f64) -> Self {
+        ZeroSpreadedTermStructure {
+            base,
+            spread,
+            compounding: Compounding::Continuous,
+            frequency: Frequency::Annual,
+        }
+    }
+
+    /// Adds the spread to zero rates with `compounding` and `frequency` instead.
+    pub fn with_compounding(mut self, compounding: Compounding, frequency: Frequency) -> Self {
+        self.compounding = compounding;
+        self.frequency = frequency;
+        self
+    }
+
+    pub fn base(&self) -> &Rc<dyn YieldTermStructure> {
+        &self.base
+    }
+
+    pub fn spread(&self) -> f64 {
+        self.spread
+    }
+}
+
+impl YieldTermStructure for ZeroSpreadedTermStructure {
+    fn reference_date(&self) -> NaiveDate {
+        self.base.reference_date()
+    }
+
+    fn day_count(&self) -> DayCountConvention {
+        self.base.day_count()
+    }
+
+    fn discount_time(&self, time: f64) -> f64 {
+        let base = self.base.discount_time(time);
+
+        if self.compounding == Compounding::Continuous {
+            return base * (-self.spread * time).exp();
+        }
+
+        if time == 0.0 {
+            return base;
+        }
+
+        let day_count = self.day_count();
+        let zero = InterestRate::implied_rate(
+            1.0 / base,
+            day_count,
+            self.compounding,
+            self.frequency,
+            time,
+        );
+
+        InterestRate::new(
+            zero.rate() + self.spread,
+            day_count,
+            self.compounding,
+            self.frequency,
+        )
+        .discount_factor(time)
+    }
+}
+
+impl fmt::Debug for ZeroSpreadedTermStructure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ZeroSpreadedTermStructure")
+            .field("spread", &self.spread)
+            .field("compounding", &self.compounding)
+            .field("frequency", &self.frequency)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A term structure whose instantaneous forward rates are those of a base curve plus a constant
+/// spread.
+#[derive(Clone)]
+pub struct ForwardSpreadedTermStructure {
+    base: Rc<dyn YieldTermStructure>,
+    spread: f64,
+}
+
+impl ForwardSpreadedTermStructure {
+    /// Creates a curve adding `spread` to the instantaneous forward rates of `base`.
+    pub fn new(base: Rc<dyn YieldTermStructure>, spread: f64) -> Self {
+        ForwardSpreadedTermStructure { base, spread }
+    }
+
+    pub fn base(&self) -> &Rc<dyn YieldTermStructure> {
+        &self.base
+    }
+
+    pub fn spread(&self) -> f64 {
+        self.spread
+    }
+}
+
+impl YieldTermStructure for ForwardSpreadedTermStructure {
+    fn reference_date(&self) -> NaiveDate {
+        self.base.reference_date()
+    }
+
+    fn day_count(&self) -> DayCountConvention {
+        self.base.day_count()
+    }
+
+    fn discount_time(&self, time: f64) -> f64 {
+        self.base.discount_time(time) * (-self.spread * time).exp()
+    }
+
+    fn instantaneous_forward(&self, time: f64) -> f64 {
+        self.base.instantaneous_forward(time) + self.spread
+    }
+}
+
+impl fmt::Debug for ForwardSpreadedTermStructure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ForwardSpreadedTermStructure")
+            .field("spread", &self.spread)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A term structure whose continuously compounded zero rates are those of a base curve bumped
+/// by amounts given at a set of key dates.
+///
+/// Bumps are interpolated linearly in time between key dates and held flat outside them, so
+/// that bumping a single key date moves the curve by a triangle peaking at that date, as used
+/// for key rate durations.
+#[derive(Clone)]
+pub struct KeyRateBumpedTermStructure {
+    base: Rc<dyn YieldTermStructure>,
+    dates: Vec<NaiveDate>,
+    bumps: Interpolator,
+}
+
+impl KeyRateBumpedTermStructure {
+    /// Creates a bumped curve.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The curve to bump.
+    /// * `dates` - The strictly increasing key dates, no earlier than the reference date of
+    ///   `base`.
+    /// * `bumps` - The amount added to the zero rate at each key date.
+    ///
+    /// # Returns
+    ///
+    /// The curve, or an error if fewer than two key dates are given, a date is repeated or out
+    /// of order, the number of bumps differs, or a date precedes the reference date of `base`.
+    pub fn new(
+        base: Rc<dyn YieldTermStructure>,
+        dates: Vec<NaiveDate>,
+        bumps: Vec<f64>,
+    ) -> Result<Self, LibraError> {
+        let times = dates
+            .iter()
+            .map(|d| base.time_from_reference(*d))
+            .collect::<Result<_, _>>()?;
+
+        Ok(KeyRateBumpedTermStructure {
+            bumps: Interpolator::new(Interpolation::Linear, times, bumps)?
+                .with_extrapolation(Extrapolation::Flat),
+            base,
+            dates,
+        })
+    }
+
+    /// Creates a curve bumping only the zero rate at key date `key` by `bump`, leaving the
+    /// rates at the other key dates unchanged.
+    pub fn single(
+        base: Rc<dyn YieldTermStructure>,
+        dates: Vec<NaiveDate>,
+        key: usize,
+        bump: f64,
+    ) -> Result<Self, LibraError> {
+        let bumps = (0..dates.len())
+            .map(|i| if i == key { bump } else { 0.0 })
+            .collect();
+
+        Self::new(base, dates, bumps)
+    }
+
+    pub fn base(&self) -> &Rc<dyn YieldTermStructure> {
+        &self.base
+    }
+
+    pub fn dates(&self) -> &[NaiveDate] {
+        &self.dates
+    }
+
+    pub fn bumps(&self) -> &[f64] {
+        self.bumps.ys()
+    }
+}
+
+impl YieldTermStructure for KeyRateBumpedTermStructure {
+    fn reference_date(&self) -> NaiveDate {
+        self.base.reference_date()
+    }
+
+    fn day_count(&self) -> DayCountConvention {
+        self.base.day_count()
+    }
+
+    fn discount_time(&self, time: f64) -> f64 {
+        self.base.discount_time(time) * (-self.bumps.value(time) * time).exp()
+    }
+}
+
+impl fmt::Debug for KeyRateBumpedTermStructure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyRateBumpedTermStructure")
+            .field("dates", &self.dates)
+            .field("bumps", &self.bumps())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A term structure rolled forward to a later reference date, whose discount factors are the
+/// forward discount factors implied by a base curve from that date.
+#[derive(Clone)]
+pub struct ImpliedTermStructure {
+    base: Rc<dyn YieldTermStructure>,
+    reference_date: NaiveDate,
+    reference_time: f64,
+}
+
+impl ImpliedTermStructure {
+    /// Creates a curve implied by `base` from `reference_date`.
+    ///
+    /// Returns an error if `reference_date` is before the reference date of `base`.
+    pub fn new(
+        base: Rc<dyn YieldTermStructure>,
+        reference_date: NaiveDate,
+    ) -> Result<Self, LibraError> {
+        Ok(ImpliedTermStructure {
+            reference_time: base.time_from_reference(reference_date)?,
+            base,
+            reference_date,
+        })
+    }
+
+    pub fn base(&self) -> &Rc<dyn YieldTermStructure> {
+        &self.base
+    }
+}
+
+impl YieldTermStructure for ImpliedTermStructure {
+    fn reference_date(&self) -> NaiveDate {
+        self.reference_date
+    }
+
+    fn day_count(&self) -> DayCountConvention {
+        self.base.day_count()
+    }
+
+    fn discount_time(&self, time: f64) -> f64 {
+        self.base.discount_time(self.reference_time + time)
+            / self.base.discount_time(self.reference_time)
+    }
+
+    fn instantaneous_forward(&self, time: f64) -> f64 {
+        self.base.instantaneous_forward(self.reference_time + time)
+    }
+}
+
+impl fmt::Debug for ImpliedTermStructure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImpliedTermStructure")
+            .field("reference_date", &self.reference_date)
+            .finish_non_exhaustive()
+    }
+}
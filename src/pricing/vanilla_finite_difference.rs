@@ -0,0 +1,373 @@
+use crate::{
+    cashflows::Dividend,
+    error::{LibraError, NotPositive},
+    instruments::VanillaOption,
+    math::{
+        interpolation::{Extrapolation, Interpolation, Interpolator},
+        solvers::solve_tridiagonal,
+    },
+};
+
+use super::{
+    european_results, second_derivative, ExerciseTimes, GeneralizedBlackScholesProcess,
+    OptionResults,
+};
+
+/// The number of standard deviations of the logarithm of the price at expiry the grid spans
+/// either side of the spot and strike.
+const GRID_STANDARD_DEVIATIONS: f64 = 5.0;
+
+/// The number of fully implicit steps taken from expiry before switching to Crank-Nicolson, which
+/// damps the oscillations a kinked payoff otherwise causes.
+const RANNACHER_STEPS: usize = 2;
+
+/// The number of points the payoff is averaged over in the cell around each node at expiry.
+const CELL_AVERAGING_POINTS: usize = 16;
+
+/// The gap below which two times on the grid are treated as the same.
+const TIME_TOLERANCE: f64 = 1.0e-10;
+
+/// Prices vanilla options with European, American or Bermudan exercise by solving the
+/// Black-Scholes equation backwards from expiry with the Crank-Nicolson finite-difference scheme,
+/// on a uniform grid in the logarithm of the price of the underlying.
+///
+/// Rates and the continuous dividend yield are taken from the curves of the process over each
+/// time step. Discrete dividends are paid as a fall in the price of the underlying on their
+/// ex-dividend date, on top of the continuous dividend yield. Exercise and ex-dividend dates are
+/// placed on the time grid, and exercise is allowed by comparing the value with the payoff after
+/// each step.
+///
+/// Theta is implied by the Black-Scholes equation, and the sensitivities to rates and volatility
+/// are not found.
+#[derive(Clone, Debug)]
+pub struct FdBlackScholesVanillaEngine {
+    process: GeneralizedBlackScholesProcess,
+    time_steps: usize,
+    grid_points: usize,
+    dividends: Vec<Dividend>,
+}
+
+impl FdBlackScholesVanillaEngine {
+    /// Creates an engine with no discrete dividends.
+    ///
+    /// # Arguments
+    ///
+    /// * `process` - The process of the underlying.
+    /// * `time_steps` - The number of time steps to the last exercise date, to which exercise and
+    ///   ex-dividend dates are added.
+    /// * `grid_points` - The number of points in the grid of prices, of which there are at least
+    ///   five.
+    pub fn new(
+        process: GeneralizedBlackScholesProcess,
+        time_steps: usize,
+        grid_points: usize,
+    ) -> Self {
+        FdBlackScholesVanillaEngine {
+            process,
+            time_steps,
+            grid_points,
+            dividends: Vec::new(),
+        }
+    }
+
+    /// Pays `dividends` on the underlying, ignoring any going ex before the reference date of the
+    /// process or after the last exercise date.
+    pub fn with_dividends(mut self, dividends: Vec<Dividend>) -> Self {
+        self.dividends = dividends;
+        self
+    }
+
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    pub fn time_steps(&self) -> usize {
+        self.time_steps
+    }
+
+    pub fn grid_points(&self) -> usize {
+        self.grid_points
+    }
+
+    pub fn dividends(&self) -> &[Dividend] {
+        &self.dividends
+    }
+
+    /// Prices `option`.
+    ///
+    /// # Returns
+    ///
+    /// The value, delta, gamma and theta, or an error if the option has expired or the
+    /// volatility is not positive.
+    pub fn calculate(&self, option: &VanillaOption) -> Result<OptionResults, LibraError> {
+        let process = &self.process;
+        let payoff = option.payoff();
+        let exercise = ExerciseTimes::new(process, option.exercise())?;
+        let maturity = exercise.maturity();
+        let sigma = process.volatility();
+
+        if sigma <= 0.0 {
+            return Err(NotPositive::new("volatility", sigma).into());
+        }
+        if maturity == 0.0 {
+            return Ok(european_results(process, payoff, 0.0));
+        }
+
+        let dividends = self
+            .dividends
+            .iter()
+            .filter(|d| {
+                d.date() > process.reference_date() && d.date() <= option.exercise().last_date()
+            })
+            .map(|d| Ok((process.time(d.date())?, d.amount())))
+            .collect::<Result<Vec<_>, LibraError>>()?;
+
+        // The grid of log-prices is uniform and has the spot on a node with a node either side,
+        // even if the strike is so far away that the spot would otherwise be on the boundary.
+        let spot = process.spot();
+        let spot_x = spot.ln();
+        let strike_x = payoff.strike().max(f64::MIN_POSITIVE).ln();
+        let width = GRID_STANDARD_DEVIATIONS * sigma * maturity.sqrt();
+        let lower = spot_x.min(strike_x) - width;
+        let upper = spot_x.max(strike_x) + width;
+
+        let points = self.grid_points.max(5);
+        let dx = (upper - lower) / (points - 1) as f64;
+        let spot_index = (((spot_x - lower) / dx).round() as usize).clamp(1, points - 2);
+        let xs: Vec<f64> = (0..points)
+            .map(|i| spot_x + (i as f64 - spot_index as f64) * dx)
+            .collect();
+        let prices: Vec<f64> = xs.iter().map(|x| x.exp()).collect();
+
+        let mut times: Vec<f64> = (0..=self.time_steps.max(1))
+            .map(|i| maturity * i as f64 / self.time_steps.max(1) as f64)
+            .chain(exercise.stopping_times())
+            .chain(dividends.iter().map(|(t, _)| *t))
+            .filter(|t| (0.0..=maturity).contains(t))
+            .collect();
+        times.sort_by(f64::total_cmp);
+        times.dedup_by(|a, b| (*a - *b).abs() < TIME_TOLERANCE);
+
+        let risk_free_curve = process.risk_free_curve();
+        let dividend_curve = process.dividend_curve();
+
+        // Deep in or out of the money, the option is worth its payoff on the forward.
+        let boundary = |price: f64, time: f64| {
+            let discount =
+                risk_free_curve.discount_time(maturity) / risk_free_curve.discount_time(time);
+            let dividend_discount =
+                dividend_curve.discount_time(maturity) / dividend_curve.discount_time(time);
+
+            discount * payoff.value(price * dividend_discount / discount)
+        };
+
+        // Pays dividends and allows exercise at `time`, going back from just after it to just
+        // before it.
+        let apply_events = |values: &mut Vec<f64>, time: f64| -> Result<(), LibraError> {
+            let exercisable = exercise.allows(time, TIME_TOLERANCE);
+            let exercise_nodes = |values: &mut Vec<f64>| {
+                if exercisable {
+                    for (value, price) in values.iter_mut().zip(&prices) {
+                        *value = value.max(payoff.value(*price));
+                    }
+                }
+            };
+
+            // The values at expiry are already the averaged payoff.
+            if time < maturity {
+                exercise_nodes(values);
+            }
+
+            for (_, amount) in dividends
+                .iter()
+                .filter(|(t, _)| (t - time).abs() < TIME_TOLERANCE)
+            {
+                let after =
+                    Interpolator::new(Interpolation::CubicNatural, xs.clone(), values.clone())?
+                        .with_extrapolation(Extrapolation::Flat);
+
+                *values = prices
+                    .iter()
+                    .map(|price| after.value((price - amount).max(prices[0]).ln()))
+                    .collect();
+
+                exercise_nodes(values);
+            }
+
+            Ok(())
+        };
+
+        // Averaging the payoff over the cell around each node stops a kink or jump between
+        // nodes from biasing the solution.
+        let mut values: Vec<f64> = xs
+            .iter()
+            .map(|x| {
+                (0..CELL_AVERAGING_POINTS)
+                    .map(|j| {
+                        let offset = (j as f64 + 0.5) / CELL_AVERAGING_POINTS as f64 - 0.5;
+                        payoff.value((x + offset * dx).exp())
+                    })
+                    .sum::<f64>()
+                    / CELL_AVERAGING_POINTS as f64
+            })
+            .collect();
+        apply_events(&mut values, maturity)?;
+
+        for (step, pair) in times.windows(2).rev().enumerate() {
+            let (start, end) = (pair[0], pair[1]);
+            let dt = end - start;
+            let r = (risk_free_curve.discount_time(start) / risk_free_curve.discount_time(end))
+                .ln()
+                / dt;
+            let q =
+                (dividend_curve.discount_time(start) / dividend_curve.discount_time(end)).ln() / dt;
+            let theta = if step < RANNACHER_STEPS { 1.0 } else { 0.5 };
+
+            // The coefficients of the neighbouring values in the Black-Scholes operator.
+            let diffusion = 0.5 * sigma * sigma / (dx * dx);
+            let convection = 0.5 * (r - q - 0.5 * sigma * sigma) / dx;
+            let (below, centre, above) = (
+                diffusion - convection,
+                -2.0 * diffusion - r,
+                diffusion + convection,
+            );
+
+            let mut lower_diagonal = vec![-theta * dt * below; points];
+            let mut diagonal = vec![1.0 - theta * dt * centre; points];
+            let mut upper_diagonal = vec![-theta * dt * above; points];
+            let rhs: Vec<f64> = (0..points)
+                .map(|i| match i {
+                    0 => boundary(prices[0], start),
+                    i if i == points - 1 => boundary(prices[i], start),
+                    i => {
+                        values[i]
+                            + (1.0 - theta)
+                                * dt
+                                * (below * values[i - 1]
+                                    + centre * values[i]
+                                    + above * values[i + 1])
+                    }
+                })
+                .collect();
+
+            for i in [0, points - 1] {
+                lower_diagonal[i] = 0.0;
+                diagonal[i] = 1.0;
+                upper_diagonal[i] = 0.0;
+            }
+
+            values = solve_tridiagonal(&lower_diagonal, &diagonal, &upper_diagonal, &rhs);
+            apply_events(&mut values, start)?;
+        }
+
+        let k = spot_index;
+        let delta = (values[k + 1] - values[k - 1]) / (prices[k + 1] - prices[k - 1]);
+        let gamma = second_derivative(
+            [prices[k - 1], prices[k], prices[k + 1]],
+            [values[k - 1], values[k], values[k + 1]],
+        );
+
+        Ok(OptionResults::from_grid(
+            process, maturity, values[k], delta, gamma,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use chrono::{Duration, NaiveDate};
+
+    use crate::{
+        cashflows::{Compounding, InterestRate},
+        instruments::{Exercise, OptionType, Payoff, VanillaOption},
+        pricing::{
+            AnalyticEuropeanEngine, BinomialTree, BinomialVanillaEngine, FlatForward,
+            GeneralizedBlackScholesProcess,
+        },
+        time::{DayCountConvention, Frequency},
+    };
+
+    use super::FdBlackScholesVanillaEngine;
+
+    fn reference_date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 1, 2).unwrap()
+    }
+
+    fn process(
+        spot: f64,
+        rate: f64,
+        dividend_yield: f64,
+        volatility: f64,
+    ) -> GeneralizedBlackScholesProcess {
+        let curve = |rate: f64| {
+            Rc::new(FlatForward::new(
+                reference_date(),
+                InterestRate::new(
+                    rate,
+                    DayCountConvention::Actual365Fixed,
+                    Compounding::Continuous,
+                    Frequency::Annual,
+                ),
+            ))
+        };
+
+        GeneralizedBlackScholesProcess::new(spot, curve(dividend_yield), curve(rate), volatility)
+    }
+
+    fn option(option_type: OptionType, strike: f64, exercise: Exercise) -> VanillaOption {
+        VanillaOption::new(
+            Payoff::PlainVanilla {
+                option_type,
+                strike,
+            },
+            exercise,
+        )
+    }
+
+    #[test]
+    fn prices_far_out_of_the_money_short_expiries() {
+        let expiry = reference_date() + Duration::days(3);
+        let exercise = Exercise::american(reference_date(), expiry).unwrap();
+
+        for (option_type, strike) in [(OptionType::Call, 10000.0), (OptionType::Put, 1.0)] {
+            let engine = FdBlackScholesVanillaEngine::new(process(100.0, 0.05, 0.0, 0.01), 10, 100);
+            let results = engine
+                .calculate(&option(option_type, strike, exercise.clone()))
+                .unwrap();
+
+            assert!(results.value.abs() < 1.0e-12);
+            assert!(results.delta.abs() < 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn converges_to_the_analytic_european_price() {
+        let expiry = NaiveDate::from_ymd_opt(2025, 1, 2).unwrap();
+        let process = process(100.0, 0.05, 0.02, 0.25);
+
+        for option_type in [OptionType::Call, OptionType::Put] {
+            for strike in [80.0, 100.0, 120.0] {
+                let option = option(option_type, strike, Exercise::European(expiry));
+                let analytic = AnalyticEuropeanEngine::new(process.clone())
+                    .calculate(&option)
+                    .unwrap();
+                let finite_difference = FdBlackScholesVanillaEngine::new(process.clone(), 400, 801)
+                    .calculate(&option)
+                    .unwrap();
+                let binomial = BinomialVanillaEngine::new(
+                    process.clone(),
+                    BinomialTree::CoxRossRubinstein,
+                    2000,
+                )
+                .calculate(&option)
+                .unwrap();
+
+                assert!((finite_difference.value - analytic.value).abs() < 1.0e-3);
+                assert!((finite_difference.delta - analytic.delta).abs() < 1.0e-4);
+                assert!((binomial.value - analytic.value).abs() < 1.0e-2);
+            }
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use std::fmt;
+
+use crate::{
+    error::{LibraError, NotPositive},
+    instruments::{Payoff, VanillaOption},
+};
+
+use super::{
+    european_results, second_derivative, ExerciseTimes, GeneralizedBlackScholesProcess,
+    OptionResults,
+};
+
+/// A recombining binomial tree for the price of an equity. The trees differ in how the up and
+/// down moves and their probabilities are chosen to match the distribution of the process.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinomialTree {
+    /// Cox-Ross-Rubinstein: up and down moves of equal size in the logarithm of the price.
+    CoxRossRubinstein,
+    /// Jarrow-Rudd: up and down moves of equal probability around the drift of the logarithm of
+    /// the price.
+    JarrowRudd,
+    /// Tian: moves matching the first three moments of the lognormal distribution over a step.
+    Tian,
+    /// Leisen-Reimer: probabilities found by the Peizer-Pratt inversion of the normal
+    /// distribution at the strike, so that prices converge smoothly and at second order. The
+    /// tree always has an odd number of steps.
+    LeisenReimer,
+}
+
+impl fmt::Display for BinomialTree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BinomialTree::CoxRossRubinstein => write!(f, "Cox-Ross-Rubinstein"),
+            BinomialTree::JarrowRudd => write!(f, "Jarrow-Rudd"),
+            BinomialTree::Tian => write!(f, "Tian"),
+            BinomialTree::LeisenReimer => write!(f, "Leisen-Reimer"),
+        }
+    }
+}
+
+/// Prices vanilla options with European, American or Bermudan exercise by backward induction on
+/// a binomial tree for the generalized Black-Scholes process.
+///
+/// The tree uses the zero rates and dividend yield to the last exercise date. Bermudan exercise
+/// dates are taken at the nearest step. Theta is implied by the Black-Scholes equation, and the
+/// sensitivities to rates and volatility are not found.
+#[derive(Clone, Debug)]
+pub struct BinomialVanillaEngine {
+    process: GeneralizedBlackScholesProcess,
+    tree: BinomialTree,
+    steps: usize,
+}
+
+impl BinomialVanillaEngine {
+    /// Creates an engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `process` - The process of the underlying.
+    /// * `tree` - The kind of binomial tree.
+    /// * `steps` - The number of time steps to the last exercise date, of which there are at
+    ///   least two.
+    pub fn new(process: GeneralizedBlackScholesProcess, tree: BinomialTree, steps: usize) -> Self {
+        BinomialVanillaEngine {
+            process,
+            tree,
+            steps,
+        }
+    }
+
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    pub fn tree(&self) -> BinomialTree {
+        self.tree
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Prices `option`.
+    ///
+    /// # Returns
+    ///
+    /// The value, delta, gamma and theta, or an error if the option has expired or the
+    /// volatility is not positive.
+    pub fn calculate(&self, option: &VanillaOption) -> Result<OptionResults, LibraError> {
+        let process = &self.process;
+        let payoff = option.payoff();
+        let exercise = ExerciseTimes::new(process, option.exercise())?;
+        let maturity = exercise.maturity();
+        let sigma = process.volatility();
+
+        if sigma <= 0.0 {
+            return Err(NotPositive::new("volatility", sigma).into());
+        }
+        if maturity == 0.0 {
+            return Ok(european_results(process, payoff, 0.0));
+        }
+
+        let steps = match self.tree {
+            BinomialTree::LeisenReimer => self.steps.max(2) | 1,
+            _ => self.steps.max(2),
+        };
+
+        let dt = maturity / steps as f64;
+        let r = process.risk_free_rate(maturity);
+        let q = process.dividend_yield(maturity);
+        let growth = ((r - q) * dt).exp();
+        let discount = (-r * dt).exp();
+        let spot = process.spot();
+
+        let (up, down, p) = match self.tree {
+            BinomialTree::CoxRossRubinstein => {
+                let up = (sigma * dt.sqrt()).exp();
+                (up, 1.0 / up, (growth - 1.0 / up) / (up - 1.0 / up))
+            }
+            BinomialTree::JarrowRudd => {
+                let drift = (r - q - 0.5 * sigma * sigma) * dt;
+                let move_size = sigma * dt.sqrt();
+                ((drift + move_size).exp(), (drift - move_size).exp(), 0.5)
+            }
+            BinomialTree::Tian => {
+                let v = (sigma * sigma * dt).exp();
+                let root = (v * v + 2.0 * v - 3.0).sqrt();
+                let up = 0.5 * growth * v * (v + 1.0 + root);
+                let down = 0.5 * growth * v * (v + 1.0 - root);
+                (up, down, (growth - down) / (up - down))
+            }
+            BinomialTree::LeisenReimer => {
+                let std_dev = sigma * maturity.sqrt();
+                let d1 =
+                    ((spot / payoff.strike()).ln() + (r - q) * maturity) / std_dev + 0.5 * std_dev;
+                let d2 = d1 - std_dev;
+                let p = peizer_pratt_inversion(d2, steps);
+                let up = growth * peizer_pratt_inversion(d1, steps) / p;
+                (up, (growth - p * up) / (1.0 - p), p)
+            }
+        };
+
+        let price = |i: usize, j: usize| spot * up.powi(j as i32) * down.powi((i - j) as i32);
+
+        let mut values: Vec<f64> = (0..=steps).map(|j| payoff.value(price(steps, j))).collect();
+        let mut step_values = [Vec::new(), Vec::new()];
+
+        for i in (0..steps).rev() {
+            values = (0..=i)
+                .map(|j| discount * (p * values[j + 1] + (1.0 - p) * values[j]))
+                .collect();
+
+            exercise_nodes(
+                &exercise,
+                payoff,
+                i as f64 * dt,
+                0.5 * dt,
+                &mut values,
+                price(i, 0),
+                up / down,
+            );
+
+            if i <= 2 && i > 0 {
+                step_values[i - 1] = values.clone();
+            }
+        }
+
+        let [one, two] = step_values;
+        let delta = (one[1] - one[0]) / (price(1, 1) - price(1, 0));
+        let gamma = second_derivative(
+            [price(2, 0), price(2, 1), price(2, 2)],
+            [two[0], two[1], two[2]],
+        );
+
+        Ok(OptionResults::from_grid(
+            process, maturity, values[0], delta, gamma,
+        ))
+    }
+}
+
+/// Prices vanilla options with European, American or Bermudan exercise by backward induction on
+/// a trinomial tree for the logarithm of the price of the underlying under the generalized
+/// Black-Scholes process.
+///
+/// The tree has nodes spaced by the volatility over three time steps, and uses the zero rates
+/// and dividend yield to the last exercise date. Bermudan exercise dates are taken at the
+/// nearest step. Theta is implied by the Black-Scholes equation, and the sensitivities to rates
+/// and volatility are not found.
+#[derive(Clone, Debug)]
+pub struct TrinomialVanillaEngine {
+    process: GeneralizedBlackScholesProcess,
+    steps: usize,
+}
+
+impl TrinomialVanillaEngine {
+    /// Creates an engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `process` - The process of the underlying.
+    /// * `steps` - The number of time steps to the last exercise date, of which there are at
+    ///   least two.
+    pub fn new(process: GeneralizedBlackScholesProcess, steps: usize) -> Self {
+        TrinomialVanillaEngine { process, steps }
+    }
+
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Prices `option`.
+    ///
+    /// # Returns
+    ///
+    /// The value, delta, gamma and theta, or an error if the option has expired or the
+    /// volatility is not positive.
+    pub fn calculate(&self, option: &VanillaOption) -> Result<OptionResults, LibraError> {
+        let process = &self.process;
+        let payoff = option.payoff();
+        let exercise = ExerciseTimes::new(process, option.exercise())?;
+        let maturity = exercise.maturity();
+        let sigma = process.volatility();
+
+        if sigma <= 0.0 {
+            return Err(NotPositive::new("volatility", sigma).into());
+        }
+        if maturity == 0.0 {
+            return Ok(european_results(process, payoff, 0.0));
+        }
+
+        let steps = self.steps.max(2);
+        let dt = maturity / steps as f64;
+        let r = process.risk_free_rate(maturity);
+        let q = process.dividend_yield(maturity);
+        let discount = (-r * dt).exp();
+        let spot = process.spot();
+
+        let dx = sigma * (3.0 * dt).sqrt();
+        let drift = (r - q - 0.5 * sigma * sigma) * dt;
+        let variance = (sigma * sigma * dt + drift * drift) / (dx * dx);
+        let p_up = 0.5 * (variance + drift / dx);
+        let p_down = 0.5 * (variance - drift / dx);
+        let p_middle = 1.0 - variance;
+
+        // Node k of step i is at log-price ln(spot) + (k - i) dx.
+        let price = |i: usize, k: usize| spot * ((k as f64 - i as f64) * dx).exp();
+
+        let mut values: Vec<f64> = (0..=2 * steps)
+            .map(|k| payoff.value(price(steps, k)))
+            .collect();
+        let mut step_one = Vec::new();
+
+        for i in (0..steps).rev() {
+            values = (0..=2 * i)
+                .map(|k| {
+                    discount
+                        * (p_up * values[k + 2] + p_middle * values[k + 1] + p_down * values[k])
+                })
+                .collect();
+
+            exercise_nodes(
+                &exercise,
+                payoff,
+                i as f64 * dt,
+                0.5 * dt,
+                &mut values,
+                price(i, 0),
+                dx.exp(),
+            );
+
+            if i == 1 {
+                step_one = values.clone();
+            }
+        }
+
+        let prices = [price(1, 0), price(1, 1), price(1, 2)];
+        let delta = (step_one[2] - step_one[0]) / (prices[2] - prices[0]);
+        let gamma = second_derivative(prices, [step_one[0], step_one[1], step_one[2]]);
+
+        Ok(OptionResults::from_grid(
+            process, maturity, values[0], delta, gamma,
+        ))
+    }
+}
+
+/// The probability that a binomial variable with `steps` trials exceeds its median, matching the
+/// probability `N(z)` of the normal distribution, by method 2 of Peizer and Pratt.
+fn peizer_pratt_inversion(z: f64, steps: usize) -> f64 {
+    let n = steps as f64;
+    let ratio = z / (n + 1.0 / 3.0 + 0.1 / (n + 1.0));
+
+    0.5 + z.signum() * 0.5 * (1.0 - (-ratio * ratio * (n + 1.0 / 6.0)).exp()).sqrt()
+}
+
+/// Replaces the values at each node with the exercise value where that is greater, if exercise
+/// is allowed at `time`. The price at the lowest node is `lowest_price`, and rises by `ratio`
+/// from each node to the next.
+fn exercise_nodes(
+    exercise: &ExerciseTimes,
+    payoff: &Payoff,
+    time: f64,
+    tolerance: f64,
+    values: &mut [f64],
+    lowest_price: f64,
+    ratio: f64,
+) {
+    if !exercise.allows(time, tolerance) {
+        return;
+    }
+
+    let mut price = lowest_price;
+    for value in values.iter_mut() {
+        *value = value.max(payoff.value(price));
+        price *= ratio;
+    }
+}
@@ -0,0 +1,372 @@
+use crate::{
+    error::{DifferentVariant, InvalidDate, LibraError},
+    instruments::{Exercise, Payoff, VanillaOption},
+    math::distributions::{normal_cdf, normal_pdf},
+};
+
+use super::{implied_black_volatility, GeneralizedBlackScholesProcess};
+
+/// The relative bump to the spot used to find delta and gamma of options valued by functions
+/// of the process.
+const SPOT_BUMP: f64 = 1.0e-4;
+
+/// The value of an option on an equity and its sensitivities.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OptionResults {
+    pub value: f64,
+    /// The change in value per unit change in the spot.
+    pub delta: f64,
+    /// The change in delta per unit change in the spot.
+    pub gamma: f64,
+    /// The change in value per unit change in the volatility.
+    pub vega: f64,
+    /// The change in value per year of time passing, holding rates and volatility fixed.
+    pub theta: f64,
+    /// The change in value per unit parallel shift in the continuously compounded zero rates of
+    /// the risk-free curve.
+    pub rho: f64,
+    /// The change in value per unit parallel shift in the dividend yield.
+    pub dividend_rho: f64,
+    /// The change in delta per unit change in the volatility.
+    pub vanna: f64,
+    /// The change in vega per unit change in the volatility.
+    pub volga: f64,
+}
+
+impl OptionResults {
+    /// The value and sensitivities of `a` of one option plus `b` of another.
+    fn weighted_sum(a: f64, x: &OptionResults, b: f64, y: &OptionResults) -> Self {
+        OptionResults {
+            value: a * x.value + b * y.value,
+            delta: a * x.delta + b * y.delta,
+            gamma: a * x.gamma + b * y.gamma,
+            vega: a * x.vega + b * y.vega,
+            theta: a * x.theta + b * y.theta,
+            rho: a * x.rho + b * y.rho,
+            dividend_rho: a * x.dividend_rho + b * y.dividend_rho,
+            vanna: a * x.vanna + b * y.vanna,
+            volga: a * x.volga + b * y.volga,
+        }
+    }
+
+    /// The results of a numerical method that finds the value, delta and gamma at the spot, with
+    /// theta implied by the Black-Scholes equation using rates to `time` years. Where the option
+    /// would be exercised, this is the theta of holding it instead.
+    pub(crate) fn from_grid(
+        process: &GeneralizedBlackScholesProcess,
+        time: f64,
+        value: f64,
+        delta: f64,
+        gamma: f64,
+    ) -> Self {
+        let r = process.risk_free_rate(time);
+        let q = process.dividend_yield(time);
+        let spot = process.spot();
+        let sigma = process.volatility();
+
+        OptionResults {
+            value,
+            delta,
+            gamma,
+            theta: r * value - (r - q) * spot * delta - 0.5 * sigma * sigma * spot * spot * gamma,
+            ..Default::default()
+        }
+    }
+}
+
+/// Prices European options under the generalized Black-Scholes process in closed form.
+///
+/// Every payoff is a combination of an asset-or-nothing and a cash-or-nothing option on the
+/// trigger, whose values and sensitivities are found analytically.
+#[derive(Clone, Debug)]
+pub struct AnalyticEuropeanEngine {
+    process: GeneralizedBlackScholesProcess,
+}
+
+impl AnalyticEuropeanEngine {
+    pub fn new(process: GeneralizedBlackScholesProcess) -> Self {
+        AnalyticEuropeanEngine { process }
+    }
+
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    /// Prices `option`.
+    ///
+    /// # Returns
+    ///
+    /// The value and sensitivities, or an error if the option is not European or has expired.
+    pub fn calculate(&self, option: &VanillaOption) -> Result<OptionResults, LibraError> {
+        let Exercise::European(expiry) = option.exercise() else {
+            return Err(DifferentVariant.into());
+        };
+
+        let time = self.process.time(*expiry)?;
+
+        Ok(european_results(&self.process, option.payoff(), time))
+    }
+
+    /// The Black-Scholes volatility at which `option` is worth `price`, with the spot and curves
+    /// of the process.
+    ///
+    /// # Returns
+    ///
+    /// The volatility, or an error if the option is not a plain vanilla European option or if
+    /// no volatility gives `price`.
+    pub fn implied_volatility(
+        &self,
+        option: &VanillaOption,
+        price: f64,
+    ) -> Result<f64, LibraError> {
+        let (
+            Exercise::European(expiry),
+            Payoff::PlainVanilla {
+                option_type,
+                strike,
+            },
+        ) = (option.exercise(), option.payoff())
+        else {
+            return Err(DifferentVariant.into());
+        };
+
+        let time = self.process.time(*expiry)?;
+        let discount = self.process.risk_free_curve().discount_time(time);
+
+        implied_black_volatility(
+            *option_type,
+            price / discount,
+            self.process.forward(time),
+            *strike,
+            time,
+        )
+    }
+}
+
+/// The times at which an option may be exercised, in years from the reference date of a process.
+#[derive(Clone, Debug)]
+pub(crate) enum ExerciseTimes {
+    European(f64),
+    American { earliest: f64, latest: f64 },
+    Bermudan(Vec<f64>),
+}
+
+impl ExerciseTimes {
+    /// The times of `exercise` under `process`, ignoring exercise dates already passed.
+    ///
+    /// # Returns
+    ///
+    /// The times, or an error if the option can no longer be exercised.
+    pub(crate) fn new(
+        process: &GeneralizedBlackScholesProcess,
+        exercise: &Exercise,
+    ) -> Result<Self, LibraError> {
+        let reference_date = process.reference_date();
+
+        Ok(match exercise {
+            Exercise::European(date) => ExerciseTimes::European(process.time(*date)?),
+            Exercise::American { earliest, latest } => ExerciseTimes::American {
+                earliest: process.time((*earliest).max(reference_date))?,
+                latest: process.time(*latest)?,
+            },
+            Exercise::Bermudan(dates) => {
+                let times = dates
+                    .iter()
+                    .filter(|d| **d >= reference_date)
+                    .map(|d| process.time(*d))
+                    .collect::<Result<Vec<_>, LibraError>>()?;
+
+                if times.is_empty() {
+                    return Err(InvalidDate.into());
+                }
+
+                ExerciseTimes::Bermudan(times)
+            }
+        })
+    }
+
+    /// The last time at which the option may be exercised.
+    pub(crate) fn maturity(&self) -> f64 {
+        match self {
+            ExerciseTimes::European(time) => *time,
+            ExerciseTimes::American { latest, .. } => *latest,
+            ExerciseTimes::Bermudan(times) => times[times.len() - 1],
+        }
+    }
+
+    /// The times a grid must fall on for exercise to be captured exactly.
+    pub(crate) fn stopping_times(&self) -> Vec<f64> {
+        match self {
+            ExerciseTimes::European(time) => vec![*time],
+            ExerciseTimes::American { earliest, latest } => vec![*earliest, *latest],
+            ExerciseTimes::Bermudan(times) => times.clone(),
+        }
+    }
+
+    /// Whether the option may be exercised at `time`, counting exercise times within
+    /// `tolerance` of it.
+    pub(crate) fn allows(&self, time: f64, tolerance: f64) -> bool {
+        match self {
+            ExerciseTimes::European(expiry) => (time - expiry).abs() <= tolerance,
+            ExerciseTimes::American { earliest, latest } => {
+                time >= earliest - tolerance && time <= latest + tolerance
+            }
+            ExerciseTimes::Bermudan(times) => times.iter().any(|t| (time - t).abs() <= tolerance),
+        }
+    }
+}
+
+/// The second derivative of a function through three points with increasing abscissae.
+pub(crate) fn second_derivative(xs: [f64; 3], ys: [f64; 3]) -> f64 {
+    let lower_slope = (ys[1] - ys[0]) / (xs[1] - xs[0]);
+    let upper_slope = (ys[2] - ys[1]) / (xs[2] - xs[1]);
+
+    (upper_slope - lower_slope) / (0.5 * (xs[2] - xs[0]))
+}
+
+/// The value, delta, gamma and theta of an option worth `value` under each process, with delta
+/// and gamma found by bumping the spot and theta implied by the Black-Scholes equation using
+/// rates to `time` years.
+pub(crate) fn bumped_results(
+    process: &GeneralizedBlackScholesProcess,
+    time: f64,
+    value: &dyn Fn(&GeneralizedBlackScholesProcess) -> Result<f64, LibraError>,
+) -> Result<OptionResults, LibraError> {
+    let spot = process.spot();
+    let bump = SPOT_BUMP * spot;
+    let value_at = |spot: f64| value(&process.clone().with_spot(spot));
+    let values = [
+        value_at(spot - bump)?,
+        value(process)?,
+        value_at(spot + bump)?,
+    ];
+
+    Ok(OptionResults::from_grid(
+        process,
+        time,
+        values[1],
+        (values[2] - values[0]) / (2.0 * bump),
+        second_derivative([spot - bump, spot, spot + bump], values),
+    ))
+}
+
+/// The time to the expiry of an option with European `exercise` under `process`.
+///
+/// # Returns
+///
+/// The time, or an error if the exercise is not European or the option has expired.
+pub(crate) fn european_expiry(
+    process: &GeneralizedBlackScholesProcess,
+    exercise: &Exercise,
+) -> Result<f64, LibraError> {
+    let Exercise::European(expiry) = exercise else {
+        return Err(DifferentVariant.into());
+    };
+
+    process.time(*expiry)
+}
+
+/// The value and sensitivities of a European option paying `payoff` in `time` years.
+pub(crate) fn european_results(
+    process: &GeneralizedBlackScholesProcess,
+    payoff: &Payoff,
+    time: f64,
+) -> OptionResults {
+    let omega = payoff.option_type().sign();
+    let (asset, cash) = binaries(process, omega, payoff.trigger(), time);
+
+    match payoff {
+        Payoff::PlainVanilla { strike, .. } | Payoff::Gap { strike, .. } => {
+            OptionResults::weighted_sum(omega, &asset, -omega * strike, &cash)
+        }
+        Payoff::CashOrNothing { cash: amount, .. } => {
+            OptionResults::weighted_sum(0.0, &asset, *amount, &cash)
+        }
+        Payoff::AssetOrNothing { .. } => OptionResults::weighted_sum(1.0, &asset, 0.0, &cash),
+    }
+}
+
+/// The values and sensitivities of an option paying one unit of the underlying and one paying
+/// one unit of cash if the underlying finishes beyond `trigger` in the direction of `omega`.
+fn binaries(
+    process: &GeneralizedBlackScholesProcess,
+    omega: f64,
+    trigger: f64,
+    time: f64,
+) -> (OptionResults, OptionResults) {
+    let spot = process.spot();
+    let sigma = process.volatility();
+    let dividend_discount = process.dividend_curve().discount_time(time);
+    let risk_free_discount = process.risk_free_curve().discount_time(time);
+    let forward = spot * dividend_discount / risk_free_discount;
+
+    let r = process.risk_free_rate(time);
+    let q = process.dividend_yield(time);
+
+    let std_dev = sigma * time.sqrt();
+
+    if std_dev <= 0.0 || trigger <= 0.0 {
+        let exercised = if omega * (forward - trigger) > 0.0 {
+            1.0
+        } else {
+            0.0
+        };
+        let asset_value = spot * dividend_discount * exercised;
+        let cash_value = risk_free_discount * exercised;
+
+        let asset = OptionResults {
+            value: asset_value,
+            delta: dividend_discount * exercised,
+            theta: q * asset_value,
+            dividend_rho: -time * asset_value,
+            ..Default::default()
+        };
+        let cash = OptionResults {
+            value: cash_value,
+            theta: r * cash_value,
+            rho: -time * cash_value,
+            ..Default::default()
+        };
+
+        return (asset, cash);
+    }
+
+    let d1 = (forward / trigger).ln() / std_dev + 0.5 * std_dev;
+    let d2 = d1 - std_dev;
+    let d1_dt = (r - q + 0.5 * sigma * sigma) / std_dev - d1 / (2.0 * time);
+    let d2_dt = d1_dt - sigma / (2.0 * time.sqrt());
+
+    // The density terms carry the sign of the option, as the probability of exercise is
+    // N(omega * d).
+    let asset_density = omega * spot * dividend_discount * normal_pdf(d1);
+    let cash_density = omega * risk_free_discount * normal_pdf(d2);
+
+    let asset_value = spot * dividend_discount * normal_cdf(omega * d1);
+    let cash_value = risk_free_discount * normal_cdf(omega * d2);
+
+    let asset = OptionResults {
+        value: asset_value,
+        delta: dividend_discount * normal_cdf(omega * d1) + asset_density / (spot * std_dev),
+        gamma: -asset_density * d2 / (spot * spot * std_dev * std_dev),
+        vega: -asset_density * d2 / sigma,
+        theta: q * asset_value - asset_density * d1_dt,
+        rho: asset_density * time / std_dev,
+        dividend_rho: -time * asset_value - asset_density * time / std_dev,
+        vanna: asset_density * (d1 * d2 - 1.0 - d2 * std_dev) / (spot * sigma * std_dev),
+        volga: asset_density * (d2 - d1 * (d2 * d2 - 1.0)) / (sigma * sigma),
+    };
+
+    let cash = OptionResults {
+        value: cash_value,
+        delta: cash_density / (spot * std_dev),
+        gamma: -cash_density * d1 / (spot * spot * std_dev * std_dev),
+        vega: -cash_density * d1 / sigma,
+        theta: r * cash_value - cash_density * d2_dt,
+        rho: -time * cash_value + cash_density * time / std_dev,
+        dividend_rho: -cash_density * time / std_dev,
+        vanna: cash_density * (d1 * d2 - 1.0) / (spot * sigma * std_dev),
+        volga: cash_density * (d1 - d2 * (d1 * d1 - 1.0)) / (sigma * sigma),
+    };
+
+    (asset, cash)
+}
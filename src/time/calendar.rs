@@ -1,6 +1,7 @@
-use chrono::{Days, NaiveDate};
+use chrono::{Datelike, Days, NaiveDate};
 
 use super::calendars::{self};
+use super::{DateAdjustment, Period, TimeUnit};
 
 #[derive(Clone, Copy, Debug)]
 pub enum Calendar {
@@ -49,6 +50,91 @@ impl Calendar {
     pub fn is_buisness_day(&self, date: &NaiveDate) -> bool {
         self.get_holiday(date).is_none()
     }
+
+    /// Adjusts `date` to a business day according to `adjustment`.
+    pub fn adjust(&self, date: NaiveDate, adjustment: DateAdjustment) -> NaiveDate {
+        let following = || {
+            let mut d = date;
+            while !self.is_buisness_day(&d) {
+                d = d.succ_opt().expect("could not increment day");
+            }
+            d
+        };
+
+        let preceding = || {
+            let mut d = date;
+            while !self.is_buisness_day(&d) {
+                d = d.pred_opt().expect("could not decrement day");
+            }
+            d
+        };
+
+        match adjustment {
+            DateAdjustment::Following => following(),
+            DateAdjustment::Preceding => preceding(),
+            DateAdjustment::ModifiedFollowing => {
+                let d = following();
+                if d.month() != date.month() {
+                    preceding()
+                } else {
+                    d
+                }
+            }
+            DateAdjustment::ModifiedPreceding => {
+                let d = preceding();
+                if d.month() != date.month() {
+                    following()
+                } else {
+                    d
+                }
+            }
+        }
+    }
+
+    /// Moves `date` by `days` business days, backwards if `days` is negative.
+    ///
+    /// If `days` is zero, `date` is adjusted to the following business day.
+    pub fn advance(&self, date: NaiveDate, days: i64) -> NaiveDate {
+        if days == 0 {
+            return self.adjust(date, DateAdjustment::Following);
+        }
+
+        let mut d = date;
+        let mut remaining = days;
+
+        while remaining > 0 {
+            d = d.succ_opt().expect("could not increment day");
+            if self.is_buisness_day(&d) {
+                remaining -= 1;
+            }
+        }
+
+        while remaining < 0 {
+            d = d.pred_opt().expect("could not decrement day");
+            if self.is_buisness_day(&d) {
+                remaining += 1;
+            }
+        }
+
+        d
+    }
+
+    /// Moves `date` by `period`, adjusting the result to a business day according to
+    /// `adjustment`.
+    ///
+    /// A period in days is a number of business days, as in `advance`. Other periods are
+    /// calendar periods, and only the resulting date is adjusted.
+    pub fn advance_by(
+        &self,
+        date: NaiveDate,
+        period: Period,
+        adjustment: DateAdjustment,
+    ) -> NaiveDate {
+        match period.unit() {
+            TimeUnit::Days => self.advance(date, period.length() as i64),
+            _ => self.adjust(date + period, adjustment),
+        }
+    }
 }
 
 impl std::fmt::Display for Calendar {
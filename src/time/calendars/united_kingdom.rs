@@ -97,7 +97,7 @@ impl CalendarInterface for UnitedKingdom {
         // Good Friday
         if *date
             == easter_monday
-                .checked_add_days(Days::new(3))
+                .checked_sub_days(Days::new(3))
                 .expect("could not subtract 3 days")
         {
             return Some("Good Friday".to_string());
@@ -132,7 +132,7 @@ impl CalendarInterface for UnitedKingdom {
         }
 
         // Millenium Celebrations
-        if *date == NaiveDate::from_ymd_opt(31, 12, 1999).unwrap() {
+        if *date == NaiveDate::from_ymd_opt(1999, 12, 31).unwrap() {
             return Some("Millenium Celebrations".to_string());
         }
 
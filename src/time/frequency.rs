@@ -0,0 +1,51 @@
+use std::fmt;
+
+/// The frequency of a recurring event, such as coupon payments or interest compounding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Frequency {
+    Annual,
+    Semiannual,
+    EveryFourthMonth,
+    Quarterly,
+    Bimonthly,
+    Monthly,
+    EveryFourthWeek,
+    Biweekly,
+    Weekly,
+    Daily,
+}
+
+impl Frequency {
+    /// The number of events per year.
+    pub fn periods_per_year(&self) -> u32 {
+        match self {
+            Frequency::Annual => 1,
+            Frequency::Semiannual => 2,
+            Frequency::EveryFourthMonth => 3,
+            Frequency::Quarterly => 4,
+            Frequency::Bimonthly => 6,
+            Frequency::Monthly => 12,
+            Frequency::EveryFourthWeek => 13,
+            Frequency::Biweekly => 26,
+            Frequency::Weekly => 52,
+            Frequency::Daily => 365,
+        }
+    }
+}
+
+impl fmt::Display for Frequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Frequency::Annual => write!(f, "Annual"),
+            Frequency::Semiannual => write!(f, "Semiannual"),
+            Frequency::EveryFourthMonth => write!(f, "Every Fourth Month"),
+            Frequency::Quarterly => write!(f, "Quarterly"),
+            Frequency::Bimonthly => write!(f, "Bimonthly"),
+            Frequency::Monthly => write!(f, "Monthly"),
+            Frequency::EveryFourthWeek => write!(f, "Every Fourth Week"),
+            Frequency::Biweekly => write!(f, "Biweekly"),
+            Frequency::Weekly => write!(f, "Weekly"),
+            Frequency::Daily => write!(f, "Daily"),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use chrono::{Datelike, Months, NaiveDate, Weekday};
+
+use crate::error::InvalidDate;
+
+/// The month codes used in IMM contract codes, from January to December.
+const MONTH_CODES: [char; 12] = ['F', 'G', 'H', 'J', 'K', 'M', 'N', 'Q', 'U', 'V', 'X', 'Z'];
+
+/// The IMM date of a month, which is its third Wednesday.
+///
+/// Returns `None` if the month is out of range.
+pub fn imm_date(year: i32, month: u32) -> Option<NaiveDate> {
+    NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Wed, 3)
+}
+
+/// Returns `true` if `date` is an IMM date.
+///
+/// # Arguments
+///
+/// * `date` - The date to check.
+/// * `main_cycle` - Whether only the IMM dates of March, June, September and December count.
+pub fn is_imm_date(date: NaiveDate, main_cycle: bool) -> bool {
+    if main_cycle && !date.month().is_multiple_of(3) {
+        return false;
+    }
+
+    imm_date(date.year(), date.month()) == Some(date)
+}
+
+/// The first IMM date strictly after `date`.
+///
+/// # Arguments
+///
+/// * `date` - The date to search from.
+/// * `main_cycle` - Whether to consider only the IMM dates of March, June, September and
+///   December.
+///
+/// # Returns
+///
+/// The next IMM date, or an error if it is out of range.
+pub fn next_imm_date(date: NaiveDate, main_cycle: bool) -> Result<NaiveDate, InvalidDate> {
+    let mut month = NaiveDate::from_ymd_opt(date.year(), date.month(), 1).ok_or(InvalidDate)?;
+
+    loop {
+        if !main_cycle || month.month().is_multiple_of(3) {
+            let imm = imm_date(month.year(), month.month()).ok_or(InvalidDate)?;
+            if imm > date {
+                return Ok(imm);
+            }
+        }
+
+        month = month
+            .checked_add_months(Months::new(1))
+            .ok_or(InvalidDate)?;
+    }
+}
+
+/// The `count` consecutive IMM dates following `date`.
+pub fn imm_dates(
+    date: NaiveDate,
+    count: usize,
+    main_cycle: bool,
+) -> Result<Vec<NaiveDate>, InvalidDate> {
+    let mut dates = Vec::with_capacity(count);
+    let mut previous = date;
+
+    for _ in 0..count {
+        previous = next_imm_date(previous, main_cycle)?;
+        dates.push(previous);
+    }
+
+    Ok(dates)
+}
+
+/// The IMM code of the contract month containing `date`, such as `H5` for March 2025.
+pub fn imm_code(date: NaiveDate) -> String {
+    let month = MONTH_CODES[date.month0() as usize];
+
+    format!("{}{}", month, date.year().rem_euclid(10))
+}
+
+/// The IMM date of the contract coded `code`, taking the first year on or after that of
+/// `reference_date` for which the date does not precede `reference_date`.
+///
+/// Returns an error if the code is not a month code followed by a single digit.
+pub fn imm_date_from_code(code: &str, reference_date: NaiveDate) -> Result<NaiveDate, InvalidDate> {
+    let mut chars = code.chars();
+    let (Some(month), Some(digit), None) = (chars.next(), chars.next(), chars.next()) else {
+        return Err(InvalidDate);
+    };
+
+    let month = MONTH_CODES
+        .iter()
+        .position(|c| *c == month.to_ascii_uppercase())
+        .ok_or(InvalidDate)? as u32
+        + 1;
+    let digit = digit.to_digit(10).ok_or(InvalidDate)? as i32;
+
+    let decade = reference_date.year() - reference_date.year().rem_euclid(10);
+    let mut year = decade + digit;
+
+    loop {
+        let date = imm_date(year, month).ok_or(InvalidDate)?;
+        if date >= reference_date {
+            return Ok(date);
+        }
+        year += 10;
+    }
+}
@@ -1,16 +1,22 @@
 mod calendar;
 mod calendars;
 mod convention;
+mod frequency;
+mod imm;
+mod period;
 mod schedule;
 
 pub use calendar::*;
 pub use convention::*;
+pub use frequency::*;
+pub use imm::*;
+pub use period::*;
 pub use schedule::*;
 
 use chrono::NaiveDate;
 
 fn get_easter_monday(year: i32, orthodox: bool) -> Option<NaiveDate> {
-    if year < 1900 || year > 2199 {
+    if !(1900..=2199).contains(&year) {
         return None;
     }
 
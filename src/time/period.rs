@@ -0,0 +1,126 @@
+use std::{
+    fmt,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use chrono::{Days, Months, NaiveDate};
+
+/// The unit a `Period` is measured in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeUnit {
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+/// A length of time, such as the three months to the start of a 3x6 FRA or the ten years to
+/// the maturity of a swap.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Period {
+    length: i32,
+    unit: TimeUnit,
+}
+
+impl Period {
+    /// Creates a period of `length` units, which is backwards in time if `length` is negative.
+    pub fn new(length: i32, unit: TimeUnit) -> Self {
+        Period { length, unit }
+    }
+
+    pub fn days(length: i32) -> Self {
+        Period::new(length, TimeUnit::Days)
+    }
+
+    pub fn weeks(length: i32) -> Self {
+        Period::new(length, TimeUnit::Weeks)
+    }
+
+    pub fn months(length: i32) -> Self {
+        Period::new(length, TimeUnit::Months)
+    }
+
+    pub fn years(length: i32) -> Self {
+        Period::new(length, TimeUnit::Years)
+    }
+
+    pub fn length(&self) -> i32 {
+        self.length
+    }
+
+    pub fn unit(&self) -> TimeUnit {
+        self.unit
+    }
+
+    /// The date this period after `date`, or `None` if it is out of range.
+    ///
+    /// Months and years are added as calendar months, so the day of the month is kept where
+    /// possible and otherwise clamped to the end of the month.
+    pub fn add_to(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let n = self.length.unsigned_abs();
+
+        match (self.unit, self.length >= 0) {
+            (TimeUnit::Days, true) => date.checked_add_days(Days::new(n as u64)),
+            (TimeUnit::Days, false) => date.checked_sub_days(Days::new(n as u64)),
+            (TimeUnit::Weeks, true) => date.checked_add_days(Days::new(7 * n as u64)),
+            (TimeUnit::Weeks, false) => date.checked_sub_days(Days::new(7 * n as u64)),
+            (TimeUnit::Months, true) => date.checked_add_months(Months::new(n)),
+            (TimeUnit::Months, false) => date.checked_sub_months(Months::new(n)),
+            (TimeUnit::Years, true) => date.checked_add_months(Months::new(12 * n)),
+            (TimeUnit::Years, false) => date.checked_sub_months(Months::new(12 * n)),
+        }
+    }
+}
+
+impl Neg for Period {
+    type Output = Period;
+
+    fn neg(self) -> Period {
+        Period::new(-self.length, self.unit)
+    }
+}
+
+impl Mul<i32> for Period {
+    type Output = Period;
+
+    fn mul(self, rhs: i32) -> Period {
+        Period::new(self.length * rhs, self.unit)
+    }
+}
+
+impl Add<Period> for NaiveDate {
+    type Output = NaiveDate;
+
+    /// Panics if the resulting date is out of range.
+    fn add(self, rhs: Period) -> NaiveDate {
+        rhs.add_to(self).expect("date out of range")
+    }
+}
+
+impl Sub<Period> for NaiveDate {
+    type Output = NaiveDate;
+
+    /// Panics if the resulting date is out of range.
+    fn sub(self, rhs: Period) -> NaiveDate {
+        self + -rhs
+    }
+}
+
+impl From<Months> for Period {
+    fn from(months: Months) -> Self {
+        Period::months(months.as_u32() as i32)
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let unit = match self.unit {
+            TimeUnit::Days => "D",
+            TimeUnit::Weeks => "W",
+            TimeUnit::Months => "M",
+            TimeUnit::Years => "Y",
+        };
+
+        write!(f, "{}{}", self.length, unit)
+    }
+}
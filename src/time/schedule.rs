@@ -1,5 +1,148 @@
-use chrono::Months;
+use chrono::{Months, NaiveDate};
 
-pub enum Schedule {
-    FixedRate(f64, Months),
+use crate::error;
+
+use super::{Calendar, DateAdjustment};
+
+/// The direction in which schedule dates are generated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DateGeneration {
+    /// Dates are generated forwards from the effective date, leaving any stub period at the end.
+    Forward,
+    /// Dates are generated backwards from the termination date, leaving any stub period at the start.
+    Backward,
+}
+
+/// A sequence of adjusted dates defining consecutive periods, such as coupon periods.
+#[derive(Clone, Debug)]
+pub struct Schedule {
+    dates: Vec<NaiveDate>,
+    calendar: Calendar,
+    adjustment: DateAdjustment,
+}
+
+impl Schedule {
+    /// Generates a schedule between two dates.
+    ///
+    /// # Arguments
+    ///
+    /// * `effective` - The start of the first period.
+    /// * `termination` - The end of the last period.
+    /// * `tenor` - The length of each regular period.
+    /// * `calendar` - The calendar used to adjust the generated dates.
+    /// * `adjustment` - How dates falling on holidays are adjusted.
+    /// * `generation` - The direction in which dates are generated.
+    ///
+    /// # Returns
+    ///
+    /// The schedule, or an error if `effective` is not before `termination`.
+    pub fn new(
+        effective: NaiveDate,
+        termination: NaiveDate,
+        tenor: Months,
+        calendar: Calendar,
+        adjustment: DateAdjustment,
+        generation: DateGeneration,
+    ) -> Result<Self, error::InvalidDate> {
+        if effective >= termination || tenor == Months::new(0) {
+            return Err(error::InvalidDate);
+        }
+
+        let mut unadjusted = Vec::new();
+        let mut periods = 0;
+
+        match generation {
+            DateGeneration::Forward => {
+                let mut date = effective;
+                while date < termination {
+                    unadjusted.push(date);
+                    periods += 1;
+                    date = effective
+                        .checked_add_months(Months::new(tenor.as_u32() * periods))
+                        .ok_or(error::InvalidDate)?;
+                }
+                unadjusted.push(termination);
+            }
+            DateGeneration::Backward => {
+                let mut date = termination;
+                while date > effective {
+                    unadjusted.push(date);
+                    periods += 1;
+                    date = termination
+                        .checked_sub_months(Months::new(tenor.as_u32() * periods))
+                        .ok_or(error::InvalidDate)?;
+                }
+                unadjusted.push(effective);
+                unadjusted.reverse();
+            }
+        }
+
+        Self::from_dates(unadjusted, calendar, adjustment)
+    }
+
+    /// Creates a schedule from explicit unadjusted dates, which are adjusted using `calendar`.
+    ///
+    /// Dates which coincide after adjustment are only included once.
+    ///
+    /// # Returns
+    ///
+    /// The schedule, or an error if there are fewer than two distinct dates after adjustment.
+    pub fn from_dates(
+        dates: Vec<NaiveDate>,
+        calendar: Calendar,
+        adjustment: DateAdjustment,
+    ) -> Result<Self, error::InvalidDate> {
+        let mut adjusted: Vec<NaiveDate> = dates
+            .into_iter()
+            .map(|d| calendar.adjust(d, adjustment))
+            .collect();
+        adjusted.sort();
+        adjusted.dedup();
+
+        if adjusted.len() < 2 {
+            return Err(error::InvalidDate);
+        }
+
+        Ok(Schedule {
+            dates: adjusted,
+            calendar,
+            adjustment,
+        })
+    }
+
+    pub fn dates(&self) -> &[NaiveDate] {
+        &self.dates
+    }
+
+    pub fn calendar(&self) -> Calendar {
+        self.calendar
+    }
+
+    pub fn adjustment(&self) -> DateAdjustment {
+        self.adjustment
+    }
+
+    /// The first date of the schedule.
+    pub fn start_date(&self) -> NaiveDate {
+        self.dates[0]
+    }
+
+    /// The last date of the schedule.
+    pub fn end_date(&self) -> NaiveDate {
+        self.dates[self.dates.len() - 1]
+    }
+
+    /// The number of periods in this schedule, which is one less than the number of dates.
+    pub fn len(&self) -> usize {
+        self.dates.len().saturating_sub(1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The `(start, end)` dates of each period.
+    pub fn periods(&self) -> impl Iterator<Item = (NaiveDate, NaiveDate)> + '_ {
+        self.dates.windows(2).map(|w| (w[0], w[1]))
+    }
 }